@@ -31,10 +31,22 @@ enum CommanderRuntimeExecutor {
             }
             let runtime = await CommandRuntime.makeDefaultAsync(options: runtimeOptions)
             try await runtimeCommand.run(using: runtime)
+            try self.failOnPromotedWarnings(logger: runtime.logger, jsonOutput: runtimeOptions.jsonOutput)
             return
         }
 
         var plainCommand = command
         try await plainCommand.run()
     }
+
+    /// JSON output already swapped the result for a `WARNINGS_AS_ERRORS` error in `outputSuccessCodable`;
+    /// human output printed the warnings as they happened, so only the verdict is left to report.
+    static func failOnPromotedWarnings(logger: Logger, jsonOutput: Bool) throws {
+        let warnings = logger.getWarnings()
+        guard logger.warningsAsErrors, !warnings.isEmpty else { return }
+        if !jsonOutput {
+            fputs("Error: \(warningsAsErrorsInfo(for: warnings).message)\n", stderr)
+        }
+        throw ExitCode.failure
+    }
 }
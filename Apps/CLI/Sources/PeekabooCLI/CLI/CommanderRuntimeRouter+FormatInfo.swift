@@ -0,0 +1,47 @@
+import Foundation
+import PeekabooCore
+
+struct ImageFormatInfoData: Codable {
+    let formats: [ImageFormatMetadata]
+}
+
+extension CommanderRuntimeRouter {
+    /// `peekaboo --image-format-info [--json]`, handled before command resolution like `--version`.
+    static func handleImageFormatInfoRequest(arguments: [String]) -> Bool {
+        guard arguments.first == "--image-format-info" else { return false }
+
+        let formats = ImageFormat.allCases.map(\.metadata)
+        let jsonOutput = arguments.contains { ["--json", "-j", "--json-output"].contains($0) } ||
+            EnvironmentConfig.jsonOutput()
+        if jsonOutput {
+            let logger = Logger.shared
+            logger.setJsonOutputMode(true)
+            outputSuccessCodable(data: ImageFormatInfoData(formats: formats), logger: logger)
+        } else {
+            print(self.renderImageFormatTable(formats))
+        }
+        return true
+    }
+
+    static func renderImageFormatTable(_ formats: [ImageFormatMetadata]) -> String {
+        let header = ["FORMAT", "EXTENSIONS", "MIME TYPE", "QUALITY", "LOSSLESS"]
+        let rows = formats.map { format in
+            [
+                format.format,
+                format.extensions.map { ".\($0)" }.joined(separator: ", "),
+                format.mime_type,
+                format.supports_quality ? "yes (PEEKABOO_QUALITY)" : "no",
+                format.lossless ? "yes" : "no",
+            ]
+        }
+        let widths = header.indices.map { column in
+            ([header] + rows).map { $0[column].count }.max() ?? 0
+        }
+        return ([header] + rows).map { row in
+            zip(row, widths)
+                .map { $0.padding(toLength: $1, withPad: " ", startingAt: 0) }
+                .joined(separator: "  ")
+                .trimmingCharacters(in: .whitespaces)
+        }.joined(separator: "\n")
+    }
+}
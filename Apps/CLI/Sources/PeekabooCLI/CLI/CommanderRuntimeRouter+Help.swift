@@ -55,6 +55,10 @@ extension CommanderRuntimeRouter {
         [
             theme.bullet(label: "--json/-j (alias: --json-output)", description: "Emit machine-readable JSON output"),
             theme.bullet(label: "--verbose/-v", description: "Enable verbose logging"),
+            theme.bullet(
+                label: "--image-format-info",
+                description: "List supported image formats, extensions, and MIME types, then exit"
+            ),
             theme.bullet(
                 label: "--log-level <level>",
                 description: "trace | verbose | debug | info | warning | error | critical"
@@ -67,6 +71,10 @@ extension CommanderRuntimeRouter {
                 label: "--bridge-socket <path>",
                 description: "Override the Peekaboo Bridge socket path"
             ),
+            theme.bullet(
+                label: "--warnings-as-errors",
+                description: "Fail when a command succeeds with warnings (listed under `warnings` in JSON)"
+            ),
             theme.bullet(
                 label: "--input-strategy <mode>",
                 description: "Override UI input strategy: actionFirst | synthFirst | actionOnly | synthOnly"
@@ -83,6 +91,52 @@ extension CommanderRuntimeRouter {
         return lines.joined(separator: "\n")
     }
 
+    /// Every flag that bounds a wait, so a `TIMEOUT` failure points at the knob to turn.
+    static func timeoutFlagSummaries(theme: HelpTheme) -> [String] {
+        [
+            theme.bullet(
+                label: "image --capture-timeout-ms <ms>",
+                description: "Per-capture limit before the capture is abandoned (default 5000)"
+            ),
+            theme.bullet(
+                label: "image --focus-timeout-ms <ms>",
+                description: "How long --window-state wait-visible waits for a window (default 5000)"
+            ),
+            theme.bullet(
+                label: "image --monitor-hotplug-wait-ms <ms>",
+                description: "How long a screen capture waits for a just-connected display"
+            ),
+            theme.bullet(
+                label: "app launch --launch-timeout-ms <ms>",
+                description: "How long to wait for the app to finish launching (default 10000)"
+            ),
+            theme.bullet(
+                label: "see --timeout-seconds <s>",
+                description: "Overall limit (default 20, or 60 with --analyze)"
+            ),
+            theme.bullet(
+                label: "dialog --timeout-seconds <s>",
+                description: "Limit for handling or listing a dialog"
+            ),
+            theme.bullet(
+                label: "--focus-timeout-seconds <s>",
+                description: "Limit for focusing the target before interacting"
+            )
+        ]
+    }
+
+    static func renderTimeoutsSection(theme: HelpTheme) -> String {
+        var lines: [String] = []
+        lines.append(theme.heading("Timeouts"))
+        for entry in self.timeoutFlagSummaries(theme: theme) {
+            lines.append("  \(entry)")
+        }
+        lines.append(
+            "  A timeout exits with status 30; with --json the error has code TIMEOUT plus operation and timeout_ms."
+        )
+        return lines.joined(separator: "\n")
+    }
+
     static func renderCommandList(
         for commands: [CommanderCommandDescriptor],
         theme: HelpTheme,
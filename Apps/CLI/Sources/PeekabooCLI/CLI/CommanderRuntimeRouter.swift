@@ -19,6 +19,9 @@ enum CommanderRuntimeRouter {
         if Self.handleVersionRequest(arguments: trimmedArgs) {
             throw ExitCode.success
         }
+        if Self.handleImageFormatInfoRequest(arguments: trimmedArgs) {
+            throw ExitCode.success
+        }
         if try Self.handleBareInvocation(arguments: trimmedArgs, descriptors: descriptors) {
             throw ExitCode.success
         }
@@ -217,6 +220,8 @@ enum CommanderRuntimeRouter {
 
         print(self.renderGlobalFlagsSection(theme: theme))
         print("")
+        print(self.renderTimeoutsSection(theme: theme))
+        print("")
         print(theme.dim("Use `peekaboo help <command>` or `peekaboo <command> --help` for detailed options."))
     }
 
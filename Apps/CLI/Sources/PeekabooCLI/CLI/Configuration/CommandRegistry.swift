@@ -33,6 +33,7 @@ enum CommandRegistry {
     @MainActor
     static let entries: [CommandRegistryEntry] = [
         .init(type: ImageCommand.self, category: .core),
+        .init(type: DiffCommand.self, category: .vision),
         .init(type: CaptureCommand.self, category: .core),
         .init(type: BridgeCommand.self, category: .core),
         .init(type: DaemonCommand.self, category: .core),
@@ -53,9 +54,14 @@ enum CommandRegistry {
         .init(type: SwipeCommand.self, category: .interaction),
         .init(type: DragCommand.self, category: .interaction),
         .init(type: MoveCommand.self, category: .interaction),
+        .init(type: CursorPositionCommand.self, category: .interaction),
         .init(type: RunCommand.self, category: .core),
         .init(type: SleepCommand.self, category: .core),
+        .init(type: WaitForFileCommand.self, category: .core),
+        .init(type: VerifyChecksumCommand.self, category: .core),
+        .init(type: HealthCheckCommand.self, category: .core),
         .init(type: CleanCommand.self, category: .core),
+        .init(type: CacheCommand.self, category: .core),
         .init(type: WindowCommand.self, category: .system),
         .init(type: MenuCommand.self, category: .system),
         .init(type: MenuBarCommand.self, category: .system),
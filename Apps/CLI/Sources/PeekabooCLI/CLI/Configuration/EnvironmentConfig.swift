@@ -0,0 +1,92 @@
+import Foundation
+import PeekabooCore
+
+/// Environment variables that stand in for CLI flags in containers and CI.
+///
+/// Explicit flags always win; a variable only applies when its flag is absent. Blank values count as unset.
+enum EnvironmentConfig {
+    struct Variable: Equatable {
+        let name: String
+        /// The flag the variable stands in for; nil when no flag sets the same value.
+        let flag: String?
+        let description: String
+    }
+
+    static let outputPathName = "PEEKABOO_OUTPUT_PATH"
+    static let formatName = "PEEKABOO_FORMAT"
+    static let qualityName = "PEEKABOO_QUALITY"
+    static let jsonOutputName = "PEEKABOO_JSON_OUTPUT"
+    static let profileName = "PEEKABOO_PROFILE"
+
+    static let supported: [Variable] = [
+        Variable(
+            name: outputPathName,
+            flag: "--path",
+            description: "File or directory for `peekaboo image` output when --path is omitted"
+        ),
+        Variable(
+            name: formatName,
+            flag: "--format",
+            description: "Image format (png or jpg) when neither --format nor the path extension sets one"
+        ),
+        Variable(
+            name: qualityName,
+            flag: nil,
+            description: "JPEG quality from 1 to 100 (default 92); ignored for PNG"
+        ),
+        Variable(
+            name: jsonOutputName,
+            flag: "--json",
+            description: "Set to 1, true, or yes to emit JSON from every command"
+        ),
+        Variable(
+            name: profileName,
+            flag: "--profile",
+            description: "Name of the ~/.peekaboo/profiles/<name>.json defaults `peekaboo image` loads"
+        ),
+    ]
+
+    static func outputPath(environment: [String: String] = ProcessInfo.processInfo.environment) -> String? {
+        self.value(self.outputPathName, in: environment)
+    }
+
+    static func imageFormat(
+        environment: [String: String] = ProcessInfo.processInfo.environment
+    ) throws -> PeekabooCore.ImageFormat? {
+        guard let raw = self.value(self.formatName, in: environment) else { return nil }
+        switch raw.lowercased() {
+        case "png":
+            return .png
+        case "jpg", "jpeg":
+            return .jpg
+        default:
+            throw ValidationError("\(self.formatName) must be png or jpg (got '\(raw)')")
+        }
+    }
+
+    /// JPEG quality scaled to `0...1` for ImageIO/AppKit encoders.
+    static func jpegQuality(environment: [String: String] = ProcessInfo.processInfo.environment) throws -> Double? {
+        guard let raw = self.value(self.qualityName, in: environment) else { return nil }
+        guard let quality = Int(raw), (1...100).contains(quality) else {
+            throw ValidationError("\(self.qualityName) must be an integer from 1 to 100 (got '\(raw)')")
+        }
+        return Double(quality) / 100
+    }
+
+    static func jsonOutput(environment: [String: String] = ProcessInfo.processInfo.environment) -> Bool {
+        guard let raw = self.value(self.jsonOutputName, in: environment) else { return false }
+        return ["1", "true", "yes"].contains(raw.lowercased())
+    }
+
+    static func profile(environment: [String: String] = ProcessInfo.processInfo.environment) -> String? {
+        self.value(self.profileName, in: environment)
+    }
+
+    private static func value(_ name: String, in environment: [String: String]) -> String? {
+        guard let value = environment[name]?.trimmingCharacters(in: .whitespacesAndNewlines),
+              !value.isEmpty else {
+            return nil
+        }
+        return value
+    }
+}
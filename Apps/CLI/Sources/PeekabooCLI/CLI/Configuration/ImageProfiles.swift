@@ -0,0 +1,167 @@
+import Commander
+import Foundation
+import PeekabooCore
+
+/// Named sets of `peekaboo image` defaults, stored as `~/.peekaboo/profiles/<name>.json` and picked with
+/// `--profile` or `PEEKABOO_PROFILE`.
+///
+/// A profile maps long option names to values: strings or numbers for options, `true` for flags, and arrays for
+/// repeatable options, e.g. `{"format": "jpg", "window-padding": 8, "retina": true}`. Values merge as explicit
+/// flags, then the profile, then `defaults` in `config.json`, then built-in defaults.
+@MainActor
+enum ImageProfiles {
+    static var directory: String {
+        "\(ConfigurationManager.baseDir)/profiles"
+    }
+
+    static func path(for name: String, in directory: String = Self.directory) -> String {
+        "\(directory)/\(name).json"
+    }
+
+    /// Profile names in `directory`, sorted; a missing directory simply has none.
+    static func names(in directory: String = Self.directory) -> [String] {
+        let files = (try? FileManager.default.contentsOfDirectory(atPath: directory)) ?? []
+        return files.filter { $0.hasSuffix(".json") }.map { String($0.dropLast(".json".count)) }.sorted()
+    }
+
+    /// `values` with the selected profile and the global config defaults filled in underneath them.
+    static func resolve(
+        _ values: CommanderBindableValues,
+        environment: [String: String] = ProcessInfo.processInfo.environment,
+        directory: String = Self.directory,
+        globalDefaults: Configuration.DefaultsConfig? = ConfigurationManager.shared.getConfiguration()?.defaults
+    ) throws -> CommanderBindableValues {
+        var layers: [CommanderBindableValues] = []
+        if let name = values.singleOption("profile") ?? EnvironmentConfig.profile(environment: environment) {
+            layers.append(try self.load(name, from: directory))
+        }
+        layers.append(self.globalDefaultsLayer(globalDefaults, environment: environment))
+        return self.merging(values, over: layers)
+    }
+
+    static func load(_ name: String, from directory: String = Self.directory) throws -> CommanderBindableValues {
+        guard !name.isEmpty, !name.contains("/"), !name.hasPrefix(".") else {
+            throw ValidationError("Invalid profile name '\(name)'; use a file name without '/' or a leading '.'")
+        }
+        let path = self.path(for: name, in: directory)
+        guard let contents = FileManager.default.contents(atPath: path) else {
+            throw ValidationError("Profile '\(name)' not found at \(path); run `peekaboo list profiles`")
+        }
+        return try self.layer(from: contents, profile: name)
+    }
+
+    /// Parses a profile's JSON (comments allowed, like `config.json`) into option and flag values.
+    static func layer(
+        from contents: Data,
+        profile name: String,
+        signature: CommandSignature = ImageCommand.commanderSignature()
+    ) throws -> CommanderBindableValues {
+        let text = ConfigurationManager.shared.stripJSONComments(from: String(decoding: contents, as: UTF8.self))
+        guard let object = try? JSONSerialization.jsonObject(with: Data(text.utf8)) as? [String: Any] else {
+            throw ValidationError("Profile '\(name)' must be a JSON object of option names to values")
+        }
+
+        var optionLabels: [String: String] = [:]
+        for option in signature.options {
+            for case let .long(long) in option.names {
+                optionLabels[long] = option.label
+            }
+        }
+        var flagLabels: [String: String] = [:]
+        for flag in signature.flags {
+            for case let .long(long) in flag.names {
+                flagLabels[long] = flag.label
+            }
+        }
+
+        var options: [String: [String]] = [:]
+        var flags: Set<String> = []
+        for (key, value) in object {
+            let long = key.hasPrefix("--") ? String(key.dropFirst(2)) : key
+            guard long != "profile" else {
+                throw ValidationError("Profile '\(name)' cannot select another profile")
+            }
+            if let label = flagLabels[long] {
+                guard let enabled = value as? Bool, Self.isBoolean(value) else {
+                    throw ValidationError("Profile '\(name)': '\(long)' is a flag and takes true or false")
+                }
+                if enabled {
+                    flags.insert(label)
+                }
+            } else if let label = optionLabels[long] {
+                let scalars = (value as? [Any]) ?? [value]
+                options[label] = try scalars.map { scalar in
+                    guard let string = Self.scalarString(scalar) else {
+                        throw ValidationError("Profile '\(name)': '\(long)' takes a string or number")
+                    }
+                    return string
+                }
+            } else {
+                throw ValidationError("Profile '\(name)': unknown option '\(long)'")
+            }
+        }
+        return CommanderBindableValues(positional: [], options: options, flags: flags)
+    }
+
+    /// `defaults` from `config.json`. Save path and format yield to their environment variables, which sit
+    /// above the config file.
+    static func globalDefaultsLayer(
+        _ defaults: Configuration.DefaultsConfig?,
+        environment: [String: String]
+    ) -> CommanderBindableValues {
+        guard let defaults else {
+            return CommanderBindableValues(positional: [], options: [:], flags: [])
+        }
+        let environmentPath = EnvironmentConfig.outputPath(environment: environment)
+        var options: [String: [String]] = [:]
+        if let savePath = defaults.savePath, environmentPath == nil {
+            options["path"] = [savePath]
+        }
+        if let imageFormat = defaults.imageFormat, environmentPath == nil,
+           environment[EnvironmentConfig.formatName]?.trimmingCharacters(in: .whitespaces).isEmpty ?? true {
+            options["format"] = [imageFormat]
+        }
+        if let captureMode = defaults.captureMode {
+            options["mode"] = [captureMode]
+        }
+        if let captureFocus = defaults.captureFocus {
+            options["captureFocus"] = [captureFocus]
+        }
+        return CommanderBindableValues(positional: [], options: options, flags: [])
+    }
+
+    /// Fills options missing from `explicit` from each layer in turn, highest priority first; flags add up.
+    static func merging(
+        _ explicit: CommanderBindableValues,
+        over layers: [CommanderBindableValues]
+    ) -> CommanderBindableValues {
+        var options = explicit.options
+        var flags = explicit.flags
+        for layer in layers {
+            // A path from a higher layer picks its format from the extension; a lower --format would conflict.
+            let pathIsSet = options["path"] != nil
+            for (label, values) in layer.options where options[label] == nil {
+                if label == "format", pathIsSet {
+                    continue
+                }
+                options[label] = values
+            }
+            flags.formUnion(layer.flags)
+        }
+        return CommanderBindableValues(positional: explicit.positional, options: options, flags: flags)
+    }
+
+    private static func isBoolean(_ value: Any) -> Bool {
+        CFGetTypeID(value as CFTypeRef) == CFBooleanGetTypeID()
+    }
+
+    private static func scalarString(_ value: Any) -> String? {
+        if let string = value as? String {
+            return string
+        }
+        if let number = value as? NSNumber, !self.isBoolean(value) {
+            return number.stringValue
+        }
+        return nil
+    }
+}
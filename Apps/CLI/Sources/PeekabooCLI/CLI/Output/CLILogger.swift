@@ -36,7 +36,10 @@ final class Logger: @unchecked Sendable {
     static let shared = Logger()
     private nonisolated(unsafe) var debugLogs: [String] = []
     private nonisolated(unsafe) var isJsonOutputMode = false
+    private nonisolated(unsafe) var jsonOutputFormat: JSONOutputFormat = .json
     private nonisolated(unsafe) var verboseMode = false
+    private nonisolated(unsafe) var warnings: [PeekabooWarning] = []
+    private nonisolated(unsafe) var promotesWarnings = false
     private let defaultMinimumLogLevel: LogLevel
     private nonisolated(unsafe) var minimumLogLevel: LogLevel
     private let queue = DispatchQueue(label: "logger.queue", attributes: .concurrent)
@@ -66,6 +69,19 @@ final class Logger: @unchecked Sendable {
         }
     }
 
+    func setOutputFormat(_ format: JSONOutputFormat) {
+        self.queue.sync(flags: .barrier) {
+            self.jsonOutputFormat = format
+        }
+    }
+
+    /// How `outputJSON`/`outputSuccessCodable` encode the response envelope.
+    var outputFormat: JSONOutputFormat {
+        self.queue.sync {
+            self.jsonOutputFormat
+        }
+    }
+
     func setVerboseMode(_ enabled: Bool) {
         self.queue.sync(flags: .barrier) {
             self.verboseMode = enabled
@@ -141,6 +157,14 @@ final class Logger: @unchecked Sendable {
         self.log(.warning, message, category: category, metadata: metadata)
     }
 
+    /// Logs the warning and records it for the `warnings` field of the JSON response.
+    func warn(_ code: WarningCode, _ message: String, category: String? = nil) {
+        self.queue.sync(flags: .barrier) {
+            self.warnings.append(PeekabooWarning(code, message))
+        }
+        self.log(.warning, message, category: category, metadata: ["code": code.rawValue])
+    }
+
     func error(_ message: String, category: String? = nil, metadata: [String: Any]? = nil) {
         self.log(.error, message, category: category, metadata: metadata)
     }
@@ -229,6 +253,31 @@ final class Logger: @unchecked Sendable {
         }
     }
 
+    func getWarnings() -> [PeekabooWarning] {
+        self.queue.sync {
+            self.warnings
+        }
+    }
+
+    func clearWarnings() {
+        self.queue.sync(flags: .barrier) {
+            self.warnings.removeAll()
+        }
+    }
+
+    func setWarningsAsErrors(_ enabled: Bool) {
+        self.queue.sync(flags: .barrier) {
+            self.promotesWarnings = enabled
+        }
+    }
+
+    /// Set by `--warnings-as-errors`: recorded warnings fail the command instead of riding along with the result.
+    var warningsAsErrors: Bool {
+        self.queue.sync {
+            self.promotesWarnings
+        }
+    }
+
     /// For testing - ensures all pending operations are complete
     func flush() {
         // For testing - ensures all pending operations are complete
@@ -0,0 +1,22 @@
+import Foundation
+
+/// RFC 4180 CSV for `--export-csv`: CRLF line endings, fields quoted only when they need it.
+enum CSVWriter {
+    /// Quotes `value` when it contains a comma, quote, or line break, doubling embedded quotes.
+    static func field(_ value: String) -> String {
+        guard value.contains(where: { $0 == "," || $0 == "\"" || $0.isNewline }) else {
+            return value
+        }
+        return "\"" + value.replacingOccurrences(of: "\"", with: "\"\"") + "\""
+    }
+
+    static func row(_ fields: [String]) -> String {
+        fields.map(Self.field).joined(separator: ",")
+    }
+
+    /// Every record, including the last, ends with CRLF; `header: nil` omits the header record.
+    static func document(header: [String]?, rows: [[String]]) -> String {
+        let records = (header.map { [$0] } ?? []) + rows
+        return records.map { Self.row($0) + "\r\n" }.joined()
+    }
+}
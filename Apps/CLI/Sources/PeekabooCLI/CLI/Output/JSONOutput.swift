@@ -26,6 +26,7 @@ struct JSONResponse: Codable {
     let data: Empty? // Added for test compatibility
     let messages: [String]?
     let debug_logs: [String]
+    let warnings: [PeekabooWarning]?
     let error: ErrorInfo?
 
     init(
@@ -33,12 +34,14 @@ struct JSONResponse: Codable {
         data: Empty? = nil, // Added for test compatibility
         messages: [String]? = nil,
         debugLogs: [String] = [],
+        warnings: [PeekabooWarning] = [],
         error: ErrorInfo? = nil
     ) {
         self.success = success
         self.data = data
         self.messages = messages
         self.debug_logs = debugLogs
+        self.warnings = warnings.isEmpty ? nil : warnings
         self.error = error
     }
 }
@@ -46,16 +49,66 @@ struct JSONResponse: Codable {
 /// Error information structure for JSON responses.
 ///
 /// Contains error details including message, standardized error code,
-/// and optional additional context.
+/// optional additional context, and the operation chain recorded by `withErrorContext`.
+/// `PeekabooError`s also carry their case name as `variant` and their associated values as `fields`;
+/// `multipleErrors` lists each contained failure under `errors`, and `timedOut` adds `operation` and `timeout_ms`.
 struct ErrorInfo: Codable {
     let message: String
     let code: String
     let details: String?
+    let context: [String]?
+    let variant: String?
+    let fields: [String: String]?
+    let errors: [ErrorInfo]?
+    let operation: String?
+    let timeout_ms: Int?
 
-    init(message: String, code: ErrorCode, details: String? = nil) {
+    init(
+        message: String,
+        code: ErrorCode,
+        details: String? = nil,
+        context: [String]? = nil,
+        variant: String? = nil,
+        fields: [String: String]? = nil,
+        errors: [ErrorInfo]? = nil,
+        operation: String? = nil,
+        timeoutMs: Int? = nil
+    ) {
         self.message = message
         self.code = code.rawValue
         self.details = details
+        self.context = context
+        self.variant = variant
+        self.fields = fields
+        self.errors = errors
+        self.operation = operation
+        self.timeout_ms = timeoutMs
+    }
+
+    init(
+        message: String,
+        code: ErrorCode,
+        details: String? = nil,
+        context: [String]? = nil,
+        peekabooError: PeekabooError,
+        errors: [ErrorInfo]? = nil
+    ) {
+        let fields = peekabooError.context
+        var timeout: (operation: String, timeoutMs: Int)?
+        if case let .timedOut(operation, timeoutMs) = peekabooError {
+            timeout = (operation, timeoutMs)
+        }
+        self.init(
+            message: message,
+            code: code,
+            details: details,
+            context: context,
+            variant: peekabooError.variant,
+            fields: fields.isEmpty ? nil : fields,
+            errors: errors,
+            operation: timeout?.operation,
+            timeoutMs: timeout?.timeoutMs
+        )
     }
 }
 
@@ -100,50 +153,32 @@ enum ErrorCode: String, Codable {
     case INTERACTION_FAILED
     case TIMEOUT
     case INVALID_INPUT
+    case MULTIPLE_ERRORS
+    case WARNINGS_AS_ERRORS
 }
 
 func outputJSON(_ response: JSONResponse, logger: Logger) {
-    do {
-        let encoder = JSONEncoder()
-        encoder.outputFormatting = .prettyPrinted
-        let data = try encoder.encode(response)
-        if let jsonString = String(data: data, encoding: .utf8) {
-            print(jsonString)
-        }
-    } catch {
-        logger.error("Failed to encode JSON response: \(error)")
-        // Fallback to simple error JSON
-        print("""
-        {
-          "success": false,
-          "error": {
-            "message": "Failed to encode JSON response",
-            "code": "INTERNAL_SWIFT_ERROR"
-          },
-          "debug_logs": []
-        }
-        """)
-    }
+    outputJSONCodable(response, logger: logger)
 }
 
 func outputSuccessCodable(data: some Codable, messages: [String]? = nil, logger: Logger) {
+    let warnings = logger.getWarnings()
+    if logger.warningsAsErrors, !warnings.isEmpty {
+        outputError(warningsAsErrorsInfo(for: warnings), logger: logger)
+        return
+    }
     let debugLogs = logger.getDebugLogs()
     let response = CodableJSONResponse(
-        success: true, data: data, messages: messages, debug_logs: debugLogs
+        success: true, data: data, messages: messages, debug_logs: debugLogs, warnings: warnings
     )
     outputJSONCodable(response, logger: logger)
 }
 
 func outputJSONCodable(_ response: some Encodable, logger: Logger) {
     do {
-        let encoder = JSONEncoder()
-        encoder.outputFormatting = .prettyPrinted
         // Note: JSONEncoder by default omits nil values from optionals
         // This is standard behavior and generally desirable for cleaner output
-        let data = try encoder.encode(response)
-        if let jsonString = String(data: data, encoding: .utf8) {
-            print(jsonString)
-        }
+        try logger.outputFormat.write(response)
     } catch {
         logger.error("Failed to encode JSON response: \(error)")
         // Fallback to simple error JSON
@@ -169,12 +204,37 @@ struct CodableJSONResponse<T: Codable>: Codable {
     let data: T
     let messages: [String]?
     let debug_logs: [String]
+    /// Degraded-but-successful notes such as a fallback capture path; omitted when there are none.
+    let warnings: [PeekabooWarning]?
+
+    init(success: Bool, data: T, messages: [String]?, debug_logs: [String], warnings: [PeekabooWarning] = []) {
+        self.success = success
+        self.data = data
+        self.messages = messages
+        self.debug_logs = debug_logs
+        self.warnings = warnings.isEmpty ? nil : warnings
+    }
 }
 
-func outputError(message: String, code: ErrorCode, details: String? = nil, logger: Logger) {
-    let error = ErrorInfo(message: message, code: code, details: details)
-    let debugLogs = logger.getDebugLogs()
-    outputJSON(JSONResponse(success: false, messages: nil, debugLogs: debugLogs, error: error), logger: logger)
+func outputError(
+    message: String,
+    code: ErrorCode,
+    details: String? = nil,
+    context: [String]? = nil,
+    logger: Logger
+) {
+    outputError(ErrorInfo(message: message, code: code, details: details, context: context), logger: logger)
+}
+
+func outputError(_ error: ErrorInfo, logger: Logger) {
+    let response = JSONResponse(
+        success: false,
+        messages: nil,
+        debugLogs: logger.getDebugLogs(),
+        warnings: logger.getWarnings(),
+        error: error
+    )
+    outputJSON(response, logger: logger)
 }
 
 func outputFailure(message: String, logger: Logger, error: (any Error)? = nil) {
@@ -0,0 +1,193 @@
+import Darwin
+import Foundation
+
+/// Encoding for the standard JSON envelope, chosen with the global `--output-format`.
+enum JSONOutputFormat: String, CaseIterable, Sendable {
+    /// Pretty-printed JSON, what `--json` has always produced.
+    case json
+    /// The same document on a single line.
+    case jsonCompact = "json-compact"
+    /// The same document as MessagePack, written to stdout as raw bytes.
+    case msgpack
+    /// Stdout as a stream of single-line records: a streaming command's events and `app launch` progress, then the
+    /// envelope, for consumers reading line by line.
+    case ndjson
+
+    /// The encoded response; text formats leave the trailing newline to the writer.
+    func encode(_ value: some Encodable) throws -> Data {
+        let encoder = JSONEncoder()
+        switch self {
+        case .json:
+            encoder.outputFormatting = .prettyPrinted
+            return try encoder.encode(value)
+        case .jsonCompact, .ndjson:
+            return try encoder.encode(value)
+        case .msgpack:
+            // Going through the JSON tree keeps key names, omitted nils, and date encoding identical to `--json`.
+            let object = try JSONSerialization.jsonObject(with: encoder.encode(value), options: .fragmentsAllowed)
+            return try MessagePack.encode(object)
+        }
+    }
+
+    /// Writes one response to stdout.
+    func write(_ value: some Encodable) throws {
+        let data = try self.encode(value)
+        guard self == .msgpack else {
+            print(String(decoding: data, as: UTF8.self))
+            return
+        }
+        // Earlier `print` output sits in the stdio buffer; flush it so the binary payload lands after it.
+        fflush(stdout)
+        FileHandle.standardOutput.write(data)
+    }
+
+    /// One stream record: a single sorted-key JSON line for every text format, or one MessagePack value.
+    func encodeRecord(_ value: some Encodable) throws -> Data {
+        let encoder = JSONEncoder()
+        encoder.outputFormatting = [.sortedKeys, .withoutEscapingSlashes]
+        let json = try encoder.encode(value)
+        guard self == .msgpack else { return json + Data("\n".utf8) }
+        return try MessagePack.encode(JSONSerialization.jsonObject(with: json, options: .fragmentsAllowed))
+    }
+
+    /// Writes one stream record right away, so piped consumers see events as they happen.
+    func writeRecord(_ value: some Encodable, to handle: FileHandle = .standardOutput) {
+        guard let data = try? self.encodeRecord(value) else { return }
+        fflush(stdout)
+        handle.write(data)
+    }
+
+    /// Whether progress records share stdout with the result. Only `ndjson` promises a record stream there; the other
+    /// formats keep stdout to one document and report progress on stderr.
+    var streamsProgressToStdout: Bool {
+        self == .ndjson
+    }
+}
+
+/// MessagePack serializer for the Foundation values `JSONSerialization` produces.
+enum MessagePack {
+    static func encode(_ object: Any) throws -> Data {
+        var data = Data()
+        try self.append(object, to: &data)
+        return data
+    }
+
+    private static func append(_ object: Any, to data: inout Data) throws {
+        switch object {
+        case is NSNull:
+            data.append(0xC0)
+        case let number as NSNumber:
+            self.append(number, to: &data)
+        case let string as String:
+            self.append(string, to: &data)
+        case let array as [Any]:
+            self.appendHeader(count: array.count, fix: 0x90, fixLimit: 16, wide16: 0xDC, wide32: 0xDD, to: &data)
+            for element in array {
+                try self.append(element, to: &data)
+            }
+        case let dictionary as [String: Any]:
+            self.appendHeader(count: dictionary.count, fix: 0x80, fixLimit: 16, wide16: 0xDE, wide32: 0xDF, to: &data)
+            // Sorted so identical responses encode to identical bytes.
+            for key in dictionary.keys.sorted() {
+                self.append(key, to: &data)
+                try self.append(dictionary[key] as Any, to: &data)
+            }
+        default:
+            throw EncodingError.invalidValue(
+                object,
+                EncodingError.Context(codingPath: [], debugDescription: "MessagePack cannot encode \(type(of: object))")
+            )
+        }
+    }
+
+    private static func append(_ number: NSNumber, to data: inout Data) {
+        if CFGetTypeID(number) == CFBooleanGetTypeID() {
+            data.append(number.boolValue ? 0xC3 : 0xC2)
+        } else if CFNumberIsFloatType(number) {
+            data.append(0xCB)
+            self.appendBigEndian(number.doubleValue.bitPattern, to: &data)
+        } else if number.int64Value < 0 {
+            self.appendSigned(number.int64Value, to: &data)
+        } else {
+            self.appendUnsigned(number.uint64Value, to: &data)
+        }
+    }
+
+    private static func appendSigned(_ value: Int64, to data: inout Data) {
+        switch value {
+        case -32 ..< 0:
+            data.append(UInt8(bitPattern: Int8(value)))
+        case Int64(Int8.min) ..< -32:
+            data.append(0xD0)
+            data.append(UInt8(bitPattern: Int8(value)))
+        case Int64(Int16.min) ..< Int64(Int8.min):
+            data.append(0xD1)
+            self.appendBigEndian(UInt16(bitPattern: Int16(value)), to: &data)
+        case Int64(Int32.min) ..< Int64(Int16.min):
+            data.append(0xD2)
+            self.appendBigEndian(UInt32(bitPattern: Int32(value)), to: &data)
+        default:
+            data.append(0xD3)
+            self.appendBigEndian(UInt64(bitPattern: value), to: &data)
+        }
+    }
+
+    private static func appendUnsigned(_ value: UInt64, to data: inout Data) {
+        switch value {
+        case 0 ..< 0x80:
+            data.append(UInt8(value))
+        case 0x80 ... UInt64(UInt8.max):
+            data.append(0xCC)
+            data.append(UInt8(value))
+        case 0x100 ... UInt64(UInt16.max):
+            data.append(0xCD)
+            self.appendBigEndian(UInt16(value), to: &data)
+        case 0x1_0000 ... UInt64(UInt32.max):
+            data.append(0xCE)
+            self.appendBigEndian(UInt32(value), to: &data)
+        default:
+            data.append(0xCF)
+            self.appendBigEndian(value, to: &data)
+        }
+    }
+
+    private static func append(_ string: String, to data: inout Data) {
+        let bytes = Data(string.utf8)
+        if bytes.count < 32 {
+            data.append(0xA0 | UInt8(bytes.count))
+        } else if bytes.count <= Int(UInt8.max) {
+            data.append(0xD9)
+            data.append(UInt8(bytes.count))
+        } else if bytes.count <= Int(UInt16.max) {
+            data.append(0xDA)
+            self.appendBigEndian(UInt16(bytes.count), to: &data)
+        } else {
+            data.append(0xDB)
+            self.appendBigEndian(UInt32(bytes.count), to: &data)
+        }
+        data.append(bytes)
+    }
+
+    private static func appendHeader(
+        count: Int,
+        fix: UInt8,
+        fixLimit: Int,
+        wide16: UInt8,
+        wide32: UInt8,
+        to data: inout Data
+    ) {
+        if count < fixLimit {
+            data.append(fix | UInt8(count))
+        } else if count <= Int(UInt16.max) {
+            data.append(wide16)
+            self.appendBigEndian(UInt16(count), to: &data)
+        } else {
+            data.append(wide32)
+            self.appendBigEndian(UInt32(count), to: &data)
+        }
+    }
+
+    private static func appendBigEndian(_ value: some FixedWidthInteger, to data: inout Data) {
+        withUnsafeBytes(of: value.bigEndian) { data.append(contentsOf: $0) }
+    }
+}
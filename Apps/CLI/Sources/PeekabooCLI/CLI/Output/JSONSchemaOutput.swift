@@ -0,0 +1,73 @@
+import Foundation
+
+/// The subset of JSON Schema (draft-07) that `--json-schema` needs to describe CLI payloads.
+indirect enum JSONSchema: Equatable {
+    case string(allowed: [String]? = nil)
+    case integer
+    case number
+    case boolean
+    case array(JSONSchema)
+    /// Objects reject undeclared keys, so a new field shows up as a schema change for typed clients.
+    case object([String: JSONSchema], required: [String])
+    /// Points at an entry in the document's `definitions`, for recursive types.
+    case ref(String)
+
+    var jsonObject: [String: Any] {
+        switch self {
+        case let .string(allowed):
+            var object: [String: Any] = ["type": "string"]
+            if let allowed {
+                object["enum"] = allowed
+            }
+            return object
+        case .integer:
+            return ["type": "integer"]
+        case .number:
+            return ["type": "number"]
+        case .boolean:
+            return ["type": "boolean"]
+        case let .array(items):
+            return ["type": "array", "items": items.jsonObject]
+        case let .object(properties, required):
+            return [
+                "type": "object",
+                "properties": properties.mapValues(\.jsonObject),
+                "required": required,
+                "additionalProperties": false,
+            ]
+        case let .ref(name):
+            return ["$ref": "#/definitions/\(name)"]
+        }
+    }
+
+    /// A draft-07 document for the standard `{ success, data, messages, debug_logs }` JSON envelope.
+    static func responseDocument(
+        title: String,
+        data: JSONSchema,
+        definitions: [String: JSONSchema] = [:]
+    ) -> [String: Any] {
+        var document = JSONSchema.object(
+            [
+                "success": .boolean,
+                "data": data,
+                "messages": .array(.string()),
+                "debug_logs": .array(.string()),
+            ],
+            required: ["success", "data", "debug_logs"]
+        ).jsonObject
+        document["$schema"] = "http://json-schema.org/draft-07/schema#"
+        document["title"] = title
+        if !definitions.isEmpty {
+            document["definitions"] = definitions.mapValues(\.jsonObject)
+        }
+        return document
+    }
+
+    static func render(_ document: [String: Any]) -> String {
+        guard let data = try? JSONSerialization.data(
+            withJSONObject: document,
+            options: [.prettyPrinted, .sortedKeys, .withoutEscapingSlashes]
+        ) else { return "{}" }
+        return String(decoding: data, as: UTF8.self)
+    }
+}
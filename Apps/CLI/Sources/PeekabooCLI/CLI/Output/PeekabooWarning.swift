@@ -0,0 +1,46 @@
+import Foundation
+
+/// Codes for operations that succeeded with a degraded result.
+///
+/// Listed under `warnings` in the JSON envelope so automation can react without scraping `debug_logs`;
+/// `--warnings-as-errors` turns any of them into a failure.
+enum WarningCode: String, Codable, CaseIterable, Sendable {
+    /// A secondary capture or detection path produced the result.
+    case FALLBACK_TOOL_USED
+    /// Part of the captured window lies outside every display, so the image is cut off.
+    case WINDOW_PARTIALLY_OFFSCREEN
+    /// `--include-cursor-position` could not read the pointer location.
+    case CURSOR_NOT_CAPTURED
+    /// The target could not be confirmed frontmost before acting on it.
+    case FOCUS_NOT_CONFIRMED
+    /// Window or process details could not be refreshed, so the reported values may be out of date.
+    case STALE_PROCESS_INFO
+    /// One window of a multi-window capture failed and was left out.
+    case WINDOW_CAPTURE_SKIPPED
+    /// A multi-window capture failed after saving some windows; the saved files are kept and listed.
+    case PARTIAL_CAPTURE_SAVED
+    /// `--restore-layout` could not put every cached window back because some have closed.
+    case LAYOUT_NOT_FULLY_RESTORED
+    /// An option was accepted but cannot affect this output, e.g. an alpha setting on a JPEG.
+    case OPTION_HAS_NO_EFFECT
+}
+
+/// A warning as it appears in the JSON envelope.
+struct PeekabooWarning: Codable, Equatable, Sendable {
+    let code: String
+    let message: String
+
+    init(_ code: WarningCode, _ message: String) {
+        self.code = code.rawValue
+        self.message = message
+    }
+}
+
+/// The failure reported instead of a result when `--warnings-as-errors` is set and warnings were recorded.
+func warningsAsErrorsInfo(for warnings: [PeekabooWarning]) -> ErrorInfo {
+    let count = warnings.count == 1 ? "1 warning" : "\(warnings.count) warnings"
+    return ErrorInfo(
+        message: "\(count) promoted to errors by --warnings-as-errors; first: \(warnings[0].message)",
+        code: .WARNINGS_AS_ERRORS
+    )
+}
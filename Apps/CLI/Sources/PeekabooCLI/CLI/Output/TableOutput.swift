@@ -0,0 +1,113 @@
+import Commander
+import Foundation
+
+/// Column-aligned text tables for `--table`: widths follow the widest value, long values end in `…`.
+struct TableFormatter {
+    enum BorderStyle: String, CaseIterable, ExpressibleFromArgument {
+        case none
+        case ascii
+        case unicode
+
+        init?(argument: String) {
+            self.init(rawValue: argument.lowercased())
+        }
+    }
+
+    /// Columns never shrink below this when fitting the terminal, so every column keeps a readable stub.
+    static let minimumColumnWidth = 3
+
+    var borderStyle: BorderStyle = .none
+    var showsHeader = true
+    /// Values longer than this many characters are cut and end in `…`.
+    var maxColumnWidth: Int?
+    /// Line width to fit, usually the terminal's; the widest columns give up space first. nil never shrinks.
+    var availableWidth: Int?
+
+    func render(header: [String], rows: [[String]]) -> String {
+        let columnCount = max(header.count, rows.map(\.count).max() ?? 0)
+        guard columnCount > 0 else { return "" }
+
+        let cells = ((self.showsHeader ? [header] : []) + rows).map { row in
+            (0..<columnCount).map { $0 < row.count ? row[$0] : "" }
+        }
+        let widths = self.columnWidths(for: cells, columnCount: columnCount)
+        let lines = cells.map { self.line($0, widths: widths) }
+
+        switch self.borderStyle {
+        case .none:
+            return lines.joined(separator: "\n")
+        case .ascii, .unicode:
+            let glyphs = self.glyphs
+            var output = [self.rule(widths, glyphs.top)]
+            for (index, line) in lines.enumerated() {
+                output.append(line)
+                if index == 0, self.showsHeader, lines.count > 1 {
+                    output.append(self.rule(widths, glyphs.middle))
+                }
+            }
+            output.append(self.rule(widths, glyphs.bottom))
+            return output.joined(separator: "\n")
+        }
+    }
+
+    /// Cuts `value` to `width` characters, using the last one for `…`.
+    static func truncate(_ value: String, to width: Int) -> String {
+        guard value.count > width else { return value }
+        guard width > 1 else { return String(repeating: "…", count: max(width, 0)) }
+        return String(value.prefix(width - 1)) + "…"
+    }
+
+    private func columnWidths(for cells: [[String]], columnCount: Int) -> [Int] {
+        var widths = (0..<columnCount).map { column in
+            cells.map { $0[column].count }.max() ?? 0
+        }
+        if let maxColumnWidth {
+            widths = widths.map { min($0, maxColumnWidth) }
+        }
+        guard let availableWidth else { return widths }
+
+        let overhead = self.borderStyle == .none ? 2 * (columnCount - 1) : 3 * columnCount + 1
+        while widths.reduce(0, +) + overhead > availableWidth,
+              let widest = widths.indices.max(by: { widths[$0] < widths[$1] }),
+              widths[widest] > Self.minimumColumnWidth {
+            widths[widest] -= 1
+        }
+        return widths
+    }
+
+    private func line(_ row: [String], widths: [Int]) -> String {
+        let padded = zip(row, widths).map { value, width in
+            let cell = Self.truncate(value, to: width)
+            return cell + String(repeating: " ", count: width - cell.count)
+        }
+        switch self.borderStyle {
+        case .none:
+            let joined = padded.joined(separator: "  ")
+            return String(joined.reversed().drop(while: { $0 == " " }).reversed())
+        case .ascii, .unicode:
+            let bar = self.glyphs.vertical
+            return "\(bar) " + padded.joined(separator: " \(bar) ") + " \(bar)"
+        }
+    }
+
+    private func rule(_ widths: [Int], _ corners: (left: String, joint: String, right: String)) -> String {
+        let horizontal = self.glyphs.horizontal
+        let segments = widths.map { String(repeating: horizontal, count: $0 + 2) }
+        return corners.left + segments.joined(separator: corners.joint) + corners.right
+    }
+
+    private var glyphs: (
+        horizontal: String,
+        vertical: String,
+        top: (left: String, joint: String, right: String),
+        middle: (left: String, joint: String, right: String),
+        bottom: (left: String, joint: String, right: String)
+    ) {
+        switch self.borderStyle {
+        case .unicode:
+            ("─", "│", ("┌", "┬", "┐"), ("├", "┼", "┤"), ("└", "┴", "┘"))
+        case .ascii, .none:
+            ("-", "|", ("+", "+", "+"), ("+", "+", "+"), ("+", "+", "+"))
+        }
+    }
+}
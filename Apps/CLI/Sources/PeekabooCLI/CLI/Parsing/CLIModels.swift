@@ -17,8 +17,10 @@ extension PeekabooCore.CaptureMode: @retroactive ExpressibleFromArgument {
 }
 
 extension PeekabooCore.ImageFormat: @retroactive ExpressibleFromArgument {
+    /// Accepts `jpeg` as well as `jpg`, matching the extensions `init?(path:)` recognizes.
     public init?(argument: String) {
-        self.init(rawValue: argument.lowercased())
+        let name = argument.lowercased()
+        self.init(rawValue: name == "jpeg" ? "jpg" : name)
     }
 }
 
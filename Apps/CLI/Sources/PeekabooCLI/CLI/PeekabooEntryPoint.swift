@@ -25,7 +25,7 @@ func executePeekabooCLI(arguments: [String]) async -> Int32 {
     // the initial load, so avoid a second credentials/config read on every CLI invocation.
     _ = ConfigurationManager.shared.getConfiguration()
 
-    let shouldEmitJSONErrors = containsJSONOutputFlag(arguments)
+    let shouldEmitJSONErrors = containsJSONOutputFlag(arguments) || EnvironmentConfig.jsonOutput()
 
     do {
         try await CommanderRuntimeExecutor.resolveAndRun(arguments: arguments)
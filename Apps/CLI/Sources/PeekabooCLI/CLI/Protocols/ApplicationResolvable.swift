@@ -1,3 +1,4 @@
+import Commander
 import Foundation
 import PeekabooCore
 import PeekabooFoundation
@@ -85,6 +86,55 @@ extension ApplicationResolvable {
         // Log the redundancy and prefer the textual identifier for readability.
         return app
     }
+
+    /// `resolveApplicationIdentifier()`, but with `caseSensitive` a name or bundle ID has to match as typed
+    /// (`Signal` never resolves to `signal-desktop`); the matched app is returned as `PID:<pid>`.
+    func resolveApplicationIdentifier(
+        caseSensitive: Bool,
+        services: any PeekabooServiceProviding
+    ) async throws -> String {
+        let identifier = try self.resolveApplicationIdentifier()
+        guard caseSensitive, !identifier.uppercased().hasPrefix("PID:") else { return identifier }
+
+        let applications = try await services.applications.listApplications().data.applications
+        guard let match = ApplicationNameMatcher.caseSensitiveMatch(for: identifier, in: applications) else {
+            throw PeekabooError.appNotFound(identifier)
+        }
+        return "PID:\(match.processIdentifier)"
+    }
+}
+
+/// Case-sensitive counterpart to `ApplicationService.findApplication`'s matching order:
+/// exact bundle ID, exact name, then the best-scoring name containing the identifier.
+enum ApplicationNameMatcher {
+    static func caseSensitiveMatch(
+        for identifier: String,
+        in applications: [ServiceApplicationInfo]
+    ) -> ServiceApplicationInfo? {
+        let identifier = identifier.trimmingCharacters(in: .whitespacesAndNewlines)
+        if let bundleMatch = applications.first(where: { $0.bundleIdentifier == identifier }) {
+            return bundleMatch
+        }
+        if let exactName = applications.first(where: { $0.name == identifier }) {
+            return exactName
+        }
+
+        // Same ranking as the service: prefix matches first, regular apps over helpers, shorter names win.
+        return applications
+            .filter { $0.activationPolicy != .prohibited && $0.name.contains(identifier) }
+            .max { self.score($0, identifier) < self.score($1, identifier) }
+    }
+
+    private static func score(_ app: ServiceApplicationInfo, _ identifier: String) -> Int {
+        var score = -app.name.count
+        if app.name.hasPrefix(identifier) {
+            score += 100
+        }
+        if app.activationPolicy == .regular {
+            score += 50
+        }
+        return score
+    }
 }
 
 /// Extension for commands with positional app argument (like AppCommand subcommands)
@@ -99,3 +149,34 @@ extension ApplicationResolvablePositional {
         self.positionalAppIdentifier
     }
 }
+
+/// `--pid-file` support for daemons that record their PID (`/var/run/myapp.pid`, `~/.myapp/pid`).
+enum PIDFile {
+    static func validateExclusive(pidFile: String?, app: String?, pid: Int32?) throws {
+        guard pidFile != nil else { return }
+        if app != nil || pid != nil {
+            throw ValidationError("--pid-file, --pid, and --app each pick the target app; use only one")
+        }
+    }
+
+    /// Reads the PID on the file's first line and checks the process is still alive.
+    static func runningPID(at path: String) throws -> Int32 {
+        let expanded = (path as NSString).expandingTildeInPath
+        guard let contents = try? String(contentsOfFile: expanded, encoding: .utf8) else {
+            throw PeekabooError.appNotFound("PID file \(path) does not exist or is unreadable")
+        }
+        let firstLine = contents.split(whereSeparator: \.isNewline).first.map(String.init) ?? ""
+        guard let pid = Int32(firstLine.trimmingCharacters(in: .whitespaces)), pid > 0 else {
+            throw PeekabooError.invalidInput("PID file \(path) does not start with a process ID")
+        }
+        guard self.isRunning(pid) else {
+            throw PeekabooError.appNotFound("PID \(pid) from \(path) is not running (stale PID file)")
+        }
+        return pid
+    }
+
+    /// Signal 0 only probes; EPERM still means the process exists under another user.
+    static func isRunning(_ pid: Int32) -> Bool {
+        kill(pid, 0) == 0 || errno == EPERM
+    }
+}
@@ -0,0 +1,53 @@
+import Foundation
+
+// MARK: - Error Context
+
+/// Wraps an error with the chain of operations it bubbled up through.
+///
+/// The wrapper is transparent for messages: `localizedDescription` is the underlying error's,
+/// and `handleError` unwraps it before mapping error codes. The chain only surfaces as
+/// `error.context` in JSON responses.
+struct ContextualError: LocalizedError {
+    let underlying: any Error
+    /// Operation names, outermost first (e.g. `["image_command", "perform_capture", "capture_window"]`).
+    let context: [String]
+
+    var errorDescription: String? {
+        self.underlying.localizedDescription
+    }
+
+    /// Prepends `operation` to an existing chain, or starts a new one.
+    static func wrapping(_ error: any Error, in operation: String) -> any Error {
+        switch error {
+        case is CancellationError:
+            // Cancellation is control flow; keep it recognizable to `catch is CancellationError`.
+            error
+        case let contextual as ContextualError:
+            ContextualError(underlying: contextual.underlying, context: [operation] + contextual.context)
+        default:
+            ContextualError(underlying: error, context: [operation])
+        }
+    }
+}
+
+extension Error {
+    /// Operation chain recorded by `withErrorContext`, outermost first; empty when none was recorded.
+    var errorContextChain: [String] {
+        (self as? ContextualError)?.context ?? []
+    }
+
+    /// The original error with any `ContextualError` wrapper removed.
+    var withoutErrorContext: any Error {
+        (self as? ContextualError)?.underlying ?? self
+    }
+}
+
+/// Runs `body`, tagging any thrown error with `operation` so JSON output can report where it failed.
+@MainActor
+func withErrorContext<T>(_ operation: String, _ body: () async throws -> T) async throws -> T {
+    do {
+        return try await body()
+    } catch {
+        throw ContextualError.wrapping(error, in: operation)
+    }
+}
@@ -115,7 +115,11 @@ extension SeeCommand {
             observation = try await self.services.desktopObservation
                 .observe(self.makeObservationRequest(target: target))
         } catch DesktopObservationError.targetNotFound(_) where self.menubar {
-            self.logger.verbose("No observation-backed menu bar popover found; falling back", category: "Capture")
+            self.logger.warn(
+                .FALLBACK_TOOL_USED,
+                "No observation-backed menu bar popover found; falling back to the legacy menu bar capture",
+                category: "Capture"
+            )
             self.logger.operationComplete("capture_phase", success: false, metadata: [
                 "mode": mode.rawValue,
                 "fallback": "legacy_menubar",
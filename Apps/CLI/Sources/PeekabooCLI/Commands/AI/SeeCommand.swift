@@ -148,7 +148,7 @@ struct SeeCommand: ApplicationResolvable, ErrorHandlingCommand, RuntimeOptionsCo
                 ]
             )
             self.handleError(error)
-            throw ExitCode.failure
+            throw self.exitCode(for: error)
         }
     }
 
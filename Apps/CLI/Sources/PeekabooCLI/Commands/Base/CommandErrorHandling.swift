@@ -6,6 +6,11 @@ import PeekabooFoundation
 
 // MARK: - Error Handling Protocol
 
+extension ExitCode {
+    /// Exit status for timeouts (`TIMEOUT` in JSON), distinct from the generic failure status 1.
+    static let timedOut = ExitCode(30)
+}
+
 /// Protocol for commands that need standardized error handling
 @MainActor
 protocol ErrorHandlingCommand {
@@ -15,6 +20,9 @@ protocol ErrorHandlingCommand {
 extension ErrorHandlingCommand {
     /// Handle errors with appropriate output format
     func handleError(_ error: any Error, customCode: ErrorCode? = nil) {
+        let contextChain = error.errorContextChain
+        let error = error.withoutErrorContext
+        let remediation = PermissionHelpers.explainFailure(error)
         if jsonOutput {
             let errorCode = customCode ?? self.mapErrorToCode(error)
             let logger: Logger = if let formattable = self as? any OutputFormattable {
@@ -22,7 +30,9 @@ extension ErrorHandlingCommand {
             } else {
                 Logger.shared
             }
-            outputError(message: error.localizedDescription, code: errorCode, logger: logger)
+            let context = contextChain.isEmpty ? nil : contextChain
+            let info = self.errorInfo(for: error, code: errorCode, details: remediation, context: context)
+            outputError(info, logger: logger)
         } else {
             let errorMessage: String = if let peekabooError = error as? PeekabooError {
                 peekabooError.errorDescription ?? String(describing: error)
@@ -36,7 +46,50 @@ extension ErrorHandlingCommand {
                 error.localizedDescription
             }
             fputs("Error: \(errorMessage)\n", stderr)
+            if case let .multipleErrors(errors) = error as? PeekabooError {
+                for nested in errors {
+                    fputs("  - \(nested.userMessage)\n", stderr)
+                }
+            }
+            if let remediation {
+                fputs("\(remediation)\n", stderr)
+            }
+        }
+    }
+
+    /// JSON payload for `error`; the entries of `multipleErrors` are nested with their own codes.
+    func errorInfo(
+        for error: any Error,
+        code: ErrorCode,
+        details: String? = nil,
+        context: [String]? = nil
+    ) -> ErrorInfo {
+        guard let peekabooError = error as? PeekabooError else {
+            return ErrorInfo(message: error.localizedDescription, code: code, details: details, context: context)
+        }
+        var nested: [ErrorInfo]?
+        if case let .multipleErrors(errors) = peekabooError {
+            nested = errors.map { self.errorInfo(for: $0, code: self.mapErrorToCode($0)) }
+        }
+        return ErrorInfo(
+            message: error.localizedDescription,
+            code: code,
+            details: details,
+            context: context,
+            peekabooError: peekabooError,
+            errors: nested
+        )
+    }
+
+    /// Status to exit with after `handleError`: `ExitCode.timedOut` for anything reported as `TIMEOUT`, so scripts can
+    /// tell a slow target from a real failure without parsing output; 1 otherwise. `multipleErrors` exits with the
+    /// status of its first error.
+    func exitCode(for error: any Error) -> ExitCode {
+        let error = error.withoutErrorContext
+        if case let .multipleErrors(errors) = error as? PeekabooError, let first = errors.first {
+            return self.exitCode(for: first)
         }
+        return self.mapErrorToCode(error) == .TIMEOUT ? .timedOut : ExitCode(1)
     }
 
     /// Map various error types to error codes
@@ -112,6 +165,8 @@ extension ErrorHandlingCommand {
             .MENU_BAR_NOT_FOUND
         case .menuItemNotFound:
             .MENU_ITEM_NOT_FOUND
+        case .multipleErrors:
+            .MULTIPLE_ERRORS
         default:
             nil
         }
@@ -132,7 +187,7 @@ extension ErrorHandlingCommand {
 
     private func timeoutErrorCode(for error: PeekabooError) -> ErrorCode? {
         switch error {
-        case .captureTimeout, .timeout:
+        case .captureTimeout, .timeout, .timedOut:
             .TIMEOUT
         default:
             nil
@@ -17,6 +17,7 @@ import Tachikoma
 struct CommandRuntimeOptions {
     var verbose = false
     var jsonOutput = false
+    var outputFormat: JSONOutputFormat = .json
     var logLevel: LogLevel?
     var captureEnginePreference: String?
     var inputStrategy: UIInputStrategy?
@@ -24,14 +25,17 @@ struct CommandRuntimeOptions {
     var autoStartDaemon = true
     var bridgeSocketPath: String?
     var requiresElementActions = false
+    var warningsAsErrors = false
 
     func makeConfiguration() -> CommandRuntime.Configuration {
         CommandRuntime.Configuration(
             verbose: self.verbose,
             jsonOutput: self.jsonOutput,
+            outputFormat: self.outputFormat,
             logLevel: self.logLevel,
             captureEnginePreference: self.captureEnginePreference,
-            inputStrategy: self.inputStrategy
+            inputStrategy: self.inputStrategy,
+            warningsAsErrors: self.warningsAsErrors
         )
     }
 
@@ -42,6 +46,9 @@ struct CommandRuntimeOptions {
             options.captureEnginePreference = captureEngine
             options.preferRemote = false
         }
+        if !options.jsonOutput, EnvironmentConfig.jsonOutput(environment: environment) {
+            options.jsonOutput = true
+        }
         return options
     }
 
@@ -65,9 +72,11 @@ struct CommandRuntime {
     struct Configuration {
         var verbose: Bool
         var jsonOutput: Bool
+        var outputFormat: JSONOutputFormat = .json
         var logLevel: LogLevel?
         var captureEnginePreference: String?
         var inputStrategy: UIInputStrategy?
+        var warningsAsErrors = false
     }
 
     let configuration: Configuration
@@ -92,6 +101,8 @@ struct CommandRuntime {
         services.installAgentRuntimeDefaults()
 
         self.logger.setJsonOutputMode(configuration.jsonOutput)
+        self.logger.setOutputFormat(configuration.outputFormat)
+        self.logger.setWarningsAsErrors(configuration.warningsAsErrors)
         let explicitLevel = configuration.logLevel
         var shouldEnableVerbose = configuration.verbose
         if configuration.jsonOutput && explicitLevel == nil {
@@ -23,6 +23,14 @@ extension CommandSignature {
             help: "Force local execution; skip remote hosts even if available"
         )
 
+        let warningsAsErrorsFlag = FlagDefinition.make(
+            label: "warnings-as-errors",
+            names: [
+                .long("warnings-as-errors"),
+            ],
+            help: "Fail instead of succeeding with warnings (fallbacks, unconfirmed focus, clipped windows)"
+        )
+
         let inputStrategyOption = OptionDefinition.make(
             label: "inputStrategy",
             names: [
@@ -33,10 +41,20 @@ extension CommandSignature {
             parsing: .singleValue
         )
 
+        let outputFormatOption = OptionDefinition.make(
+            label: "outputFormat",
+            names: [
+                .long("output-format"),
+                .aliasLong("outputFormat"),
+            ],
+            help: "Encoding for JSON output: json, json-compact, msgpack, or ndjson (implies --json)",
+            parsing: .singleValue
+        )
+
         return CommandSignature(
             arguments: base.arguments,
-            options: base.options + [bridgeSocketOption, inputStrategyOption],
-            flags: base.flags + [noRemoteFlag],
+            options: base.options + [bridgeSocketOption, inputStrategyOption, outputFormatOption],
+            flags: base.flags + [noRemoteFlag, warningsAsErrorsFlag],
             optionGroups: base.optionGroups
         )
     }
@@ -217,7 +217,7 @@ extension WindowIdentificationOptions {
         context: StaticString
     ) async -> ServiceWindowInfo? {
         guard let target = try? self.toWindowTarget() else {
-            logger.warn("Failed to refetch window info (\(context)): invalid target")
+            logger.warn(.STALE_PROCESS_INFO, "Failed to refetch window info (\(context)): invalid target")
             return nil
         }
 
@@ -228,7 +228,10 @@ extension WindowIdentificationOptions {
             )
             return self.selectWindow(from: refreshedWindows)
         } catch {
-            logger.warn("Failed to refetch window info (\(context)): \(error.localizedDescription)")
+            logger.warn(
+                .STALE_PROCESS_INFO,
+                "Failed to refetch window info (\(context)): \(error.localizedDescription)"
+            )
             return nil
         }
     }
@@ -283,3 +286,38 @@ extension Error {
         return .unknownError(self.localizedDescription)
     }
 }
+
+extension CaptureError {
+    /// The `PeekabooError` reported with the same error code, for places that only hold `PeekabooError`
+    /// (such as the entries of `multipleErrors`). Codes without a `PeekabooError` case fall back to the nearest one.
+    var asPeekabooError: PeekabooError {
+        let message = self.errorDescription ?? String(describing: self)
+        switch self {
+        case .screenRecordingPermissionDenied, .permissionDeniedScreenRecording:
+            return .permissionDeniedScreenRecording
+        case .accessibilityPermissionDenied:
+            return .permissionDeniedAccessibility
+        case .appleScriptPermissionDenied:
+            return .permissionDenied(message)
+        case let .appNotFound(identifier):
+            return .appNotFound(identifier)
+        case let .ambiguousAppIdentifier(identifier, candidates):
+            let suggestions = candidates.split(separator: ",").map { $0.trimmingCharacters(in: .whitespaces) }
+            return .ambiguousAppIdentifier(identifier, suggestions: suggestions)
+        case .windowNotFound, .windowTitleNotFound, .noWindowsFound, .noFrontmostApplication:
+            return .windowNotFound(criteria: message)
+        case .fileWriteError, .fileIOError:
+            return .fileIOError(message)
+        case .invalidDisplayID, .invalidDisplayIndex, .invalidWindowIndexOld, .invalidWindowIndex,
+             .invalidArgument, .invalidCaptureArea:
+            return .invalidInput(message)
+        case let .detectionTimedOut(seconds):
+            return .timedOut(operation: "Detection", timeoutMs: Int(seconds * 1000))
+        case .unknownError:
+            return .operationError(message: message)
+        case .noDisplaysAvailable, .noDisplaysFound, .captureCreationFailed, .windowCaptureFailed,
+             .captureFailure, .captureFailed, .imageConversionFailed:
+            return .captureFailed(message)
+        }
+    }
+}
@@ -49,6 +49,20 @@ enum CommanderCLIBinder {
         if let level: LogLevel = try values.decodeOption("logLevel", as: LogLevel.self) {
             options.logLevel = level
         }
+        if let rawOutputFormat = values.singleOption("outputFormat")?
+            .trimmingCharacters(in: .whitespacesAndNewlines),
+            !rawOutputFormat.isEmpty {
+            guard let format = JSONOutputFormat(rawValue: rawOutputFormat.lowercased()) else {
+                throw CommanderBindingError.invalidArgument(
+                    label: "output-format",
+                    value: rawOutputFormat,
+                    reason: "expected one of \(JSONOutputFormat.allCases.map(\.rawValue).joined(separator: ", "))"
+                )
+            }
+            // Every format is a machine-readable envelope, so picking one implies --json.
+            options.outputFormat = format
+            options.jsonOutput = true
+        }
         if let captureEngine = values.singleOption("captureEngine")?
             .trimmingCharacters(in: .whitespacesAndNewlines),
             !captureEngine.isEmpty {
@@ -71,6 +85,7 @@ enum CommanderCLIBinder {
         if values.flag("no-remote") {
             options.preferRemote = false
         }
+        options.warningsAsErrors = values.flag("warnings-as-errors")
         let explicitBridgeSocket = values.singleOption("bridge-socket")?.trimmingCharacters(in: .whitespacesAndNewlines)
         if commandType == AgentCommand.self && !values.flag("no-remote") {
             // Agent execution should stay local by default unless explicitly overridden.
@@ -96,9 +111,17 @@ enum CommanderCLIBinder {
     private static func prefersLocalRuntime(_ commandType: (any ParsableCommand.Type)?) -> Bool {
         commandType == MCPCommand.Serve.self ||
             commandType == ToolsCommand.self ||
+            commandType == DiffCommand.self ||
             commandType == SleepCommand.self ||
+            commandType == WaitForFileCommand.self ||
+            commandType == VerifyChecksumCommand.self ||
+            commandType == HealthCheckCommand.self ||
+            commandType == CursorPositionCommand.self ||
             commandType == LearnCommand.self ||
             commandType == CleanCommand.self ||
+            commandType == CacheCommand.List.self ||
+            commandType == CacheCommand.Stats.self ||
+            commandType == CacheCommand.Clear.self ||
             commandType == ConfigCommand.InitCommand.self ||
             commandType == ConfigCommand.ShowCommand.self ||
             commandType == ConfigCommand.EditCommand.self ||
@@ -113,7 +136,8 @@ enum CommanderCLIBinder {
             commandType == ConfigCommand.ModelsProviderCommand.self ||
             commandType == AppCommand.ListSubcommand.self ||
             commandType == ListCommand.AppsSubcommand.self ||
-            commandType == ListCommand.ScreensSubcommand.self
+            commandType == ListCommand.ScreensSubcommand.self ||
+            commandType == ListCommand.ProfilesSubcommand.self
     }
 
     private static func isDaemonCommand(_ commandType: (any ParsableCommand.Type)?) -> Bool {
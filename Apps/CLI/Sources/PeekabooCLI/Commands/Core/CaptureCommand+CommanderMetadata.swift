@@ -44,10 +44,13 @@ extension CaptureLiveCommand: CommanderSignatureProviding {
                     help: "Minutes before temp sessions auto-clean (default 120)",
                     long: "autoclean-minutes"
                 ),
-                .commandOption("videoOut", help: "Optional MP4 output path", long: "video-out")
+                .commandOption("videoOut", help: "Optional MP4 output path", long: "video-out"),
+                .commandOption("gifOut", help: "Optional animated GIF output path", long: "gif-out"),
+                .commandOption("gifFps", help: "GIF frames per second (default 2)", long: "gif-fps")
             ],
             flags: [
-                .commandFlag("highlightChanges", help: "Overlay motion boxes", long: "highlight-changes")
+                .commandFlag("highlightChanges", help: "Overlay motion boxes", long: "highlight-changes"),
+                .commandFlag("noGifLoop", help: "Play the GIF once", long: "no-gif-loop")
             ]
         )
     }
@@ -84,6 +87,28 @@ extension CaptureVideoCommand: CommanderSignatureProviding {
     }
 }
 
+extension CaptureFocusCommand: CommanderSignatureProviding {
+    static func commanderSignature() -> CommandSignature {
+        CommandSignature(
+            options: [
+                .commandOption(
+                    "pollIntervalMs",
+                    help: "Milliseconds between focus checks (default 100)",
+                    long: "poll-interval-ms"
+                ),
+                .commandOption(
+                    "debounceMs",
+                    help: "Milliseconds a window must stay focused before it is captured (default 500)",
+                    long: "debounce-ms"
+                ),
+                .commandOption("maxCaptures", help: "Stop after this many captures", long: "max-captures"),
+                .commandOption("path", help: "Output directory (defaults to temp capture session)", long: "path"),
+                .commandOption("format", help: "Image format: png or jpg", long: "format")
+            ]
+        )
+    }
+}
+
 extension CaptureWatchAlias: CommanderSignatureProviding {
     static func commanderSignature() -> CommandSignature {
         CaptureLiveCommand.commanderSignature()
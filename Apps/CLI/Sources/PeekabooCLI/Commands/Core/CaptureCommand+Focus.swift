@@ -0,0 +1,243 @@
+import Commander
+import CoreGraphics
+import Foundation
+import ImageIO
+import PeekabooCore
+import PeekabooFoundation
+
+/// Decides when a newly focused window has held focus long enough to capture.
+struct FocusChangeDebouncer {
+    let debounceMs: Int
+    private(set) var capturedWindowID: Int?
+    private var candidate: (windowID: Int, sinceMs: Int)?
+
+    init(debounceMs: Int) {
+        self.debounceMs = debounceMs
+    }
+
+    /// Feeds the window focused at `nowMs`; returns its ID once it has stayed focused for `debounceMs`
+    /// and differs from the last window returned.
+    mutating func observe(windowID: Int?, atMs nowMs: Int) -> Int? {
+        guard let windowID else {
+            self.candidate = nil
+            return nil
+        }
+        if self.candidate?.windowID != windowID {
+            self.candidate = (windowID, nowMs)
+        }
+        guard windowID != self.capturedWindowID,
+              let candidate = self.candidate,
+              nowMs - candidate.sinceMs >= self.debounceMs
+        else {
+            return nil
+        }
+        self.capturedWindowID = windowID
+        return windowID
+    }
+}
+
+/// One saved focus change; JSON mode prints each as a line with `type: "capture"`.
+struct FocusChangeCapture: Codable {
+    var type = "capture"
+    let index: Int
+    let window_id: Int
+    let title: String
+    let path: String
+    let captured_at: String
+}
+
+/// Final JSON line of a `capture focus` run.
+struct FocusChangeSummary: Codable {
+    var type = "summary"
+    let captures: Int
+    let output_dir: String
+    /// Signal name when SIGINT/SIGTERM ended the run; nil when `--max-captures` did.
+    let stopped_by: String?
+}
+
+@MainActor
+struct CaptureFocusCommand: ErrorHandlingCommand, OutputFormattable, RuntimeOptionsConfigurable {
+    static let defaultPollIntervalMs = 100
+    static let defaultDebounceMs = 500
+
+    @Option(name: .long, help: "Milliseconds between focus checks (default 100)") var pollIntervalMs: Int?
+    @Option(
+        name: .long,
+        help: "Milliseconds a window must stay focused before it is captured (default 500)"
+    ) var debounceMs: Int?
+    @Option(name: .long, help: "Stop after this many captures") var maxCaptures: Int?
+    @Option(name: .long, help: "Output directory (defaults to temp capture session)") var path: String?
+    @Option(name: .long, help: "Image format: png or jpg") var format: PeekabooCore.ImageFormat = .png
+
+    @RuntimeStorage private var runtime: CommandRuntime?
+    var runtimeOptions = CommandRuntimeOptions()
+
+    private var resolvedRuntime: CommandRuntime {
+        guard let runtime else {
+            preconditionFailure("CommandRuntime must be configured before accessing runtime resources")
+        }
+        return runtime
+    }
+
+    private var logger: Logger {
+        self.resolvedRuntime.logger
+    }
+
+    var services: any PeekabooServiceProviding {
+        self.resolvedRuntime.services
+    }
+
+    var jsonOutput: Bool {
+        self.runtime?.configuration.jsonOutput ?? self.runtimeOptions.jsonOutput
+    }
+
+    var outputLogger: Logger {
+        self.logger
+    }
+
+    func validate() throws {
+        if let pollIntervalMs, pollIntervalMs <= 0 {
+            throw ValidationError("--poll-interval-ms must be greater than zero")
+        }
+        if let debounceMs, debounceMs < 0 {
+            throw ValidationError("--debounce-ms must be zero or greater")
+        }
+        if let maxCaptures, maxCaptures <= 0 {
+            throw ValidationError("--max-captures must be greater than zero")
+        }
+    }
+
+    mutating func run(using runtime: CommandRuntime) async throws {
+        self.runtime = runtime
+        self.logger.setJsonOutputMode(self.jsonOutput)
+        self.logger.operationStart("capture_focus")
+
+        do {
+            try self.validate()
+            let outputDir = CaptureCommandPathResolver.outputDirectory(from: self.path)
+            try FileManager.default.createDirectory(at: outputDir, withIntermediateDirectories: true)
+
+            let stop = FocusCaptureStopRequest()
+            let monitor = CaptureSignalMonitor()
+            monitor.start { name in stop.reason = name }
+            defer { monitor.stop() }
+
+            let pollInterval = self.pollIntervalMs ?? Self.defaultPollIntervalMs
+            var debouncer = FocusChangeDebouncer(debounceMs: self.debounceMs ?? Self.defaultDebounceMs)
+            var captures = 0
+            let startedAt = Date()
+
+            while stop.reason == nil, captures < self.maxCaptures ?? .max {
+                let focused = try? await self.services.windows.getFocusedWindow()
+                let elapsedMs = Int(Date().timeIntervalSince(startedAt) * 1000)
+                let settled = debouncer.observe(windowID: focused?.windowID, atMs: elapsedMs)
+                if let focused, settled != nil,
+                   let capture = await self.capture(focused, index: captures + 1, into: outputDir) {
+                    captures += 1
+                    self.emit(capture)
+                }
+                try await Task.sleep(for: .milliseconds(pollInterval))
+            }
+
+            let summary = FocusChangeSummary(captures: captures, output_dir: outputDir.path, stopped_by: stop.reason)
+            if self.jsonOutput {
+                self.logger.outputFormat.writeRecord(summary)
+            } else {
+                print("Captured \(captures) focus change\(captures == 1 ? "" : "s") into \(outputDir.path)")
+            }
+            self.logger.operationComplete("capture_focus", success: true, metadata: ["captures": captures])
+        } catch {
+            self.handleError(error)
+            self.logger.operationComplete(
+                "capture_focus",
+                success: false,
+                metadata: ["error": error.localizedDescription]
+            )
+            throw self.exitCode(for: error)
+        }
+    }
+
+    /// A window that closes between the focus check and the capture is skipped with a warning.
+    private func capture(_ window: ServiceWindowInfo, index: Int, into directory: URL) async -> FocusChangeCapture? {
+        do {
+            let result = try await self.services.screenCapture.captureWindow(windowID: CGWindowID(window.windowID))
+            guard let source = CGImageSourceCreateWithData(result.imageData as CFData, nil),
+                  let image = CGImageSourceCreateImageAtIndex(source, 0, nil)
+            else {
+                throw CaptureError.captureFailure("Failed to decode capture of window \(window.windowID)")
+            }
+            let url = directory.appendingPathComponent(
+                Self.filename(index: index, title: window.title, format: self.format)
+            )
+            try CapturedImageIO.write(image, to: url, format: self.format)
+            return FocusChangeCapture(
+                index: index,
+                window_id: window.windowID,
+                title: window.title,
+                path: url.path,
+                captured_at: Date().ISO8601Format()
+            )
+        } catch {
+            self.logger.warn(
+                .WINDOW_CAPTURE_SKIPPED,
+                "Skipping focus change to window \(window.windowID): \(error.localizedDescription)"
+            )
+            return nil
+        }
+    }
+
+    private func emit(_ capture: FocusChangeCapture) {
+        if self.jsonOutput {
+            self.logger.outputFormat.writeRecord(capture)
+        } else {
+            print("📸 #\(capture.index) \(capture.title.isEmpty ? "[Untitled]" : capture.title) → \(capture.path)")
+        }
+    }
+
+    /// `{focus_change_count}_{window_title}.{format}`, with the title sanitized like `image` filenames.
+    static func filename(index: Int, title: String, format: PeekabooCore.ImageFormat) -> String {
+        let sanitized = ImageCommand.sanitizeFilenameComponent(title)
+        return "\(index)_\(sanitized.isEmpty ? "untitled" : sanitized).\(format.fileExtension)"
+    }
+}
+
+/// Set from the signal handler and read by the polling loop, both on the main actor.
+@MainActor
+private final class FocusCaptureStopRequest {
+    var reason: String?
+}
+
+extension CaptureFocusCommand: ParsableCommand {
+    nonisolated(unsafe) static var commandDescription: CommandDescription {
+        MainActorCommandDescription.describe {
+            CommandDescription(
+                commandName: "focus",
+                abstract: "Capture each window as it gains focus until interrupted",
+                discussion: """
+                Polls the focused window and saves a capture whenever a different window has stayed
+                focused for --debounce-ms. Runs until Ctrl-C (or --max-captures); --json prints one
+                JSON line per capture and a final summary line.
+
+                Examples:
+                  peekaboo capture focus --path ~/walkthrough
+                  peekaboo capture focus --max-captures 10 --debounce-ms 1000 --json
+                """
+            )
+        }
+    }
+}
+
+extension CaptureFocusCommand: AsyncRuntimeCommand {}
+
+@MainActor
+extension CaptureFocusCommand: CommanderBindableCommand {
+    mutating func applyCommanderValues(_ values: CommanderBindableValues) throws {
+        self.pollIntervalMs = try values.decodeOption("pollIntervalMs", as: Int.self)
+        self.debounceMs = try values.decodeOption("debounceMs", as: Int.self)
+        self.maxCaptures = try values.decodeOption("maxCaptures", as: Int.self)
+        self.path = values.singleOption("path")
+        if let parsedFormat: PeekabooCore.ImageFormat = try values.decodeOptionEnum("format") {
+            self.format = parsedFormat
+        }
+    }
+}
@@ -0,0 +1,125 @@
+import Commander
+import CoreGraphics
+import Foundation
+import ImageIO
+import PeekabooCore
+import UniformTypeIdentifiers
+
+/// Encodes kept frames as an animated GIF. ImageIO quantizes each frame to its own 256-color palette.
+struct AnimatedGIFWriter {
+    static let defaultFramesPerSecond = 2.0
+    /// Browsers clamp GIF delays under 20ms, so faster rates would play back slower than requested.
+    static let maxFramesPerSecond = 50.0
+
+    let framesPerSecond: Double
+    let loops: Bool
+
+    /// Reads each frame from disk as it is added, so long sessions don't hold every frame in memory.
+    func write(framePaths: [String], to url: URL) throws {
+        guard !framePaths.isEmpty else {
+            throw CaptureError.captureFailure("No frames were kept, so there is nothing to write to \(url.path)")
+        }
+        try FileManager.default.createDirectory(
+            at: url.deletingLastPathComponent(),
+            withIntermediateDirectories: true
+        )
+        guard let destination = CGImageDestinationCreateWithURL(
+            url as CFURL,
+            UTType.gif.identifier as CFString,
+            framePaths.count,
+            nil
+        ) else {
+            throw CaptureError.fileIOError("Failed to create GIF destination for \(url.path)")
+        }
+
+        // Without a loop count ImageIO omits the NETSCAPE extension and viewers play the animation once.
+        if self.loops {
+            let fileProperties = [kCGImagePropertyGIFDictionary: [kCGImagePropertyGIFLoopCount: 0]]
+            CGImageDestinationSetProperties(destination, fileProperties as CFDictionary)
+        }
+        let delay = 1 / self.framesPerSecond
+        let frameProperties = [
+            kCGImagePropertyGIFDictionary: [
+                kCGImagePropertyGIFDelayTime: delay,
+                kCGImagePropertyGIFUnclampedDelayTime: delay,
+            ],
+        ]
+        for path in framePaths {
+            try autoreleasepool {
+                let image = try CapturedImageIO.load(at: path)
+                CGImageDestinationAddImage(destination, image, frameProperties as CFDictionary)
+            }
+        }
+        guard CGImageDestinationFinalize(destination) else {
+            throw CaptureError.fileIOError("Failed to write GIF to \(url.path)")
+        }
+    }
+}
+
+/// The `gif` entry of `capture live --gif-out` output.
+struct CaptureGifSummary: Codable, Sendable, Equatable {
+    let path: String
+    let frames: Int
+    let fileSizeBytes: Int
+    let fps: Double
+    let loops: Bool
+}
+
+/// Session result plus the optional GIF, encoded flat so existing JSON consumers see the same keys.
+struct CaptureLiveOutput: Codable, Sendable {
+    let result: LiveCaptureSessionResult
+    let gif: CaptureGifSummary?
+
+    private enum CodingKeys: String, CodingKey {
+        case gif
+    }
+
+    init(result: LiveCaptureSessionResult, gif: CaptureGifSummary?) {
+        self.result = result
+        self.gif = gif
+    }
+
+    init(from decoder: any Decoder) throws {
+        self.result = try LiveCaptureSessionResult(from: decoder)
+        self.gif = try decoder.container(keyedBy: CodingKeys.self).decodeIfPresent(CaptureGifSummary.self, forKey: .gif)
+    }
+
+    func encode(to encoder: any Encoder) throws {
+        try self.result.encode(to: encoder)
+        var container = encoder.container(keyedBy: CodingKeys.self)
+        try container.encodeIfPresent(self.gif, forKey: .gif)
+    }
+}
+
+@MainActor
+extension CaptureLiveCommand {
+    func validateGifOptions() throws {
+        if self.gifOut == nil, self.gifFps != nil || self.noGifLoop {
+            throw ValidationError("--gif-fps and --no-gif-loop require --gif-out")
+        }
+        if let fps = self.gifFps, !(fps > 0 && fps <= AnimatedGIFWriter.maxFramesPerSecond) {
+            throw ValidationError("--gif-fps must be greater than 0 and at most 50")
+        }
+    }
+
+    /// Builds the GIF from the frames the session kept, after it has stopped (duration, caps, or a signal).
+    func writeGifIfRequested(for result: LiveCaptureSessionResult) throws -> CaptureGifSummary? {
+        guard let gifOut else { return nil }
+        let url = CaptureCommandPathResolver.fileURL(from: gifOut)
+        let writer = AnimatedGIFWriter(
+            framesPerSecond: self.gifFps ?? AnimatedGIFWriter.defaultFramesPerSecond,
+            loops: !self.noGifLoop
+        )
+        let framePaths = result.frames.map(\.path)
+        try writer.write(framePaths: framePaths, to: url)
+
+        let size = try FileManager.default.attributesOfItem(atPath: url.path)[.size] as? Int ?? 0
+        return CaptureGifSummary(
+            path: url.path,
+            frames: framePaths.count,
+            fileSizeBytes: size,
+            fps: writer.framesPerSecond,
+            loops: writer.loops
+        )
+    }
+}
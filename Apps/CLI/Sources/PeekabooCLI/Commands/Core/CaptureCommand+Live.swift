@@ -50,6 +50,9 @@ struct CaptureLiveCommand: ApplicationResolvable, ErrorHandlingCommand, OutputFo
     @Option(name: .long, help: "Output directory (defaults to temp capture session)") var path: String?
     @Option(name: .long, help: "Minutes before temp sessions auto-clean (default 120)") var autocleanMinutes: Int?
     @Option(name: .long, help: "Optional MP4 output path (built from kept frames)") var videoOut: String?
+    @Option(name: .long, help: "Optional animated GIF output path (built from kept frames)") var gifOut: String?
+    @Option(name: .long, help: "GIF playback rate in frames per second (default 2, max 50)") var gifFps: Double?
+    @Flag(name: .long, help: "Play the GIF once instead of looping") var noGifLoop = false
 
     @RuntimeStorage private var runtime: CommandRuntime?
     var runtimeOptions = CommandRuntimeOptions()
@@ -88,6 +91,7 @@ struct CaptureLiveCommand: ApplicationResolvable, ErrorHandlingCommand, OutputFo
             // with concurrent screenshot commands and report transient TCC denial.
             let scope = try await self.resolveScope()
             let options = try self.buildOptions()
+            try self.validateGifOptions()
             if scope.kind == .window, let identifier = scope.applicationIdentifier {
                 try await self.focusIfNeeded(appIdentifier: identifier)
             }
@@ -109,7 +113,7 @@ struct CaptureLiveCommand: ApplicationResolvable, ErrorHandlingCommand, OutputFo
             )
             let session = WatchCaptureSession(dependencies: deps, configuration: config)
             let runSession: @MainActor @Sendable () async throws -> CaptureSessionResult = {
-                try await session.run()
+                try await session.runStoppingOnSignals()
             }
             let enginePreference = self.liveCaptureEnginePreference(for: scope)
             let result: CaptureSessionResult = if let engineAware = self.services.screenCapture
@@ -118,7 +122,11 @@ struct CaptureLiveCommand: ApplicationResolvable, ErrorHandlingCommand, OutputFo
             } else {
                 try await runSession()
             }
-            self.output(result)
+            let gif = try self.writeGifIfRequested(for: result)
+            self.output(result, gif: gif)
+            if let signal = result.interruptingSignal {
+                self.reportInterruption(by: signal, completedCaptures: result.stats.framesKept)
+            }
             self.logger.operationComplete(
                 "capture_live",
                 success: true,
@@ -131,7 +139,7 @@ struct CaptureLiveCommand: ApplicationResolvable, ErrorHandlingCommand, OutputFo
                 success: false,
                 metadata: ["error": error.localizedDescription]
             )
-            throw ExitCode(1)
+            throw self.exitCode(for: error)
         }
     }
 }
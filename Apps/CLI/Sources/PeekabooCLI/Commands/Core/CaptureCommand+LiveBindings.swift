@@ -43,5 +43,8 @@ extension CaptureLiveCommand: CommanderBindableCommand {
         self.path = values.singleOption("path")
         self.autocleanMinutes = try values.decodeOption("autocleanMinutes", as: Int.self)
         self.videoOut = values.singleOption("videoOut")
+        self.gifOut = values.singleOption("gifOut")
+        self.gifFps = try values.decodeOption("gifFps", as: Double.self)
+        if values.flag("noGifLoop") { self.noGifLoop = true }
     }
 }
@@ -3,10 +3,10 @@ import PeekabooCore
 
 @MainActor
 extension CaptureLiveCommand {
-    func output(_ result: LiveCaptureSessionResult) {
+    func output(_ result: LiveCaptureSessionResult, gif: CaptureGifSummary? = nil) {
         let meta = CaptureMetaSummary.make(from: result)
         if self.jsonOutput {
-            outputSuccessCodable(data: result, logger: self.outputLogger)
+            outputSuccessCodable(data: CaptureLiveOutput(result: result, gif: gif), logger: self.outputLogger)
             return
         }
         print("""
@@ -21,6 +21,9 @@ extension CaptureLiveCommand {
                     + "Δ=\(String(format: "%.2f", frame.changePercent))% → \(frame.path)"
             )
         }
+        if let gif {
+            print("🎞️  GIF \(gif.frames) frames @ \(gif.fps) fps, \(gif.fileSizeBytes) bytes → \(gif.path)")
+        }
         for warning in result.warnings {
             print("⚠️  \(warning.code.rawValue): \(warning.message)")
         }
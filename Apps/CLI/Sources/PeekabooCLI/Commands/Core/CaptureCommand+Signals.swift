@@ -0,0 +1,82 @@
+import Darwin
+import Dispatch
+import Foundation
+import PeekabooCore
+
+/// Turns SIGINT/SIGTERM into a graceful stop so capture sessions keep what they already saved.
+///
+/// The first signal asks the session to stop after the current frame; a second one falls through to
+/// the default handler and terminates the process immediately.
+@MainActor
+final class CaptureSignalMonitor {
+    private static let handledSignals: [(number: Int32, name: String)] = [(SIGINT, "SIGINT"), (SIGTERM, "SIGTERM")]
+
+    private var sources: [any DispatchSourceSignal] = []
+    /// Name of the first signal received, kept after `stop()` so the run can report it.
+    private(set) var receivedSignal: String?
+
+    func start(onSignal: @escaping @MainActor (String) -> Void = { _ in }) {
+        for (number, name) in Self.handledSignals {
+            signal(number, SIG_IGN)
+            let source = DispatchSource.makeSignalSource(signal: number, queue: .main)
+            source.setEventHandler { [weak self] in
+                MainActor.assumeIsolated {
+                    for (handled, _) in Self.handledSignals {
+                        signal(handled, SIG_DFL)
+                    }
+                    self?.receivedSignal = name
+                    onSignal(name)
+                }
+            }
+            source.resume()
+            self.sources.append(source)
+        }
+    }
+
+    func stop() {
+        for source in self.sources {
+            source.cancel()
+        }
+        self.sources.removeAll()
+        for (number, _) in Self.handledSignals {
+            signal(number, SIG_DFL)
+        }
+    }
+}
+
+extension WatchCaptureSession {
+    /// Runs the session with SIGINT/SIGTERM mapped to `requestStop(reason:)`.
+    func runStoppingOnSignals() async throws -> CaptureSessionResult {
+        let monitor = CaptureSignalMonitor()
+        monitor.start { [weak self] name in
+            self?.requestStop(reason: name)
+        }
+        defer { monitor.stop() }
+        return try await self.run()
+    }
+}
+
+/// Final record of a capture run stopped by SIGINT/SIGTERM, written after the result in JSON mode.
+struct CaptureInterruptionRecord: Codable, Equatable {
+    var interrupted = true
+    let completed_captures: Int
+}
+
+extension OutputFormattable {
+    /// Ends a signal-stopped run with `CaptureInterruptionRecord` in JSON mode, or a one-line summary otherwise.
+    func reportInterruption(by signal: String, completedCaptures: Int) {
+        if self.jsonOutput {
+            self.outputLogger.outputFormat.writeRecord(CaptureInterruptionRecord(completed_captures: completedCaptures))
+        } else {
+            let noun = completedCaptures == 1 ? "capture" : "captures"
+            print("Interrupted by \(signal) after \(completedCaptures) completed \(noun)")
+        }
+    }
+}
+
+extension CaptureSessionResult {
+    /// The signal that stopped the session early, from its `interrupted` warning.
+    var interruptingSignal: String? {
+        self.warnings.first { $0.code == .interrupted }?.details?["reason"]
+    }
+}
@@ -95,8 +95,11 @@ struct CaptureVideoCommand: ErrorHandlingCommand, OutputFormattable, RuntimeOpti
                 )
             )
             let session = WatchCaptureSession(dependencies: deps, configuration: config)
-            let result = try await session.run()
+            let result = try await session.runStoppingOnSignals()
             self.output(result)
+            if let signal = result.interruptingSignal {
+                self.reportInterruption(by: signal, completedCaptures: result.stats.framesKept)
+            }
             self.logger.operationComplete(
                 "capture_video",
                 success: true,
@@ -118,7 +121,7 @@ struct CaptureVideoCommand: ErrorHandlingCommand, OutputFormattable, RuntimeOpti
                 success: false,
                 metadata: ["error": error.localizedDescription]
             )
-            throw ExitCode(1)
+            throw self.exitCode(for: error)
         }
     }
 
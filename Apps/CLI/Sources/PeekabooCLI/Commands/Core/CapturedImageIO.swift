@@ -0,0 +1,181 @@
+import CoreGraphics
+import Foundation
+import ImageIO
+import PeekabooCore
+import PeekabooFoundation
+import UniformTypeIdentifiers
+
+/// Reads and re-encodes saved captures for CLI-side post-processing (merging, annotations, …).
+enum CapturedImageIO {
+    static func load(at path: String) throws -> CGImage {
+        guard let source = CGImageSourceCreateWithURL(URL(fileURLWithPath: path) as CFURL, nil),
+              let image = CGImageSourceCreateImageAtIndex(source, 0, nil)
+        else {
+            throw CaptureError.captureFailure("Failed to read captured image at \(path)")
+        }
+        return image
+    }
+
+    /// Confirms the file at `path` is a complete image without decoding its pixels: ImageIO must have seen the
+    /// whole stream, and the header and first frame must agree on non-zero dimensions.
+    static func verify(at path: String) throws {
+        guard let source = CGImageSourceCreateWithURL(URL(fileURLWithPath: path) as CFURL, nil),
+              CGImageSourceGetCount(source) > 0
+        else {
+            throw PeekabooError.fileIOError("Saved capture at \(path) is not a readable image")
+        }
+        guard CGImageSourceGetStatusAtIndex(source, 0) == .statusComplete else {
+            throw PeekabooError.fileIOError("Saved capture at \(path) is truncated or corrupt")
+        }
+
+        let properties = CGImageSourceCopyPropertiesAtIndex(source, 0, nil) as? [CFString: Any]
+        let width = properties?[kCGImagePropertyPixelWidth] as? Int ?? 0
+        let height = properties?[kCGImagePropertyPixelHeight] as? Int ?? 0
+        guard let image = CGImageSourceCreateImageAtIndex(source, 0, nil),
+              width > 0, height > 0, image.width == width, image.height == height
+        else {
+            throw PeekabooError.fileIOError(
+                "Saved capture at \(path) reports \(width)×\(height) pixels but does not decode to that size"
+            )
+        }
+    }
+
+    /// Matches the observation pipeline so post-processing doesn't shift JPEG quality.
+    static let defaultJPEGQuality = 0.92
+
+    /// Writes `image`; `jpegQuality` is in `0...1` and ignored for PNG. `codecProfile` must belong to `format`.
+    static func write(
+        _ image: CGImage,
+        to url: URL,
+        format: PeekabooCore.ImageFormat,
+        jpegQuality: Double? = nil,
+        codecProfile: ImageCodecProfile? = nil
+    ) throws {
+        try FileManager.default.createDirectory(
+            at: url.deletingLastPathComponent(),
+            withIntermediateDirectories: true
+        )
+
+        let type: UTType = format == .png ? .png : .jpeg
+        guard let destination = CGImageDestinationCreateWithURL(url as CFURL, type.identifier as CFString, 1, nil)
+        else {
+            throw CaptureError.fileIOError("Failed to create image destination for \(url.path)")
+        }
+
+        var properties: [CFString: Any] = format == .jpg
+            ? [kCGImageDestinationLossyCompressionQuality: jpegQuality ?? Self.defaultJPEGQuality]
+            : [:]
+        if let codecProfile {
+            properties.merge(codecProfile.destinationProperties) { _, profile in profile }
+        }
+        CGImageDestinationAddImage(destination, image, properties.isEmpty ? nil : properties as CFDictionary)
+        guard CGImageDestinationFinalize(destination) else {
+            throw CaptureError.fileIOError("Failed to write image to \(url.path)")
+        }
+    }
+
+    /// Creates an RGBA drawing context; CoreGraphics' origin is bottom-left.
+    static func makeContext(width: Int, height: Int) throws -> CGContext {
+        guard let context = CGContext(
+            data: nil,
+            width: width,
+            height: height,
+            bitsPerComponent: 8,
+            bytesPerRow: 0,
+            space: CGColorSpaceCreateDeviceRGB(),
+            bitmapInfo: CGImageAlphaInfo.premultipliedLast.rawValue
+        ) else {
+            throw CaptureError.captureFailure("Failed to allocate a \(width)×\(height) image canvas")
+        }
+        return context
+    }
+
+    /// Decodes `image` into tightly packed RGBA8 rows, top row first.
+    static func rgbaBytes(of image: CGImage) throws -> [UInt8] {
+        let bytesPerRow = image.width * 4
+        var buffer = [UInt8](repeating: 0, count: bytesPerRow * image.height)
+        let rendered = buffer.withUnsafeMutableBytes { bytes -> Bool in
+            guard let context = CGContext(
+                data: bytes.baseAddress,
+                width: image.width,
+                height: image.height,
+                bitsPerComponent: 8,
+                bytesPerRow: bytesPerRow,
+                space: CGColorSpaceCreateDeviceRGB(),
+                bitmapInfo: CGImageAlphaInfo.premultipliedLast.rawValue
+            ) else { return false }
+            context.draw(image, in: CGRect(x: 0, y: 0, width: image.width, height: image.height))
+            return true
+        }
+        guard rendered else {
+            throw CaptureError.captureFailure("Failed to decode a \(image.width)×\(image.height) image")
+        }
+        return buffer
+    }
+
+    /// Inverse of `rgbaBytes(of:)`.
+    static func makeImage(rgbaBytes bytes: [UInt8], width: Int, height: Int) throws -> CGImage {
+        guard bytes.count == width * height * 4,
+              let provider = CGDataProvider(data: Data(bytes) as CFData),
+              let image = CGImage(
+                  width: width,
+                  height: height,
+                  bitsPerComponent: 8,
+                  bitsPerPixel: 32,
+                  bytesPerRow: width * 4,
+                  space: CGColorSpaceCreateDeviceRGB(),
+                  bitmapInfo: CGBitmapInfo(rawValue: CGImageAlphaInfo.premultipliedLast.rawValue),
+                  provider: provider,
+                  decode: nil,
+                  shouldInterpolate: false,
+                  intent: .defaultIntent
+              )
+        else {
+            throw CaptureError.captureFailure("Failed to build a \(width)×\(height) image")
+        }
+        return image
+    }
+}
+
+/// RGBA color parsed from `#RRGGBB` or `#RRGGBBAA` CLI arguments.
+struct HexColor: Equatable {
+    let red: CGFloat
+    let green: CGFloat
+    let blue: CGFloat
+    let alpha: CGFloat
+
+    static let black = HexColor(red: 0, green: 0, blue: 0, alpha: 1)
+
+    init(red: CGFloat, green: CGFloat, blue: CGFloat, alpha: CGFloat) {
+        self.red = red
+        self.green = green
+        self.blue = blue
+        self.alpha = alpha
+    }
+
+    /// Parses `#RRGGBB` or `#RRGGBBAA` (the leading `#` is optional).
+    init?(hex: String) {
+        var digits = hex.trimmingCharacters(in: .whitespacesAndNewlines)
+        if digits.hasPrefix("#") {
+            digits.removeFirst()
+        }
+        guard digits.count == 6 || digits.count == 8,
+              digits.allSatisfy(\.isHexDigit),
+              let value = UInt32(digits, radix: 16)
+        else {
+            return nil
+        }
+
+        let rgba = digits.count == 6 ? (value << 8) | 0xFF : value
+        self.red = CGFloat((rgba >> 24) & 0xFF) / 255
+        self.green = CGFloat((rgba >> 16) & 0xFF) / 255
+        self.blue = CGFloat((rgba >> 8) & 0xFF) / 255
+        self.alpha = CGFloat(rgba & 0xFF) / 255
+    }
+
+    /// Device RGB matches `CapturedImageIO.makeContext`, so drawn pixels keep the parsed components.
+    var cgColor: CGColor {
+        CGColor(colorSpace: CGColorSpaceCreateDeviceRGB(), components: [self.red, self.green, self.blue, self.alpha])
+            ?? CGColor(gray: 0, alpha: self.alpha)
+    }
+}
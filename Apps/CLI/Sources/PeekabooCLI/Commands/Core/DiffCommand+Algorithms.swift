@@ -0,0 +1,136 @@
+import Commander
+import Foundation
+
+/// How `peekaboo diff` turns two images into a similarity score.
+enum DiffAlgorithm: String, CaseIterable, Codable, ExpressibleFromArgument {
+    /// Structural similarity over sliding windows; sensitive to layout and texture changes.
+    case ssim
+    /// One minus the mean absolute sample difference; every changed pixel counts, weighted by how much it moved.
+    case pixelwise
+    /// DCT perceptual hash; tolerant of re-encoding and slight scaling, blind to small local edits.
+    case phash
+    /// Overlap of the value histograms; ignores where pixels are, only how many of each value exist.
+    case histogram
+
+    init?(argument: String) {
+        self.init(rawValue: argument.lowercased())
+    }
+
+    var differ: any ImageDiffer {
+        switch self {
+        case .ssim: SSIMDiffer()
+        case .pixelwise: PixelwiseDiffer()
+        case .phash: PerceptualHashDiffer()
+        case .histogram: HistogramDiffer()
+        }
+    }
+
+    var displayName: String {
+        switch self {
+        case .ssim: "SSIM"
+        case .pixelwise: "Pixelwise"
+        case .phash: "pHash"
+        case .histogram: "Histogram"
+        }
+    }
+}
+
+/// Scores one channel plane at a time; `ImageDiff.compare` averages the planes the `--diff-channel` selects.
+protocol ImageDiffer {
+    /// Similarity of two same-sized planes (row-major, top row first) in `0...1`; `1` means identical.
+    func similarity(_ lhs: [UInt8], _ rhs: [UInt8], width: Int, height: Int) -> Double
+}
+
+struct SSIMDiffer: ImageDiffer {
+    func similarity(_ lhs: [UInt8], _ rhs: [UInt8], width: Int, height: Int) -> Double {
+        ImageDiff.ssim(lhs, rhs, width: width, height: height)
+    }
+}
+
+struct PixelwiseDiffer: ImageDiffer {
+    func similarity(_ lhs: [UInt8], _ rhs: [UInt8], width: Int, height: Int) -> Double {
+        guard !lhs.isEmpty else { return 1 }
+        let difference = zip(lhs, rhs).reduce(0) { total, pair in
+            total + abs(Int(pair.0) - Int(pair.1))
+        }
+        return 1 - Double(difference) / (255 * Double(lhs.count))
+    }
+}
+
+struct PerceptualHashDiffer: ImageDiffer {
+    /// Planes are box-averaged down to this square before the DCT.
+    static let sampleSize = 32
+    /// The lowest `hashSize`×`hashSize` frequencies, minus the DC term, form the hash.
+    static let hashSize = 8
+
+    func similarity(_ lhs: [UInt8], _ rhs: [UInt8], width: Int, height: Int) -> Double {
+        let lhsHash = Self.hash(lhs, width: width, height: height)
+        let rhsHash = Self.hash(rhs, width: width, height: height)
+        let distance = zip(lhsHash, rhsHash).count(where: { $0 != $1 })
+        return 1 - Double(distance) / Double(lhsHash.count)
+    }
+
+    static func hash(_ plane: [UInt8], width: Int, height: Int) -> [Bool] {
+        let size = Self.sampleSize
+        var samples = [Double](repeating: 0, count: size * size)
+        for sampleY in 0..<size {
+            let minY = sampleY * height / size
+            let maxY = max(minY + 1, (sampleY + 1) * height / size)
+            for sampleX in 0..<size {
+                let minX = sampleX * width / size
+                let maxX = max(minX + 1, (sampleX + 1) * width / size)
+                var sum = 0
+                for y in minY..<maxY {
+                    for x in minX..<maxX {
+                        sum += Int(plane[y * width + x])
+                    }
+                }
+                samples[sampleY * size + sampleX] = Double(sum) / Double((maxY - minY) * (maxX - minX))
+            }
+        }
+
+        var coefficients: [Double] = []
+        for v in 0..<Self.hashSize {
+            for u in 0..<Self.hashSize {
+                var sum = 0.0
+                for y in 0..<size {
+                    let rowWeight = Self.cosines[v * size + y]
+                    for x in 0..<size {
+                        sum += samples[y * size + x] * Self.cosines[u * size + x] * rowWeight
+                    }
+                }
+                coefficients.append(sum)
+            }
+        }
+
+        // The DC term only encodes mean brightness, which would swamp the median.
+        let frequencies = coefficients.dropFirst()
+        let median = frequencies.sorted()[frequencies.count / 2]
+        return frequencies.map { $0 > median }
+    }
+
+    /// DCT-II basis: `cosines[frequency * sampleSize + position]`.
+    private static let cosines: [Double] = (0..<Self.hashSize).flatMap { frequency in
+        (0..<Self.sampleSize).map { position in
+            cos(Double(2 * position + 1) * Double(frequency) * .pi / Double(2 * Self.sampleSize))
+        }
+    }
+}
+
+struct HistogramDiffer: ImageDiffer {
+    func similarity(_ lhs: [UInt8], _ rhs: [UInt8], width: Int, height: Int) -> Double {
+        guard !lhs.isEmpty else { return 1 }
+        let lhsCounts = Self.histogram(lhs)
+        let rhsCounts = Self.histogram(rhs)
+        let overlap = zip(lhsCounts, rhsCounts).reduce(0) { $0 + min($1.0, $1.1) }
+        return Double(overlap) / Double(lhs.count)
+    }
+
+    private static func histogram(_ plane: [UInt8]) -> [Int] {
+        var counts = [Int](repeating: 0, count: 256)
+        for value in plane {
+            counts[Int(value)] += 1
+        }
+        return counts
+    }
+}
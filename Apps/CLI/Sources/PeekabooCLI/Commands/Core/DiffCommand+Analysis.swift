@@ -0,0 +1,175 @@
+import Commander
+import CoreGraphics
+import Foundation
+
+/// Which channels `peekaboo diff` compares.
+enum DiffChannel: String, CaseIterable, Codable, ExpressibleFromArgument {
+    /// Rec. 601 luma; ignores hue shifts that keep brightness.
+    case luminance
+    /// Red, green, and blue separately, averaged into one score.
+    case rgb
+    case alpha
+
+    init?(argument: String) {
+        self.init(rawValue: argument.lowercased())
+    }
+}
+
+struct ImageDiffResult {
+    /// Similarity from the chosen `DiffAlgorithm` in `0...1`; `1` means identical in the compared channels.
+    let score: Double
+    let changedPixels: Int
+    let totalPixels: Int
+    /// Smallest rectangle enclosing every changed pixel, in image pixels from the top-left; `nil` when nothing changed.
+    let changedBounds: WindowBounds?
+    /// Row-major, top row first; `true` where any compared channel differs.
+    let changeMask: [Bool]
+}
+
+enum ImageDiff {
+    static let windowSize = 8
+    /// Windows overlap by half, which tracks full per-pixel sliding closely at a fraction of the cost.
+    static let windowStride = 4
+
+    // Standard SSIM stabilizers for 8-bit data: (0.01 · 255)² and (0.03 · 255)².
+    private static let c1 = 6.5025
+    private static let c2 = 58.5225
+
+    static func compare(
+        _ baseline: CGImage,
+        _ candidate: CGImage,
+        channel: DiffChannel,
+        algorithm: DiffAlgorithm = .ssim
+    ) throws -> ImageDiffResult {
+        guard baseline.width == candidate.width, baseline.height == candidate.height else {
+            throw ValidationError(
+                "Image sizes differ: baseline is \(baseline.width)×\(baseline.height), " +
+                    "candidate is \(candidate.width)×\(candidate.height)"
+            )
+        }
+
+        let width = baseline.width
+        let height = baseline.height
+        let baselinePlanes = try Self.planes(of: CapturedImageIO.rgbaBytes(of: baseline), channel: channel)
+        let candidatePlanes = try Self.planes(of: CapturedImageIO.rgbaBytes(of: candidate), channel: channel)
+
+        var mask = [Bool](repeating: false, count: width * height)
+        for (lhs, rhs) in zip(baselinePlanes, candidatePlanes) {
+            for index in mask.indices where lhs[index] != rhs[index] {
+                mask[index] = true
+            }
+        }
+
+        let differ = algorithm.differ
+        let scores = zip(baselinePlanes, candidatePlanes).map { lhs, rhs in
+            differ.similarity(lhs, rhs, width: width, height: height)
+        }
+
+        return ImageDiffResult(
+            score: scores.reduce(0, +) / Double(scores.count),
+            changedPixels: mask.count(where: { $0 }),
+            totalPixels: width * height,
+            changedBounds: Self.bounds(of: mask, width: width),
+            changeMask: mask
+        )
+    }
+
+    /// Mean SSIM over `windowSize` windows sliding by `windowStride`; images smaller than a window use one window.
+    static func ssim(_ lhs: [UInt8], _ rhs: [UInt8], width: Int, height: Int) -> Double {
+        let windowWidth = min(Self.windowSize, width)
+        let windowHeight = min(Self.windowSize, height)
+        let xs = Self.windowOrigins(extent: width, window: windowWidth)
+        let ys = Self.windowOrigins(extent: height, window: windowHeight)
+        let count = Double(windowWidth * windowHeight)
+
+        var total = 0.0
+        for originY in ys {
+            for originX in xs {
+                var sumL = 0.0, sumR = 0.0, sumLL = 0.0, sumRR = 0.0, sumLR = 0.0
+                for y in originY..<(originY + windowHeight) {
+                    let row = y * width
+                    for x in originX..<(originX + windowWidth) {
+                        let l = Double(lhs[row + x])
+                        let r = Double(rhs[row + x])
+                        sumL += l
+                        sumR += r
+                        sumLL += l * l
+                        sumRR += r * r
+                        sumLR += l * r
+                    }
+                }
+
+                let meanL = sumL / count
+                let meanR = sumR / count
+                let varianceL = sumLL / count - meanL * meanL
+                let varianceR = sumRR / count - meanR * meanR
+                let covariance = sumLR / count - meanL * meanR
+
+                let numerator = (2 * meanL * meanR + Self.c1) * (2 * covariance + Self.c2)
+                let denominator = (meanL * meanL + meanR * meanR + Self.c1) * (varianceL + varianceR + Self.c2)
+                total += numerator / denominator
+            }
+        }
+        return total / Double(xs.count * ys.count)
+    }
+
+    /// Copy of `image` with every masked pixel blended toward `color`.
+    static func highlight(_ image: CGImage, mask: [Bool], color: HexColor) throws -> CGImage {
+        var bytes = try CapturedImageIO.rgbaBytes(of: image)
+        let overlay = [color.red, color.green, color.blue].map { $0 * 255 }
+
+        for (index, changed) in mask.enumerated() where changed {
+            let offset = index * 4
+            for component in 0..<3 {
+                let original = CGFloat(bytes[offset + component])
+                bytes[offset + component] = UInt8((overlay[component] * color.alpha + original * (1 - color.alpha))
+                    .rounded())
+            }
+            bytes[offset + 3] = 255
+        }
+
+        return try CapturedImageIO.makeImage(rgbaBytes: bytes, width: image.width, height: image.height)
+    }
+
+    private static func planes(of rgba: [UInt8], channel: DiffChannel) -> [[UInt8]] {
+        let pixelCount = rgba.count / 4
+        switch channel {
+        case .luminance:
+            return [(0..<pixelCount).map { index in
+                let offset = index * 4
+                let luma = 0.299 * Double(rgba[offset]) + 0.587 * Double(rgba[offset + 1]) +
+                    0.114 * Double(rgba[offset + 2])
+                return UInt8(luma.rounded())
+            }]
+        case .rgb:
+            return (0..<3).map { component in
+                (0..<pixelCount).map { rgba[$0 * 4 + component] }
+            }
+        case .alpha:
+            return [(0..<pixelCount).map { rgba[$0 * 4 + 3] }]
+        }
+    }
+
+    private static func windowOrigins(extent: Int, window: Int) -> [Int] {
+        var origins = Array(stride(from: 0, through: extent - window, by: Self.windowStride))
+        // Cover the trailing edge when the extent isn't a multiple of the stride.
+        if let last = origins.last, last < extent - window {
+            origins.append(extent - window)
+        }
+        return origins
+    }
+
+    private static func bounds(of mask: [Bool], width: Int) -> WindowBounds? {
+        var minX = Int.max, minY = Int.max, maxX = -1, maxY = -1
+        for (index, changed) in mask.enumerated() where changed {
+            let x = index % width
+            let y = index / width
+            minX = min(minX, x)
+            minY = min(minY, y)
+            maxX = max(maxX, x)
+            maxY = max(maxY, y)
+        }
+        guard maxX >= 0 else { return nil }
+        return WindowBounds(x: minX, y: minY, width: maxX - minX + 1, height: maxY - minY + 1)
+    }
+}
@@ -0,0 +1,54 @@
+import Commander
+
+extension DiffCommand: CommanderSignatureProviding {
+    static func commanderSignature() -> CommandSignature {
+        CommandSignature(
+            arguments: [
+                .make(
+                    label: "baseline",
+                    help: "Baseline image (PNG or JPEG)",
+                    isOptional: false
+                ),
+                .make(
+                    label: "candidate",
+                    help: "Candidate image to compare against the baseline",
+                    isOptional: false
+                ),
+            ],
+            options: [
+                .commandOption(
+                    "ssimThreshold",
+                    help: "Minimum score (0-1) that still counts as unchanged (default 0.99)",
+                    long: "ssim-threshold"
+                ),
+                .commandOption(
+                    "diffChannel",
+                    help: "Channels to compare: luminance|rgb|alpha",
+                    long: "diff-channel"
+                ),
+                .commandOption(
+                    "algorithm",
+                    help: "Similarity algorithm: ssim|pixelwise|phash|histogram",
+                    long: "algorithm"
+                ),
+                .commandOption(
+                    "outputPath",
+                    help: "Write the candidate with changed pixels highlighted to this path",
+                    long: "output"
+                ),
+                .commandOption(
+                    "highlightColor",
+                    help: "Highlight color for --output (#RRGGBB or #RRGGBBAA)",
+                    long: "highlight-color"
+                ),
+            ],
+            flags: [
+                .commandFlag(
+                    "failOnChange",
+                    help: "Exit with code 1 when the score falls below --ssim-threshold",
+                    long: "fail-on-change"
+                ),
+            ]
+        )
+    }
+}
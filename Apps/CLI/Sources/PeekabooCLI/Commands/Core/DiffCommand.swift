@@ -0,0 +1,199 @@
+import Commander
+import Foundation
+import PeekabooCore
+
+/// Compares two screenshots (SSIM by default) so CI can gate on visual regressions.
+@MainActor
+struct DiffCommand: ErrorHandlingCommand, OutputFormattable, RuntimeOptionsConfigurable {
+    static let defaultThreshold = 0.99
+    static let defaultHighlightColor = "#FF00FF"
+
+    @Argument(help: "Baseline image (PNG or JPEG)")
+    var baseline: String
+
+    @Argument(help: "Candidate image to compare against the baseline")
+    var candidate: String
+
+    @Option(name: .long, help: "Minimum score (0-1) that still counts as unchanged (default 0.99)")
+    var ssimThreshold: Double?
+
+    @Flag(name: .long, help: "Exit with code 1 when the score falls below --ssim-threshold")
+    var failOnChange = false
+
+    @Option(name: .long, help: "Similarity algorithm: ssim|pixelwise|phash|histogram (default ssim)")
+    var algorithm: DiffAlgorithm?
+
+    @Option(name: .long, help: "Channels to compare: luminance|rgb|alpha (default luminance)")
+    var diffChannel: DiffChannel?
+
+    @Option(name: .customLong("output"), help: "Write the candidate with changed pixels highlighted to this path")
+    var outputPath: String?
+
+    @Option(name: .long, help: "Highlight color for --output as #RRGGBB or #RRGGBBAA (default #FF00FF)")
+    var highlightColor: String?
+
+    @RuntimeStorage private var runtime: CommandRuntime?
+    var runtimeOptions = CommandRuntimeOptions()
+
+    private var resolvedRuntime: CommandRuntime {
+        guard let runtime else {
+            preconditionFailure("CommandRuntime must be configured before accessing runtime resources")
+        }
+        return runtime
+    }
+
+    private var logger: Logger {
+        self.resolvedRuntime.logger
+    }
+
+    var outputLogger: Logger {
+        self.logger
+    }
+
+    var jsonOutput: Bool {
+        self.runtime?.configuration.jsonOutput ?? self.runtimeOptions.jsonOutput
+    }
+
+    mutating func run(using runtime: CommandRuntime) async throws {
+        self.runtime = runtime
+        self.logger.setJsonOutputMode(self.jsonOutput)
+
+        let result: DiffCommandResult
+        do {
+            result = try self.compare()
+        } catch {
+            self.handleError(error)
+            throw self.exitCode(for: error)
+        }
+
+        self.output(result) {
+            let verdict = result.passed ? "✅ Unchanged" : "❌ Changed"
+            let score = String(format: "%.4f", result.score)
+            print("\(verdict): \(result.algorithm.displayName) \(score) (threshold \(result.threshold))")
+            print("   Changed pixels: \(result.changed_pixels) of \(result.total_pixels)")
+            if let box = result.bounding_box_of_changes {
+                print("   Changes within: x=\(box.x) y=\(box.y) \(box.width)×\(box.height)")
+            }
+            if let path = result.diff_path {
+                print("   Highlight: \(path)")
+            }
+        }
+
+        if self.failOnChange, !result.passed {
+            throw ExitCode(1)
+        }
+    }
+
+    func compare() throws -> DiffCommandResult {
+        let threshold = try self.resolvedThreshold()
+        let highlight = try self.resolvedHighlightColor()
+        let channel = self.diffChannel ?? .luminance
+        let algorithm = self.algorithm ?? .ssim
+
+        let baselineImage = try CapturedImageIO.load(at: Self.expand(self.baseline))
+        let candidateImage = try CapturedImageIO.load(at: Self.expand(self.candidate))
+        let diff = try ImageDiff.compare(baselineImage, candidateImage, channel: channel, algorithm: algorithm)
+
+        var diffPath: String?
+        if let outputPath = self.outputPath {
+            let url = URL(fileURLWithPath: Self.expand(outputPath))
+            let format = PeekabooCore.ImageFormat(path: url.path) ?? .png
+            let highlighted = try ImageDiff.highlight(candidateImage, mask: diff.changeMask, color: highlight)
+            try CapturedImageIO.write(highlighted, to: url, format: format)
+            diffPath = url.path
+        }
+
+        return DiffCommandResult(
+            baseline: Self.expand(self.baseline),
+            candidate: Self.expand(self.candidate),
+            channel: channel,
+            algorithm: algorithm,
+            score: diff.score,
+            ssim: algorithm == .ssim ? diff.score : nil,
+            threshold: threshold,
+            passed: diff.score >= threshold,
+            changed_pixels: diff.changedPixels,
+            total_pixels: diff.totalPixels,
+            bounding_box_of_changes: diff.changedBounds,
+            diff_path: diffPath
+        )
+    }
+
+    private func resolvedThreshold() throws -> Double {
+        let threshold = self.ssimThreshold ?? Self.defaultThreshold
+        guard (0...1).contains(threshold) else {
+            throw ValidationError("--ssim-threshold must be between 0 and 1")
+        }
+        return threshold
+    }
+
+    private func resolvedHighlightColor() throws -> HexColor {
+        if self.highlightColor != nil, self.outputPath == nil {
+            throw ValidationError("--highlight-color requires --output")
+        }
+        let raw = self.highlightColor ?? Self.defaultHighlightColor
+        guard let color = HexColor(hex: raw) else {
+            throw ValidationError("--highlight-color must be #RRGGBB or #RRGGBBAA")
+        }
+        return color
+    }
+
+    private static func expand(_ path: String) -> String {
+        (path as NSString).expandingTildeInPath
+    }
+}
+
+struct DiffCommandResult: Codable {
+    let baseline: String
+    let candidate: String
+    let channel: DiffChannel
+    let algorithm: DiffAlgorithm
+    let score: Double
+    /// Same as `score` for `--algorithm ssim`; kept so existing consumers keep reading `ssim`.
+    let ssim: Double?
+    let threshold: Double
+    let passed: Bool
+    let changed_pixels: Int
+    let total_pixels: Int
+    let bounding_box_of_changes: WindowBounds?
+    let diff_path: String?
+}
+
+@MainActor
+extension DiffCommand: ParsableCommand {
+    nonisolated(unsafe) static var commandDescription: CommandDescription {
+        MainActorCommandDescription.describe {
+            CommandDescription(
+                commandName: "diff",
+                abstract: "Compare two screenshots for visual regressions",
+                discussion: """
+                Scores the candidate against the baseline with SSIM over 8×8 windows, or with
+                --algorithm pixelwise|phash|histogram.
+
+                Examples:
+                  peekaboo diff baseline.png current.png
+                  peekaboo diff baseline.png current.png --fail-on-change --ssim-threshold 0.995
+                  peekaboo diff a.png b.png --diff-channel rgb --output /tmp/changes.png --json
+                  peekaboo diff a.png b.jpg --algorithm phash --ssim-threshold 0.9
+                """,
+                showHelpOnEmptyInvocation: true
+            )
+        }
+    }
+}
+
+extension DiffCommand: AsyncRuntimeCommand {}
+
+@MainActor
+extension DiffCommand: CommanderBindableCommand {
+    mutating func applyCommanderValues(_ values: CommanderBindableValues) throws {
+        self.baseline = try values.requiredPositional(0, label: "baseline")
+        self.candidate = try values.requiredPositional(1, label: "candidate")
+        self.ssimThreshold = try values.decodeOption("ssimThreshold", as: Double.self)
+        self.failOnChange = values.flag("failOnChange")
+        self.diffChannel = try values.decodeOptionEnum("diffChannel")
+        self.algorithm = try values.decodeOptionEnum("algorithm")
+        self.outputPath = values.singleOption("outputPath")
+        self.highlightColor = values.singleOption("highlightColor")
+    }
+}
@@ -0,0 +1,225 @@
+import Commander
+import CoreGraphics
+import CoreText
+import Foundation
+import PeekabooCore
+
+/// One `--annotate` overlay, positioned in image pixels with a top-left origin.
+enum ImageAnnotation: Equatable {
+    case text(origin: CGPoint, text: String, color: HexColor, size: CGFloat)
+    case arrow(from: CGPoint, to: CGPoint, color: HexColor, lineWidth: CGFloat)
+    case rect(CGRect, color: HexColor, fill: Bool, lineWidth: CGFloat)
+
+    static let defaultColor = "#FF0000"
+    static let defaultLineWidth: CGFloat = 3
+}
+
+extension ImageAnnotation: Decodable {
+    private enum CodingKeys: String, CodingKey {
+        case type, x, y, x1, y1, x2, y2, w, h, text, color, size, fill, width
+    }
+
+    init(from decoder: any Decoder) throws {
+        let container = try decoder.container(keyedBy: CodingKeys.self)
+        let type = try container.decode(String.self, forKey: .type)
+
+        let rawColor = try container.decodeIfPresent(String.self, forKey: .color) ?? Self.defaultColor
+        guard let color = HexColor(hex: rawColor) else {
+            throw DecodingError.dataCorruptedError(
+                forKey: .color,
+                in: container,
+                debugDescription: "color must be #RRGGBB or #RRGGBBAA (got '\(rawColor)')"
+            )
+        }
+
+        let lineWidth = try container.decodeIfPresent(CGFloat.self, forKey: .width) ?? Self.defaultLineWidth
+        guard lineWidth > 0 else {
+            throw DecodingError.dataCorruptedError(
+                forKey: .width,
+                in: container,
+                debugDescription: "width must be greater than zero"
+            )
+        }
+
+        switch type {
+        case "text":
+            let text = try container.decode(String.self, forKey: .text)
+            let size = try container.decodeIfPresent(CGFloat.self, forKey: .size) ?? 14
+            guard !text.isEmpty, size > 0 else {
+                throw DecodingError.dataCorruptedError(
+                    forKey: .text,
+                    in: container,
+                    debugDescription: "text annotations need non-empty text and a positive size"
+                )
+            }
+            self = try .text(
+                origin: CGPoint(
+                    x: container.decode(CGFloat.self, forKey: .x),
+                    y: container.decode(CGFloat.self, forKey: .y)
+                ),
+                text: text,
+                color: color,
+                size: size
+            )
+        case "arrow":
+            self = try .arrow(
+                from: CGPoint(
+                    x: container.decode(CGFloat.self, forKey: .x1),
+                    y: container.decode(CGFloat.self, forKey: .y1)
+                ),
+                to: CGPoint(
+                    x: container.decode(CGFloat.self, forKey: .x2),
+                    y: container.decode(CGFloat.self, forKey: .y2)
+                ),
+                color: color,
+                lineWidth: lineWidth
+            )
+        case "rect":
+            let rect = try CGRect(
+                x: container.decode(CGFloat.self, forKey: .x),
+                y: container.decode(CGFloat.self, forKey: .y),
+                width: container.decode(CGFloat.self, forKey: .w),
+                height: container.decode(CGFloat.self, forKey: .h)
+            )
+            guard rect.width > 0, rect.height > 0 else {
+                throw DecodingError.dataCorruptedError(
+                    forKey: .w,
+                    in: container,
+                    debugDescription: "rect annotations need positive w and h"
+                )
+            }
+            self = try .rect(
+                rect,
+                color: color,
+                fill: container.decodeIfPresent(Bool.self, forKey: .fill) ?? false,
+                lineWidth: lineWidth
+            )
+        default:
+            throw DecodingError.dataCorruptedError(
+                forKey: .type,
+                in: container,
+                debugDescription: "unknown annotation type '\(type)' (expected text, arrow, or rect)"
+            )
+        }
+    }
+}
+
+enum ImageAnnotationParser {
+    static func parse(_ json: String) throws -> [ImageAnnotation] {
+        do {
+            return try JSONDecoder().decode([ImageAnnotation].self, from: Data(json.utf8))
+        } catch let error as DecodingError {
+            throw ValidationError("Invalid --annotate JSON: \(Self.describe(error))")
+        }
+    }
+
+    private static func describe(_ error: DecodingError) -> String {
+        let context: DecodingError.Context? = switch error {
+        case let .dataCorrupted(context),
+             let .keyNotFound(_, context),
+             let .typeMismatch(_, context),
+             let .valueNotFound(_, context):
+            context
+        @unknown default:
+            nil
+        }
+        guard let context else {
+            return error.localizedDescription
+        }
+
+        let path = context.codingPath.map { $0.intValue.map { "[\($0)]" } ?? ".\($0.stringValue)" }.joined()
+        return path.isEmpty ? context.debugDescription : "\(path): \(context.debugDescription)"
+    }
+}
+
+enum ImageAnnotationRenderer {
+    static func render(_ annotations: [ImageAnnotation], onto image: CGImage) throws -> CGImage {
+        let context = try CapturedImageIO.makeContext(width: image.width, height: image.height)
+        let height = CGFloat(image.height)
+        context.draw(image, in: CGRect(x: 0, y: 0, width: image.width, height: image.height))
+
+        // Annotation coordinates use the screenshot's top-left origin; CoreGraphics draws bottom-up.
+        func flipped(_ point: CGPoint) -> CGPoint {
+            CGPoint(x: point.x, y: height - point.y)
+        }
+
+        for annotation in annotations {
+            switch annotation {
+            case let .text(origin, text, color, size):
+                let font = CTFontCreateWithName("Helvetica-Bold" as CFString, size, nil)
+                let attributes: [NSAttributedString.Key: Any] = [
+                    NSAttributedString.Key(kCTFontAttributeName as String): font,
+                    NSAttributedString.Key(kCTForegroundColorAttributeName as String): color.cgColor,
+                ]
+                let line = CTLineCreateWithAttributedString(NSAttributedString(string: text, attributes: attributes))
+                context.textPosition = CGPoint(x: origin.x, y: height - origin.y - CTFontGetAscent(font))
+                CTLineDraw(line, context)
+            case let .arrow(from, to, color, lineWidth):
+                self.drawArrow(from: flipped(from), to: flipped(to), color: color, lineWidth: lineWidth, in: context)
+            case let .rect(rect, color, fill, lineWidth):
+                let drawRect = CGRect(x: rect.minX, y: height - rect.maxY, width: rect.width, height: rect.height)
+                if fill {
+                    context.setFillColor(color.cgColor)
+                    context.fill(drawRect)
+                } else {
+                    context.setStrokeColor(color.cgColor)
+                    context.setLineWidth(lineWidth)
+                    context.stroke(drawRect.insetBy(dx: lineWidth / 2, dy: lineWidth / 2))
+                }
+            }
+        }
+
+        guard let annotated = context.makeImage() else {
+            throw CaptureError.captureFailure("Failed to render annotations")
+        }
+        return annotated
+    }
+
+    private static func drawArrow(
+        from start: CGPoint,
+        to end: CGPoint,
+        color: HexColor,
+        lineWidth: CGFloat,
+        in context: CGContext
+    ) {
+        let angle = atan2(end.y - start.y, end.x - start.x)
+        let headLength = max(12, lineWidth * 4)
+        let headAngle = CGFloat.pi / 7
+        // Stop the shaft at the arrowhead's base so the tip stays sharp for thick lines.
+        let shaftEnd = CGPoint(
+            x: end.x - cos(angle) * headLength * 0.8,
+            y: end.y - sin(angle) * headLength * 0.8
+        )
+
+        context.setStrokeColor(color.cgColor)
+        context.setFillColor(color.cgColor)
+        context.setLineWidth(lineWidth)
+        context.setLineCap(.round)
+        context.move(to: start)
+        context.addLine(to: shaftEnd)
+        context.strokePath()
+
+        context.move(to: end)
+        context.addLine(to: CGPoint(
+            x: end.x - cos(angle - headAngle) * headLength,
+            y: end.y - sin(angle - headAngle) * headLength
+        ))
+        context.addLine(to: CGPoint(
+            x: end.x - cos(angle + headAngle) * headLength,
+            y: end.y - sin(angle + headAngle) * headLength
+        ))
+        context.closePath()
+        context.fillPath()
+    }
+}
+
+@MainActor
+extension ImageCommand {
+    /// Parses `--annotate` up front so malformed JSON fails before anything is captured.
+    func parsedAnnotations() throws -> [ImageAnnotation] {
+        guard let raw = self.annotate?.trimmingCharacters(in: .whitespacesAndNewlines), !raw.isEmpty else {
+            return []
+        }
+        return try ImageAnnotationParser.parse(raw)
+    }
+}
@@ -1,3 +1,4 @@
+import CoreGraphics
 import Foundation
 import PeekabooCore
 import PeekabooFoundation
@@ -7,13 +8,15 @@ extension ImageCommand {
     func capturedFile(
         from observation: DesktopObservationResult,
         preferredName: String?,
-        windowIndex: Int?
+        windowIndex: Int?,
+        paddedWindow: PaddedWindowCapture? = nil
     ) throws -> ImageCapturedFile {
         try ImageCapturedFile(
             file: self.savedFile(
                 from: observation,
                 preferredName: preferredName,
-                windowIndex: windowIndex
+                windowIndex: windowIndex,
+                paddedWindow: paddedWindow
             ),
             observation: ImageObservationDiagnostics(
                 timings: observation.timings,
@@ -30,7 +33,7 @@ extension ImageCommand {
                 .appendingPathExtension(self.format.fileExtension)
         }
 
-        if let explicit = self.path {
+        if let explicit = self.path ?? EnvironmentConfig.outputPath() {
             let expanded = (explicit as NSString).expandingTildeInPath
             if ObservationOutputPathResolver.isDirectoryLike(expanded) {
                 return URL(fileURLWithPath: expanded, isDirectory: true)
@@ -61,11 +64,35 @@ extension ImageCommand {
     private func savedFile(
         from observation: DesktopObservationResult,
         preferredName: String?,
-        windowIndex: Int?
+        windowIndex: Int?,
+        paddedWindow: PaddedWindowCapture?
     ) throws -> SavedFile {
         guard let path = observation.files.rawScreenshotPath else {
             throw CaptureError.captureFailure("Observation completed without a saved screenshot path")
         }
+        if let window = paddedWindow?.window ?? observation.capture.metadata.windowInfo {
+            self.warnIfPartiallyOffscreen(window)
+        }
+
+        if let paddedWindow {
+            // Area captures carry no window metadata, so report the window the padding was cut around.
+            let window = paddedWindow.window
+            return SavedFile(
+                path: path,
+                item_label: preferredName ?? window.title,
+                window_title: window.title,
+                window_id: UInt32(window.windowID),
+                window_index: windowIndex ?? window.index,
+                mime_type: self.format.mimeType,
+                padded_bounds: WindowBounds(
+                    x: Int(paddedWindow.bounds.minX.rounded()),
+                    y: Int(paddedWindow.bounds.minY.rounded()),
+                    width: Int(paddedWindow.bounds.width.rounded()),
+                    height: Int(paddedWindow.bounds.height.rounded())
+                ),
+                window_was_offscreen: Self.offscreenMarker(for: window)
+            )
+        }
 
         let windowInfo = observation.capture.metadata.windowInfo
         return SavedFile(
@@ -74,7 +101,34 @@ extension ImageCommand {
             window_title: windowInfo?.title,
             window_id: windowInfo.map { UInt32($0.windowID) },
             window_index: windowIndex ?? windowInfo?.index,
-            mime_type: self.format.mimeType
+            mime_type: self.format.mimeType,
+            window_was_offscreen: windowInfo.flatMap(Self.offscreenMarker(for:))
+        )
+    }
+
+    /// Only `--window-state any` and `--window-id` reach off-screen windows; on-screen captures omit the key.
+    static func offscreenMarker(for window: ServiceWindowInfo) -> Bool? {
+        window.isOnScreen && !window.isOffScreen ? nil : true
+    }
+
+    /// True when some but not all of `bounds` lies on a display. Windows with no visible part are reported by
+    /// `window_was_offscreen` instead.
+    static func isPartiallyOffscreen(_ bounds: CGRect, displays: [CGRect]) -> Bool {
+        let area = bounds.width * bounds.height
+        guard area > 0 else { return false }
+        let visible = displays.reduce(CGFloat(0)) { total, display in
+            let overlap = bounds.intersection(display)
+            return overlap.isNull ? total : total + overlap.width * overlap.height
+        }
+        return visible > 0 && visible < area
+    }
+
+    private func warnIfPartiallyOffscreen(_ window: ServiceWindowInfo) {
+        let displays = self.services.screens.listScreens().map { CGDisplayBounds($0.displayID) }
+        guard Self.isPartiallyOffscreen(window.bounds, displays: displays) else { return }
+        self.outputLogger.warn(
+            .WINDOW_PARTIALLY_OFFSCREEN,
+            "Window '\(window.title)' extends past the edge of the screen; the capture is cut off"
         )
     }
 
@@ -82,9 +136,9 @@ extension ImageCommand {
         let timestamp = Self.imageFilenameDateFormatter.string(from: Date())
         var components: [String] = []
         if let preferred = preferredName {
-            components.append(self.sanitizeFilenameComponent(preferred))
+            components.append(Self.sanitizeFilenameComponent(preferred))
         } else if let appName = self.app {
-            components.append(self.sanitizeFilenameComponent(appName))
+            components.append(Self.sanitizeFilenameComponent(appName))
         } else if let mode = self.mode {
             components.append(mode.rawValue)
         } else {
@@ -98,7 +152,7 @@ extension ImageCommand {
         return components.joined(separator: "_") + ".\(self.format.fileExtension)"
     }
 
-    private func sanitizeFilenameComponent(_ value: String) -> String {
+    static func sanitizeFilenameComponent(_ value: String) -> String {
         let allowed = CharacterSet.alphanumerics.union(CharacterSet(charactersIn: "-_"))
         return value
             .components(separatedBy: allowed.inverted)
@@ -8,12 +8,22 @@ import PeekabooFoundation
 @MainActor
 extension ImageCommand {
     func performCapture() async throws -> [ImageCapturedFile] {
+        try await withErrorContext("perform_capture") {
+            try await self.captureForResolvedMode()
+        }
+    }
+
+    private func captureForResolvedMode() async throws -> [ImageCapturedFile] {
+        if let stitchItems {
+            let items = try ImageStitchItem.parseList(stitchItems)
+            return try await withErrorContext("capture_stitched") { try await self.captureStitched(items) }
+        }
         if let appName = self.app?.lowercased() {
             switch appName {
             case "menubar":
-                return try await self.captureMenuBar()
+                return try await withErrorContext("capture_menubar") { try await self.captureMenuBar() }
             case "frontmost":
-                return try await self.captureFrontmost()
+                return try await withErrorContext("capture_frontmost") { try await self.captureFrontmost() }
             default:
                 break
             }
@@ -24,35 +34,52 @@ extension ImageCommand {
 
         switch captureMode {
         case .screen:
-            results = try await self.captureScreens()
+            results = try await withErrorContext("capture_screens") { try await self.captureScreens() }
         case .window:
             if let windowId = self.windowId {
-                results = try await self.captureWindowById(windowId)
+                results = try await withErrorContext("capture_window_by_id") {
+                    try await self.captureWindowById(windowId)
+                }
             } else {
-                let target = try self.observationApplicationTargetForWindowCapture()
-                results = try await self.captureApplicationWindow(target)
+                let target = try await withErrorContext("resolve_window_target") {
+                    try await self.observationApplicationTargetForWindowCapture()
+                }
+                results = try await withErrorContext("capture_application_window") {
+                    try await self.captureApplicationWindow(target)
+                }
             }
         case .multi:
             if self.app != nil || self.pid != nil {
-                let identifier = try self.resolveApplicationIdentifier()
-                results = try await self.captureAllApplicationWindows(identifier)
+                let identifier = try await withErrorContext("resolve_application") {
+                    try await self.resolveApplicationIdentifier(
+                        caseSensitive: self.caseSensitive,
+                        services: self.services
+                    )
+                }
+                results = try await withErrorContext("capture_all_application_windows") {
+                    try await self.captureAllApplicationWindows(identifier)
+                }
             } else {
-                results = try await self.captureScreens()
+                results = try await withErrorContext("capture_screens") { try await self.captureScreens() }
             }
         case .frontmost:
-            results = try await self.captureFrontmost()
+            results = try await withErrorContext("capture_frontmost") { try await self.captureFrontmost() }
         case .area:
-            results = try await self.captureArea()
+            results = try await withErrorContext("capture_area") { try await self.captureArea() }
         }
 
         return results
     }
 
-    private func determineMode() -> PeekabooCore.CaptureMode {
+    func determineMode() -> PeekabooCore.CaptureMode {
         if let mode {
             return mode
         }
 
+        if self.mergeDisplays {
+            return .screen
+        }
+
         if self.region != nil {
             return .area
         }
@@ -66,6 +93,10 @@ extension ImageCommand {
     }
 
     private func captureWindowById(_ windowId: Int) async throws -> [ImageCapturedFile] {
+        if self.padsWindowCaptures {
+            return try await [self.capturePaddedWindow(id: windowId)]
+        }
+
         let observation = try await self.captureObservation(
             target: .windowID(CGWindowID(windowId)),
             preferredName: "window-\(windowId)",
@@ -89,7 +120,8 @@ extension ImageCommand {
     }
 
     private func captureScreens() async throws -> [ImageCapturedFile] {
-        if let index = self.screenIndex {
+        try await self.waitForHotpluggedDisplays()
+        if let index = try self.resolvedScreenIndex() {
             let observation = try await self.captureObservation(
                 target: .screen(index: index),
                 preferredName: "screen\(index)",
@@ -105,6 +137,10 @@ extension ImageCommand {
         }
 
         let screens = self.services.screens.listScreens()
+        if self.mergeDisplays, screens.count > 1 {
+            return try await self.captureMergedScreens(screens)
+        }
+
         let indexes = screens.isEmpty ? [0] : Array(screens.indices)
 
         var savedFiles: [ImageCapturedFile] = []
@@ -126,6 +162,37 @@ extension ImageCommand {
 
     private func captureApplicationWindow(_ target: ImageWindowObservationTarget) async throws -> [ImageCapturedFile] {
         try await self.focusIfNeeded(appIdentifier: target.focusIdentifier)
+        try await self.restoreCachedLayout(appIdentifier: target.focusIdentifier)
+        try await self.sendPreCaptureKeys()
+        let focusConfirmed = try await self.confirmWindowFocusIfRequested(appIdentifier: target.focusIdentifier)
+        return try await self.captureFocusedApplicationWindow(target)
+            .map { $0.with(focusConfirmed: focusConfirmed) }
+    }
+
+    private func captureFocusedApplicationWindow(
+        _ target: ImageWindowObservationTarget
+    ) async throws -> [ImageCapturedFile] {
+        if self.windowState == .any || self.padsWindowCaptures || (self.caseSensitive && self.windowTitle != nil) {
+            // The observation resolver only considers on-screen windows, matches titles case-insensitively,
+            // and needs the bounds for padding, so resolve here and capture by ID (or by padded area).
+            let windows = try await self.windowsForCapture(appIdentifier: target.focusIdentifier)
+            guard let window = Self.selectWindow(
+                from: windows,
+                title: self.windowTitle,
+                index: self.windowIndex,
+                caseSensitive: self.caseSensitive
+            ) else {
+                throw PeekabooError.windowNotFound(criteria: "No windows for \(target.focusIdentifier)")
+            }
+            if self.padsWindowCaptures {
+                return try await [self.capturePaddedWindow(window, index: nil)]
+            }
+            return try await self.captureWindowById(window.windowID)
+        }
+        if self.windowState == .waitVisible {
+            _ = try await self.waitForVisibleWindows(appIdentifier: target.focusIdentifier)
+        }
+
         let observation = try await self.captureObservation(
             target: target.target,
             preferredName: target.preferredName,
@@ -146,35 +213,94 @@ extension ImageCommand {
 
     private func captureAllApplicationWindows(_ identifier: String) async throws -> [ImageCapturedFile] {
         try await self.focusIfNeeded(appIdentifier: identifier)
+        try await self.restoreCachedLayout(appIdentifier: identifier)
+        try await self.sendPreCaptureKeys()
+        let focusConfirmed = try await self.confirmWindowFocusIfRequested(appIdentifier: identifier)
+        return try await self.captureFocusedApplicationWindows(identifier)
+            .map { $0.with(focusConfirmed: focusConfirmed) }
+    }
 
-        let windows = try await WindowServiceBridge.listWindows(
-            windows: self.services.windows,
-            target: .application(identifier)
-        )
-
-        let filtered = ObservationTargetResolver.captureCandidates(from: windows)
+    private func captureFocusedApplicationWindows(_ identifier: String) async throws -> [ImageCapturedFile] {
+        let filtered = try await self.windowsForCapture(appIdentifier: identifier)
 
         guard !filtered.isEmpty else {
             throw PeekabooError.windowNotFound(criteria: "No shareable windows for \(identifier)")
         }
 
+        self.windowCaptureSignals.start()
+        defer { self.windowCaptureSignals.stop() }
+
         var savedFiles: [ImageCapturedFile] = []
+        var failures: [any Error] = []
         for (ordinal, window) in filtered.indexed() {
-            let observation = try await self.captureObservation(
-                target: .windowID(CGWindowID(window.windowID)),
-                preferredName: window.title,
-                index: ordinal
-            )
+            // A signal lets the window in progress finish, then ends the run with what was saved.
+            if self.windowCaptureSignals.receivedSignal != nil { break }
+            do {
+                try await savedFiles.append(self.captureListedWindow(window, index: ordinal))
+            } catch is CancellationError {
+                throw CancellationError()
+            } catch {
+                // Keep going so one stuck window doesn't hide what happened to the others.
+                self.outputLogger.warn(
+                    .WINDOW_CAPTURE_SKIPPED,
+                    "Capturing window '\(window.title)' failed: \(error.localizedDescription)"
+                )
+                failures.append(error)
+            }
+        }
 
-            let saved = try self.capturedFile(
-                from: observation,
-                preferredName: window.title,
-                windowIndex: window.index
+        if !failures.isEmpty, !savedFiles.isEmpty {
+            let paths = savedFiles.map(\.file.path).joined(separator: ", ")
+            self.outputLogger.warn(
+                .PARTIAL_CAPTURE_SAVED,
+                "Kept \(savedFiles.count) of \(filtered.count) windows despite the failure: \(paths)"
             )
-            savedFiles.append(saved)
         }
+        return try Self.completedWindowCaptures(savedFiles, failures: failures)
+    }
 
-        return savedFiles
+    /// Returns `saved` when no window failed. Otherwise throws the failure, or `MULTIPLE_ERRORS` for two or more;
+    /// the windows that were saved stay on disk.
+    static func completedWindowCaptures(
+        _ saved: [ImageCapturedFile],
+        failures: [any Error]
+    ) throws -> [ImageCapturedFile] {
+        guard !failures.isEmpty else { return saved }
+        if failures.count == 1, let failure = failures.first {
+            throw failure
+        }
+        let errors: [PeekabooError] = failures.map { failure in
+            switch failure.withoutErrorContext {
+            case let peekabooError as PeekabooError:
+                peekabooError
+            case let captureError as CaptureError:
+                captureError.asPeekabooError
+            case let other:
+                .captureFailed(other.localizedDescription)
+            }
+        }
+        throw PeekabooError.combining(errors) ?? .captureFailed("Window capture failed")
+    }
+
+    private func captureListedWindow(
+        _ window: ServiceWindowInfo,
+        index ordinal: Int
+    ) async throws -> ImageCapturedFile {
+        if self.padsWindowCaptures {
+            return try await self.capturePaddedWindow(window, index: ordinal)
+        }
+
+        let observation = try await self.captureObservation(
+            target: .windowID(CGWindowID(window.windowID)),
+            preferredName: window.title,
+            index: ordinal
+        )
+
+        return try self.capturedFile(
+            from: observation,
+            preferredName: window.title,
+            windowIndex: window.index
+        )
     }
 
     private func captureFrontmost() async throws -> [ImageCapturedFile] {
@@ -215,23 +341,31 @@ extension ImageCommand {
             throw ValidationError("Region must be provided when using --mode area")
         }
 
-        let values = region
-            .split(separator: ",", omittingEmptySubsequences: false)
-            .map { $0.trimmingCharacters(in: .whitespacesAndNewlines) }
-        guard values.count == 4,
-              let x = Double(values[0]),
-              let y = Double(values[1]),
-              let width = Double(values[2]),
-              let height = Double(values[3])
-        else {
-            throw ValidationError("Region must be x,y,width,height")
+        let rect: CGRect
+        if let bounds = try? WindowBounds(parsing: region) {
+            rect = bounds.cgRect
+        } else {
+            // WindowBounds is whole points; fractional x,y,width,height regions keep working.
+            let values = region
+                .split(separator: ",", omittingEmptySubsequences: false)
+                .map { $0.trimmingCharacters(in: .whitespacesAndNewlines) }
+            guard values.count == 4,
+                  let x = Double(values[0]),
+                  let y = Double(values[1]),
+                  let width = Double(values[2]),
+                  let height = Double(values[3])
+            else {
+                throw ValidationError("Region must be x,y,width,height or WIDTHxHEIGHT+X+Y")
+            }
+            rect = CGRect(x: x, y: y, width: width, height: height)
         }
 
-        guard width > 0, height > 0 else {
+        // `size`, because CGRect.width/height report the magnitude of a negative size.
+        guard rect.size.width > 0, rect.size.height > 0 else {
             throw ValidationError("Region width and height must be greater than zero")
         }
 
-        return CGRect(x: x, y: y, width: width, height: height)
+        return rect
     }
 
     private func captureMenuBar() async throws -> [ImageCapturedFile] {
@@ -249,16 +383,18 @@ extension ImageCommand {
         ]
     }
 
-    private func captureObservation(
+    func captureObservation(
         target: DesktopObservationTargetRequest,
         preferredName: String?,
-        index: Int?
+        index: Int?,
+        outputURL: URL? = nil
     ) async throws -> DesktopObservationResult {
-        let url = self.makeOutputURL(preferredName: preferredName, index: index)
+        let url = try outputURL ?? self.makeCollisionFreeOutputURL(preferredName: preferredName, index: index)
+        let request = self.makeObservationRequest(target: target, outputURL: url)
+        let observation = self.services.desktopObservation
 
-        return try await self.services.desktopObservation.observe(self.makeObservationRequest(
-            target: target,
-            outputURL: url
-        ))
+        return try await Self.withCaptureTimeout(milliseconds: self.resolvedCaptureTimeoutMs) {
+            try await observation.observe(request)
+        }
     }
 }
@@ -0,0 +1,39 @@
+import Commander
+import Foundation
+import PeekabooCore
+import PeekabooFoundation
+
+@MainActor
+extension ImageCommand {
+    static let defaultCaptureTimeoutMs = 5000
+    private static let captureTimeoutOperation = "Image capture"
+
+    var resolvedCaptureTimeoutMs: Int {
+        self.captureTimeoutMs ?? Self.defaultCaptureTimeoutMs
+    }
+
+    func validateCaptureTimeoutOptions() throws {
+        if let timeout = self.captureTimeoutMs, timeout <= 0 {
+            throw ValidationError("--capture-timeout-ms must be greater than zero")
+        }
+    }
+
+    /// Gives up on a capture after `milliseconds` even if it never returns, e.g. when a hung app stalls
+    /// ScreenCaptureKit. The abandoned capture keeps running in the background until it finishes or the process
+    /// exits; only its result is dropped.
+    static func withCaptureTimeout<T: Sendable>(
+        milliseconds: Int,
+        operation: @escaping @MainActor () async throws -> T
+    ) async throws -> T {
+        do {
+            return try await withMainActorCommandTimeout(
+                seconds: Double(milliseconds) / 1000,
+                operationName: Self.captureTimeoutOperation,
+                operation: operation
+            )
+        } catch PeekabooError.timedOut(let operation, _) where operation == Self.captureTimeoutOperation {
+            // Report the limit as given; the race itself only knows it in seconds.
+            throw PeekabooError.timedOut(operation: operation, timeoutMs: milliseconds)
+        }
+    }
+}
@@ -0,0 +1,144 @@
+import Algorithms
+import Commander
+import CoreGraphics
+import Foundation
+import PeekabooCore
+
+/// Where `image --changed-region` keeps the full capture each later run is diffed against.
+enum ChangedRegionBaseline {
+    static var defaultDirectory: URL {
+        FileManager.default.homeDirectoryForCurrentUser
+            .appendingPathComponent(".peekaboo")
+            .appendingPathComponent("cache")
+            .appendingPathComponent("baselines")
+    }
+
+    static func url(for identifier: String, in directory: URL = Self.defaultDirectory) -> URL {
+        directory.appendingPathComponent("\(Self.sanitized(identifier))_baseline.png")
+    }
+
+    /// Window titles and display names become file names, so anything outside `[A-Za-z0-9._-]` turns into `_`.
+    static func sanitized(_ identifier: String) -> String {
+        let allowed = CharacterSet.alphanumerics.union(CharacterSet(charactersIn: "._-"))
+        let scalars = identifier.unicodeScalars.map { allowed.contains($0) && $0.isASCII ? Character($0) : "_" }
+        let name = String(scalars)
+        return name.isEmpty ? "capture" : name
+    }
+
+    /// Bounding box of every pixel that differs, or nil when the images match. A size change counts as all changed.
+    static func changedBounds(from baseline: CGImage, to current: CGImage) throws -> CGRect? {
+        guard baseline.width == current.width, baseline.height == current.height else {
+            return CGRect(x: 0, y: 0, width: current.width, height: current.height)
+        }
+
+        let before = try CapturedImageIO.rgbaBytes(of: baseline)
+        let after = try CapturedImageIO.rgbaBytes(of: current)
+        var minX = current.width
+        var minY = current.height
+        var maxX = -1
+        var maxY = -1
+        for y in 0..<current.height {
+            for x in 0..<current.width {
+                let offset = (y * current.width + x) * 4
+                guard before[offset..<offset + 4] != after[offset..<offset + 4] else { continue }
+                minX = min(minX, x)
+                minY = min(minY, y)
+                maxX = max(maxX, x)
+                maxY = max(maxY, y)
+            }
+        }
+        guard maxX >= 0 else { return nil }
+        return CGRect(x: minX, y: minY, width: maxX - minX + 1, height: maxY - minY + 1)
+    }
+
+    /// Grows `rect` by `margin` on every side without leaving `bounds`.
+    static func padded(_ rect: CGRect, by margin: Int, within bounds: CGRect) -> CGRect {
+        rect.insetBy(dx: -CGFloat(margin), dy: -CGFloat(margin)).intersection(bounds)
+    }
+}
+
+@MainActor
+extension ImageCommand {
+    func validateChangedRegionOptions() throws {
+        if let changeMargin {
+            guard self.changedRegion else {
+                throw ValidationError("--change-margin requires --changed-region")
+            }
+            guard changeMargin >= 0 else {
+                throw ValidationError("--change-margin must be zero or greater")
+            }
+        }
+        if self.resetBaseline, !self.changedRegion {
+            throw ValidationError("--reset-baseline requires --changed-region")
+        }
+        if self.changedRegion, self.streamsImageToStdout {
+            throw ValidationError("--changed-region rewrites saved files and cannot be combined with --path -")
+        }
+    }
+
+    /// First run (or `--reset-baseline`) stores each capture as its baseline and keeps it whole; later runs crop
+    /// the file to what changed. An unchanged capture is deleted and `path` points at the baseline instead.
+    func croppingToChangedRegion(
+        _ captures: [ImageCapturedFile],
+        baselineDirectory: URL = ChangedRegionBaseline.defaultDirectory
+    ) throws -> [ImageCapturedFile] {
+        guard self.changedRegion else { return captures }
+
+        let margin = self.changeMargin ?? 0
+        return try captures.indexed().map { index, capture in
+            let path = capture.file.path
+            let identifier = Self.baselineIdentifier(for: capture.file, index: index, count: captures.count)
+            let baselineURL = ChangedRegionBaseline.url(for: identifier, in: baselineDirectory)
+            let image = try CapturedImageIO.load(at: path)
+            let fullFrame = CGRect(x: 0, y: 0, width: image.width, height: image.height)
+
+            guard !self.resetBaseline, FileManager.default.fileExists(atPath: baselineURL.path) else {
+                try CapturedImageIO.write(image, to: baselineURL, format: .png)
+                return Self.withChangedRegion(fullFrame, capture: capture)
+            }
+
+            let baseline = try CapturedImageIO.load(at: baselineURL.path)
+            guard let changed = try ChangedRegionBaseline.changedBounds(from: baseline, to: image) else {
+                try FileManager.default.removeItem(atPath: path)
+                return Self.withChangedRegion(.zero, capture: capture, path: baselineURL.path)
+            }
+
+            let region = ChangedRegionBaseline.padded(changed, by: margin, within: fullFrame)
+            if region != fullFrame {
+                guard let cropped = image.cropping(to: region) else {
+                    throw CaptureError.captureFailure("Failed to crop capture to its changed region")
+                }
+                try CapturedImageIO.write(
+                    cropped,
+                    to: URL(fileURLWithPath: path),
+                    format: self.format,
+                    jpegQuality: self.jpegQuality
+                )
+            }
+            return Self.withChangedRegion(region, capture: capture)
+        }
+    }
+
+    /// Keyed by what was captured rather than the output path, so runs writing to fresh paths share a baseline.
+    static func baselineIdentifier(for file: SavedFile, index: Int, count: Int) -> String {
+        let label = file.item_label ?? file.window_title ?? "capture"
+        return count > 1 ? "\(label)-\(index)" : label
+    }
+
+    private static func withChangedRegion(
+        _ rect: CGRect,
+        capture: ImageCapturedFile,
+        path: String? = nil
+    ) -> ImageCapturedFile {
+        let region = ChangedRegion(
+            x: Int(rect.minX),
+            y: Int(rect.minY),
+            w: Int(rect.width),
+            h: Int(rect.height)
+        )
+        return ImageCapturedFile(
+            file: capture.file.with(path: path, changedRegion: region),
+            observation: capture.observation
+        )
+    }
+}
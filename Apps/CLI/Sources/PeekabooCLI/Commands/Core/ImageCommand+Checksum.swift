@@ -0,0 +1,81 @@
+import Commander
+import CryptoKit
+import Foundation
+import PeekabooCore
+import PeekabooFoundation
+
+/// Digest used by `--compute-checksum` and `peekaboo verify-checksum`.
+enum FileChecksumAlgorithm: String, CaseIterable, ExpressibleFromArgument {
+    case sha256
+    case sha512
+    case md5
+
+    /// Accepts `SHA256`, `sha-256`, … as well as the raw values.
+    init?(argument: String) {
+        self.init(rawValue: argument.lowercased().replacingOccurrences(of: "-", with: ""))
+    }
+
+    /// Lowercase hex digest of the file at `path`, read in chunks so large captures are not loaded at once.
+    func hexDigest(ofFileAt path: String) throws -> String {
+        let handle: FileHandle
+        do {
+            handle = try FileHandle(forReadingFrom: URL(fileURLWithPath: path))
+        } catch {
+            throw PeekabooError.fileIOError("Could not read \(path) to checksum it: \(error.localizedDescription)")
+        }
+        defer { try? handle.close() }
+
+        switch self {
+        case .sha256:
+            var hasher = SHA256()
+            try Self.forEachChunk(of: handle) { hasher.update(data: $0) }
+            return Self.hex(hasher.finalize())
+        case .sha512:
+            var hasher = SHA512()
+            try Self.forEachChunk(of: handle) { hasher.update(data: $0) }
+            return Self.hex(hasher.finalize())
+        case .md5:
+            var hasher = Insecure.MD5()
+            try Self.forEachChunk(of: handle) { hasher.update(data: $0) }
+            return Self.hex(hasher.finalize())
+        }
+    }
+
+    private static let chunkSize = 1 << 20
+
+    private static func forEachChunk(of handle: FileHandle, _ body: (Data) -> Void) throws {
+        while let chunk = try handle.read(upToCount: Self.chunkSize), !chunk.isEmpty {
+            body(chunk)
+        }
+    }
+
+    private static func hex(_ digest: some Digest) -> String {
+        digest.map { String(format: "%02x", $0) }.joined()
+    }
+}
+
+@MainActor
+extension ImageCommand {
+    var resolvedChecksumAlgorithm: FileChecksumAlgorithm {
+        self.checksumAlgorithm ?? .sha256
+    }
+
+    func validateChecksumOptions() throws {
+        if self.checksumAlgorithm != nil, !self.computeChecksum {
+            throw ValidationError("--checksum-algorithm requires --compute-checksum")
+        }
+    }
+
+    /// `--compute-checksum` runs after every step that rewrites a file, so the digest matches what is kept.
+    func computingChecksums(_ captures: [ImageCapturedFile]) throws -> [ImageCapturedFile] {
+        guard self.computeChecksum else { return captures }
+        let algorithm = self.resolvedChecksumAlgorithm
+        return try captures.map { capture in
+            let digest = try algorithm.hexDigest(ofFileAt: capture.file.path)
+            return ImageCapturedFile(
+                file: capture.file.with(checksum: digest, checksumAlgorithm: algorithm.rawValue),
+                observation: capture.observation
+            )
+        }
+    }
+}
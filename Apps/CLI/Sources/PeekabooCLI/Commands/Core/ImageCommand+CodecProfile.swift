@@ -0,0 +1,139 @@
+import Commander
+import Foundation
+import ImageIO
+import PeekabooCore
+
+/// Encoder settings for `--codec-profile`; each profile belongs to exactly one output format.
+enum ImageCodecProfile: String, CaseIterable, ExpressibleFromArgument {
+    // JPEG
+    case baseline
+    case progressive
+    // PNG row filters
+    case none
+    case sub
+    case up
+    case average
+    case paeth
+    case adaptive
+
+    init?(argument: String) {
+        self.init(rawValue: argument.lowercased())
+    }
+
+    var format: ImageFormat {
+        switch self {
+        case .baseline, .progressive: .jpg
+        case .none, .sub, .up, .average, .paeth, .adaptive: .png
+        }
+    }
+
+    static func profiles(for format: ImageFormat) -> [ImageCodecProfile] {
+        self.allCases.filter { $0.format == format }
+    }
+
+    var summary: String {
+        switch self {
+        case .baseline: "Sequential scan; the default and the most widely decoded"
+        case .progressive: "Multiple scans that render coarse-to-fine while loading"
+        case .none: "No row filter; fastest to encode, usually largest"
+        case .sub: "Predict each pixel from its left neighbour"
+        case .up: "Predict each pixel from the row above"
+        case .average: "Predict from the mean of the left and upper pixels"
+        case .paeth: "Paeth predictor; often smallest for UI screenshots"
+        case .adaptive: "Let the encoder pick a filter per row"
+        }
+    }
+
+    /// Merged into the `CGImageDestinationAddImage` properties by `CapturedImageIO.write`.
+    var destinationProperties: [CFString: Any] {
+        switch self {
+        case .baseline:
+            [kCGImagePropertyJFIFDictionary: [kCGImagePropertyJFIFIsProgressive: false]]
+        case .progressive:
+            [kCGImagePropertyJFIFDictionary: [kCGImagePropertyJFIFIsProgressive: true]]
+        case .none:
+            Self.pngFilter(IMAGEIO_PNG_FILTER_NONE)
+        case .sub:
+            Self.pngFilter(IMAGEIO_PNG_FILTER_SUB)
+        case .up:
+            Self.pngFilter(IMAGEIO_PNG_FILTER_UP)
+        case .average:
+            Self.pngFilter(IMAGEIO_PNG_FILTER_AVG)
+        case .paeth:
+            Self.pngFilter(IMAGEIO_PNG_FILTER_PAETH)
+        case .adaptive:
+            Self.pngFilter(IMAGEIO_PNG_ALL_FILTERS)
+        }
+    }
+
+    private static func pngFilter(_ filter: Int32) -> [CFString: Any] {
+        [kCGImagePropertyPNGDictionary: [kCGImagePropertyPNGCompressionFilter: Int(filter)]]
+    }
+}
+
+/// Result of `peekaboo image --list-codec-profiles`.
+struct ImageCodecProfileList: Codable, Equatable {
+    struct Profile: Codable, Equatable {
+        let name: String
+        let description: String
+    }
+
+    let format: String
+    let profiles: [Profile]
+}
+
+@MainActor
+extension ImageCommand {
+    func validateCodecProfileOptions() throws {
+        guard let codecProfile, codecProfile.format != self.format else { return }
+        let supported = ImageCodecProfile.profiles(for: self.format).map(\.rawValue).joined(separator: ", ")
+        throw ValidationError(
+            "Profile \(codecProfile.rawValue) is not supported for format \(self.format.rawValue) " +
+                "(supported: \(supported))"
+        )
+    }
+
+    static func codecProfileList(for format: ImageFormat) -> ImageCodecProfileList {
+        ImageCodecProfileList(
+            format: format.rawValue,
+            profiles: ImageCodecProfile.profiles(for: format).map { profile in
+                ImageCodecProfileList.Profile(name: profile.rawValue, description: profile.summary)
+            }
+        )
+    }
+
+    /// `--list-codec-profiles` prints the profiles for the resolved `--format` instead of capturing.
+    func outputCodecProfiles() {
+        let result = Self.codecProfileList(for: self.format)
+        self.output(result) {
+            print("Codec profiles for \(result.format) (--codec-profile):")
+            for profile in result.profiles {
+                print("  \(profile.name.padding(toLength: 12, withPad: " ", startingAt: 0))\(profile.description)")
+            }
+        }
+    }
+
+    /// Re-encodes each kept file after every other rewrite, so the profile applies whichever steps ran. A JPEG
+    /// therefore takes one more lossy pass at the configured quality.
+    func encodingWithCodecProfile(_ captures: [ImageCapturedFile]) throws -> [ImageCapturedFile] {
+        guard let codecProfile else { return captures }
+        return try captures.map { capture in
+            // An unchanged `--changed-region` capture points at its PNG baseline; leave that file alone.
+            if let region = capture.file.changed_region, region.w == 0 || region.h == 0 {
+                return capture
+            }
+            let image = try CapturedImageIO.load(at: capture.file.path)
+            try CapturedImageIO.write(
+                image,
+                to: URL(fileURLWithPath: capture.file.path),
+                format: self.format,
+                jpegQuality: self.jpegQuality,
+                codecProfile: codecProfile
+            )
+            return ImageCapturedFile(
+                file: capture.file.with(codecProfile: codecProfile.rawValue),
+                observation: capture.observation
+            )
+        }
+    }
+}
@@ -0,0 +1,63 @@
+import Commander
+import CoreGraphics
+import Foundation
+import PeekabooCore
+
+/// Output color space for `--color-space`; captures otherwise keep whatever profile the display reported.
+enum ImageColorSpace: String, CaseIterable, ExpressibleFromArgument {
+    case srgb
+    case p3
+
+    init?(argument: String) {
+        self.init(rawValue: argument.lowercased())
+    }
+
+    var cgColorSpace: CGColorSpace? {
+        switch self {
+        case .srgb: CGColorSpace(name: CGColorSpace.sRGB)
+        case .p3: CGColorSpace(name: CGColorSpace.displayP3)
+        }
+    }
+}
+
+/// Color-matches a capture into `colorSpace` and tags it, so the written file embeds that ICC profile.
+struct ColorSpaceFilter: CapturedImageFilter {
+    let colorSpace: ImageColorSpace
+    let stepName = "color_space"
+
+    func apply(to image: CGImage) throws -> CGImage {
+        guard let target = self.colorSpace.cgColorSpace,
+              let context = CGContext(
+                  data: nil,
+                  width: image.width,
+                  height: image.height,
+                  bitsPerComponent: 8,
+                  bytesPerRow: 0,
+                  space: target,
+                  bitmapInfo: CGImageAlphaInfo.premultipliedLast.rawValue
+              )
+        else {
+            throw CaptureError.captureFailure("Failed to create a \(self.colorSpace.rawValue) drawing context")
+        }
+        // Drawing across color spaces runs ColorSync matching from the image's own profile.
+        context.draw(image, in: CGRect(x: 0, y: 0, width: image.width, height: image.height))
+        guard let converted = context.makeImage() else {
+            throw CaptureError.captureFailure("Failed to convert capture to \(self.colorSpace.rawValue)")
+        }
+        return converted
+    }
+}
+
+@MainActor
+extension ImageCommand {
+    /// Records `--color-space` on each file once the filter chain has rewritten it.
+    func taggingColorSpace(_ captures: [ImageCapturedFile]) -> [ImageCapturedFile] {
+        guard let colorSpace else { return captures }
+        return captures.map { capture in
+            ImageCapturedFile(
+                file: capture.file.with(colorSpace: colorSpace.rawValue),
+                observation: capture.observation
+            )
+        }
+    }
+}
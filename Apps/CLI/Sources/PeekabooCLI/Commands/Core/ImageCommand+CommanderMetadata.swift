@@ -14,14 +14,24 @@ extension ImageCommand: CommanderSignatureProviding {
                     help: "Target application by process ID",
                     long: "pid"
                 ),
+                .commandOption(
+                    "pidFile",
+                    help: "Target the process whose PID is on the first line of this file",
+                    long: "pid-file"
+                ),
                 .commandOption(
                     "path",
                     help: "Output path for saved image, or '-' to write one image to stdout",
                     long: "path"
                 ),
+                .commandOption(
+                    "onCollision",
+                    help: "When the output file exists: rename (default, adds -1, -2, …), overwrite, or error",
+                    long: "on-collision"
+                ),
                 .commandOption(
                     "mode",
-                    help: "Capture mode (screen, window, frontmost, multi, area)",
+                    help: "Capture mode (screen, window, frontmost, multi, area, interactive)",
                     long: "mode"
                 ),
                 .commandOption(
@@ -44,9 +54,19 @@ extension ImageCommand: CommanderSignatureProviding {
                     help: "Screen index for screen captures",
                     long: "screen-index"
                 ),
+                .commandOption(
+                    "monitorName",
+                    help: "Display name for screen captures (case-insensitive prefix, e.g. 'DELL')",
+                    long: "monitor-name"
+                ),
+                .commandOption(
+                    "monitorHotplugWaitMs",
+                    help: "Wait up to N ms for a just-connected display to appear before a screen capture",
+                    long: "monitor-hotplug-wait-ms"
+                ),
                 .commandOption(
                     "region",
-                    help: "Region for area captures as x,y,width,height in global display coordinates",
+                    help: "Area capture region: x,y,width,height or WIDTHxHEIGHT+X+Y in global display points",
                     long: "region"
                 ),
                 .commandOption(
@@ -69,6 +89,176 @@ extension ImageCommand: CommanderSignatureProviding {
                     help: "Analyze the captured image with AI",
                     long: "analyze"
                 ),
+                .commandOption(
+                    "windowState",
+                    help: "Window readiness: wait-visible|any",
+                    long: "window-state"
+                ),
+                .commandOption(
+                    "focusTimeoutMs",
+                    help: "Milliseconds --window-state wait-visible waits for a window (default 5000)",
+                    long: "focus-timeout-ms"
+                ),
+                .commandOption(
+                    "focusRetries",
+                    help: "Refocus attempts --window-focus-check makes before capturing anyway (default 3)",
+                    long: "focus-retries"
+                ),
+                .commandOption(
+                    "captureTimeoutMs",
+                    help: "Milliseconds each capture may take before it is abandoned (default 5000)",
+                    long: "capture-timeout-ms"
+                ),
+                .commandOption(
+                    "windowPadding",
+                    help: "Points of surrounding screen to include around window captures (default 0)",
+                    long: "window-padding"
+                ),
+                .commandOption(
+                    "stitchItems",
+                    help: "Capture these and stitch them into one image, e.g. screen:0,app:Safari,window-id:42",
+                    long: "stitch-items"
+                ),
+                .commandOption(
+                    "stitchLayout",
+                    help: "How --stitch-items are arranged: horizontal, vertical, or grid (default horizontal)",
+                    long: "stitch-layout"
+                ),
+                .commandOption(
+                    "stitchPadding",
+                    help: "Pixels of --gap-color between stitched captures (default 0)",
+                    long: "stitch-padding"
+                ),
+                .commandOption(
+                    "gapColor",
+                    help: "Fill color for gaps between merged displays (#RRGGBB or #RRGGBBAA)",
+                    long: "gap-color"
+                ),
+                .commandOption(
+                    "annotate",
+                    help: "JSON array of text/arrow/rect overlays drawn onto each capture",
+                    long: "annotate"
+                ),
+                .commandOption(
+                    "colorSpace",
+                    help: "Convert captures to srgb or p3 and embed that ICC profile",
+                    long: "color-space"
+                ),
+                .commandOption(
+                    "alphaThreshold",
+                    help: "Make pixels with alpha below this value (0-255) fully transparent; PNG only",
+                    long: "alpha-threshold"
+                ),
+                .commandOption(
+                    "codecProfile",
+                    help: "Encoder settings for --format, e.g. progressive (jpg) or paeth (png)",
+                    long: "codec-profile"
+                ),
+                .commandOption(
+                    "cropToBounds",
+                    help: "Crop each capture to x,y,width,height or WIDTHxHEIGHT+X+Y, in image pixels",
+                    long: "crop-to-bounds"
+                ),
+                .commandOption(
+                    "trimTolerance",
+                    help: "Channel difference (0-255, default 10) still counted as border by --trim-whitespace",
+                    long: "trim-tolerance"
+                ),
+                .commandOption(
+                    "smartCrop",
+                    help: "Crop each capture to its most detailed WIDTHxHEIGHT region, in image pixels",
+                    long: "smart-crop"
+                ),
+                .commandOption(
+                    "execAfter",
+                    help: "Run this command after a successful capture with the saved paths appended (or at {})",
+                    long: "exec-after"
+                ),
+                .commandOption(
+                    "execAfterEnv",
+                    help: "KEY=VALUE added to the --exec-after environment; repeatable",
+                    long: "exec-after-env"
+                ),
+                .commandOption(
+                    "vignetteStrength",
+                    help: "How dark --vignette makes the corners (0.0-1.0, default 0.5)",
+                    long: "vignette-strength"
+                ),
+                .commandOption(
+                    "watermarkImage",
+                    help: "Overlay this PNG on each capture, e.g. a logo or 'CONFIDENTIAL' stamp",
+                    long: "watermark-image"
+                ),
+                .commandOption(
+                    "watermarkPosition",
+                    help: "Corner for --watermark-image: topleft|topright|bottomleft|bottomright (default)|center",
+                    long: "watermark-position"
+                ),
+                .commandOption(
+                    "watermarkOpacity",
+                    help: "Opacity of --watermark-image (0.0-1.0, default 0.8)",
+                    long: "watermark-opacity"
+                ),
+                .commandOption(
+                    "watermarkScale",
+                    help: "Resize --watermark-image to this fraction of the capture width (0.0-1.0]",
+                    long: "watermark-scale"
+                ),
+                .commandOption(
+                    "profile",
+                    help: "Load defaults from ~/.peekaboo/profiles/NAME.json (or PEEKABOO_PROFILE)",
+                    long: "profile"
+                ),
+                .commandOption(
+                    "preCaptureKeys",
+                    help: "Key chord to press in the target before capture, e.g. 'cmd+shift+p'; repeatable",
+                    long: "pre-capture-keys"
+                ),
+                .commandOption(
+                    "preCaptureDelayMs",
+                    help: "Milliseconds to wait after --pre-capture-keys before capturing",
+                    long: "pre-capture-delay-ms"
+                ),
+                .commandOption(
+                    "previewTimeoutMs",
+                    help: "Press Save in the --mode interactive preview after this many ms (default 0: wait)",
+                    long: "preview-timeout-ms"
+                ),
+                .commandOption(
+                    "sessionId",
+                    help: "UUID to report as session.id for correlating this run's files",
+                    long: "session-id"
+                ),
+                .commandOption(
+                    "sessionMetadata",
+                    help: "key=value to add to session.metadata; repeatable",
+                    long: "session-metadata"
+                ),
+                .commandOption(
+                    "dedupThreshold",
+                    help: "Differing dHash bits still treated as a duplicate (0-64, default 3); needs --dedup",
+                    long: "dedup-threshold"
+                ),
+                .commandOption(
+                    "changeMargin",
+                    help: "Pixels of padding around the --changed-region bounding box (default 0)",
+                    long: "change-margin"
+                ),
+                .commandOption(
+                    "maxFileSizeKb",
+                    help: "Fail instead of keeping a capture whose encoded file is larger than this many KB",
+                    long: "max-file-size-kb"
+                ),
+                .commandOption(
+                    "outputJsonPath",
+                    help: "Also write the JSON result to this file; works with --path -",
+                    long: "output-json-path"
+                ),
+                .commandOption(
+                    "checksumAlgorithm",
+                    help: "Checksum algorithm for --compute-checksum: sha256 (default), sha512, or md5",
+                    long: "checksum-algorithm"
+                ),
             ],
             flags: [
                 .commandFlag(
@@ -76,6 +266,91 @@ extension ImageCommand: CommanderSignatureProviding {
                     help: "Capture at native Retina resolution instead of 1x logical",
                     long: "retina"
                 ),
+                .commandFlag(
+                    "caseSensitive",
+                    help: "Match --app and --window-title case-sensitively",
+                    long: "case-sensitive"
+                ),
+                .commandFlag(
+                    "windowFocusCheck",
+                    help: "Confirm the target app is focused before capturing, refocusing if needed",
+                    long: "window-focus-check"
+                ),
+                .commandFlag(
+                    "includeCursorPosition",
+                    help: "Record the mouse pointer location as cursor_at_capture on each file",
+                    long: "include-cursor-position"
+                ),
+                .commandFlag(
+                    "mergeDisplays",
+                    help: "Stitch every display into one image using the desktop arrangement",
+                    long: "merge-displays"
+                ),
+                .commandFlag(
+                    "trimWhitespace",
+                    help: "Crop away solid-color borders that match the top-left pixel",
+                    long: "trim-whitespace"
+                ),
+                .commandFlag(
+                    "vignette",
+                    help: "Darken each capture toward its corners, e.g. for presentation screenshots",
+                    long: "vignette"
+                ),
+                .commandFlag(
+                    "debugCaptureSteps",
+                    help: "Save a debug_<step>_<timestamp>.png next to each capture after every trim or filter step",
+                    long: "debug-capture-steps"
+                ),
+                .commandFlag(
+                    "printEnvVars",
+                    help: "List the PEEKABOO_* environment variables that stand in for flags, then exit",
+                    long: "print-env-vars"
+                ),
+                .commandFlag(
+                    "listCodecProfiles",
+                    help: "List the --codec-profile values for --format, then exit",
+                    long: "list-codec-profiles"
+                ),
+                .commandFlag(
+                    "noTiming",
+                    help: "Omit the per-phase timing block from JSON output",
+                    long: "no-timing"
+                ),
+                .commandFlag(
+                    "verifyCapture",
+                    help: "Re-read each saved file and fail if it is truncated or its dimensions disagree",
+                    long: "verify-capture"
+                ),
+                .commandFlag(
+                    "computeChecksum",
+                    help: "Add a checksum of each final file to the result",
+                    long: "compute-checksum"
+                ),
+                .commandFlag(
+                    "dedup",
+                    help: "Reuse an earlier capture whose dHash matches instead of keeping a new file",
+                    long: "dedup"
+                ),
+                .commandFlag(
+                    "changedRegion",
+                    help: "Keep only the part of each capture that differs from its saved baseline",
+                    long: "changed-region"
+                ),
+                .commandFlag(
+                    "resetBaseline",
+                    help: "Replace the --changed-region baseline with this capture",
+                    long: "reset-baseline"
+                ),
+                .commandFlag(
+                    "allowInputInjection",
+                    help: "Allow --pre-capture-keys to send keystrokes to the target app",
+                    long: "allow-input-injection"
+                ),
+                .commandFlag(
+                    "restoreLayout",
+                    help: "Move the target's windows back to the layout saved by 'list windows --cache-layout'",
+                    long: "restore-layout"
+                ),
             ]
         )
     }
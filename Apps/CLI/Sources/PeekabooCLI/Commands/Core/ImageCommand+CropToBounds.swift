@@ -0,0 +1,71 @@
+import Commander
+import CoreGraphics
+import Foundation
+import PeekabooCore
+
+@MainActor
+extension ImageCommand {
+    func validateCropToBoundsOptions() throws {
+        _ = try self.cropBoundsValue()
+    }
+
+    func cropBoundsValue() throws -> WindowBounds? {
+        guard let cropToBounds else { return nil }
+        let bounds: WindowBounds
+        do {
+            bounds = try WindowBounds(parsing: cropToBounds)
+        } catch {
+            throw ValidationError("--crop-to-bounds: \(error.localizedDescription)")
+        }
+        guard bounds.width > 0, bounds.height > 0 else {
+            throw ValidationError("--crop-to-bounds width and height must be greater than zero")
+        }
+        return bounds
+    }
+
+    /// Cuts each file to `--crop-to-bounds`, in pixels of the saved image, before `--trim-whitespace` and filters.
+    /// Bounds reaching past the image are clamped; `original_bounds` records the rect that was kept.
+    func croppingToBounds(
+        _ captures: [ImageCapturedFile],
+        recorder: CaptureStepRecorder? = nil
+    ) throws -> [ImageCapturedFile] {
+        guard let bounds = try self.cropBoundsValue() else { return captures }
+
+        return try captures.enumerated().map { index, capture in
+            let image = try CapturedImageIO.load(at: capture.file.path)
+            let startedAt = Date()
+            let kept = bounds.cgRect.intersection(CGRect(x: 0, y: 0, width: image.width, height: image.height))
+            guard !kept.isNull, kept.width > 0, kept.height > 0 else {
+                throw PeekabooError.invalidInput(
+                    "--crop-to-bounds \(bounds) lies outside the \(image.width)x\(image.height) capture"
+                )
+            }
+            guard let cropped = image.cropping(to: kept) else {
+                throw CaptureError.captureFailure("Failed to crop capture to --crop-to-bounds")
+            }
+            try recorder?.record(
+                CaptureStepRecorder.cropStepName,
+                image: cropped,
+                durationMs: ImageCaptureTimings.milliseconds(since: startedAt),
+                besides: capture.file.path,
+                captureIndex: captures.count > 1 ? index : nil
+            )
+            try CapturedImageIO.write(
+                cropped,
+                to: URL(fileURLWithPath: capture.file.path),
+                format: self.format,
+                jpegQuality: self.jpegQuality
+            )
+            let originalBounds = WindowBounds(
+                x: Int(kept.minX),
+                y: Int(kept.minY),
+                width: Int(kept.width),
+                height: Int(kept.height)
+            )
+            return ImageCapturedFile(
+                file: capture.file.with(originalBounds: originalBounds),
+                observation: capture.observation
+            )
+        }
+    }
+}
@@ -0,0 +1,21 @@
+import Foundation
+import PeekabooCore
+
+@MainActor
+extension ImageCommand {
+    /// Samples the pointer once, right after the capture returns, and records it on every file of the run.
+    /// A missing location only warns: the screenshot itself is still good.
+    func stampingCursorPosition(_ files: [ImageCapturedFile]) -> [ImageCapturedFile] {
+        guard self.includeCursorPosition else { return files }
+        guard let position = try? CursorPositionCommand.cursorPosition(from: self.services.automation) else {
+            self.outputLogger.warn(
+                .CURSOR_NOT_CAPTURED,
+                "--include-cursor-position: the mouse pointer location is unavailable"
+            )
+            return files
+        }
+        return files.map {
+            ImageCapturedFile(file: $0.file.with(cursorAtCapture: position), observation: $0.observation)
+        }
+    }
+}
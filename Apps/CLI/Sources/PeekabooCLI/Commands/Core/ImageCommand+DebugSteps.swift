@@ -0,0 +1,89 @@
+import CoreGraphics
+import Foundation
+import PeekabooCore
+
+/// One `--debug-capture-steps` snapshot: the image as it left `step`.
+struct CaptureDebugStep: Codable, Equatable {
+    let step: String
+    let path: String
+    let width: Int
+    let height: Int
+    let duration_ms: Double
+}
+
+/// Saves `debug_<step>_<timestamp>.png` next to each capture after every pipeline step.
+final class CaptureStepRecorder {
+    static let cropStepName = "crop"
+    static let trimStepName = "trim"
+    static let smartCropStepName = "smart_crop"
+
+    /// Matches only names this recorder writes, so cleanup never touches the user's own `debug_*.png` files.
+    private static let fileNamePattern = try? NSRegularExpression(
+        pattern: #"^debug_[a-z_]+_\d{8}-\d{6}-\d{3}(_\d+)?\.png$"#
+    )
+
+    let timestamp: String
+    private(set) var steps: [CaptureDebugStep] = []
+
+    init(date: Date = Date()) {
+        let formatter = DateFormatter()
+        formatter.locale = Locale(identifier: "en_US_POSIX")
+        formatter.dateFormat = "yyyyMMdd-HHmmss-SSS"
+        self.timestamp = formatter.string(from: date)
+    }
+
+    /// Writes `image` as PNG whatever `--format` is, so an artifact can't come from JPEG compression.
+    /// `captureIndex` tells apart the files of multi-capture runs.
+    func record(
+        _ step: String,
+        image: CGImage,
+        durationMs: Double,
+        besides capturePath: String,
+        captureIndex: Int?
+    ) throws {
+        let directory = URL(fileURLWithPath: capturePath).deletingLastPathComponent()
+        let suffix = captureIndex.map { "_\($0)" } ?? ""
+        let url = directory.appendingPathComponent("debug_\(step)_\(self.timestamp)\(suffix).png")
+        try CapturedImageIO.write(image, to: url, format: .png)
+        self.steps.append(CaptureDebugStep(
+            step: step,
+            path: url.path,
+            width: image.width,
+            height: image.height,
+            duration_ms: durationMs
+        ))
+    }
+
+    static func isDebugImage(named name: String) -> Bool {
+        let range = NSRange(name.startIndex..., in: name)
+        return self.fileNamePattern?.firstMatch(in: name, range: range) != nil
+    }
+
+    /// Deletes debug images earlier runs left in `directories`, sparing the ones in `keeping`.
+    static func removeStaleImages(in directories: Set<String>, keeping: Set<String> = []) {
+        let fileManager = FileManager.default
+        let kept = Set(keeping.map { URL(fileURLWithPath: $0).standardizedFileURL.path })
+        for directory in directories {
+            guard let names = try? fileManager.contentsOfDirectory(atPath: directory) else { continue }
+            for name in names where self.isDebugImage(named: name) {
+                let path = URL(fileURLWithPath: directory).appendingPathComponent(name).standardizedFileURL.path
+                if !kept.contains(path) {
+                    try? fileManager.removeItem(atPath: path)
+                }
+            }
+        }
+    }
+}
+
+@MainActor
+extension ImageCommand {
+    func makeStepRecorder() -> CaptureStepRecorder? {
+        self.debugCaptureSteps ? CaptureStepRecorder() : nil
+    }
+
+    /// Runs once the capture has been reported, so a failed run leaves the last debug images for inspection.
+    func removeStaleDebugImages(for captures: [ImageCapturedFile], recorder: CaptureStepRecorder?) {
+        let directories = Set(captures.map { URL(fileURLWithPath: $0.file.path).deletingLastPathComponent().path })
+        CaptureStepRecorder.removeStaleImages(in: directories, keeping: Set(recorder?.steps.map(\.path) ?? []))
+    }
+}
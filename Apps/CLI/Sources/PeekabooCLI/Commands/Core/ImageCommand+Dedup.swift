@@ -0,0 +1,49 @@
+import Commander
+import Foundation
+import PeekabooCore
+
+@MainActor
+extension ImageCommand {
+    /// dHash bits two captures may differ by and still count as the same screen; absorbs re-encoding noise.
+    static let defaultDedupThreshold = 3
+
+    func validateDedupOptions() throws {
+        if let dedupThreshold {
+            guard self.dedup else {
+                throw ValidationError("--dedup-threshold requires --dedup")
+            }
+            guard (0...64).contains(dedupThreshold) else {
+                throw ValidationError("--dedup-threshold must be between 0 and 64")
+            }
+        }
+        if self.dedup, self.streamsImageToStdout {
+            throw ValidationError("--dedup saves to disk and cannot be combined with --path -")
+        }
+    }
+
+    /// Replaces captures that match an earlier one with that file, deleting the new copy, and records the rest.
+    func deduplicating(
+        _ captures: [ImageCapturedFile],
+        cacheURL: URL = ImageHashCache.defaultURL
+    ) throws -> [ImageCapturedFile] {
+        guard self.dedup else { return captures }
+
+        var cache = try ImageHashCache.load(from: cacheURL)
+        let threshold = self.dedupThreshold ?? Self.defaultDedupThreshold
+        let deduplicated = try captures.map { capture in
+            let path = capture.file.path
+            let hash = try DHash(image: CapturedImageIO.load(at: path))
+            if let earlier = cache.match(for: hash, maxDistance: threshold), earlier.path != path {
+                try FileManager.default.removeItem(atPath: path)
+                return ImageCapturedFile(
+                    file: capture.file.with(path: earlier.path, deduplicated: true),
+                    observation: capture.observation
+                )
+            }
+            cache.record(path: path, hash: hash)
+            return capture
+        }
+        try cache.save(to: cacheURL)
+        return deduplicated
+    }
+}
@@ -0,0 +1,40 @@
+import Foundation
+
+struct ImageEnvironmentVariablesResult: Codable {
+    let variables: [EnvironmentVariableStatus]
+}
+
+struct EnvironmentVariableStatus: Codable {
+    let name: String
+    let flag: String?
+    let description: String
+    let value: String?
+}
+
+@MainActor
+extension ImageCommand {
+    func outputEnvironmentVariables(environment: [String: String] = ProcessInfo.processInfo.environment) {
+        let result = Self.environmentVariables(environment: environment)
+        self.output(result) {
+            print("Environment variables (explicit flags take precedence):")
+            for variable in result.variables {
+                print("  \(variable.name)\(variable.flag.map { " (\($0))" } ?? "")")
+                print("      \(variable.description)")
+                if let value = variable.value {
+                    print("      current: \(value)")
+                }
+            }
+        }
+    }
+
+    static func environmentVariables(environment: [String: String]) -> ImageEnvironmentVariablesResult {
+        ImageEnvironmentVariablesResult(variables: EnvironmentConfig.supported.map { variable in
+            EnvironmentVariableStatus(
+                name: variable.name,
+                flag: variable.flag,
+                description: variable.description,
+                value: environment[variable.name]
+            )
+        })
+    }
+}
@@ -0,0 +1,143 @@
+import Commander
+import Foundation
+import PeekabooCore
+
+/// The `--exec-after` command: run once after a successful capture, with the saved file paths as arguments.
+struct PostCaptureHook {
+    /// A standalone `{}` argument is replaced by the file paths, like `find -exec`; otherwise they are appended.
+    static let pathsPlaceholder = "{}"
+
+    let arguments: [String]
+    let environment: [String: String]
+
+    init(command: String, environment: [String] = []) throws {
+        let arguments = try Self.splitArguments(command)
+        guard !arguments.isEmpty else {
+            throw ValidationError("--exec-after needs a command")
+        }
+        self.arguments = arguments
+        self.environment = try Self.parseEnvironment(environment)
+    }
+
+    /// Shell-style word splitting without a shell: whitespace separates words, quotes group them, and a
+    /// backslash escapes the next character outside single quotes. Nothing is expanded. `option` names the flag in
+    /// the error for an unterminated quote.
+    static func splitArguments(_ command: String, option: String = "--exec-after") throws -> [String] {
+        var arguments: [String] = []
+        var current = ""
+        var inWord = false
+        var quote: Character?
+        var escaping = false
+
+        for character in command {
+            if escaping {
+                current.append(character)
+                escaping = false
+            } else if character == "\\", quote != "'" {
+                escaping = true
+                inWord = true
+            } else if let open = quote {
+                if character == open {
+                    quote = nil
+                } else {
+                    current.append(character)
+                }
+            } else if character == "'" || character == "\"" {
+                quote = character
+                inWord = true
+            } else if character.isWhitespace {
+                if inWord {
+                    arguments.append(current)
+                    current = ""
+                    inWord = false
+                }
+            } else {
+                current.append(character)
+                inWord = true
+            }
+        }
+
+        if quote != nil || escaping {
+            throw ValidationError("\(option) has an unterminated quote or trailing backslash")
+        }
+        if inWord {
+            arguments.append(current)
+        }
+        return arguments
+    }
+
+    static func parseEnvironment(_ assignments: [String]) throws -> [String: String] {
+        var environment: [String: String] = [:]
+        for assignment in assignments {
+            guard let separator = assignment.firstIndex(of: "="), separator != assignment.startIndex else {
+                throw ValidationError("--exec-after-env expects KEY=VALUE, got '\(assignment)'")
+            }
+            environment[String(assignment[..<separator])] = String(assignment[assignment.index(after: separator)...])
+        }
+        return environment
+    }
+
+    func invocation(paths: [String]) -> [String] {
+        guard self.arguments.contains(Self.pathsPlaceholder) else {
+            return self.arguments + paths
+        }
+        return self.arguments.flatMap { $0 == Self.pathsPlaceholder ? paths : [$0] }
+    }
+
+    /// Runs the hook to completion. Its stdout goes to stderr so `--json` output stays parseable; its stderr is
+    /// kept for the error when it exits non-zero.
+    func run(paths: [String]) throws {
+        let process = Process()
+        process.executableURL = URL(fileURLWithPath: "/usr/bin/env")
+        process.arguments = self.invocation(paths: paths)
+        process.environment = ProcessInfo.processInfo.environment.merging(self.environment) { _, new in new }
+        process.standardOutput = FileHandle.standardError
+        let errorPipe = Pipe()
+        process.standardError = errorPipe
+
+        do {
+            try process.run()
+        } catch {
+            throw PeekabooError.commandFailed(
+                "--exec-after could not start '\(self.arguments[0])': \(error.localizedDescription)"
+            )
+        }
+        // Drain before waiting so a chatty hook can't block on a full pipe.
+        let stderr = errorPipe.fileHandleForReading.readDataToEndOfFile()
+        process.waitUntilExit()
+
+        guard process.terminationStatus == 0 else {
+            let message = String(decoding: stderr, as: UTF8.self).trimmingCharacters(in: .whitespacesAndNewlines)
+            throw PeekabooError.commandFailed(
+                "--exec-after '\(self.arguments[0])' exited with status \(process.terminationStatus)" +
+                    (message.isEmpty ? "" : ": \(message)")
+            )
+        }
+    }
+}
+
+@MainActor
+extension ImageCommand {
+    func validateExecAfterOptions() throws {
+        _ = try self.postCaptureHook()
+        if self.execAfter != nil, self.streamsImageToStdout {
+            throw ValidationError("--exec-after cannot be combined with --path - because no file is kept")
+        }
+    }
+
+    func postCaptureHook() throws -> PostCaptureHook? {
+        guard let execAfter else {
+            if !self.execAfterEnv.isEmpty {
+                throw ValidationError("--exec-after-env requires --exec-after")
+            }
+            return nil
+        }
+        return try PostCaptureHook(command: execAfter, environment: self.execAfterEnv)
+    }
+
+    /// Runs after every pipeline step, so the hook sees the files exactly as they are reported.
+    func runPostCaptureHook(for captures: [ImageCapturedFile]) throws {
+        guard let hook = try self.postCaptureHook() else { return }
+        try hook.run(paths: captures.map(\.file.path))
+    }
+}
@@ -0,0 +1,43 @@
+import Commander
+import Foundation
+import PeekabooCore
+
+@MainActor
+extension ImageCommand {
+    func validateMaxFileSizeOptions() throws {
+        if let maxFileSizeKb, maxFileSizeKb <= 0 {
+            throw ValidationError("--max-file-size-kb must be greater than zero")
+        }
+    }
+
+    /// Checks the final encoded bytes against `--max-file-size-kb`. Over the limit, every file this run wrote is
+    /// removed before the error, so `--path -` streams nothing and pipelines never pick up an oversized image.
+    func enforcingFileSizeLimit(_ captures: [ImageCapturedFile]) throws -> [ImageCapturedFile] {
+        guard let maxFileSizeKb else { return captures }
+
+        // An unchanged `--changed-region` capture points at its baseline; that file was not written by this run.
+        let written = captures.filter { $0.file.changed_region.map { $0.w > 0 } ?? true }
+        for capture in written {
+            let attributes = try FileManager.default.attributesOfItem(atPath: capture.file.path)
+            let bytes = (attributes[.size] as? NSNumber)?.intValue ?? 0
+            do {
+                try Self.checkFileSize(bytes: bytes, limitKB: maxFileSizeKb)
+            } catch {
+                for file in written {
+                    try? FileManager.default.removeItem(atPath: file.file.path)
+                }
+                throw error
+            }
+        }
+        return captures
+    }
+
+    static func checkFileSize(bytes: Int, limitKB: Int) throws {
+        let actualKB = (bytes + 1023) / 1024
+        guard actualKB > limitKB else { return }
+        throw PeekabooError.invalidInput(
+            "Encoded image exceeds max-file-size-kb limit: \(actualKB)kb > \(limitKB)kb. " +
+                "Try --format jpg with PEEKABOO_QUALITY=50, drop --retina, or capture a smaller --region"
+        )
+    }
+}
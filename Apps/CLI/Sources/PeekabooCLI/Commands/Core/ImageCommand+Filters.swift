@@ -0,0 +1,189 @@
+import Commander
+import CoreGraphics
+import Foundation
+import PeekabooCore
+
+/// A post-capture step that rewrites a saved image, e.g. burning in `--annotate` overlays.
+protocol CapturedImageFilter {
+    /// Names the filter in `--debug-capture-steps` file names and JSON.
+    var stepName: String { get }
+
+    func apply(to image: CGImage) throws -> CGImage
+}
+
+struct AnnotationFilter: CapturedImageFilter {
+    let annotations: [ImageAnnotation]
+    let stepName = "annotate"
+
+    func apply(to image: CGImage) throws -> CGImage {
+        try ImageAnnotationRenderer.render(self.annotations, onto: image)
+    }
+}
+
+/// Clears pixels whose alpha is below `threshold`, e.g. the soft shadow and rounded-corner fringe around a
+/// window capture. Only PNG keeps the result; JPEG has no alpha channel.
+struct AlphaThresholdFilter: CapturedImageFilter {
+    let threshold: UInt8
+    let stepName = "alpha_threshold"
+
+    func apply(to image: CGImage) throws -> CGImage {
+        var bytes = try CapturedImageIO.rgbaBytes(of: image)
+        for offset in stride(from: 0, to: bytes.count, by: 4) where bytes[offset + 3] < self.threshold {
+            // Premultiplied: a fully transparent pixel is all zeros.
+            bytes.replaceSubrange(offset..<(offset + 4), with: [0, 0, 0, 0])
+        }
+        return try CapturedImageIO.makeImage(rgbaBytes: bytes, width: image.width, height: image.height)
+    }
+}
+
+/// Darkens toward the edges for presentation shots: color is scaled by a radial gradient that is 1.0 at the
+/// image center and `1 - strength` at the corners, falling off linearly with distance.
+struct VignetteFilter: CapturedImageFilter {
+    static let defaultStrength = 0.5
+
+    let strength: Double
+    let stepName = "vignette"
+
+    func apply(to image: CGImage) throws -> CGImage {
+        var bytes = try CapturedImageIO.rgbaBytes(of: image)
+        let centerX = Double(image.width) / 2
+        let centerY = Double(image.height) / 2
+        let cornerDistance = (centerX * centerX + centerY * centerY).squareRoot()
+        guard cornerDistance > 0 else { return image }
+
+        for y in 0..<image.height {
+            for x in 0..<image.width {
+                // Measure from pixel centers so an odd-sized image keeps its middle pixel untouched.
+                let dx = Double(x) + 0.5 - centerX
+                let dy = Double(y) + 0.5 - centerY
+                let factor = 1 - self.strength * (dx * dx + dy * dy).squareRoot() / cornerDistance
+                let offset = (y * image.width + x) * 4
+                // Bytes are premultiplied, so scaling color alone leaves alpha and transparency intact.
+                for component in 0..<3 {
+                    bytes[offset + component] = UInt8((Double(bytes[offset + component]) * factor).rounded())
+                }
+            }
+        }
+
+        return try CapturedImageIO.makeImage(rgbaBytes: bytes, width: image.width, height: image.height)
+    }
+}
+
+/// Filters run in order over every capture; each file is decoded and re-encoded once for the whole chain.
+struct CapturedImageFilterChain {
+    var filters: [any CapturedImageFilter] = []
+
+    var isEmpty: Bool {
+        self.filters.isEmpty
+    }
+
+    func apply(to image: CGImage) throws -> CGImage {
+        try self.filters.reduce(image) { image, filter in
+            try filter.apply(to: image)
+        }
+    }
+
+    /// Like `apply(to:)`, handing each filter's output and its duration in milliseconds to `observe`.
+    func apply(
+        to image: CGImage,
+        observe: (any CapturedImageFilter, CGImage, Double) throws -> Void
+    ) throws -> CGImage {
+        try self.filters.reduce(image) { image, filter in
+            let startedAt = Date()
+            let filtered = try filter.apply(to: image)
+            try observe(filter, filtered, ImageCaptureTimings.milliseconds(since: startedAt))
+            return filtered
+        }
+    }
+}
+
+@MainActor
+extension ImageCommand {
+    /// Builds the chain before capturing so invalid filter options fail without side effects.
+    func makeFilterChain() throws -> CapturedImageFilterChain {
+        var chain = CapturedImageFilterChain()
+        // Alpha trimming first so it only touches captured pixels, never the opaque overlays added below.
+        if let threshold = try self.alphaThresholdValue() {
+            chain.filters.append(AlphaThresholdFilter(threshold: threshold))
+        }
+        let annotations = try self.parsedAnnotations()
+        if !annotations.isEmpty {
+            chain.filters.append(AnnotationFilter(annotations: annotations))
+        }
+        // Vignette last so overlays near the edges are shaded with the rest of the frame.
+        if let strength = try self.vignetteStrengthValue() {
+            chain.filters.append(VignetteFilter(strength: strength))
+        }
+        // Watermark after the vignette so the mark keeps its own colors in a darkened corner.
+        if let watermark = try self.watermarkFilter() {
+            chain.filters.append(watermark)
+        }
+        // Color conversion last: the other filters draw untagged RGB, and this decides the profile that gets written.
+        if let colorSpace {
+            chain.filters.append(ColorSpaceFilter(colorSpace: colorSpace))
+        }
+        return chain
+    }
+
+    func alphaThresholdValue() throws -> UInt8? {
+        guard let alphaThreshold else { return nil }
+        guard let threshold = UInt8(exactly: alphaThreshold) else {
+            throw ValidationError("--alpha-threshold must be between 0 and 255")
+        }
+        return threshold
+    }
+
+    /// `--alpha-threshold` still runs for JPEG, but cleared pixels are flattened to black on encode.
+    var alphaThresholdFormatWarning: String? {
+        guard self.alphaThreshold != nil, self.format == .jpg else { return nil }
+        return "--alpha-threshold has no visible effect on JPEG captures, which have no alpha channel; use --format png"
+    }
+
+    func vignetteStrengthValue() throws -> Double? {
+        if let vignetteStrength {
+            guard self.vignette else {
+                throw ValidationError("--vignette-strength requires --vignette")
+            }
+            guard (0...1).contains(vignetteStrength) else {
+                throw ValidationError("--vignette-strength must be between 0.0 and 1.0")
+            }
+        }
+        return self.vignette ? self.vignetteStrength ?? VignetteFilter.defaultStrength : nil
+    }
+
+    func applyFilterChain(
+        _ chain: CapturedImageFilterChain,
+        to captures: [ImageCapturedFile],
+        recorder: CaptureStepRecorder? = nil
+    ) throws {
+        guard !chain.isEmpty else { return }
+
+        for (index, capture) in captures.enumerated() {
+            let image = try CapturedImageIO.load(at: capture.file.path)
+            let filtered = try chain.apply(to: image) { filter, output, durationMs in
+                try recorder?.record(
+                    filter.stepName,
+                    image: output,
+                    durationMs: durationMs,
+                    besides: capture.file.path,
+                    captureIndex: captures.count > 1 ? index : nil
+                )
+            }
+            try CapturedImageIO.write(
+                filtered,
+                to: URL(fileURLWithPath: capture.file.path),
+                format: self.format,
+                jpegQuality: self.jpegQuality
+            )
+        }
+    }
+
+    /// `--verify-capture` runs after filters so it checks the bytes that are actually kept.
+    func verifyingCaptures(_ captures: [ImageCapturedFile]) throws -> [ImageCapturedFile] {
+        guard self.verifyCapture else { return captures }
+        return try captures.map { capture in
+            try CapturedImageIO.verify(at: capture.file.path)
+            return ImageCapturedFile(file: capture.file.with(verified: true), observation: capture.observation)
+        }
+    }
+}
@@ -1,3 +1,4 @@
+import Commander
 import Foundation
 import PeekabooCore
 
@@ -34,6 +35,95 @@ extension ImageCommand {
         }
     }
 
+    /// Refocus attempts `--window-focus-check` makes before capturing anyway.
+    static let defaultFocusRetries = 3
+    static let focusCheckIntervalMs = 120
+
+    func validateWindowFocusCheckOptions() throws {
+        if let focusRetries {
+            guard self.windowFocusCheck else {
+                throw ValidationError("--focus-retries requires --window-focus-check")
+            }
+            guard focusRetries >= 0 else {
+                throw ValidationError("--focus-retries must be zero or greater")
+            }
+        }
+        if self.windowFocusCheck, self.captureFocus == .background {
+            throw ValidationError("--window-focus-check cannot be combined with --capture-focus background")
+        }
+    }
+
+    /// `nil` unless `--window-focus-check` is set; otherwise whether the target app (and the `--window-title`
+    /// window, when given) ended up focused. A failed check only warns so the capture still happens.
+    func confirmWindowFocusIfRequested(appIdentifier: String) async throws -> Bool? {
+        guard self.windowFocusCheck else { return nil }
+
+        let target = try? await self.services.applications.findApplication(identifier: appIdentifier)
+        let retries = self.focusRetries ?? Self.defaultFocusRetries
+        let confirmed = try await Self.confirmFocus(
+            retries: retries,
+            isFocused: { await self.isCaptureTargetFocused(target) },
+            refocus: {
+                let options = FocusOptions(
+                    autoFocus: true,
+                    spaceSwitch: self.captureFocus == .foreground,
+                    bringToCurrentSpace: self.captureFocus == .foreground
+                )
+                try? await ensureFocused(
+                    applicationName: target.map { "PID:\($0.processIdentifier)" } ?? appIdentifier,
+                    windowTitle: self.windowTitle,
+                    options: options,
+                    services: self.services
+                )
+            }
+        )
+        if !confirmed {
+            self.outputLogger.warn(
+                .FOCUS_NOT_CONFIRMED,
+                "\(appIdentifier) was not focused after \(retries) refocus attempt\(retries == 1 ? "" : "s"); " +
+                    "capturing anyway"
+            )
+        }
+        return confirmed
+    }
+
+    /// Checks focus, refocusing between checks up to `retries` times.
+    static func confirmFocus(
+        retries: Int,
+        isFocused: () async -> Bool,
+        refocus: () async -> Void
+    ) async throws -> Bool {
+        for attempt in 0...retries {
+            if await isFocused() {
+                return true
+            }
+            guard attempt < retries else { break }
+            await refocus()
+            try await Task.sleep(for: .milliseconds(Self.focusCheckIntervalMs))
+        }
+        return false
+    }
+
+    private func isCaptureTargetFocused(_ target: ServiceApplicationInfo?) async -> Bool {
+        guard let target,
+              let frontmost = try? await self.services.applications.getFrontmostApplication(),
+              frontmost.processIdentifier == target.processIdentifier
+        else {
+            return false
+        }
+
+        guard let windowTitle = self.windowTitle?.trimmingCharacters(in: .whitespacesAndNewlines),
+              !windowTitle.isEmpty
+        else {
+            return true
+        }
+
+        guard let focused = try? await self.services.windows.getFocusedWindow() else {
+            return false
+        }
+        return Self.windowTitle(focused.title, matches: windowTitle, caseSensitive: self.caseSensitive)
+    }
+
     private func hasVisibleCaptureWindow(appIdentifier: String) async -> Bool {
         guard let app = try? await self.services.applications.findApplication(identifier: appIdentifier) else {
             return false
@@ -58,7 +148,7 @@ extension ImageCommand {
         }
 
         return candidates.contains {
-            $0.title.localizedCaseInsensitiveContains(windowTitle)
+            Self.windowTitle($0.title, matches: windowTitle, caseSensitive: self.caseSensitive)
         }
     }
 
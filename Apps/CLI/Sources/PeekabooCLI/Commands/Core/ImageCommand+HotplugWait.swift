@@ -0,0 +1,79 @@
+import Commander
+import Foundation
+import PeekabooCore
+import PeekabooFoundation
+
+/// Progress line printed to stderr in JSON mode while `--monitor-hotplug-wait-ms` waits for a display.
+struct DisplayWaitProgress: Encodable, Equatable {
+    let type = "waiting_for_display"
+    let elapsed_ms: Int
+}
+
+@MainActor
+extension ImageCommand {
+    static let displayPollInterval: Duration = .milliseconds(250)
+
+    func validateMonitorHotplugWaitOptions() throws {
+        guard let waitMs = self.monitorHotplugWaitMs else { return }
+        guard waitMs >= 0 else {
+            throw ValidationError("--monitor-hotplug-wait-ms must be zero (disabled) or greater")
+        }
+    }
+
+    /// Whether `screens` can satisfy the capture: the `--monitor-name` display, display `--screen-index`, or any.
+    static func displaysReady(_ screens: [ScreenInfo], screenIndex: Int?, monitorName: String?) -> Bool {
+        if let monitorName {
+            return (try? Self.displayIndex(named: monitorName, in: screens)) != nil
+        }
+        if let screenIndex {
+            return screens.contains { $0.index == screenIndex }
+        }
+        return !screens.isEmpty
+    }
+
+    /// Blocks screen captures until a just-plugged-in display is registered, up to `--monitor-hotplug-wait-ms`.
+    func waitForHotpluggedDisplays() async throws {
+        guard let waitMs = self.monitorHotplugWaitMs, waitMs > 0 else { return }
+        let screens = self.services.screens
+        let screenIndex = self.screenIndex
+        let monitorName = self.monitorName
+        let jsonOutput = self.jsonOutput
+        try await Self.waitForDisplays(
+            timeoutMs: waitMs,
+            ready: { Self.displaysReady(screens.listScreens(), screenIndex: screenIndex, monitorName: monitorName) },
+            progress: { elapsedMs in
+                if jsonOutput {
+                    Self.emitProgressLine(DisplayWaitProgress(elapsed_ms: elapsedMs))
+                }
+            }
+        )
+    }
+
+    /// Polls `ready` every `interval`, reporting elapsed milliseconds after each miss, until `timeoutMs` passes.
+    static func waitForDisplays(
+        timeoutMs: Int,
+        interval: Duration = Self.displayPollInterval,
+        ready: () -> Bool,
+        progress: (Int) -> Void
+    ) async throws {
+        let clock = ContinuousClock()
+        let startedAt = clock.now
+        let deadline = startedAt + .milliseconds(timeoutMs)
+
+        while !ready() {
+            guard clock.now < deadline else {
+                throw PeekabooError.timedOut(operation: "Waiting for a hotplugged display", timeoutMs: timeoutMs)
+            }
+            progress(Int((clock.now - startedAt) / .milliseconds(1)))
+            try await Task.sleep(for: interval)
+        }
+    }
+
+    /// Progress goes to stderr so stdout keeps exactly one JSON document.
+    private static func emitProgressLine(_ value: some Encodable) {
+        let encoder = JSONEncoder()
+        encoder.outputFormatting = [.sortedKeys, .withoutEscapingSlashes]
+        guard let data = try? encoder.encode(value) else { return }
+        FileHandle.standardError.write(data + Data("\n".utf8))
+    }
+}
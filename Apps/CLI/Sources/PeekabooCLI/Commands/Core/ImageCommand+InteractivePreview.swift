@@ -0,0 +1,142 @@
+import AppKit
+import Commander
+import CoreGraphics
+import Foundation
+import PeekabooCore
+
+/// The button picked in the `--mode interactive` preview.
+enum ImagePreviewDecision: Equatable {
+    case save
+    case discard
+    case retry
+}
+
+@MainActor
+extension ImageCommand {
+    static let interactiveModeName = "interactive"
+
+    func validateInteractivePreviewOptions(hasGUISession: Bool = ImagePreviewWindow.hasGUISession) throws {
+        guard self.interactivePreview else {
+            if self.previewTimeoutMs != nil {
+                throw ValidationError("--preview-timeout-ms requires --mode interactive")
+            }
+            return
+        }
+        if let previewTimeoutMs, previewTimeoutMs < 0 {
+            throw ValidationError("--preview-timeout-ms must be zero or greater")
+        }
+        if self.jsonOutput || self.streamsImageToStdout {
+            throw ValidationError(
+                "--mode interactive asks a person to confirm and cannot be combined with --json or --path -"
+            )
+        }
+        guard hasGUISession else {
+            throw ValidationError(
+                "--mode interactive needs a logged-in desktop session; it is unavailable over SSH or in CI"
+            )
+        }
+    }
+
+    /// Shows the first capture of each attempt and asks whether to keep it. Discard deletes the files and returns none;
+    /// Retry deletes them and captures again. Without `--mode interactive` this is just `capture()`.
+    func previewingCaptures(
+        presenting present: (URL, Int, Int?) -> ImagePreviewDecision = {
+            ImagePreviewWindow.present(imageURL: $0, captureCount: $1, timeoutMs: $2)
+        },
+        capture: () async throws -> [ImageCapturedFile]
+    ) async throws -> [ImageCapturedFile] {
+        guard self.interactivePreview else { return try await capture() }
+        let timeoutMs = self.previewTimeoutMs.flatMap { $0 > 0 ? $0 : nil }
+        while true {
+            let captures = try await capture()
+            guard let first = captures.first else { return captures }
+            switch present(URL(fileURLWithPath: first.file.path), captures.count, timeoutMs) {
+            case .save:
+                return captures
+            case .discard:
+                Self.removeCaptureFiles(captures)
+                self.logger.info("Preview discarded; no files kept")
+                return []
+            case .retry:
+                Self.removeCaptureFiles(captures)
+                self.logger.info("Preview retry requested; capturing again")
+            }
+        }
+    }
+
+    private static func removeCaptureFiles(_ captures: [ImageCapturedFile]) {
+        for capture in captures {
+            try? FileManager.default.removeItem(atPath: capture.file.path)
+        }
+    }
+}
+
+/// Modal Save / Discard / Retry alert with the capture as its accessory view.
+@MainActor
+enum ImagePreviewWindow {
+    private static let maxPreviewSize = CGSize(width: 960, height: 640)
+
+    /// False without a window server session (SSH logins, launchd jobs) or under CI, where nobody can click.
+    static var hasGUISession: Bool {
+        guard !TerminalDetector.detectCapabilities().isCI,
+              let session = CGSessionCopyCurrentDictionary() as? [String: Any]
+        else {
+            return false
+        }
+        return session[kCGSessionOnConsoleKey as String] as? Bool ?? false
+    }
+
+    /// Blocks until a button is clicked. A positive `timeoutMs` presses Save once it elapses.
+    static func present(imageURL: URL, captureCount: Int, timeoutMs: Int?) -> ImagePreviewDecision {
+        let application = NSApplication.shared
+        application.setActivationPolicy(.accessory)
+        application.activate()
+
+        let alert = NSAlert()
+        alert.messageText = "Keep this capture?"
+        alert.informativeText = [
+            captureCount > 1 ? "Showing the first of \(captureCount) captures." : nil,
+            timeoutMs.map { "Saving automatically in \($0)ms." },
+        ].compactMap(\.self).joined(separator: " ")
+        alert.addButton(withTitle: "Save")
+        alert.addButton(withTitle: "Discard")
+        alert.addButton(withTitle: "Retry")
+        if let image = NSImage(contentsOf: imageURL) {
+            alert.accessoryView = Self.imageView(for: image)
+        }
+
+        var autoSave: Timer?
+        if let timeoutMs {
+            let timer = Timer(timeInterval: Double(timeoutMs) / 1000, repeats: false) { _ in
+                MainActor.assumeIsolated {
+                    NSApp.stopModal(withCode: .alertFirstButtonReturn)
+                }
+            }
+            RunLoop.main.add(timer, forMode: .modalPanel)
+            autoSave = timer
+        }
+        defer { autoSave?.invalidate() }
+
+        switch alert.runModal() {
+        case .alertSecondButtonReturn:
+            return .discard
+        case .alertThirdButtonReturn:
+            return .retry
+        default:
+            return .save
+        }
+    }
+
+    private static func imageView(for image: NSImage) -> NSImageView {
+        let scale = min(1, Self.maxPreviewSize.width / image.size.width, Self.maxPreviewSize.height / image.size.height)
+        let view = NSImageView(frame: NSRect(
+            x: 0,
+            y: 0,
+            width: (image.size.width * scale).rounded(),
+            height: (image.size.height * scale).rounded()
+        ))
+        view.image = image
+        view.imageScaling = .scaleProportionallyUpOrDown
+        return view
+    }
+}
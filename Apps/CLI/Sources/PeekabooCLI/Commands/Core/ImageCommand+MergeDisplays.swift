@@ -0,0 +1,152 @@
+import Commander
+import CoreGraphics
+import Foundation
+import PeekabooCore
+import PeekabooFoundation
+
+/// Pixel placement of per-display captures on a single merged canvas.
+///
+/// Display frames use global desktop points with a bottom-left origin, which matches CoreGraphics
+/// bitmap contexts, so placements can be drawn without flipping.
+struct DisplayMergeLayout: Equatable {
+    let canvasSize: CGSize
+    let placements: [CGRect]
+
+    init(frames: [CGRect], pixelSizes: [CGSize]) {
+        // Use the densest display so Retina captures keep their detail on mixed-DPI setups.
+        let scale = zip(frames, pixelSizes)
+            .compactMap { frame, size in frame.width > 0 ? size.width / frame.width : nil }
+            .max() ?? 1
+        let union = frames.reduce(CGRect.null) { $0.union($1) }
+        guard !union.isNull else {
+            self.canvasSize = .zero
+            self.placements = []
+            return
+        }
+
+        self.canvasSize = CGSize(
+            width: (union.width * scale).rounded(),
+            height: (union.height * scale).rounded()
+        )
+        self.placements = frames.map { frame in
+            CGRect(
+                x: ((frame.minX - union.minX) * scale).rounded(),
+                y: ((frame.minY - union.minY) * scale).rounded(),
+                width: (frame.width * scale).rounded(),
+                height: (frame.height * scale).rounded()
+            )
+        }
+    }
+}
+
+extension ImageObservationDiagnostics {
+    /// Combines the diagnostics of every display capture that fed a merged image.
+    init(merging parts: [ImageObservationDiagnostics]) {
+        self.spans = parts.flatMap(\.spans)
+        self.warnings = parts.flatMap(\.warnings)
+        self.state_snapshot = nil
+        self.target = nil
+    }
+}
+
+@MainActor
+extension ImageCommand {
+    func validateMergeDisplaysOptions() throws {
+        guard self.mergeDisplays else {
+            if self.gapColor != nil, self.stitchItems == nil {
+                throw ValidationError("--gap-color only applies together with --merge-displays or --stitch-items")
+            }
+            return
+        }
+
+        if self.screenIndex != nil {
+            throw ValidationError("--merge-displays captures every display; remove --screen-index")
+        }
+
+        if let mode = self.mode, mode != .screen {
+            throw ValidationError("--merge-displays requires --mode screen")
+        }
+
+        _ = try self.mergeGapColor()
+    }
+
+    func mergeGapColor() throws -> HexColor {
+        guard let raw = self.gapColor else {
+            return .black
+        }
+        guard let color = HexColor(hex: raw) else {
+            throw ValidationError("--gap-color must be #RRGGBB or #RRGGBBAA (got '\(raw)')")
+        }
+        return color
+    }
+
+    func captureMergedScreens(_ screens: [PeekabooCore.ScreenInfo]) async throws -> [ImageCapturedFile] {
+        let gapColor = try self.mergeGapColor()
+        var partPaths: [String] = []
+        defer {
+            for path in partPaths {
+                try? FileManager.default.removeItem(atPath: path)
+            }
+        }
+
+        var images: [CGImage] = []
+        var diagnostics: [ImageObservationDiagnostics] = []
+        for screen in screens {
+            let partURL = FileManager.default.temporaryDirectory
+                .appendingPathComponent("peekaboo-merge-\(UUID().uuidString)")
+                .appendingPathExtension(self.format.fileExtension)
+            let observation = try await self.captureObservation(
+                target: .screen(index: screen.index),
+                preferredName: "screen\(screen.index)",
+                index: nil,
+                outputURL: partURL
+            )
+            let part = try self.capturedFile(
+                from: observation,
+                preferredName: "screen\(screen.index)",
+                windowIndex: nil
+            )
+            partPaths.append(part.file.path)
+            try images.append(CapturedImageIO.load(at: part.file.path))
+            diagnostics.append(part.observation)
+        }
+
+        let layout = DisplayMergeLayout(
+            frames: screens.map(\.frame),
+            pixelSizes: images.map { CGSize(width: $0.width, height: $0.height) }
+        )
+        let merged = try Self.compositeMergedImage(images, layout: layout, gapColor: gapColor)
+        let outputURL = try self.makeCollisionFreeOutputURL(preferredName: "screens-merged", index: nil)
+        try CapturedImageIO.write(merged, to: outputURL, format: self.format, jpegQuality: self.jpegQuality)
+
+        return [
+            ImageCapturedFile(
+                file: SavedFile(path: outputURL.path, item_label: "screens-merged", mime_type: self.format.mimeType),
+                observation: ImageObservationDiagnostics(merging: diagnostics)
+            ),
+        ]
+    }
+
+    static func compositeMergedImage(
+        _ images: [CGImage],
+        layout: DisplayMergeLayout,
+        gapColor: HexColor
+    ) throws -> CGImage {
+        let context = try CapturedImageIO.makeContext(
+            width: Int(layout.canvasSize.width),
+            height: Int(layout.canvasSize.height)
+        )
+
+        context.setFillColor(gapColor.cgColor)
+        context.fill(CGRect(origin: .zero, size: layout.canvasSize))
+        context.interpolationQuality = .high
+        for (image, placement) in zip(images, layout.placements) {
+            context.draw(image, in: placement)
+        }
+
+        guard let merged = context.makeImage() else {
+            throw CaptureError.captureFailure("Failed to render merged display image")
+        }
+        return merged
+    }
+}
@@ -0,0 +1,49 @@
+import Commander
+import Foundation
+import PeekabooCore
+
+@MainActor
+extension ImageCommand {
+    func validateMonitorNameOptions() throws {
+        guard let name = self.monitorName else { return }
+        if self.screenIndex != nil {
+            throw ValidationError("--monitor-name and --screen-index both pick a display; use only one")
+        }
+        if self.mergeDisplays {
+            throw ValidationError("--merge-displays captures every display; remove --monitor-name")
+        }
+        if name.trimmingCharacters(in: .whitespacesAndNewlines).isEmpty {
+            throw ValidationError("--monitor-name must not be empty")
+        }
+    }
+
+    /// `--screen-index`, or the display `--monitor-name` resolves to right now.
+    func resolvedScreenIndex() throws -> Int? {
+        guard let name = self.monitorName else { return self.screenIndex }
+        return try Self.displayIndex(named: name, in: self.services.screens.listScreens())
+    }
+
+    /// Case-insensitive match against `ScreenInfo.name`; an exact name wins over a prefix, and a prefix must be unique.
+    ///
+    /// Names survive hotplugging, unlike indexes, which shift whenever displays are added or removed.
+    static func displayIndex(named name: String, in screens: [ScreenInfo]) throws -> Int {
+        let query = name.trimmingCharacters(in: .whitespacesAndNewlines)
+        if let exact = screens.first(where: { $0.name.caseInsensitiveCompare(query) == .orderedSame }) {
+            return exact.index
+        }
+
+        let prefixed = screens.filter { $0.name.lowercased().hasPrefix(query.lowercased()) }
+        if prefixed.count == 1, let match = prefixed.first {
+            return match.index
+        }
+
+        let available = screens.map { "'\($0.name)'" }.joined(separator: ", ")
+        if prefixed.count > 1 {
+            let matches = prefixed.map { "'\($0.name)'" }.joined(separator: ", ")
+            throw ValidationError("Monitor name '\(query)' matches several displays (\(matches)); be more specific")
+        }
+        throw ValidationError(
+            "No display matches '\(query)'. Available monitors: \(available.isEmpty ? "none" : available)"
+        )
+    }
+}
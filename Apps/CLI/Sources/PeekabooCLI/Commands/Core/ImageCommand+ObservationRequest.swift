@@ -20,7 +20,7 @@ extension ImageCommand {
         return .automatic
     }
 
-    func observationApplicationTargetForWindowCapture() throws -> ImageWindowObservationTarget {
+    func observationApplicationTargetForWindowCapture() async throws -> ImageWindowObservationTarget {
         if let pid = try self.resolveExplicitPIDObservationTarget() {
             let identifier = "PID:\(pid)"
             return ImageWindowObservationTarget(
@@ -30,11 +30,15 @@ extension ImageCommand {
             )
         }
 
-        let identifier = try self.resolveApplicationIdentifier()
-        return ImageWindowObservationTarget(
+        let identifier = try await self.resolveApplicationIdentifier(
+            caseSensitive: self.caseSensitive,
+            services: self.services
+        )
+        return try ImageWindowObservationTarget(
             target: .app(identifier: identifier, window: self.observationWindowSelection),
             focusIdentifier: identifier,
-            preferredName: identifier
+            // Case-sensitive matching hands back `PID:<pid>`; filenames keep the name as typed.
+            preferredName: self.resolveApplicationIdentifier()
         )
     }
 
@@ -54,6 +58,7 @@ extension ImageCommand {
             output: DesktopObservationOutputOptions(
                 path: outputURL.path,
                 format: self.format,
+                jpegQuality: self.jpegQuality,
                 saveRawScreenshot: true
             )
         )
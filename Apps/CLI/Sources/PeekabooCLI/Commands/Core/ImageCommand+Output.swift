@@ -12,6 +12,10 @@ struct ImageAnalysisData: Codable {
 struct ImageCapturedFile {
     let file: SavedFile
     let observation: ImageObservationDiagnostics
+
+    func with(focusConfirmed: Bool?) -> ImageCapturedFile {
+        ImageCapturedFile(file: self.file.with(focusConfirmed: focusConfirmed), observation: self.observation)
+    }
 }
 
 struct ImageObservationDiagnostics: Codable {
@@ -28,15 +32,35 @@ struct ImageObservationDiagnostics: Codable {
     }
 }
 
+/// Wall-clock milliseconds per `peekaboo image` phase; `capture_ms` includes the observation pipeline's file write.
+struct ImageCaptureTimings: Codable {
+    let total_ms: Double
+    let capture_ms: Double
+    let filter_ms: Double
+    let analysis_ms: Double?
+
+    static func milliseconds(since start: Date) -> Double {
+        Date().timeIntervalSince(start) * 1000
+    }
+}
+
 struct ImageCaptureResult: Codable {
     let files: [SavedFile]
     let observations: [ImageObservationDiagnostics]
+    let session: ImageCaptureSession?
+    let timing: ImageCaptureTimings?
+    /// Set only with `--debug-capture-steps`.
+    var debug_steps: [CaptureDebugStep]?
 }
 
 struct ImageAnalyzeResult: Codable {
     let files: [SavedFile]
     let analysis: ImageAnalysisData
     let observations: [ImageObservationDiagnostics]
+    let session: ImageCaptureSession?
+    let timing: ImageCaptureTimings?
+    /// Set only with `--debug-capture-steps`.
+    var debug_steps: [CaptureDebugStep]?
 }
 
 @MainActor
@@ -46,6 +70,10 @@ extension ImageCommand {
     }
 
     func validateStdoutStreamingOptions() throws {
+        if let outputJsonPath, ["", "-"].contains(outputJsonPath.trimmingCharacters(in: .whitespacesAndNewlines)) {
+            throw ValidationError("--output-json-path needs a file path; use --json to print JSON to stdout")
+        }
+
         guard self.streamsImageToStdout else { return }
 
         if self.jsonOutput {
@@ -57,7 +85,13 @@ extension ImageCommand {
         }
     }
 
-    func outputImageToStdout(_ captures: [ImageCapturedFile]) throws {
+    /// Streams the single capture to stdout; the `--output-json-path` result reports `files[].path` as `-`.
+    func outputImageToStdout(
+        _ captures: [ImageCapturedFile],
+        session: ImageCaptureSession,
+        timing: ImageCaptureTimings,
+        debugSteps: [CaptureDebugStep]? = nil
+    ) throws {
         defer {
             for capture in captures {
                 try? FileManager.default.removeItem(atPath: capture.file.path)
@@ -72,41 +106,116 @@ extension ImageCommand {
 
         let data = try Data(contentsOf: URL(fileURLWithPath: capture.file.path))
         FileHandle.standardOutput.write(data)
+        try self.writeJSONResultIfRequested(ImageCaptureResult(
+            files: [capture.file.with(path: "-")],
+            observations: [capture.observation],
+            session: session,
+            timing: self.noTiming ? nil : timing,
+            debug_steps: debugSteps
+        ))
     }
 
-    func outputResults(_ captures: [ImageCapturedFile]) {
+    func outputResults(
+        _ captures: [ImageCapturedFile],
+        session: ImageCaptureSession,
+        timing: ImageCaptureTimings,
+        debugSteps: [CaptureDebugStep]? = nil
+    ) throws {
         let output = ImageCaptureResult(
             files: captures.map(\.file),
-            observations: captures.map(\.observation)
+            observations: captures.map(\.observation),
+            session: session,
+            timing: self.noTiming ? nil : timing,
+            debug_steps: debugSteps
         )
+        try self.writeJSONResultIfRequested(output)
         if self.jsonOutput {
             outputSuccessCodable(data: output, logger: self.outputLogger)
         } else {
             captures.map(\.file).forEach { print("📸 \(self.describeSavedFile($0))") }
+            self.printDebugSteps(debugSteps)
         }
     }
 
-    func outputResultsWithAnalysis(_ captures: [ImageCapturedFile], analysis: ImageAnalysisData) {
+    func outputResultsWithAnalysis(
+        _ captures: [ImageCapturedFile],
+        analysis: ImageAnalysisData,
+        session: ImageCaptureSession,
+        timing: ImageCaptureTimings,
+        debugSteps: [CaptureDebugStep]? = nil
+    ) throws {
         let output = ImageAnalyzeResult(
             files: captures.map(\.file),
             analysis: analysis,
-            observations: captures.map(\.observation)
+            observations: captures.map(\.observation),
+            session: session,
+            timing: self.noTiming ? nil : timing,
+            debug_steps: debugSteps
         )
+        try self.writeJSONResultIfRequested(output)
         if self.jsonOutput {
             outputSuccessCodable(data: output, logger: self.outputLogger)
         } else {
             captures.map(\.file).forEach { print("📸 \(self.describeSavedFile($0))") }
+            self.printDebugSteps(debugSteps)
             print("\n🤖 Analysis (\(analysis.provider)) - \(analysis.model):")
             print(analysis.text)
         }
     }
 
+    func writeJSONResultIfRequested(_ data: some Codable) throws {
+        guard let outputJsonPath else { return }
+        try Self.writeJSONResult(
+            data,
+            debugLogs: self.outputLogger.getDebugLogs(),
+            warnings: self.outputLogger.getWarnings(),
+            to: outputJsonPath
+        )
+    }
+
+    /// Writes the same envelope `--json` prints to `path`, creating parent directories.
+    static func writeJSONResult(
+        _ data: some Codable,
+        debugLogs: [String],
+        warnings: [PeekabooWarning] = [],
+        to path: String
+    ) throws {
+        let url = URL(fileURLWithPath: (path as NSString).expandingTildeInPath)
+        let response = CodableJSONResponse(
+            success: true,
+            data: data,
+            messages: nil,
+            debug_logs: debugLogs,
+            warnings: warnings
+        )
+        do {
+            try FileManager.default.createDirectory(
+                at: url.deletingLastPathComponent(),
+                withIntermediateDirectories: true
+            )
+            let encoder = JSONEncoder()
+            encoder.outputFormatting = .prettyPrinted
+            try encoder.encode(response).write(to: url, options: .atomic)
+        } catch {
+            throw PeekabooError.fileIOError(
+                "Failed to write JSON result to \(url.path): \(error.localizedDescription)"
+            )
+        }
+    }
+
     func analyzeImage(at path: String, with prompt: String) async throws -> ImageAnalysisData {
         let aiService = PeekabooAIService()
         let response = try await aiService.analyzeImageFileDetailed(at: path, question: prompt, model: nil)
         return ImageAnalysisData(provider: response.provider, model: response.model, text: response.text)
     }
 
+    private func printDebugSteps(_ steps: [CaptureDebugStep]?) {
+        for step in steps ?? [] {
+            let duration = String(format: "%.1f", step.duration_ms)
+            print("🔍 \(step.step) → \(step.path) (\(step.width)x\(step.height), \(duration) ms)")
+        }
+    }
+
     private func describeSavedFile(_ file: SavedFile) -> String {
         var segments: [String] = []
         if let label = file.item_label ?? file.window_title {
@@ -115,11 +224,69 @@ extension ImageCommand {
             segments.append("window \(index)")
         }
         segments.append("→ \(file.path)")
+        if file.deduplicated == true {
+            segments.append("(duplicate of an earlier capture)")
+        }
         return segments.joined(separator: " ")
     }
 }
 
+extension SavedFile {
+    /// A copy with the given fields replaced; `nil` keeps the current value.
+    func with(
+        path: String? = nil,
+        deduplicated: Bool? = nil,
+        focusConfirmed: Bool? = nil,
+        verified: Bool? = nil,
+        colorSpace: String? = nil,
+        changedRegion: ChangedRegion? = nil,
+        originalBounds: WindowBounds? = nil,
+        cursorAtCapture: CursorPosition? = nil,
+        smartCropRegion: WindowBounds? = nil,
+        renamed: Bool? = nil,
+        collisionWith: String? = nil,
+        checksum: String? = nil,
+        checksumAlgorithm: String? = nil,
+        codecProfile: String? = nil
+    ) -> SavedFile {
+        SavedFile(
+            path: path ?? self.path,
+            item_label: self.item_label,
+            window_title: self.window_title,
+            window_id: self.window_id,
+            window_index: self.window_index,
+            mime_type: self.mime_type,
+            padded_bounds: self.padded_bounds,
+            deduplicated: deduplicated ?? self.deduplicated,
+            focus_confirmed: focusConfirmed ?? self.focus_confirmed,
+            verified: verified ?? self.verified,
+            window_was_offscreen: self.window_was_offscreen,
+            color_space: colorSpace ?? self.color_space,
+            changed_region: changedRegion ?? self.changed_region,
+            original_bounds: originalBounds ?? self.original_bounds,
+            cursor_at_capture: cursorAtCapture ?? self.cursor_at_capture,
+            smart_crop_region: smartCropRegion ?? self.smart_crop_region,
+            renamed: renamed ?? self.renamed,
+            collision_with: collisionWith ?? self.collision_with,
+            checksum: checksum ?? self.checksum,
+            checksum_algorithm: checksumAlgorithm ?? self.checksum_algorithm,
+            codec_profile: codecProfile ?? self.codec_profile
+        )
+    }
+}
+
 extension ImageFormat {
+    /// The format a path's extension names, or `nil` when it is missing or not one Peekaboo writes
+    /// (`.webp`, `.tiff`, `.bmp`, `.avif`, …), so callers can fall back to their default.
+    init?(path: String) {
+        let expanded = (path as NSString).expandingTildeInPath
+        switch URL(fileURLWithPath: expanded).pathExtension.lowercased() {
+        case "png": self = .png
+        case "jpg", "jpeg": self = .jpg
+        default: return nil
+        }
+    }
+
     var fileExtension: String {
         switch self {
         case .png: "png"
@@ -133,4 +300,32 @@ extension ImageFormat {
         case .jpg: "image/jpeg"
         }
     }
+
+    /// Every extension `init?(path:)` maps to this format, primary (`fileExtension`) first.
+    var acceptedExtensions: [String] {
+        switch self {
+        case .png: ["png"]
+        case .jpg: ["jpg", "jpeg"]
+        }
+    }
+
+    var metadata: ImageFormatMetadata {
+        ImageFormatMetadata(
+            format: self.rawValue,
+            extensions: self.acceptedExtensions,
+            mime_type: self.mimeType,
+            supports_quality: self == .jpg,
+            lossless: self == .png
+        )
+    }
+}
+
+/// One row of `peekaboo --image-format-info`.
+struct ImageFormatMetadata: Codable, Equatable {
+    let format: String
+    let extensions: [String]
+    let mime_type: String
+    /// Whether `PEEKABOO_QUALITY` changes the encoded output.
+    let supports_quality: Bool
+    let lossless: Bool
 }
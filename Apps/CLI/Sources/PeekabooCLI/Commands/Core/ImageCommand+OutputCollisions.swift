@@ -0,0 +1,81 @@
+import Commander
+import Foundation
+import PeekabooCore
+import PeekabooFoundation
+
+/// What `--on-collision` does when a capture's output path already exists.
+enum ImageOutputCollisionPolicy: String, CaseIterable, ExpressibleFromArgument {
+    /// Write next to it as `name-1.png`, `name-2.png`, … and report `renamed`/`collision_with`.
+    case rename
+    /// Replace the existing file.
+    case overwrite
+    /// Fail before capturing.
+    case error
+
+    init?(argument: String) {
+        self.init(rawValue: argument.lowercased())
+    }
+}
+
+/// Paths `--on-collision rename` moved off an existing file during one run, keyed by the path actually written.
+@MainActor
+final class ImageOutputCollisionLog {
+    private(set) var originals: [String: String] = [:]
+
+    func record(renamed: String, original: String) {
+        self.originals[renamed] = original
+    }
+}
+
+@MainActor
+extension ImageCommand {
+    var resolvedCollisionPolicy: ImageOutputCollisionPolicy {
+        self.onCollision ?? .rename
+    }
+
+    /// `makeOutputURL` with `--on-collision` applied, checked before the capture so `error` leaves nothing behind.
+    func makeCollisionFreeOutputURL(preferredName: String?, index: Int?) throws -> URL {
+        let url = self.makeOutputURL(preferredName: preferredName, index: index)
+        // Stdout streaming writes to a fresh temporary file that is deleted afterwards.
+        guard !self.streamsImageToStdout, FileManager.default.fileExists(atPath: url.path) else { return url }
+
+        switch self.resolvedCollisionPolicy {
+        case .overwrite:
+            return url
+        case .error:
+            throw PeekabooError.fileIOError(
+                "\(url.path) already exists; pass --on-collision rename or overwrite, or choose another --path"
+            )
+        case .rename:
+            let unique = Self.uniqueOutputURL(for: url)
+            self.outputCollisions.record(renamed: unique.path, original: url.path)
+            return unique
+        }
+    }
+
+    /// The first of `name-1.ext`, `name-2.ext`, … next to `url` that does not exist yet.
+    static func uniqueOutputURL(for url: URL, fileManager: FileManager = .default) -> URL {
+        let directory = url.deletingLastPathComponent()
+        let stem = url.deletingPathExtension().lastPathComponent
+        let ext = url.pathExtension
+        var suffix = 1
+        while true {
+            let candidate = directory.appendingPathComponent("\(stem)-\(suffix)").appendingPathExtension(ext)
+            if !fileManager.fileExists(atPath: candidate.path) {
+                return candidate
+            }
+            suffix += 1
+        }
+    }
+
+    /// Marks files written under a new name by `--on-collision rename` with the path they would have replaced.
+    func markingRenamedFiles(_ files: [ImageCapturedFile]) -> [ImageCapturedFile] {
+        files.map { capture in
+            guard let original = self.outputCollisions.originals[capture.file.path] else { return capture }
+            return ImageCapturedFile(
+                file: capture.file.with(renamed: true, collisionWith: original),
+                observation: capture.observation
+            )
+        }
+    }
+}
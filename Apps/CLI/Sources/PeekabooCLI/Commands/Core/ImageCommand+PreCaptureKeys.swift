@@ -0,0 +1,66 @@
+import Commander
+import Foundation
+import PeekabooCore
+
+@MainActor
+extension ImageCommand {
+    static let defaultPreCaptureHoldDurationMs = 50
+
+    /// Each `--pre-capture-keys` value is one chord, split like `peekaboo hotkey` keys (`cmd+shift+p`, `cmd,l`).
+    var preCaptureChords: [[String]] {
+        self.preCaptureKeys.map(HotkeyCommand.parseKeyNames).filter { !$0.isEmpty }
+    }
+
+    func validatePreCaptureKeysOptions() throws {
+        guard !self.preCaptureKeys.isEmpty else {
+            if self.preCaptureDelayMs != nil {
+                throw ValidationError("--pre-capture-delay-ms requires --pre-capture-keys")
+            }
+            if self.allowInputInjection {
+                throw ValidationError("--allow-input-injection only applies to --pre-capture-keys; remove it")
+            }
+            return
+        }
+        guard self.allowInputInjection else {
+            throw ValidationError("--pre-capture-keys presses keys in the target app; add --allow-input-injection")
+        }
+        guard self.preCaptureChords.count == self.preCaptureKeys.count else {
+            throw ValidationError("--pre-capture-keys values must name at least one key, e.g. 'cmd+shift+p'")
+        }
+        if let preCaptureDelayMs, preCaptureDelayMs < 0 {
+            throw ValidationError("--pre-capture-delay-ms must be zero or greater")
+        }
+
+        let appName = self.app?.lowercased()
+        let mode = self.determineMode()
+        guard self.app != nil || self.pid != nil,
+              appName != "menubar", appName != "frontmost",
+              self.windowId == nil, self.stitchItems == nil,
+              mode == .window || mode == .multi
+        else {
+            throw ValidationError("--pre-capture-keys needs a window or multi capture of an --app or --pid target")
+        }
+        if self.captureFocus == .background {
+            throw ValidationError("--pre-capture-keys needs the target focused; remove --capture-focus background")
+        }
+    }
+
+    /// Presses the `--pre-capture-keys` chords in order once the target is focused, then waits
+    /// `--pre-capture-delay-ms` so the UI state they trigger (a menu, an expanded tree) can settle.
+    func sendPreCaptureKeys() async throws {
+        let chords = self.preCaptureChords
+        guard !chords.isEmpty else { return }
+        try await withErrorContext("pre_capture_keys") {
+            for chord in chords {
+                try await AutomationServiceBridge.hotkey(
+                    automation: self.services.automation,
+                    keys: chord.joined(separator: ","),
+                    holdDuration: Self.defaultPreCaptureHoldDurationMs
+                )
+            }
+            if let preCaptureDelayMs, preCaptureDelayMs > 0 {
+                try await Task.sleep(for: .milliseconds(preCaptureDelayMs))
+            }
+        }
+    }
+}
@@ -0,0 +1,50 @@
+import Commander
+import CoreGraphics
+import Foundation
+import PeekabooCore
+
+@MainActor
+extension ImageCommand {
+    func validateRestoreLayoutOptions() throws {
+        guard self.restoreLayout else { return }
+        let appName = self.app?.lowercased()
+        let mode = self.determineMode()
+        guard self.app != nil || self.pid != nil,
+              appName != "menubar", appName != "frontmost",
+              self.windowId == nil, self.stitchItems == nil,
+              mode == .window || mode == .multi
+        else {
+            throw ValidationError("--restore-layout needs a window or multi capture of an --app or --pid target")
+        }
+    }
+
+    /// Moves the target's windows back to the bounds `list windows --cache-layout` saved for its PID, so repeated
+    /// runs capture the same geometry. Runs after focusing, since some apps ignore resizes while inactive.
+    func restoreCachedLayout(appIdentifier: String) async throws {
+        guard self.restoreLayout else { return }
+        try await withErrorContext("restore_layout") {
+            let application = try await self.services.applications.findApplication(identifier: appIdentifier)
+            let layout = try WindowLayoutCache.load(pid: application.processIdentifier)
+            let windows = try await WindowServiceBridge.listWindows(
+                windows: self.services.windows,
+                target: .application(appIdentifier)
+            )
+
+            let restorations = WindowLayoutCache.restorations(for: layout, in: windows)
+            let missing = layout.windows.count - restorations.count
+            if missing > 0 {
+                self.outputLogger.warn(
+                    .LAYOUT_NOT_FULLY_RESTORED,
+                    "--restore-layout: \(missing) cached window(s) are no longer open; skipping"
+                )
+            }
+            for (window, bounds) in restorations where window.bounds != bounds {
+                try await WindowServiceBridge.setWindowBounds(
+                    windows: self.services.windows,
+                    target: .windowId(window.windowID),
+                    bounds: bounds
+                )
+            }
+        }
+    }
+}
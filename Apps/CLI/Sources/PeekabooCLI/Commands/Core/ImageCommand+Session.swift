@@ -0,0 +1,54 @@
+import Commander
+import Foundation
+import PeekabooCore
+
+/// Groups the files from one `peekaboo image` run so downstream tools can correlate them.
+struct ImageCaptureSession: Codable {
+    let id: String
+    let started_at: String
+    let ended_at: String
+    let mode: String
+    let app: String?
+    let metadata: [String: String]
+}
+
+@MainActor
+extension ImageCommand {
+    func validateSessionOptions() throws {
+        if let id = self.sessionId, UUID(uuidString: id) == nil {
+            throw ValidationError("--session-id must be a UUID (e.g. \(UUID().uuidString))")
+        }
+        _ = try self.parsedSessionMetadata()
+    }
+
+    /// `--session-metadata key=value` pairs; later repeats of a key win.
+    func parsedSessionMetadata() throws -> [String: String] {
+        try self.sessionMetadata.reduce(into: [:]) { metadata, entry in
+            guard let separator = entry.firstIndex(of: "=") else {
+                throw ValidationError("--session-metadata expects key=value, got '\(entry)'")
+            }
+            let key = entry[..<separator].trimmingCharacters(in: .whitespacesAndNewlines)
+            guard !key.isEmpty else {
+                throw ValidationError("--session-metadata key must not be empty in '\(entry)'")
+            }
+            metadata[key] = String(entry[entry.index(after: separator)...])
+        }
+    }
+
+    func makeCaptureSession(startedAt: Date, endedAt: Date = Date()) throws -> ImageCaptureSession {
+        // `--app menubar|frontmost` bypass the mode picker, so report them as their own modes.
+        let mode = switch self.app?.lowercased() {
+        case "menubar": "menubar"
+        case "frontmost": "frontmost"
+        default: self.determineMode().rawValue
+        }
+        return try ImageCaptureSession(
+            id: self.sessionId?.uppercased() ?? UUID().uuidString,
+            started_at: startedAt.ISO8601Format(),
+            ended_at: endedAt.ISO8601Format(),
+            mode: mode,
+            app: self.app,
+            metadata: self.parsedSessionMetadata()
+        )
+    }
+}
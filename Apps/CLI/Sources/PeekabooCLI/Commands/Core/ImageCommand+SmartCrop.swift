@@ -0,0 +1,143 @@
+import Commander
+import CoreGraphics
+import Foundation
+import PeekabooCore
+
+/// Picks the `--smart-crop` window: the one whose pixels carry the most edge energy, i.e. the largest sum of
+/// grayscale gradient magnitudes. Dense text, charts, and controls score high; flat backgrounds score zero.
+enum SaliencyCropper {
+    /// The `width`×`height` rect with the highest saliency sum; ties go to the topmost, then leftmost.
+    /// Nil when the image is smaller than the requested size.
+    static func mostSalientRegion(in image: CGImage, width: Int, height: Int) throws -> CGRect? {
+        guard width > 0, height > 0, width <= image.width, height <= image.height else { return nil }
+
+        let saliency = try self.saliencyMap(of: image)
+        let imageWidth = image.width
+
+        // Slide a band of `height` rows down the image, keeping per-column sums, and slide the window across it.
+        var columnSums = [Double](repeating: 0, count: imageWidth)
+        for y in 0..<height {
+            for x in 0..<imageWidth {
+                columnSums[x] += Double(saliency[y * imageWidth + x])
+            }
+        }
+
+        var best = (score: -1.0, x: 0, y: 0)
+        for top in 0...(image.height - height) {
+            if top > 0 {
+                let leaving = (top - 1) * imageWidth
+                let entering = (top + height - 1) * imageWidth
+                for x in 0..<imageWidth {
+                    columnSums[x] += Double(saliency[entering + x]) - Double(saliency[leaving + x])
+                }
+            }
+            var score = columnSums[0..<width].reduce(0, +)
+            for left in 0...(imageWidth - width) {
+                if left > 0 {
+                    score += columnSums[left + width - 1] - columnSums[left - 1]
+                }
+                if score > best.score {
+                    best = (score, left, top)
+                }
+            }
+        }
+
+        return CGRect(x: best.x, y: best.y, width: width, height: height)
+    }
+
+    /// Per-pixel L2 norm of the forward-difference gradient of the image's luma, row-major and top-down.
+    static func saliencyMap(of image: CGImage) throws -> [Float] {
+        let width = image.width
+        let height = image.height
+        let bytes = try CapturedImageIO.rgbaBytes(of: image)
+        let luma = (0..<(width * height)).map { index -> Float in
+            let offset = index * 4
+            return 0.299 * Float(bytes[offset]) + 0.587 * Float(bytes[offset + 1]) + 0.114 * Float(bytes[offset + 2])
+        }
+
+        var saliency = [Float](repeating: 0, count: width * height)
+        for y in 0..<height {
+            for x in 0..<width {
+                let index = y * width + x
+                let dx = x + 1 < width ? luma[index + 1] - luma[index] : 0
+                let dy = y + 1 < height ? luma[index + width] - luma[index] : 0
+                saliency[index] = (dx * dx + dy * dy).squareRoot()
+            }
+        }
+        return saliency
+    }
+}
+
+@MainActor
+extension ImageCommand {
+    func validateSmartCropOptions() throws {
+        _ = try self.smartCropSize()
+    }
+
+    /// `--smart-crop WIDTHxHEIGHT`, in pixels of the saved image.
+    func smartCropSize() throws -> (width: Int, height: Int)? {
+        guard let smartCrop else { return nil }
+        let parts = smartCrop.lowercased().split(separator: "x", omittingEmptySubsequences: false)
+        guard parts.count == 2,
+              let width = Int(parts[0].trimmingCharacters(in: .whitespaces)),
+              let height = Int(parts[1].trimmingCharacters(in: .whitespaces))
+        else {
+            throw ValidationError("--smart-crop must be WIDTHxHEIGHT in pixels, e.g. 800x600")
+        }
+        guard width > 0, height > 0 else {
+            throw ValidationError("--smart-crop width and height must be greater than zero")
+        }
+        return (width, height)
+    }
+
+    /// Cuts each file to its most detailed `--smart-crop` window after `--crop-to-bounds` and `--trim-whitespace`,
+    /// so the filters and `--annotate` see the final pixels. `smart_crop_region` records the rect in the full capture.
+    func smartCropping(
+        _ captures: [ImageCapturedFile],
+        recorder: CaptureStepRecorder? = nil
+    ) throws -> [ImageCapturedFile] {
+        guard let size = try self.smartCropSize() else { return captures }
+
+        return try captures.enumerated().map { index, capture in
+            let image = try CapturedImageIO.load(at: capture.file.path)
+            let startedAt = Date()
+            guard let region = try SaliencyCropper.mostSalientRegion(
+                in: image,
+                width: size.width,
+                height: size.height
+            ) else {
+                let requested = "\(size.width)x\(size.height)"
+                throw PeekabooError.invalidInput(
+                    "--smart-crop \(requested) is larger than the \(image.width)x\(image.height) capture"
+                )
+            }
+            guard let cropped = image.cropping(to: region) else {
+                throw CaptureError.captureFailure("Failed to crop capture for --smart-crop")
+            }
+            try recorder?.record(
+                CaptureStepRecorder.smartCropStepName,
+                image: cropped,
+                durationMs: ImageCaptureTimings.milliseconds(since: startedAt),
+                besides: capture.file.path,
+                captureIndex: captures.count > 1 ? index : nil
+            )
+            try CapturedImageIO.write(
+                cropped,
+                to: URL(fileURLWithPath: capture.file.path),
+                format: self.format,
+                jpegQuality: self.jpegQuality
+            )
+            let origin = capture.file.original_bounds
+            let smartCropRegion = WindowBounds(
+                x: Int(region.minX) + (origin?.x ?? 0),
+                y: Int(region.minY) + (origin?.y ?? 0),
+                width: size.width,
+                height: size.height
+            )
+            return ImageCapturedFile(
+                file: capture.file.with(smartCropRegion: smartCropRegion),
+                observation: capture.observation
+            )
+        }
+    }
+}
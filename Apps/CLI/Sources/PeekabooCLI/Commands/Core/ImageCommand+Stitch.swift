@@ -0,0 +1,257 @@
+import Commander
+import CoreGraphics
+import Foundation
+import PeekabooCore
+import PeekabooFoundation
+
+/// How `--stitch-items` arranges its captures on the canvas.
+enum ImageStitchLayout: String, CaseIterable, ExpressibleFromArgument {
+    /// Left to right, scaled to the shortest capture's height.
+    case horizontal
+    /// Top to bottom, scaled to the narrowest capture's width.
+    case vertical
+    /// Rows of `ceil(sqrt(n))` cells sized to the smallest width and height, each capture fitted and centered.
+    case grid
+
+    init?(argument: String) {
+        self.init(rawValue: argument.lowercased())
+    }
+}
+
+/// One entry of `--stitch-items`, e.g. `screen:0`, `app:Safari`, `pid:4242`, or `window-id:12345`.
+enum ImageStitchItem: Equatable {
+    case screen(Int)
+    case app(String)
+    case pid(Int32)
+    case windowID(Int)
+
+    static func parseList(_ raw: String) throws -> [ImageStitchItem] {
+        try raw.split(separator: ",", omittingEmptySubsequences: false).map { try Self(parsing: String($0)) }
+    }
+
+    init(parsing raw: String) throws {
+        let entry = raw.trimmingCharacters(in: .whitespaces)
+        guard let separator = entry.firstIndex(of: ":") else {
+            throw ValidationError("--stitch-items entry '\(entry)' must be kind:value (screen, app, pid, or window-id)")
+        }
+        let kind = entry[..<separator].lowercased()
+        let value = entry[entry.index(after: separator)...].trimmingCharacters(in: .whitespaces)
+
+        switch kind {
+        case "screen":
+            guard let index = Int(value), index >= 0 else {
+                throw ValidationError("--stitch-items screen needs a display index, got '\(value)'")
+            }
+            self = .screen(index)
+        case "app":
+            guard !value.isEmpty else {
+                throw ValidationError("--stitch-items app needs an application name or bundle ID")
+            }
+            self = .app(value)
+        case "pid":
+            guard let pid = Int32(value), pid > 0 else {
+                throw ValidationError("--stitch-items pid needs a process ID, got '\(value)'")
+            }
+            self = .pid(pid)
+        case "window-id":
+            guard let id = Int(value), id > 0 else {
+                throw ValidationError("--stitch-items window-id needs a window ID, got '\(value)'")
+            }
+            self = .windowID(id)
+        default:
+            throw ValidationError("--stitch-items kind '\(kind)' is not one of screen, app, pid, window-id")
+        }
+    }
+
+    var observationTarget: DesktopObservationTargetRequest {
+        switch self {
+        case let .screen(index): .screen(index: index)
+        case let .app(identifier): .app(identifier: identifier, window: .automatic)
+        case let .pid(pid): .pid(pid, window: .automatic)
+        case let .windowID(id): .windowID(CGWindowID(id))
+        }
+    }
+
+    var preferredName: String {
+        switch self {
+        case let .screen(index): "screen\(index)"
+        case let .app(identifier): identifier
+        case let .pid(pid): "PID-\(pid)"
+        case let .windowID(id): "window-\(id)"
+        }
+    }
+}
+
+/// Pixel placement of captures on a stitched canvas, top-left origin like the screenshots themselves.
+struct ImageStitchPlan: Equatable {
+    let canvasSize: CGSize
+    let placements: [CGRect]
+
+    /// `padding` pixels separate neighbouring captures; there is no outer margin.
+    init(sizes: [CGSize], layout: ImageStitchLayout, padding: Int) {
+        let gap = CGFloat(padding)
+        guard !sizes.isEmpty else {
+            self.canvasSize = .zero
+            self.placements = []
+            return
+        }
+
+        switch layout {
+        case .horizontal:
+            let height = sizes.map(\.height).min() ?? 0
+            var x: CGFloat = 0
+            self.placements = sizes.map { size in
+                let width = (size.width * height / size.height).rounded()
+                defer { x += width + gap }
+                return CGRect(x: x, y: 0, width: width, height: height)
+            }
+            self.canvasSize = CGSize(width: x - gap, height: height)
+        case .vertical:
+            let width = sizes.map(\.width).min() ?? 0
+            var y: CGFloat = 0
+            self.placements = sizes.map { size in
+                let height = (size.height * width / size.width).rounded()
+                defer { y += height + gap }
+                return CGRect(x: 0, y: y, width: width, height: height)
+            }
+            self.canvasSize = CGSize(width: width, height: y - gap)
+        case .grid:
+            let columns = Int(Double(sizes.count).squareRoot().rounded(.up))
+            let rows = (sizes.count + columns - 1) / columns
+            let cell = CGSize(width: sizes.map(\.width).min() ?? 0, height: sizes.map(\.height).min() ?? 0)
+            self.placements = sizes.enumerated().map { index, size in
+                let scale = min(cell.width / size.width, cell.height / size.height)
+                let fitted = CGSize(width: (size.width * scale).rounded(), height: (size.height * scale).rounded())
+                let origin = CGPoint(
+                    x: CGFloat(index % columns) * (cell.width + gap),
+                    y: CGFloat(index / columns) * (cell.height + gap)
+                )
+                return CGRect(
+                    x: origin.x + ((cell.width - fitted.width) / 2).rounded(.down),
+                    y: origin.y + ((cell.height - fitted.height) / 2).rounded(.down),
+                    width: fitted.width,
+                    height: fitted.height
+                )
+            }
+            self.canvasSize = CGSize(
+                width: CGFloat(columns) * cell.width + CGFloat(columns - 1) * gap,
+                height: CGFloat(rows) * cell.height + CGFloat(rows - 1) * gap
+            )
+        }
+    }
+}
+
+@MainActor
+extension ImageCommand {
+    func validateStitchOptions() throws {
+        guard let stitchItems else {
+            if self.stitchLayout != nil || self.stitchPadding != nil {
+                throw ValidationError("--stitch-layout and --stitch-padding require --stitch-items")
+            }
+            return
+        }
+        if self.mergeDisplays {
+            throw ValidationError("--stitch-items cannot be combined with --merge-displays")
+        }
+        let targeting = [
+            self.mode.map { _ in "--mode" },
+            self.app.map { _ in "--app" },
+            self.pid.map { _ in "--pid" },
+            self.windowTitle.map { _ in "--window-title" },
+            self.windowIndex.map { _ in "--window-index" },
+            self.windowId.map { _ in "--window-id" },
+            self.screenIndex.map { _ in "--screen-index" },
+            self.monitorName.map { _ in "--monitor-name" },
+            self.region.map { _ in "--region" },
+        ].compactMap(\.self)
+        if let first = targeting.first {
+            throw ValidationError("--stitch-items names every capture itself; remove \(first)")
+        }
+        if try ImageStitchItem.parseList(stitchItems).count < 2 {
+            throw ValidationError("--stitch-items needs at least two entries")
+        }
+        if let padding = self.stitchPadding, padding < 0 {
+            throw ValidationError("--stitch-padding must not be negative")
+        }
+    }
+
+    func captureStitched(_ items: [ImageStitchItem]) async throws -> [ImageCapturedFile] {
+        let background = try self.mergeGapColor()
+        var partPaths: [String] = []
+        defer {
+            for path in partPaths {
+                try? FileManager.default.removeItem(atPath: path)
+            }
+        }
+
+        var images: [CGImage] = []
+        var diagnostics: [ImageObservationDiagnostics] = []
+        for item in items {
+            let partURL = FileManager.default.temporaryDirectory
+                .appendingPathComponent("peekaboo-stitch-\(UUID().uuidString)")
+                .appendingPathExtension(self.format.fileExtension)
+            let observation = try await self.captureObservation(
+                target: item.observationTarget,
+                preferredName: item.preferredName,
+                index: nil,
+                outputURL: partURL
+            )
+            let part = try self.capturedFile(from: observation, preferredName: item.preferredName, windowIndex: nil)
+            partPaths.append(part.file.path)
+            try images.append(CapturedImageIO.load(at: part.file.path))
+            diagnostics.append(part.observation)
+        }
+
+        let stitched = try Self.stitchImages(
+            images,
+            layout: self.stitchLayout ?? .horizontal,
+            padding: self.stitchPadding ?? 0,
+            background: background
+        )
+        let outputURL = try self.makeCollisionFreeOutputURL(preferredName: "stitched", index: nil)
+        try CapturedImageIO.write(stitched, to: outputURL, format: self.format, jpegQuality: self.jpegQuality)
+
+        return [
+            ImageCapturedFile(
+                file: SavedFile(path: outputURL.path, item_label: "stitched", mime_type: self.format.mimeType),
+                observation: ImageObservationDiagnostics(merging: diagnostics)
+            ),
+        ]
+    }
+
+    static func stitchImages(
+        _ images: [CGImage],
+        layout: ImageStitchLayout,
+        padding: Int,
+        background: HexColor
+    ) throws -> CGImage {
+        let plan = ImageStitchPlan(
+            sizes: images.map { CGSize(width: $0.width, height: $0.height) },
+            layout: layout,
+            padding: padding
+        )
+        let context = try CapturedImageIO.makeContext(
+            width: Int(plan.canvasSize.width),
+            height: Int(plan.canvasSize.height)
+        )
+
+        context.setFillColor(background.cgColor)
+        context.fill(CGRect(origin: .zero, size: plan.canvasSize))
+        context.interpolationQuality = .high
+        for (image, placement) in zip(images, plan.placements) {
+            // The plan is top-down; CoreGraphics contexts draw from the bottom-left.
+            let flipped = CGRect(
+                x: placement.minX,
+                y: plan.canvasSize.height - placement.maxY,
+                width: placement.width,
+                height: placement.height
+            )
+            context.draw(image, in: flipped)
+        }
+
+        guard let stitched = context.makeImage() else {
+            throw CaptureError.captureFailure("Failed to render stitched image")
+        }
+        return stitched
+    }
+}
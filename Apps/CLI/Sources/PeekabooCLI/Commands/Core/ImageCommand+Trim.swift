@@ -0,0 +1,100 @@
+import Commander
+import CoreGraphics
+import Foundation
+import PeekabooCore
+
+/// Finds the content inside a solid-color border for `--trim-whitespace`.
+enum BorderTrimmer {
+    static let defaultTolerance = 10
+
+    /// The rect left after peeling rows and columns that match the top-left pixel within `tolerance` on every
+    /// channel. Nil when there is nothing to trim or the whole image is that one color.
+    static func contentBounds(of image: CGImage, tolerance: UInt8) throws -> CGRect? {
+        let width = image.width
+        let height = image.height
+        guard width > 0, height > 0 else { return nil }
+
+        let bytes = try CapturedImageIO.rgbaBytes(of: image)
+        let edge = Array(bytes[0..<4])
+        func isBorder(_ x: Int, _ y: Int) -> Bool {
+            let offset = (y * width + x) * 4
+            return (0..<4).allSatisfy { abs(Int(bytes[offset + $0]) - Int(edge[$0])) <= Int(tolerance) }
+        }
+
+        guard let top = (0..<height).first(where: { y in !(0..<width).allSatisfy { isBorder($0, y) } }) else {
+            return nil
+        }
+        let bottom = (top..<height).last { y in !(0..<width).allSatisfy { isBorder($0, y) } } ?? top
+        let rows = top...bottom
+        let left = (0..<width).first { x in !rows.allSatisfy { isBorder(x, $0) } } ?? 0
+        let right = (left..<width).last { x in !rows.allSatisfy { isBorder(x, $0) } } ?? left
+
+        let bounds = CGRect(x: left, y: top, width: right - left + 1, height: bottom - top + 1)
+        return bounds == CGRect(x: 0, y: 0, width: width, height: height) ? nil : bounds
+    }
+}
+
+@MainActor
+extension ImageCommand {
+    func validateTrimOptions() throws {
+        _ = try self.trimToleranceValue()
+    }
+
+    func trimToleranceValue() throws -> UInt8? {
+        guard let trimTolerance else {
+            return self.trimWhitespace ? UInt8(BorderTrimmer.defaultTolerance) : nil
+        }
+        guard self.trimWhitespace else {
+            throw ValidationError("--trim-tolerance requires --trim-whitespace")
+        }
+        guard let tolerance = UInt8(exactly: trimTolerance) else {
+            throw ValidationError("--trim-tolerance must be between 0 and 255")
+        }
+        return tolerance
+    }
+
+    /// Crops each file to its content before the filter chain, so `--annotate` coordinates are in trimmed pixels.
+    /// `original_bounds` records the rect that was kept, in the uncropped, untrimmed capture.
+    func trimmingBorders(
+        _ captures: [ImageCapturedFile],
+        recorder: CaptureStepRecorder? = nil
+    ) throws -> [ImageCapturedFile] {
+        guard let tolerance = try self.trimToleranceValue() else { return captures }
+
+        return try captures.enumerated().map { index, capture in
+            let image = try CapturedImageIO.load(at: capture.file.path)
+            let startedAt = Date()
+            guard let bounds = try BorderTrimmer.contentBounds(of: image, tolerance: tolerance) else {
+                return capture
+            }
+            guard let trimmed = image.cropping(to: bounds) else {
+                throw CaptureError.captureFailure("Failed to trim capture borders")
+            }
+            try recorder?.record(
+                CaptureStepRecorder.trimStepName,
+                image: trimmed,
+                durationMs: ImageCaptureTimings.milliseconds(since: startedAt),
+                besides: capture.file.path,
+                captureIndex: captures.count > 1 ? index : nil
+            )
+            try CapturedImageIO.write(
+                trimmed,
+                to: URL(fileURLWithPath: capture.file.path),
+                format: self.format,
+                jpegQuality: self.jpegQuality
+            )
+            // After `--crop-to-bounds`, offsets are relative to the crop, so shift them back into the full capture.
+            let origin = capture.file.original_bounds
+            let originalBounds = WindowBounds(
+                x: Int(bounds.minX) + (origin?.x ?? 0),
+                y: Int(bounds.minY) + (origin?.y ?? 0),
+                width: Int(bounds.width),
+                height: Int(bounds.height)
+            )
+            return ImageCapturedFile(
+                file: capture.file.with(originalBounds: originalBounds),
+                observation: capture.observation
+            )
+        }
+    }
+}
@@ -0,0 +1,103 @@
+import Commander
+import CoreGraphics
+import Foundation
+import PeekabooCore
+import PeekabooFoundation
+
+/// Corner (or center) of the capture that `--watermark-image` is pinned to.
+enum ImageWatermarkPosition: String, CaseIterable, ExpressibleFromArgument {
+    case topleft
+    case topright
+    case bottomleft
+    case bottomright
+    case center
+
+    /// Accepts `top-left`, `TopLeft`, … as well as the raw values.
+    init?(argument: String) {
+        self.init(rawValue: argument.lowercased().replacingOccurrences(of: "-", with: ""))
+    }
+
+    /// Rect for a `size` watermark in a `canvas`, in CoreGraphics' bottom-up coordinates.
+    func rect(for size: CGSize, in canvas: CGSize) -> CGRect {
+        let x: CGFloat = switch self {
+        case .topleft, .bottomleft: 0
+        case .topright, .bottomright: canvas.width - size.width
+        case .center: ((canvas.width - size.width) / 2).rounded(.down)
+        }
+        let y: CGFloat = switch self {
+        case .bottomleft, .bottomright: 0
+        case .topleft, .topright: canvas.height - size.height
+        case .center: ((canvas.height - size.height) / 2).rounded(.down)
+        }
+        return CGRect(origin: CGPoint(x: x, y: y), size: size)
+    }
+}
+
+/// Composites a PNG over each capture at `opacity`, flush with `position`. With `scale`, the watermark is
+/// resized to that fraction of the capture's width, keeping its aspect ratio; otherwise it keeps its pixel size.
+struct WatermarkFilter: CapturedImageFilter {
+    static let defaultOpacity = 0.8
+    static let defaultPosition = ImageWatermarkPosition.bottomright
+
+    let watermark: CGImage
+    let position: ImageWatermarkPosition
+    let opacity: Double
+    let scale: Double?
+    let stepName = "watermark"
+
+    func size(in canvas: CGSize) -> CGSize {
+        let natural = CGSize(width: self.watermark.width, height: self.watermark.height)
+        guard let scale, natural.width > 0 else { return natural }
+        let width = max(1, (canvas.width * scale).rounded())
+        return CGSize(width: width, height: max(1, (natural.height * width / natural.width).rounded()))
+    }
+
+    func apply(to image: CGImage) throws -> CGImage {
+        let canvas = CGSize(width: image.width, height: image.height)
+        let context = try CapturedImageIO.makeContext(width: image.width, height: image.height)
+        context.draw(image, in: CGRect(origin: .zero, size: canvas))
+        context.interpolationQuality = .high
+        context.setAlpha(self.opacity)
+        context.draw(self.watermark, in: self.position.rect(for: self.size(in: canvas), in: canvas))
+
+        guard let watermarked = context.makeImage() else {
+            throw CaptureError.captureFailure("Failed to draw the watermark")
+        }
+        return watermarked
+    }
+}
+
+@MainActor
+extension ImageCommand {
+    /// Loads `--watermark-image` up front so a missing file fails before anything is captured.
+    func watermarkFilter() throws -> WatermarkFilter? {
+        guard let watermarkImage else {
+            if self.watermarkPosition != nil || self.watermarkOpacity != nil || self.watermarkScale != nil {
+                throw ValidationError(
+                    "--watermark-position, --watermark-opacity, and --watermark-scale require --watermark-image"
+                )
+            }
+            return nil
+        }
+        if let watermarkOpacity, !(0...1).contains(watermarkOpacity) {
+            throw ValidationError("--watermark-opacity must be between 0.0 and 1.0")
+        }
+        if let watermarkScale, !(watermarkScale > 0 && watermarkScale <= 1) {
+            throw ValidationError("--watermark-scale must be greater than 0.0 and at most 1.0")
+        }
+
+        let path = (watermarkImage as NSString).expandingTildeInPath
+        let watermark: CGImage
+        do {
+            watermark = try CapturedImageIO.load(at: path)
+        } catch {
+            throw PeekabooError.fileIOError("Could not read --watermark-image \(path)")
+        }
+        return WatermarkFilter(
+            watermark: watermark,
+            position: self.watermarkPosition ?? WatermarkFilter.defaultPosition,
+            opacity: self.watermarkOpacity ?? WatermarkFilter.defaultOpacity,
+            scale: self.watermarkScale
+        )
+    }
+}
@@ -0,0 +1,67 @@
+import Commander
+import CoreGraphics
+import Foundation
+import PeekabooCore
+import PeekabooFoundation
+
+/// The window a padded capture was cut around, so the saved file still reports the window it came from.
+struct PaddedWindowCapture {
+    let window: ServiceWindowInfo
+    let bounds: CGRect
+}
+
+@MainActor
+extension ImageCommand {
+    var padsWindowCaptures: Bool {
+        self.windowPadding > 0
+    }
+
+    func validateWindowPaddingOptions() throws {
+        guard self.windowPadding >= 0 else {
+            throw ValidationError("--window-padding must be zero or greater")
+        }
+    }
+
+    /// Grows `bounds` by `padding` points on every side, then trims whatever falls off `screen`.
+    ///
+    /// Both rects use global display coordinates (top-left origin), matching `--region` and `CGDisplayBounds`.
+    static func paddedBounds(_ bounds: CGRect, padding: Int, within screen: CGRect?) -> CGRect {
+        let padded = bounds.insetBy(dx: -CGFloat(padding), dy: -CGFloat(padding))
+        guard let screen else { return padded }
+        let clamped = padded.intersection(screen)
+        return clamped.isNull ? bounds : clamped
+    }
+
+    /// Captures the padded window as an area so the border includes whatever surrounds it on screen.
+    func capturePaddedWindow(_ window: ServiceWindowInfo, index: Int?) async throws -> ImageCapturedFile {
+        let screen = self.services.screens.screenContainingWindow(bounds: window.bounds)
+        let rect = Self.paddedBounds(
+            window.bounds,
+            padding: self.windowPadding,
+            within: screen.map { CGDisplayBounds($0.displayID) }
+        )
+        let preferredName = window.title.isEmpty ? "window-\(window.windowID)" : window.title
+        let observation = try await self.captureObservation(
+            target: .area(rect),
+            preferredName: preferredName,
+            index: index
+        )
+        return try self.capturedFile(
+            from: observation,
+            preferredName: preferredName,
+            windowIndex: window.index,
+            paddedWindow: PaddedWindowCapture(window: window, bounds: rect)
+        )
+    }
+
+    func capturePaddedWindow(id windowId: Int) async throws -> ImageCapturedFile {
+        let windows = try await WindowServiceBridge.listWindows(
+            windows: self.services.windows,
+            target: .windowId(windowId)
+        )
+        guard let window = windows.first(where: { $0.windowID == windowId }) ?? windows.first else {
+            throw PeekabooError.windowNotFound(criteria: "No window with id \(windowId)")
+        }
+        return try await self.capturePaddedWindow(window, index: nil)
+    }
+}
@@ -0,0 +1,96 @@
+import Commander
+import Foundation
+import PeekabooCore
+import PeekabooFoundation
+
+/// How `peekaboo image` treats an app's windows before a window or multi capture.
+enum ImageWindowState: String, CaseIterable, ExpressibleFromArgument {
+    /// Poll until the app exposes at least one on-screen window (Electron/Java apps that build windows late).
+    case waitVisible = "wait-visible"
+    /// Accept off-screen and minimized windows instead of only on-screen ones.
+    case any
+
+    init?(argument: String) {
+        self.init(rawValue: argument.lowercased())
+    }
+}
+
+@MainActor
+extension ImageCommand {
+    static let defaultFocusTimeoutMs = 5000
+    static let windowPollInterval: Duration = .milliseconds(100)
+
+    func validateWindowStateOptions() throws {
+        guard let timeout = self.focusTimeoutMs else { return }
+        guard self.windowState == .waitVisible else {
+            throw ValidationError("--focus-timeout-ms requires --window-state wait-visible")
+        }
+        guard timeout > 0 else {
+            throw ValidationError("--focus-timeout-ms must be greater than zero")
+        }
+    }
+
+    /// Windows eligible for capture under `--window-state`; the default keeps the shareable on-screen filter.
+    func windowsForCapture(appIdentifier: String) async throws -> [ServiceWindowInfo] {
+        switch self.windowState {
+        case .waitVisible:
+            return try await self.waitForVisibleWindows(appIdentifier: appIdentifier)
+        case .any:
+            let windows = try await self.listWindows(appIdentifier: appIdentifier)
+            return ObservationTargetResolver.filteredWindows(from: windows, mode: .list)
+        case nil:
+            let windows = try await self.listWindows(appIdentifier: appIdentifier)
+            return ObservationTargetResolver.captureCandidates(from: windows)
+        }
+    }
+
+    func waitForVisibleWindows(appIdentifier: String) async throws -> [ServiceWindowInfo] {
+        let timeoutMs = self.focusTimeoutMs ?? Self.defaultFocusTimeoutMs
+        let clock = ContinuousClock()
+        let deadline = clock.now + .milliseconds(timeoutMs)
+
+        while true {
+            // The app may still be launching, so lookup failures count as "not visible yet".
+            let windows = (try? await self.listWindows(appIdentifier: appIdentifier)) ?? []
+            let visible = ObservationTargetResolver.captureCandidates(from: windows)
+            if !visible.isEmpty {
+                return visible
+            }
+            guard clock.now < deadline else {
+                throw PeekabooError.timedOut(
+                    operation: "Waiting for a visible window of \(appIdentifier)",
+                    timeoutMs: timeoutMs
+                )
+            }
+            try await Task.sleep(for: Self.windowPollInterval)
+        }
+    }
+
+    /// Picks the `--window-title`/`--window-index` match from `windows`, or the best candidate when neither is set.
+    static func selectWindow(
+        from windows: [ServiceWindowInfo],
+        title: String?,
+        index: Int?,
+        caseSensitive: Bool = false
+    ) -> ServiceWindowInfo? {
+        if let title = title?.trimmingCharacters(in: .whitespacesAndNewlines), !title.isEmpty {
+            return windows.first { Self.windowTitle($0.title, matches: title, caseSensitive: caseSensitive) }
+        }
+        if let index {
+            return windows.first { $0.index == index }
+        }
+        return ObservationTargetResolver.bestWindow(from: windows) ?? windows.first
+    }
+
+    /// `--window-title` is a substring match, case-insensitive unless `--case-sensitive` is set.
+    static func windowTitle(_ title: String, matches query: String, caseSensitive: Bool) -> Bool {
+        caseSensitive ? title.contains(query) : title.localizedCaseInsensitiveContains(query)
+    }
+
+    private func listWindows(appIdentifier: String) async throws -> [ServiceWindowInfo] {
+        try await WindowServiceBridge.listWindows(
+            windows: self.services.windows,
+            target: .application(appIdentifier)
+        )
+    }
+}
@@ -15,10 +15,16 @@ struct ImageCommand: ApplicationResolvable, ErrorHandlingCommand, OutputFormatta
     @Option(name: .long, help: "Target application by process ID")
     var pid: Int32?
 
+    @Option(name: .long, help: "Target the process whose PID is on the first line of this file")
+    var pidFile: String?
+
     @Option(name: .long, help: "Output path for saved image, or '-' to write one image to stdout")
     var path: String?
 
-    @Option(name: .long, help: "Capture mode (screen, window, frontmost, multi, area)")
+    @Option(name: .long, help: "When the output file exists: rename (default, adds -1, -2, …), overwrite, or error")
+    var onCollision: ImageOutputCollisionPolicy?
+
+    @Option(name: .long, help: "Capture mode (screen, window, frontmost, multi, area, interactive)")
     var mode: PeekabooCore.CaptureMode?
 
     @Option(name: .long, help: "Capture window with specific title")
@@ -27,21 +33,149 @@ struct ImageCommand: ApplicationResolvable, ErrorHandlingCommand, OutputFormatta
     @Option(name: .long, help: "Window index to capture")
     var windowIndex: Int?
 
+    @Flag(name: .long, help: "Match --app and --window-title case-sensitively")
+    var caseSensitive = false
+
     @Option(
         name: .long,
         help: "Target window by CoreGraphics window id (window_id from `peekaboo window list --json`)"
     )
     var windowId: Int?
 
+    @Option(
+        name: .long,
+        help: "Window readiness: wait-visible polls for an on-screen window, any accepts off-screen ones"
+    )
+    var windowState: ImageWindowState?
+
+    @Option(name: .long, help: "Milliseconds --window-state wait-visible waits for a window (default 5000)")
+    var focusTimeoutMs: Int?
+
+    @Flag(name: .long, help: "Confirm the target app is focused before capturing, refocusing if needed")
+    var windowFocusCheck = false
+
+    @Option(name: .long, help: "Refocus attempts --window-focus-check makes before capturing anyway (default 3)")
+    var focusRetries: Int?
+
+    @Option(name: .long, help: "Milliseconds each capture may take before it is abandoned (default 5000)")
+    var captureTimeoutMs: Int?
+
+    @Flag(name: .long, help: "Record the mouse pointer location as cursor_at_capture on each file")
+    var includeCursorPosition = false
+
+    @Option(name: .long, help: "Points of surrounding screen to include around window captures (default 0)")
+    var windowPadding: Int = 0
+
+    @Option(name: .long, help: "Capture these and stitch them into one image, e.g. screen:0,app:Safari,window-id:42")
+    var stitchItems: String?
+
+    @Option(name: .long, help: "How --stitch-items are arranged: horizontal, vertical, or grid (default horizontal)")
+    var stitchLayout: ImageStitchLayout?
+
+    @Option(name: .long, help: "Pixels of --gap-color between stitched captures (default 0)")
+    var stitchPadding: Int?
+
     @Option(name: .long, help: "Screen index for screen captures")
     var screenIndex: Int?
 
-    @Option(name: .long, help: "Region for area captures as x,y,width,height in global display coordinates")
+    @Option(name: .long, help: "Display name for screen captures (case-insensitive prefix, e.g. 'DELL')")
+    var monitorName: String?
+
+    @Option(name: .long, help: "Wait up to N ms for a just-connected display to appear before a screen capture")
+    var monitorHotplugWaitMs: Int?
+
+    @Option(name: .long, help: "Area capture region: x,y,width,height or WIDTHxHEIGHT+X+Y in global display points")
     var region: String?
 
     @Flag(name: .long, help: "Capture at native Retina scale (default stores 1x logical resolution)")
     var retina: Bool = false
 
+    @Flag(name: .long, help: "Stitch every display into one image using the desktop arrangement")
+    var mergeDisplays: Bool = false
+
+    @Option(name: .long, help: "Fill color for gaps between merged displays as #RRGGBB or #RRGGBBAA (default #000000)")
+    var gapColor: String?
+
+    @Option(
+        name: .long,
+        help: """
+        JSON array of overlays drawn onto each capture, in image pixels from the top-left:
+        {"type":"text","x":..,"y":..,"text":..,"color":"#RRGGBB","size":..},
+        {"type":"arrow","x1":..,"y1":..,"x2":..,"y2":..,"color":..},
+        {"type":"rect","x":..,"y":..,"w":..,"h":..,"color":..,"fill":true|false}
+        """
+    )
+    var annotate: String?
+
+    @Option(name: .long, help: "Convert captures to srgb or p3 and embed that ICC profile")
+    var colorSpace: ImageColorSpace?
+
+    @Option(name: .long, help: "Make pixels with alpha below this value (0-255) fully transparent; PNG only")
+    var alphaThreshold: Int?
+
+    @Option(name: .long, help: "Encoder settings for --format, e.g. progressive (jpg) or paeth (png)")
+    var codecProfile: ImageCodecProfile?
+
+    @Option(name: .long, help: "Crop each capture to x,y,width,height or WIDTHxHEIGHT+X+Y, in image pixels")
+    var cropToBounds: String?
+
+    @Flag(name: .long, help: "Crop away solid-color borders that match the top-left pixel")
+    var trimWhitespace = false
+
+    @Option(name: .long, help: "Channel difference (0-255, default 10) still counted as border by --trim-whitespace")
+    var trimTolerance: Int?
+
+    @Option(name: .long, help: "Crop each capture to its most detailed WIDTHxHEIGHT region, in image pixels")
+    var smartCrop: String?
+
+    @Flag(name: .long, help: "Darken each capture toward its corners, e.g. for presentation screenshots")
+    var vignette = false
+
+    @Option(name: .long, help: "How dark --vignette makes the corners (0.0-1.0, default 0.5)")
+    var vignetteStrength: Double?
+
+    @Option(name: .long, help: "Overlay this PNG on each capture, e.g. a logo or 'CONFIDENTIAL' stamp")
+    var watermarkImage: String?
+
+    @Option(name: .long, help: "Corner for --watermark-image: topleft|topright|bottomleft|bottomright (default)|center")
+    var watermarkPosition: ImageWatermarkPosition?
+
+    @Option(name: .long, help: "Opacity of --watermark-image (0.0-1.0, default 0.8)")
+    var watermarkOpacity: Double?
+
+    @Option(name: .long, help: "Resize --watermark-image to this fraction of the capture width (0.0-1.0]")
+    var watermarkScale: Double?
+
+    @Option(name: .long, help: "Load defaults from ~/.peekaboo/profiles/NAME.json (or PEEKABOO_PROFILE)")
+    var profile: String?
+
+    @Option(name: .long, help: "Key chord to press in the target before capture, e.g. 'cmd+shift+p'; repeatable")
+    var preCaptureKeys: [String] = []
+
+    @Option(name: .long, help: "Milliseconds to wait after --pre-capture-keys before capturing")
+    var preCaptureDelayMs: Int?
+
+    @Flag(name: .long, help: "Allow --pre-capture-keys to send keystrokes to the target app")
+    var allowInputInjection = false
+
+    @Flag(name: .long, help: "Move the target's windows back to the layout saved by 'list windows --cache-layout'")
+    var restoreLayout = false
+
+    @Option(name: .long, help: "Press Save in the --mode interactive preview after this many ms (default 0: wait)")
+    var previewTimeoutMs: Int?
+
+    /// Set by `--mode interactive`, which leaves `mode` nil so the target flags still choose what to capture.
+    var interactivePreview = false
+
+    @Flag(name: .long, help: "Save a debug_<step>_<timestamp>.png next to each capture after every trim or filter step")
+    var debugCaptureSteps = false
+
+    @Option(name: .long, help: "Run this command after a successful capture with the saved paths appended (or at {})")
+    var execAfter: String?
+
+    @Option(name: .long, help: "KEY=VALUE added to the --exec-after environment; repeatable")
+    var execAfterEnv: [String] = []
+
     @Option(
         name: .long,
         help: """
@@ -60,6 +194,57 @@ struct ImageCommand: ApplicationResolvable, ErrorHandlingCommand, OutputFormatta
 
     @Option(name: .long, help: "Analyze the captured image with AI")
     var analyze: String?
+
+    @Flag(name: .long, help: "List the PEEKABOO_* environment variables that stand in for flags, then exit")
+    var printEnvVars = false
+
+    @Flag(name: .long, help: "List the --codec-profile values for --format, then exit")
+    var listCodecProfiles = false
+
+    @Flag(name: .long, help: "Omit the per-phase timing block from JSON output")
+    var noTiming = false
+
+    @Option(name: .long, help: "UUID to report as session.id for correlating this run's files")
+    var sessionId: String?
+
+    @Option(name: .long, help: "key=value to add to session.metadata; repeatable")
+    var sessionMetadata: [String] = []
+
+    @Flag(name: .long, help: "Reuse an earlier capture whose dHash matches instead of keeping a new file")
+    var dedup = false
+
+    @Option(name: .long, help: "Differing dHash bits still treated as a duplicate (0-64, default 3); needs --dedup")
+    var dedupThreshold: Int?
+
+    @Flag(name: .long, help: "Keep only the part of each capture that differs from its saved baseline")
+    var changedRegion = false
+
+    @Option(name: .long, help: "Pixels of padding around the --changed-region bounding box (default 0)")
+    var changeMargin: Int?
+
+    @Flag(name: .long, help: "Replace the --changed-region baseline with this capture")
+    var resetBaseline = false
+
+    @Option(name: .long, help: "Fail instead of keeping a capture whose encoded file is larger than this many KB")
+    var maxFileSizeKb: Int?
+
+    @Flag(name: .long, help: "Re-read each saved file and fail if it is truncated or its dimensions disagree")
+    var verifyCapture = false
+
+    @Flag(name: .long, help: "Add a checksum of each final file to the result")
+    var computeChecksum = false
+
+    @Option(name: .long, help: "Checksum algorithm for --compute-checksum: sha256 (default), sha512, or md5")
+    var checksumAlgorithm: FileChecksumAlgorithm?
+
+    @Option(name: .long, help: "Also write the JSON result to this file; works with --path -")
+    var outputJsonPath: String?
+
+    /// `PEEKABOO_QUALITY` scaled to `0...1`, resolved at the start of `run`.
+    var jpegQuality: Double?
+    var outputCollisions = ImageOutputCollisionLog()
+    /// Stops a multi-window capture after the current window on SIGINT/SIGTERM.
+    var windowCaptureSignals = CaptureSignalMonitor()
     @RuntimeStorage private var runtime: CommandRuntime?
     var runtimeOptions = CommandRuntimeOptions()
 
@@ -94,6 +279,15 @@ struct ImageCommand: ApplicationResolvable, ErrorHandlingCommand, OutputFormatta
     mutating func run(using runtime: CommandRuntime) async throws {
         self.runtime = runtime
         self.logger.setJsonOutputMode(self.jsonOutput)
+        let startedAt = Date()
+        if self.printEnvVars {
+            self.outputEnvironmentVariables()
+            return
+        }
+        if self.listCodecProfiles {
+            self.outputCodecProfiles()
+            return
+        }
         let startMetadata: [String: Any] = [
             "mode": self.mode?.rawValue ?? "auto",
             "app": self.app ?? "none",
@@ -103,23 +297,142 @@ struct ImageCommand: ApplicationResolvable, ErrorHandlingCommand, OutputFormatta
         self.logger.operationStart("image_command", metadata: startMetadata)
 
         do {
+            try PIDFile.validateExclusive(pidFile: self.pidFile, app: self.app, pid: self.pid)
+            if let pidFile {
+                self.pid = try PIDFile.runningPID(at: pidFile)
+            }
+            self.jpegQuality = try EnvironmentConfig.jpegQuality()
             try self.validateStdoutStreamingOptions()
+            try self.validateMergeDisplaysOptions()
+            try self.validateStitchOptions()
+            try self.validateMonitorNameOptions()
+            try self.validateMonitorHotplugWaitOptions()
+            try self.validateSessionOptions()
+            try self.validateWindowStateOptions()
+            try self.validateWindowPaddingOptions()
+            try self.validateWindowFocusCheckOptions()
+            try self.validateCaptureTimeoutOptions()
+            try self.validateDedupOptions()
+            try self.validateChangedRegionOptions()
+            try self.validateMaxFileSizeOptions()
+            try self.validateCropToBoundsOptions()
+            try self.validateTrimOptions()
+            try self.validateSmartCropOptions()
+            try self.validateExecAfterOptions()
+            try self.validateChecksumOptions()
+            try self.validateCodecProfileOptions()
+            try self.validatePreCaptureKeysOptions()
+            try self.validateRestoreLayoutOptions()
+            try self.validateInteractivePreviewOptions()
+            let filterChain = try self.makeFilterChain()
+            let stepRecorder = self.makeStepRecorder()
+            if let warning = self.alphaThresholdFormatWarning {
+                self.logger.warn(.OPTION_HAS_NO_EFFECT, warning)
+            }
 
             // ScreenCaptureService performs the authoritative permission check inside each capture path.
             // Avoid preflighting here too; it adds fixed latency to every one-shot screenshot.
-            let captures = try await CrossProcessOperationGate.withExclusiveOperation(
-                named: CrossProcessOperationGate.desktopObservationName
-            ) {
-                try await self.performCapture()
+            let captureStartedAt = Date()
+            let capturedFiles = try await self.previewingCaptures {
+                try await CrossProcessOperationGate.withExclusiveOperation(
+                    named: CrossProcessOperationGate.desktopObservationName
+                ) {
+                    try await withErrorContext("image_command") {
+                        try await self.markingRenamedFiles(self.stampingCursorPosition(self.performCapture()))
+                    }
+                }
             }
+            let captureMs = ImageCaptureTimings.milliseconds(since: captureStartedAt)
 
-            if self.streamsImageToStdout {
-                try self.outputImageToStdout(captures)
-            } else if let prompt = self.analyze, let firstFile = captures.first?.file {
-                let analysis = try await self.analyzeImage(at: firstFile.path, with: prompt)
-                self.outputResultsWithAnalysis(captures, analysis: analysis)
+            let filterStartedAt = Date()
+            let filteredFiles = try await withErrorContext("image_command") {
+                try await withErrorContext("filter_captures") {
+                    let cropped = try self.croppingToBounds(capturedFiles, recorder: stepRecorder)
+                    let trimmed = try self.trimmingBorders(cropped, recorder: stepRecorder)
+                    let smartCropped = try self.smartCropping(trimmed, recorder: stepRecorder)
+                    try self.applyFilterChain(filterChain, to: smartCropped, recorder: stepRecorder)
+                    return smartCropped
+                }
+            }
+            let filterMs = ImageCaptureTimings.milliseconds(since: filterStartedAt)
+            let changedFiles = try await withErrorContext("image_command") {
+                try await withErrorContext("changed_region") {
+                    try self.croppingToChangedRegion(self.taggingColorSpace(filteredFiles))
+                }
+            }
+            let encodedFiles = try await withErrorContext("image_command") {
+                try await withErrorContext("encode_captures") {
+                    try self.encodingWithCodecProfile(changedFiles)
+                }
+            }
+            let sizedFiles = try await withErrorContext("image_command") {
+                try await withErrorContext("check_file_size") {
+                    try self.enforcingFileSizeLimit(encodedFiles)
+                }
+            }
+            let verifiedFiles = try await withErrorContext("image_command") {
+                try await withErrorContext("verify_captures") {
+                    try self.verifyingCaptures(sizedFiles)
+                }
+            }
+            let dedupedFiles = try await withErrorContext("image_command") {
+                try await withErrorContext("deduplicate_captures") {
+                    try self.deduplicating(verifiedFiles)
+                }
+            }
+            let captures = try await withErrorContext("image_command") {
+                try await withErrorContext("compute_checksums") {
+                    try self.computingChecksums(dedupedFiles)
+                }
+            }
+            try await withErrorContext("image_command") {
+                try await withErrorContext("exec_after") {
+                    try self.runPostCaptureHook(for: captures)
+                }
+            }
+
+            if let prompt = self.analyze, let firstFile = captures.first?.file {
+                let analysisStartedAt = Date()
+                let analysis = try await withErrorContext("image_command") {
+                    try await withErrorContext("analyze_image") {
+                        try await self.analyzeImage(at: firstFile.path, with: prompt)
+                    }
+                }
+                let timing = ImageCaptureTimings(
+                    total_ms: ImageCaptureTimings.milliseconds(since: startedAt),
+                    capture_ms: captureMs,
+                    filter_ms: filterMs,
+                    analysis_ms: ImageCaptureTimings.milliseconds(since: analysisStartedAt)
+                )
+                try self.outputResultsWithAnalysis(
+                    captures,
+                    analysis: analysis,
+                    session: self.makeCaptureSession(startedAt: startedAt),
+                    timing: timing,
+                    debugSteps: stepRecorder?.steps
+                )
             } else {
-                self.outputResults(captures)
+                let timing = ImageCaptureTimings(
+                    total_ms: ImageCaptureTimings.milliseconds(since: startedAt),
+                    capture_ms: captureMs,
+                    filter_ms: filterMs,
+                    analysis_ms: nil
+                )
+                let session = try self.makeCaptureSession(startedAt: startedAt)
+                if self.streamsImageToStdout {
+                    try self.outputImageToStdout(
+                        captures,
+                        session: session,
+                        timing: timing,
+                        debugSteps: stepRecorder?.steps
+                    )
+                } else {
+                    try self.outputResults(captures, session: session, timing: timing, debugSteps: stepRecorder?.steps)
+                }
+            }
+            self.removeStaleDebugImages(for: captures, recorder: stepRecorder)
+            if let signal = self.windowCaptureSignals.receivedSignal {
+                self.reportInterruption(by: signal, completedCaptures: capturedFiles.count)
             }
 
             self.logger.operationComplete("image_command", success: true)
@@ -130,7 +443,7 @@ struct ImageCommand: ApplicationResolvable, ErrorHandlingCommand, OutputFormatta
                 success: false,
                 metadata: ["error": error.localizedDescription]
             )
-            throw ExitCode(1)
+            throw self.exitCode(for: error)
         }
     }
 }
@@ -153,36 +466,44 @@ extension ImageCommand: AsyncRuntimeCommand {}
 @MainActor
 extension ImageCommand: CommanderBindableCommand {
     mutating func applyCommanderValues(_ values: CommanderBindableValues) throws {
+        let values = try ImageProfiles.resolve(values)
+        self.profile = values.singleOption("profile")
+        self.preCaptureKeys = values.optionValues("preCaptureKeys")
+        self.preCaptureDelayMs = try values.decodeOption("preCaptureDelayMs", as: Int.self)
+        self.allowInputInjection = values.flag("allowInputInjection")
+        self.restoreLayout = values.flag("restoreLayout")
+        self.previewTimeoutMs = try values.decodeOption("previewTimeoutMs", as: Int.self)
         self.app = values.singleOption("app")
         self.pid = try values.decodeOption("pid", as: Int32.self)
+        self.pidFile = values.singleOption("pidFile")
         self.path = values.singleOption("path")
-        if let parsedMode: CaptureMode = try values.decodeOptionEnum("mode") {
+        self.onCollision = try values.decodeOptionEnum("onCollision")
+        if values.singleOption("mode")?.lowercased() == Self.interactiveModeName {
+            self.interactivePreview = true
+        } else if let parsedMode: CaptureMode = try values.decodeOptionEnum("mode") {
             self.mode = parsedMode
         }
         self.windowTitle = values.singleOption("windowTitle")
         self.windowIndex = try values.decodeOption("windowIndex", as: Int.self)
+        self.caseSensitive = values.flag("caseSensitive")
         self.windowId = try values.decodeOption("windowId", as: Int.self)
         self.screenIndex = try values.decodeOption("screenIndex", as: Int.self)
+        self.monitorName = values.singleOption("monitorName")
+        self.monitorHotplugWaitMs = try values.decodeOption("monitorHotplugWaitMs", as: Int.self)
         self.region = values.singleOption("region")
         self.captureEngine = values.singleOption("captureEngine")
         let parsedFormat: ImageFormat? = try values.decodeOptionEnum("format")
         if let parsedFormat {
             self.format = parsedFormat
         }
-        if let path = self.path?.trimmingCharacters(in: .whitespacesAndNewlines),
+        var inferred: ImageFormat?
+        let pathLabel = self.path == nil ? EnvironmentConfig.outputPathName : "path"
+        if let path = (self.path ?? EnvironmentConfig.outputPath())?.trimmingCharacters(in: .whitespacesAndNewlines),
            !path.isEmpty {
-            let expanded = (path as NSString).expandingTildeInPath
-            let ext = URL(fileURLWithPath: expanded).pathExtension.lowercased()
-            let inferred: ImageFormat? = if ext == "jpg" || ext == "jpeg" {
-                .jpg
-            } else if ext == "png" {
-                .png
-            } else {
-                nil
-            }
+            inferred = ImageFormat(path: path)
             if let parsedFormat, let inferred, parsedFormat != inferred {
                 throw CommanderBindingError.invalidArgument(
-                    label: "path",
+                    label: pathLabel,
                     value: path,
                     reason: "Conflicts with --format \(parsedFormat.rawValue). " +
                         "Use a .\(parsedFormat.fileExtension) path (or omit --format)."
@@ -192,10 +513,59 @@ extension ImageCommand: CommanderBindableCommand {
                 self.format = inferred
             }
         }
+        if parsedFormat == nil, inferred == nil, let environmentFormat = try EnvironmentConfig.imageFormat() {
+            self.format = environmentFormat
+        }
         if let parsedFocus: CaptureFocus = try values.decodeOptionEnum("captureFocus") {
             self.captureFocus = parsedFocus
         }
         self.analyze = values.singleOption("analyze")
+        self.windowState = try values.decodeOptionEnum("windowState")
+        self.focusTimeoutMs = try values.decodeOption("focusTimeoutMs", as: Int.self)
+        self.windowFocusCheck = values.flag("windowFocusCheck")
+        self.focusRetries = try values.decodeOption("focusRetries", as: Int.self)
+        self.captureTimeoutMs = try values.decodeOption("captureTimeoutMs", as: Int.self)
+        self.includeCursorPosition = values.flag("includeCursorPosition")
+        if let padding = try values.decodeOption("windowPadding", as: Int.self) {
+            self.windowPadding = padding
+        }
+        self.gapColor = values.singleOption("gapColor")
+        self.stitchItems = values.singleOption("stitchItems")
+        self.stitchLayout = try values.decodeOptionEnum("stitchLayout")
+        self.stitchPadding = try values.decodeOption("stitchPadding", as: Int.self)
+        self.annotate = values.singleOption("annotate")
+        self.colorSpace = try values.decodeOptionEnum("colorSpace")
+        self.alphaThreshold = try values.decodeOption("alphaThreshold", as: Int.self)
+        self.codecProfile = try values.decodeOptionEnum("codecProfile")
+        self.cropToBounds = values.singleOption("cropToBounds")
+        self.trimWhitespace = values.flag("trimWhitespace")
+        self.trimTolerance = try values.decodeOption("trimTolerance", as: Int.self)
+        self.smartCrop = values.singleOption("smartCrop")
+        self.vignette = values.flag("vignette")
+        self.vignetteStrength = try values.decodeOption("vignetteStrength", as: Double.self)
+        self.watermarkImage = values.singleOption("watermarkImage")
+        self.watermarkPosition = try values.decodeOptionEnum("watermarkPosition")
+        self.watermarkOpacity = try values.decodeOption("watermarkOpacity", as: Double.self)
+        self.watermarkScale = try values.decodeOption("watermarkScale", as: Double.self)
+        self.debugCaptureSteps = values.flag("debugCaptureSteps")
+        self.execAfter = values.singleOption("execAfter")
+        self.execAfterEnv = values.optionValues("execAfterEnv")
         self.retina = values.flag("retina")
+        self.mergeDisplays = values.flag("mergeDisplays")
+        self.printEnvVars = values.flag("printEnvVars")
+        self.listCodecProfiles = values.flag("listCodecProfiles")
+        self.noTiming = values.flag("noTiming")
+        self.sessionId = values.singleOption("sessionId")
+        self.sessionMetadata = values.optionValues("sessionMetadata")
+        self.dedup = values.flag("dedup")
+        self.dedupThreshold = try values.decodeOption("dedupThreshold", as: Int.self)
+        self.changedRegion = values.flag("changedRegion")
+        self.changeMargin = try values.decodeOption("changeMargin", as: Int.self)
+        self.resetBaseline = values.flag("resetBaseline")
+        self.maxFileSizeKb = try values.decodeOption("maxFileSizeKb", as: Int.self)
+        self.verifyCapture = values.flag("verifyCapture")
+        self.computeChecksum = values.flag("computeChecksum")
+        self.checksumAlgorithm = try values.decodeOptionEnum("checksumAlgorithm")
+        self.outputJsonPath = values.singleOption("outputJsonPath")
     }
 }
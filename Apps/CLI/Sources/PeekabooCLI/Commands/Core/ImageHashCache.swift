@@ -0,0 +1,73 @@
+import Foundation
+import PeekabooCore
+
+/// dHashes of earlier `image --dedup` captures, persisted so repeats are caught across invocations.
+struct ImageHashCache: Codable, Equatable {
+    struct Entry: Codable, Equatable {
+        let path: String
+        let hash: DHash
+        /// ISO 8601 time the capture was recorded.
+        let recorded_at: String
+    }
+
+    private(set) var entries: [Entry] = []
+
+    static var defaultURL: URL {
+        FileManager.default.homeDirectoryForCurrentUser
+            .appendingPathComponent(".peekaboo")
+            .appendingPathComponent("cache")
+            .appendingPathComponent("image-hashes.json")
+    }
+
+    /// A missing file is an empty cache; a corrupt one is an error so it isn't silently overwritten.
+    static func load(from url: URL = Self.defaultURL) throws -> ImageHashCache {
+        guard FileManager.default.fileExists(atPath: url.path) else { return ImageHashCache() }
+        do {
+            return try JSONDecoder().decode(ImageHashCache.self, from: Data(contentsOf: url))
+        } catch {
+            throw PeekabooError.fileIOError(
+                "Image hash cache at \(url.path) is unreadable (\(error.localizedDescription)); " +
+                    "run `peekaboo cache clear` to reset it"
+            )
+        }
+    }
+
+    func save(to url: URL = Self.defaultURL) throws {
+        try FileManager.default.createDirectory(
+            at: url.deletingLastPathComponent(),
+            withIntermediateDirectories: true
+        )
+        let encoder = JSONEncoder()
+        encoder.outputFormatting = [.prettyPrinted, .sortedKeys]
+        try encoder.encode(self).write(to: url, options: .atomic)
+    }
+
+    /// The closest earlier capture within `maxDistance` bits whose file still exists.
+    func match(
+        for hash: DHash,
+        maxDistance: Int,
+        fileExists: (String) -> Bool = { FileManager.default.fileExists(atPath: $0) }
+    ) -> Entry? {
+        self.entries
+            .map { (entry: $0, distance: $0.hash.distance(to: hash)) }
+            .filter { $0.distance <= maxDistance && fileExists($0.entry.path) }
+            .min { $0.distance < $1.distance }?
+            .entry
+    }
+
+    /// Records `path`, replacing any earlier entry for the same file.
+    mutating func record(path: String, hash: DHash, at date: Date = Date()) {
+        self.entries.removeAll { $0.path == path }
+        self.entries.append(Entry(path: path, hash: hash, recorded_at: date.ISO8601Format()))
+    }
+
+    /// Drops entries whose file is gone and returns how many were removed.
+    @discardableResult
+    mutating func pruneMissing(
+        fileExists: (String) -> Bool = { FileManager.default.fileExists(atPath: $0) }
+    ) -> Int {
+        let before = self.entries.count
+        self.entries.removeAll { !fileExists($0.path) }
+        return before - self.entries.count
+    }
+}
@@ -0,0 +1,76 @@
+import Darwin
+import Foundation
+import PeekabooCore
+
+/// One row of the process table for `list apps --include-children`.
+struct ProcessTableEntry: Equatable {
+    let pid: Int32
+    let parentPID: Int32
+    let name: String
+}
+
+enum ProcessTreeReader {
+    /// Every process libproc lets us inspect; ones that exit mid-scan are skipped.
+    static func processTable() -> [ProcessTableEntry] {
+        let estimated = proc_listallpids(nil, 0)
+        guard estimated > 0 else { return [] }
+
+        // Leave headroom for processes spawned between the two calls.
+        var pids = [Int32](repeating: 0, count: Int(estimated) + 64)
+        let count = pids.withUnsafeMutableBytes { buffer in
+            proc_listallpids(buffer.baseAddress, Int32(buffer.count))
+        }
+        guard count > 0 else { return [] }
+
+        return pids.prefix(Int(count)).compactMap { pid in
+            var info = proc_bsdinfo()
+            let size = Int32(MemoryLayout<proc_bsdinfo>.stride)
+            guard pid > 0, proc_pidinfo(pid, PROC_PIDTBSDINFO, 0, &info, size) == size else {
+                return nil
+            }
+            let name = self.string(from: info.pbi_name)
+            return ProcessTableEntry(
+                pid: pid,
+                parentPID: Int32(info.pbi_ppid),
+                name: name.isEmpty ? self.string(from: info.pbi_comm) : name
+            )
+        }
+    }
+
+    /// Descendants of `root`, children sorted by PID.
+    static func descendants(of root: Int32, in table: [ProcessTableEntry]) -> [ServiceProcessNode] {
+        let childrenByParent = Dictionary(grouping: table, by: \.parentPID)
+        var visited: Set<Int32> = [root]
+
+        func subtree(_ pid: Int32) -> [ServiceProcessNode] {
+            (childrenByParent[pid] ?? [])
+                .sorted { $0.pid < $1.pid }
+                .compactMap { entry in
+                    // A PID reused mid-scan can make the table cyclic; never revisit one.
+                    guard visited.insert(entry.pid).inserted else { return nil }
+                    return ServiceProcessNode(pid: entry.pid, name: entry.name, children: subtree(entry.pid))
+                }
+        }
+
+        return subtree(root)
+    }
+
+    private static func string<T>(from tuple: T) -> String {
+        withUnsafeBytes(of: tuple) { bytes in
+            String(decoding: bytes.prefix { $0 != 0 }, as: UTF8.self)
+        }
+    }
+}
+
+extension ListCommand.AppsSubcommand {
+    static func applyingChildren(
+        to applications: [ServiceApplicationInfo],
+        table: [ProcessTableEntry]
+    ) -> [ServiceApplicationInfo] {
+        applications.map { app in
+            var app = app
+            app.children = ProcessTreeReader.descendants(of: app.processIdentifier, in: table)
+            return app
+        }
+    }
+}
@@ -0,0 +1,115 @@
+import Commander
+import Foundation
+import PeekabooCore
+
+/// `list apps --group-by app-bundle` output. The shape differs from the flat list, hence `schema_version`.
+struct GroupedApplicationListData: Codable, Sendable {
+    let schema_version: String
+    let groups: [ApplicationGroup]
+    /// Processes without a bundle path (command-line tools, some agents).
+    let ungrouped: [ServiceApplicationInfo]
+}
+
+/// Running processes that live inside the same top-level `.app`, e.g. Chrome and its helper apps.
+struct ApplicationGroup: Codable, Sendable {
+    let name: String
+    let bundle_path: String
+    let applications: [ServiceApplicationInfo]
+}
+
+extension ListCommand.AppsSubcommand {
+    enum GroupBy: String, ExpressibleFromArgument {
+        case appBundle = "app-bundle"
+        case none
+
+        init?(argument: String) {
+            self.init(rawValue: argument.lowercased())
+        }
+    }
+
+    static let groupedSchemaVersion = "2"
+
+    var groupsApplications: Bool {
+        self.groupBy == .appBundle
+    }
+
+    func validateGroupingOptions() throws {
+        if self.groupsApplications, self.exportCsv {
+            throw ValidationError("--group-by app-bundle cannot be combined with --export-csv; CSV rows stay flat")
+        }
+    }
+
+    /// Groups in first-seen order, so `--stats-sort` still decides which group comes first.
+    static func grouped(_ applications: [ServiceApplicationInfo]) -> GroupedApplicationListData {
+        var bundleOrder: [String] = []
+        var members: [String: [ServiceApplicationInfo]] = [:]
+        var ungrouped: [ServiceApplicationInfo] = []
+
+        for app in applications {
+            guard let bundle = app.bundlePath.flatMap(Self.containingAppBundle(of:)) else {
+                ungrouped.append(app)
+                continue
+            }
+            if members[bundle] == nil {
+                bundleOrder.append(bundle)
+            }
+            members[bundle, default: []].append(app)
+        }
+
+        let groups = bundleOrder.map { bundle in
+            let applications = members[bundle] ?? []
+            return ApplicationGroup(
+                name: Self.groupName(bundle: bundle, applications: applications),
+                bundle_path: bundle,
+                applications: applications
+            )
+        }
+        return GroupedApplicationListData(
+            schema_version: Self.groupedSchemaVersion,
+            groups: groups,
+            ungrouped: ungrouped
+        )
+    }
+
+    /// The outermost `.app` in `bundlePath`, so helpers under `Contents/Frameworks` land with their host app.
+    static func containingAppBundle(of bundlePath: String) -> String? {
+        let components = (bundlePath as NSString).pathComponents
+        guard let index = components.firstIndex(where: { $0.lowercased().hasSuffix(".app") }) else {
+            return nil
+        }
+        return NSString.path(withComponents: Array(components[...index]))
+    }
+
+    static func formatGrouped(_ data: GroupedApplicationListData) -> String {
+        var lines: [String] = []
+        if !data.groups.isEmpty {
+            lines.append("Application groups:")
+            for (index, group) in data.groups.indexed() {
+                let count = group.applications.count
+                let noun = count == 1 ? "process" : "processes"
+                lines.append("\(index + 1). \(group.name) (\(group.bundle_path)) - \(count) \(noun)")
+                for app in group.applications {
+                    lines.append("   └ \(app.name) - PID: \(app.processIdentifier)")
+                }
+            }
+        }
+        if !data.ungrouped.isEmpty {
+            lines.append("Ungrouped:")
+            for (index, app) in data.ungrouped.indexed() {
+                lines.append("\(index + 1). \(app.name) - PID: \(app.processIdentifier)")
+            }
+        }
+        return lines.joined(separator: "\n")
+    }
+
+    /// Prefer the host app's own name; fall back to the bundle's file name when only helpers are running.
+    private static func groupName(bundle: String, applications: [ServiceApplicationInfo]) -> String {
+        let host = applications.first { app in
+            app.bundlePath.map { ($0 as NSString).standardizingPath } == bundle
+        }
+        if let host {
+            return host.name
+        }
+        return ((bundle as NSString).lastPathComponent as NSString).deletingPathExtension
+    }
+}
@@ -0,0 +1,63 @@
+import Foundation
+import PeekabooCore
+
+/// Info.plist fields for `list apps --rich-metadata`.
+struct ApplicationBundleMetadata: Equatable {
+    let displayName: String?
+    let category: String?
+    let iconName: String?
+    let version: String?
+
+    init(displayName: String?, category: String?, iconName: String?, version: String?) {
+        self.displayName = displayName
+        self.category = category
+        self.iconName = iconName
+        self.version = version
+    }
+
+    init(infoDictionary: [String: Any]) {
+        func string(_ key: String) -> String? {
+            guard let value = infoDictionary[key] as? String, !value.isEmpty else { return nil }
+            return value
+        }
+
+        self.init(
+            displayName: string("CFBundleDisplayName"),
+            category: string("LSApplicationCategoryType"),
+            iconName: string("CFBundleIconName") ?? string("CFBundleIconFile"),
+            version: string("CFBundleShortVersionString")
+        )
+    }
+}
+
+@MainActor
+enum ApplicationBundleMetadataReader {
+    /// Keyed by bundle path; helpers often share their host app's bundle, so each plist is read once.
+    private static var cache: [String: ApplicationBundleMetadata?] = [:]
+
+    static func metadata(bundlePath: String) -> ApplicationBundleMetadata? {
+        if let cached = self.cache[bundlePath] {
+            return cached
+        }
+        let metadata = Bundle(path: bundlePath)?.infoDictionary.map(ApplicationBundleMetadata.init(infoDictionary:))
+        self.cache[bundlePath] = metadata
+        return metadata
+    }
+}
+
+extension ListCommand.AppsSubcommand {
+    static func applyingBundleMetadata(
+        to applications: [ServiceApplicationInfo],
+        lookup: (String) -> ApplicationBundleMetadata?
+    ) -> [ServiceApplicationInfo] {
+        applications.map { app in
+            guard let bundlePath = app.bundlePath, let metadata = lookup(bundlePath) else { return app }
+            var app = app
+            app.displayName = metadata.displayName
+            app.category = metadata.category
+            app.iconName = metadata.iconName
+            app.version = metadata.version
+            return app
+        }
+    }
+}
@@ -0,0 +1,122 @@
+import Commander
+import Darwin
+import Foundation
+import PeekabooCore
+
+/// Per-process CPU and memory for `list apps --with-stats`.
+struct ProcessResourceUsage: Equatable {
+    let cpuPercent: Double
+    let memoryRSSMB: UInt64
+}
+
+enum ProcessResourceSampler {
+    /// CPU time is cumulative, so usage needs two readings; 200ms keeps the list responsive.
+    static let sampleInterval: Duration = .milliseconds(200)
+
+    /// Samples every pid twice, `interval` apart. Processes that exit or deny access are omitted.
+    static func sample(
+        pids: [Int32],
+        interval: Duration = Self.sampleInterval
+    ) async throws -> [Int32: ProcessResourceUsage] {
+        var baseline: [Int32: TaskSnapshot] = [:]
+        for pid in pids {
+            baseline[pid] = self.snapshot(pid)
+        }
+
+        let clock = ContinuousClock()
+        let start = clock.now
+        try await Task.sleep(for: interval)
+        let elapsed = start.duration(to: clock.now)
+        let elapsedNanos = Double(elapsed.components.seconds) * 1e9 + Double(elapsed.components.attoseconds) / 1e9
+        guard elapsedNanos > 0 else { return [:] }
+
+        var usage: [Int32: ProcessResourceUsage] = [:]
+        for pid in pids {
+            guard let before = baseline[pid], let after = self.snapshot(pid) else { continue }
+            let cpuNanos = after.cpuNanos >= before.cpuNanos ? Double(after.cpuNanos - before.cpuNanos) : 0
+            usage[pid] = ProcessResourceUsage(
+                cpuPercent: (cpuNanos / elapsedNanos * 1000).rounded() / 10,
+                memoryRSSMB: after.residentBytes / 1_048_576
+            )
+        }
+        return usage
+    }
+
+    private struct TaskSnapshot {
+        let cpuNanos: UInt64
+        let residentBytes: UInt64
+    }
+
+    private static func snapshot(_ pid: Int32) -> TaskSnapshot? {
+        var info = proc_taskinfo()
+        let size = Int32(MemoryLayout<proc_taskinfo>.stride)
+        guard proc_pidinfo(pid, PROC_PIDTASKINFO, 0, &info, size) == size else {
+            return nil
+        }
+
+        // pti_total_* are Mach absolute time units, which only equal nanoseconds on Intel.
+        let ticks = info.pti_total_user + info.pti_total_system
+        return TaskSnapshot(
+            cpuNanos: ticks * UInt64(self.timebase.numer) / UInt64(self.timebase.denom),
+            residentBytes: info.pti_resident_size
+        )
+    }
+
+    private static let timebase: mach_timebase_info_data_t = {
+        var info = mach_timebase_info_data_t()
+        mach_timebase_info(&info)
+        return info
+    }()
+}
+
+extension ListCommand.AppsSubcommand {
+    enum StatsSort: String, ExpressibleFromArgument {
+        case name
+        case cpu
+        case memory
+
+        init?(argument: String) {
+            self.init(rawValue: argument.lowercased())
+        }
+    }
+
+    static func applyingStats(
+        _ usage: [Int32: ProcessResourceUsage],
+        to applications: [ServiceApplicationInfo]
+    ) -> [ServiceApplicationInfo] {
+        applications.map { app in
+            var app = app
+            if let stats = usage[app.processIdentifier] {
+                app.cpuPercent = stats.cpuPercent
+                app.memoryRSSMB = stats.memoryRSSMB
+            }
+            return app
+        }
+    }
+
+    /// `cpu` and `memory` sort heaviest first; apps without stats go last. Ties fall back to name.
+    static func sorted(_ applications: [ServiceApplicationInfo], by sort: StatsSort) -> [ServiceApplicationInfo] {
+        func byName(_ lhs: ServiceApplicationInfo, _ rhs: ServiceApplicationInfo) -> Bool {
+            lhs.name.localizedCaseInsensitiveCompare(rhs.name) == .orderedAscending
+        }
+
+        return applications.sorted { lhs, rhs in
+            let keys: (Double?, Double?) = switch sort {
+            case .name: (nil, nil)
+            case .cpu: (lhs.cpuPercent, rhs.cpuPercent)
+            case .memory: (lhs.memoryRSSMB.map(Double.init), rhs.memoryRSSMB.map(Double.init))
+            }
+
+            switch keys {
+            case let (left?, right?) where left != right:
+                return left > right
+            case (.some, nil):
+                return true
+            case (nil, .some):
+                return false
+            default:
+                return byName(lhs, rhs)
+            }
+        }
+    }
+}
@@ -3,7 +3,70 @@ import PeekabooCore
 
 extension ListCommand {
     @MainActor
-    struct AppsSubcommand: ErrorHandlingCommand, OutputFormattable, RuntimeOptionsConfigurable {
+    struct AppsSubcommand: ErrorHandlingCommand, OutputFormattable, RuntimeOptionsConfigurable, ListPaginatable {
+        @Flag(name: .long, help: "Include CPU and resident memory per app (adds a 200ms sampling delay)")
+        var withStats = false
+
+        @Option(name: .long, help: "Sort by name|cpu|memory; cpu and memory imply --with-stats")
+        var statsSort: StatsSort?
+
+        @Flag(name: .long, help: "Add display name, category, icon, and version from each app's Info.plist")
+        var richMetadata = false
+
+        @Flag(name: .long, help: "Nest each app's child processes (helpers, renderers) under it")
+        var includeChildren = false
+
+        @Flag(name: .long, help: "Hide macOS system agents (default in human output; JSON lists everything)")
+        var excludeSystem = false
+
+        @Flag(name: .long, help: "Show macOS system agents in human output")
+        var includeSystem = false
+
+        @Option(name: .long, help: "Regex matched against app name or bundle ID to hide; repeatable")
+        var excludePattern: [String] = []
+
+        @Option(name: .long, help: "Keep apps whose executable path contains this text (case-insensitive)")
+        var filterByPath: String?
+
+        @Option(name: .long, help: "Keep apps whose executable path matches this regex")
+        var filterByPathRegex: String?
+
+        @Option(name: .long, help: "Group processes by app-bundle (shared top-level .app) or none (default)")
+        var groupBy: GroupBy?
+
+        @Flag(name: .long, help: "Print apps as RFC 4180 CSV instead of text or JSON")
+        var exportCsv = false
+
+        @Flag(name: .long, help: "Omit the CSV header row (requires --export-csv)")
+        var csvNoHeader = false
+
+        @Flag(name: .long, help: "Print apps as an aligned text table")
+        var table = false
+
+        @Flag(name: .long, help: "Omit the table header row (requires --table)")
+        var noHeader = false
+
+        @Option(name: .long, help: "Cut table values longer than this many characters with … (requires --table)")
+        var maxColWidth: Int?
+
+        @Option(name: .long, help: "Table borders: none (default), ascii, or unicode (requires --table)")
+        var borderStyle: TableFormatter.BorderStyle?
+
+        @Flag(name: .long, help: "Print the JSON Schema (draft-07) of the --json output instead of listing")
+        var jsonSchema = false
+
+        @Option(name: .long, help: "Return at most this many apps after sorting (default: all)")
+        var limit: Int?
+
+        @Option(name: .long, help: "Skip this many apps after sorting (default 0)")
+        var offset: Int?
+
+        @Option(name: .long, help: "Page number starting at 1; requires --page-size")
+        var page: Int?
+
+        @Option(name: .long, help: "Apps per page; sets --limit and computes --offset from --page")
+        var pageSize: Int?
+
         @RuntimeStorage private var runtime: CommandRuntime?
         var runtimeOptions = CommandRuntimeOptions()
 
@@ -36,12 +99,58 @@ extension ListCommand {
             self.runtime = runtime
             self.logger.setJsonOutputMode(self.jsonOutput)
 
+            if self.jsonSchema {
+                print(JSONSchema.render(self.groupsApplications ? ListJSONSchemas.groupedApps : ListJSONSchemas.apps))
+                return
+            }
+
             do {
+                try ListCommand.validateCSVOptions(
+                    exportCsv: self.exportCsv,
+                    csvNoHeader: self.csvNoHeader,
+                    jsonOutput: self.jsonOutput
+                )
+                try self.validateGroupingOptions()
+                try self.validateTableOptions()
+                let pagination = try self.resolvePagination()
+                if pagination.isPaginating, self.groupsApplications {
+                    throw ValidationError(
+                        "--group-by app-bundle cannot be combined with pagination; pages would split groups"
+                    )
+                }
+                let exclusions = try self.makeExclusionFilter()
+                let pathFilter = try self.makePathFilter()
                 try await requireScreenRecordingPermission(services: self.services)
-                let output = try await self.services.applications.listApplications()
+                let listed = try await self.services.applications.listApplications()
+                let filtered = Self.filteringByPath(Self.excluding(listed, matching: exclusions), with: pathFilter)
+                let sampled = try await self.withResourceStats(filtered)
+                let totalCount = sampled.data.applications.count
+                let paged = Self.paginated(sampled, by: pagination)
+                let output = self.withChildProcesses(self.withBundleMetadata(paged))
 
-                if self.jsonOutput {
-                    outputSuccessCodable(data: output.data, logger: self.outputLogger)
+                if self.exportCsv {
+                    let header = self.csvNoHeader ? nil : Self.csvHeader
+                    let rows = Self.csvRows(for: output.data.applications)
+                    print(CSVWriter.document(header: header, rows: rows), terminator: "")
+                } else if self.table {
+                    let contents = Self.tableContents(for: output.data.applications)
+                    let formatter = self.makeTableFormatter(terminal: TerminalDetector.detectCapabilities())
+                    print(formatter.render(header: contents.header, rows: contents.rows))
+                } else if self.groupsApplications {
+                    let grouped = Self.grouped(output.data.applications)
+                    if self.jsonOutput {
+                        outputSuccessCodable(data: grouped, logger: self.outputLogger)
+                    } else {
+                        print(Self.formatGrouped(grouped))
+                    }
+                } else if self.jsonOutput {
+                    let page = ApplicationListPage(
+                        applications: output.data.applications,
+                        total_count: totalCount,
+                        returned_count: output.data.applications.count,
+                        offset: pagination.offset
+                    )
+                    outputSuccessCodable(data: page, logger: self.outputLogger)
                 } else {
                     print(CLIFormatter.format(output))
                 }
@@ -50,6 +159,80 @@ extension ListCommand {
                 throw ExitCode(1)
             }
         }
+
+        var hidesSystemApps: Bool {
+            if self.includeSystem { return false }
+            // CSV is for data import, so like JSON it lists everything unless asked not to.
+            return self.excludeSystem || !(self.jsonOutput || self.exportCsv)
+        }
+
+        func makeExclusionFilter() throws -> SystemAppFilter {
+            if self.excludeSystem, self.includeSystem {
+                throw ValidationError("--exclude-system and --include-system are mutually exclusive")
+            }
+            return try SystemAppFilter(patterns: Self.exclusionPatterns(
+                hidesSystemApps: self.hidesSystemApps,
+                configured: ConfigurationManager.shared.getConfiguration()?.defaults?.systemAppPatterns,
+                custom: self.excludePattern
+            ))
+        }
+
+        private var collectsStats: Bool {
+            self.withStats || self.statsSort == .cpu || self.statsSort == .memory
+        }
+
+        private func withResourceStats(
+            _ output: UnifiedToolOutput<ServiceApplicationListData>
+        ) async throws -> UnifiedToolOutput<ServiceApplicationListData> {
+            guard self.withStats || self.statsSort != nil else { return output }
+
+            var applications = output.data.applications
+            if self.collectsStats {
+                let usage = try await ProcessResourceSampler.sample(pids: applications.map(\.processIdentifier))
+                applications = Self.applyingStats(usage, to: applications)
+            }
+            if let statsSort = self.statsSort {
+                applications = Self.sorted(applications, by: statsSort)
+            }
+
+            return UnifiedToolOutput(
+                data: ServiceApplicationListData(applications: applications),
+                summary: output.summary,
+                metadata: output.metadata
+            )
+        }
+
+        private func withBundleMetadata(
+            _ output: UnifiedToolOutput<ServiceApplicationListData>
+        ) -> UnifiedToolOutput<ServiceApplicationListData> {
+            guard self.richMetadata else { return output }
+
+            let applications = Self.applyingBundleMetadata(
+                to: output.data.applications,
+                lookup: ApplicationBundleMetadataReader.metadata(bundlePath:)
+            )
+            return UnifiedToolOutput(
+                data: ServiceApplicationListData(applications: applications),
+                summary: output.summary,
+                metadata: output.metadata
+            )
+        }
+
+        private func withChildProcesses(
+            _ output: UnifiedToolOutput<ServiceApplicationListData>
+        ) -> UnifiedToolOutput<ServiceApplicationListData> {
+            guard self.includeChildren else { return output }
+
+            let applications = Self.applyingChildren(
+                to: output.data.applications,
+                table: ProcessTreeReader.processTable()
+            )
+            return UnifiedToolOutput(
+                data: ServiceApplicationListData(applications: applications),
+                summary: output.summary,
+                metadata: output.metadata
+            )
+        }
     }
 }
 
@@ -63,7 +246,16 @@ extension ListCommand.AppsSubcommand: ParsableCommand {
                 discussion: """
                 Lists all running applications using the ApplicationService from PeekabooCore.
                 Applications are sorted by name and include process IDs, bundle identifiers,
-                and activation status.
+                and activation status. Pass --with-stats to add CPU and resident memory,
+                or --rich-metadata to add display name, category, icon, and version from Info.plist.
+                --include-children nests each app's child processes under it.
+                Human output hides macOS system agents unless --include-system is set;
+                --exclude-pattern hides more by name or bundle ID regex.
+                --export-csv prints one CSV row per app for spreadsheets and dataframes.
+                --table prints an aligned table that fits the terminal width.
+                --group-by app-bundle groups processes that share a top-level .app bundle.
+                --limit/--offset (or --page/--page-size) return one page of the sorted list;
+                JSON adds total_count, returned_count, and offset.
                 """
             )
         }
@@ -75,7 +267,26 @@ extension ListCommand.AppsSubcommand: AsyncRuntimeCommand {}
 @MainActor
 extension ListCommand.AppsSubcommand: CommanderBindableCommand {
     mutating func applyCommanderValues(_ values: CommanderBindableValues) throws {
-        // Apps has no parameters today; binding exists to keep Commander parity.
-        _ = values
+        self.withStats = values.flag("withStats")
+        self.statsSort = try values.decodeOptionEnum("statsSort")
+        self.richMetadata = values.flag("richMetadata")
+        self.includeChildren = values.flag("includeChildren")
+        self.excludeSystem = values.flag("excludeSystem")
+        self.includeSystem = values.flag("includeSystem")
+        self.excludePattern = values.optionValues("excludePattern")
+        self.filterByPath = values.singleOption("filterByPath")
+        self.filterByPathRegex = values.singleOption("filterByPathRegex")
+        self.groupBy = try values.decodeOptionEnum("groupBy")
+        self.exportCsv = values.flag("exportCsv")
+        self.csvNoHeader = values.flag("csvNoHeader")
+        self.table = values.flag("table")
+        self.noHeader = values.flag("noHeader")
+        self.maxColWidth = try values.decodeOption("maxColWidth", as: Int.self)
+        self.borderStyle = try values.decodeOptionEnum("borderStyle")
+        self.jsonSchema = values.flag("jsonSchema")
+        self.limit = try values.decodeOption("limit", as: Int.self)
+        self.offset = try values.decodeOption("offset", as: Int.self)
+        self.page = try values.decodeOption("page", as: Int.self)
+        self.pageSize = try values.decodeOption("pageSize", as: Int.self)
     }
 }
@@ -0,0 +1,70 @@
+import Commander
+import CoreGraphics
+import Foundation
+import PeekabooCore
+
+extension ListCommand {
+    /// `--export-csv` replaces both human and JSON output, so it can't be combined with `--json`.
+    static func validateCSVOptions(exportCsv: Bool, csvNoHeader: Bool, jsonOutput: Bool) throws {
+        if exportCsv, jsonOutput {
+            throw ValidationError("--export-csv and --json are mutually exclusive")
+        }
+        if csvNoHeader, !exportCsv {
+            throw ValidationError("--csv-no-header requires --export-csv")
+        }
+    }
+}
+
+extension ListCommand.WindowsSubcommand {
+    static let csvHeader = [
+        "window_index", "window_id", "title", "x", "y", "width", "height", "is_on_screen", "pid", "app_name",
+    ]
+
+    static func csvRows(for data: ServiceWindowListData) -> [[String]] {
+        let pid = data.targetApplication.map { String($0.processIdentifier) } ?? ""
+        let appName = data.targetApplication?.name ?? ""
+        return data.windows.map { window in
+            [
+                String(window.index),
+                String(window.windowID),
+                window.title,
+                Self.csvNumber(window.bounds.origin.x),
+                Self.csvNumber(window.bounds.origin.y),
+                Self.csvNumber(window.bounds.width),
+                Self.csvNumber(window.bounds.height),
+                String(window.isOnScreen),
+                pid,
+                appName,
+            ]
+        }
+    }
+
+    /// Whole points print without a trailing `.0` so spreadsheets read them as integers.
+    private static func csvNumber(_ value: CGFloat) -> String {
+        value == value.rounded() ? String(Int(value)) : String(Double(value))
+    }
+}
+
+extension ListCommand.AppsSubcommand {
+    static let csvHeader = [
+        "app_name", "bundle_id", "pid", "is_active", "is_hidden", "window_count", "bundle_path",
+        "cpu_percent", "memory_rss_mb",
+    ]
+
+    /// Stats columns stay empty unless `--with-stats` sampled them.
+    static func csvRows(for applications: [ServiceApplicationInfo]) -> [[String]] {
+        applications.map { app in
+            [
+                app.name,
+                app.bundleIdentifier ?? "",
+                String(app.processIdentifier),
+                String(app.isActive),
+                String(app.isHidden),
+                String(app.windowCount),
+                app.bundlePath ?? "",
+                app.cpuPercent.map { String(format: "%.1f", $0) } ?? "",
+                app.memoryRSSMB.map(String.init) ?? "",
+            ]
+        }
+    }
+}
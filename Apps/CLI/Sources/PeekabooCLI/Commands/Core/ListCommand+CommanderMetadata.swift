@@ -14,11 +14,78 @@ extension ListCommand.WindowsSubcommand: CommanderSignatureProviding {
                     help: "Target application by process ID",
                     long: "pid"
                 ),
+                .commandOption(
+                    "pidFile",
+                    help: "Target the process whose PID is on the first line of this file",
+                    long: "pid-file"
+                ),
+                .commandOption(
+                    "findAppByWindowTitle",
+                    help: "Find the app owning the first window whose title contains this text",
+                    long: "find-app-by-window-title"
+                ),
                 .commandOption(
                     "includeDetails",
                     help: "Additional details (comma-separated: off_screen,bounds,ids)",
                     long: "include-details"
                 ),
+                .commandOption(
+                    "watchIntervalMs",
+                    help: "Poll interval for --watch in milliseconds (default 1000)",
+                    long: "watch-interval-ms"
+                ),
+                .commandOption(
+                    "limit",
+                    help: "Return at most this many windows after sorting (default: all)",
+                    long: "limit"
+                ),
+                .commandOption(
+                    "offset",
+                    help: "Skip this many windows after sorting (default 0)",
+                    long: "offset"
+                ),
+                .commandOption(
+                    "page",
+                    help: "Page number starting at 1; requires --page-size",
+                    long: "page"
+                ),
+                .commandOption(
+                    "pageSize",
+                    help: "Windows per page; sets --limit and computes --offset from --page",
+                    long: "page-size"
+                ),
+            ],
+            flags: [
+                .commandFlag(
+                    "caseSensitive",
+                    help: "Match --app and --find-app-by-window-title case-sensitively",
+                    long: "case-sensitive"
+                ),
+                .commandFlag(
+                    "exportCsv",
+                    help: "Print windows as RFC 4180 CSV instead of text or JSON",
+                    long: "export-csv"
+                ),
+                .commandFlag(
+                    "csvNoHeader",
+                    help: "Omit the CSV header row (requires --export-csv)",
+                    long: "csv-no-header"
+                ),
+                .commandFlag(
+                    "jsonSchema",
+                    help: "Print the JSON Schema (draft-07) of the --json output instead of listing",
+                    long: "json-schema"
+                ),
+                .commandFlag(
+                    "watch",
+                    help: "Keep running and report windows opening, closing, or changing title until SIGINT",
+                    long: "watch"
+                ),
+                .commandFlag(
+                    "cacheLayout",
+                    help: "Save window bounds under ~/.peekaboo/cache/layouts for 'image --restore-layout'",
+                    long: "cache-layout"
+                ),
             ]
         )
     }
@@ -26,7 +93,117 @@ extension ListCommand.WindowsSubcommand: CommanderSignatureProviding {
 
 extension ListCommand.AppsSubcommand: CommanderSignatureProviding {
     static func commanderSignature() -> CommandSignature {
-        CommandSignature()
+        CommandSignature(
+            options: [
+                .commandOption(
+                    "statsSort",
+                    help: "Sort by name|cpu|memory; cpu and memory imply --with-stats",
+                    long: "stats-sort"
+                ),
+                .commandOption(
+                    "excludePattern",
+                    help: "Regex matched against app name or bundle ID to hide; repeatable",
+                    long: "exclude-pattern"
+                ),
+                .commandOption(
+                    "filterByPath",
+                    help: "Keep apps whose executable path contains this text (case-insensitive)",
+                    long: "filter-by-path"
+                ),
+                .commandOption(
+                    "filterByPathRegex",
+                    help: "Keep apps whose executable path matches this regex",
+                    long: "filter-by-path-regex"
+                ),
+                .commandOption(
+                    "groupBy",
+                    help: "Group processes by app-bundle (shared top-level .app) or none (default)",
+                    long: "group-by"
+                ),
+                .commandOption(
+                    "maxColWidth",
+                    help: "Cut table values longer than this many characters with … (requires --table)",
+                    long: "max-col-width"
+                ),
+                .commandOption(
+                    "borderStyle",
+                    help: "Table borders: none (default), ascii, or unicode (requires --table)",
+                    long: "border-style"
+                ),
+                .commandOption(
+                    "limit",
+                    help: "Return at most this many apps after sorting (default: all)",
+                    long: "limit"
+                ),
+                .commandOption(
+                    "offset",
+                    help: "Skip this many apps after sorting (default 0)",
+                    long: "offset"
+                ),
+                .commandOption(
+                    "page",
+                    help: "Page number starting at 1; requires --page-size",
+                    long: "page"
+                ),
+                .commandOption(
+                    "pageSize",
+                    help: "Apps per page; sets --limit and computes --offset from --page",
+                    long: "page-size"
+                ),
+            ],
+            flags: [
+                .commandFlag(
+                    "withStats",
+                    help: "Include CPU and resident memory per app (adds a 200ms sampling delay)",
+                    long: "with-stats"
+                ),
+                .commandFlag(
+                    "richMetadata",
+                    help: "Add display name, category, icon, and version from each app's Info.plist",
+                    long: "rich-metadata"
+                ),
+                .commandFlag(
+                    "includeChildren",
+                    help: "Nest each app's child processes (helpers, renderers) under it",
+                    long: "include-children"
+                ),
+                .commandFlag(
+                    "excludeSystem",
+                    help: "Hide macOS system agents (default in human output; JSON lists everything)",
+                    long: "exclude-system"
+                ),
+                .commandFlag(
+                    "includeSystem",
+                    help: "Show macOS system agents in human output",
+                    long: "include-system"
+                ),
+                .commandFlag(
+                    "exportCsv",
+                    help: "Print apps as RFC 4180 CSV instead of text or JSON",
+                    long: "export-csv"
+                ),
+                .commandFlag(
+                    "csvNoHeader",
+                    help: "Omit the CSV header row (requires --export-csv)",
+                    long: "csv-no-header"
+                ),
+                .commandFlag(
+                    "table",
+                    help: "Print apps as an aligned text table",
+                    long: "table"
+                ),
+                .commandFlag(
+                    "noHeader",
+                    help: "Omit the table header row (requires --table)",
+                    long: "no-header"
+                ),
+                .commandFlag(
+                    "jsonSchema",
+                    help: "Print the JSON Schema (draft-07) of the --json output instead of listing",
+                    long: "json-schema"
+                ),
+            ]
+        )
     }
 }
 
@@ -37,6 +214,27 @@ extension ListCommand.MenuBarSubcommand: CommanderSignatureProviding {
 }
 
 extension ListCommand.ScreensSubcommand: CommanderSignatureProviding {
+    static func commanderSignature() -> CommandSignature {
+        CommandSignature(
+            options: [
+                .commandOption(
+                    "watchIntervalMs",
+                    help: "Poll interval for --watch in milliseconds (default 1000)",
+                    long: "watch-interval-ms"
+                ),
+            ],
+            flags: [
+                .commandFlag(
+                    "watch",
+                    help: "Keep running and report displays connecting or disconnecting until SIGINT",
+                    long: "watch"
+                ),
+            ]
+        )
+    }
+}
+
+extension ListCommand.ProfilesSubcommand: CommanderSignatureProviding {
     static func commanderSignature() -> CommandSignature {
         CommandSignature()
     }
@@ -0,0 +1,119 @@
+import Foundation
+
+/// Hand-maintained schemas for `--json-schema`; `ListCommandJSONSchemaTests` checks them against encoded payloads.
+enum ListJSONSchemas {
+    static let processNode = JSONSchema.object(
+        [
+            "pid": .integer,
+            "name": .string(),
+            "children": .array(.ref("processNode")),
+        ],
+        required: ["pid", "name", "children"]
+    )
+
+    static let application = JSONSchema.object(
+        [
+            "processIdentifier": .integer,
+            "bundleIdentifier": .string(),
+            "name": .string(),
+            "bundlePath": .string(),
+            "executablePath": .string(),
+            "isActive": .boolean,
+            "isHidden": .boolean,
+            "windowCount": .integer,
+            "activationPolicy": .string(allowed: ["regular", "accessory", "prohibited", "unknown"]),
+            "cpuPercent": .number,
+            "memoryRSSMB": .integer,
+            "launchTime": .string(),
+            "uptimeSeconds": .integer,
+            "displayName": .string(),
+            "category": .string(),
+            "iconName": .string(),
+            "version": .string(),
+            "children": .array(.ref("processNode")),
+        ],
+        required: ["processIdentifier", "name", "isActive", "isHidden", "windowCount"]
+    )
+
+    /// `CGRect` encodes as `[[x, y], [width, height]]`.
+    static let rect = JSONSchema.array(.array(.number))
+
+    static let window = JSONSchema.object(
+        [
+            "window_id": .integer,
+            "title": .string(),
+            "bounds": rect,
+            "isMinimized": .boolean,
+            "isMainWindow": .boolean,
+            "windowLevel": .integer,
+            "alpha": .number,
+            "index": .integer,
+            "spaceID": .integer,
+            "spaceName": .string(),
+            "screenIndex": .integer,
+            "screenName": .string(),
+            "isOffScreen": .boolean,
+            "layer": .integer,
+            "isOnScreen": .boolean,
+            // WindowSharingState raw values: none, readOnly, readWrite.
+            "sharingState": .integer,
+            "isExcludedFromWindowsMenu": .boolean,
+        ],
+        required: [
+            "window_id", "title", "bounds", "isMinimized", "isMainWindow", "windowLevel", "alpha", "index",
+            "isOffScreen", "layer", "isOnScreen", "isExcludedFromWindowsMenu",
+        ]
+    )
+
+    static var apps: [String: Any] {
+        JSONSchema.responseDocument(
+            title: "peekaboo list apps",
+            data: .object(
+                [
+                    "applications": .array(application),
+                    "total_count": .integer,
+                    "returned_count": .integer,
+                    "offset": .integer,
+                ],
+                required: ["applications"]
+            ),
+            definitions: ["processNode": processNode]
+        )
+    }
+
+    /// `--group-by app-bundle`; `schema_version` lets clients tell it apart from the flat list.
+    static var groupedApps: [String: Any] {
+        JSONSchema.responseDocument(
+            title: "peekaboo list apps --group-by app-bundle",
+            data: .object(
+                [
+                    "schema_version": .string(allowed: [ListCommand.AppsSubcommand.groupedSchemaVersion]),
+                    "groups": .array(.object(
+                        ["name": .string(), "bundle_path": .string(), "applications": .array(application)],
+                        required: ["name", "bundle_path", "applications"]
+                    )),
+                    "ungrouped": .array(application),
+                ],
+                required: ["schema_version", "groups", "ungrouped"]
+            ),
+            definitions: ["processNode": processNode]
+        )
+    }
+
+    static var windows: [String: Any] {
+        JSONSchema.responseDocument(
+            title: "peekaboo list windows",
+            data: .object(
+                [
+                    "windows": .array(window),
+                    "targetApplication": application,
+                    "total_count": .integer,
+                    "returned_count": .integer,
+                    "offset": .integer,
+                ],
+                required: ["windows"]
+            ),
+            definitions: ["processNode": processNode]
+        )
+    }
+}
@@ -0,0 +1,21 @@
+import Foundation
+import PeekabooCore
+
+extension ListCommand.WindowsSubcommand {
+    /// Saves the full, unpaginated window list so `image --restore-layout` can put every window back.
+    func cacheWindowLayout(
+        _ data: ServiceWindowListData,
+        appIdentifier: String,
+        services: any PeekabooServiceProviding,
+        directory: URL = WindowLayoutCache.defaultDirectory
+    ) async throws {
+        let application: ServiceApplicationInfo = if let target = data.targetApplication {
+            target
+        } else {
+            try await services.applications.findApplication(identifier: appIdentifier)
+        }
+        let layout = WindowLayout(pid: application.processIdentifier, appName: application.name, windows: data.windows)
+        let url = try WindowLayoutCache.save(layout, in: directory)
+        self.outputLogger.info("Cached layout of \(layout.windows.count) window(s) at \(url.path)")
+    }
+}
@@ -0,0 +1,137 @@
+import Commander
+import PeekabooCore
+
+/// The slice of a sorted list that `--limit`/`--offset` or `--page`/`--page-size` asks for.
+struct ListPagination: Equatable {
+    let offset: Int
+    let limit: Int?
+
+    static let all = ListPagination(offset: 0, limit: nil)
+
+    /// `--page` counts from 1 and needs `--page-size`; `--page-size` alone means page 1.
+    static func resolve(limit: Int?, offset: Int?, page: Int?, pageSize: Int?) throws -> ListPagination {
+        if page != nil || pageSize != nil {
+            guard limit == nil, offset == nil else {
+                throw ValidationError("--page/--page-size cannot be combined with --limit/--offset")
+            }
+            guard let pageSize else {
+                throw ValidationError("--page requires --page-size")
+            }
+            guard pageSize > 0 else {
+                throw ValidationError("--page-size must be greater than zero")
+            }
+            let page = page ?? 1
+            guard page > 0 else {
+                throw ValidationError("--page starts at 1")
+            }
+            let (start, overflow) = (page - 1).multipliedReportingOverflow(by: pageSize)
+            guard !overflow else {
+                throw ValidationError("--page \(page) with --page-size \(pageSize) is out of range")
+            }
+            return ListPagination(offset: start, limit: pageSize)
+        }
+
+        if let limit, limit < 0 {
+            throw ValidationError("--limit must not be negative")
+        }
+        if let offset, offset < 0 {
+            throw ValidationError("--offset must not be negative")
+        }
+        return ListPagination(offset: offset ?? 0, limit: limit)
+    }
+
+    var isPaginating: Bool {
+        self != .all
+    }
+
+    /// The items on this page; an offset past the end yields an empty page rather than an error.
+    func page<Element>(of items: [Element]) -> [Element] {
+        let start = min(self.offset, items.count)
+        let end = self.limit.map { start + min($0, items.count - start) } ?? items.count
+        return Array(items[start..<end])
+    }
+
+    func brief(returned: Int, total: Int, noun: String) -> String {
+        "Showing \(returned) of \(total) \(noun) from offset \(self.offset)"
+    }
+}
+
+/// Options shared by the list subcommands whose output can be paged.
+@MainActor
+protocol ListPaginatable {
+    var limit: Int? { get }
+    var offset: Int? { get }
+    var page: Int? { get }
+    var pageSize: Int? { get }
+}
+
+extension ListPaginatable {
+    func resolvePagination() throws -> ListPagination {
+        try ListPagination.resolve(limit: self.limit, offset: self.offset, page: self.page, pageSize: self.pageSize)
+    }
+}
+
+/// `list apps --json` payload: the applications on this page plus what callers need to fetch the next one.
+struct ApplicationListPage: Codable {
+    let applications: [ServiceApplicationInfo]
+    let total_count: Int
+    let returned_count: Int
+    let offset: Int
+}
+
+/// `list windows --json` payload; see `ApplicationListPage`.
+struct WindowListPage: Codable {
+    let windows: [ServiceWindowInfo]
+    let targetApplication: ServiceApplicationInfo?
+    let total_count: Int
+    let returned_count: Int
+    let offset: Int
+}
+
+extension ListCommand.AppsSubcommand {
+    /// Cuts the already sorted list to `pagination`; the summary keeps the count from before the cut.
+    static func paginated(
+        _ output: UnifiedToolOutput<ServiceApplicationListData>,
+        by pagination: ListPagination
+    ) -> UnifiedToolOutput<ServiceApplicationListData> {
+        guard pagination.isPaginating else { return output }
+
+        let all = output.data.applications
+        let applications = pagination.page(of: all)
+        return UnifiedToolOutput(
+            data: ServiceApplicationListData(applications: applications),
+            summary: UnifiedToolOutput.Summary(
+                brief: pagination.brief(returned: applications.count, total: all.count, noun: "running applications"),
+                detail: output.summary.detail,
+                status: output.summary.status,
+                counts: output.summary.counts,
+                highlights: output.summary.highlights
+            ),
+            metadata: output.metadata
+        )
+    }
+}
+
+extension ListCommand.WindowsSubcommand {
+    /// Cuts the z-ordered window list to `pagination`; the summary keeps the count from before the cut.
+    static func paginated(
+        _ output: UnifiedToolOutput<ServiceWindowListData>,
+        by pagination: ListPagination
+    ) -> UnifiedToolOutput<ServiceWindowListData> {
+        guard pagination.isPaginating else { return output }
+
+        let all = output.data.windows
+        let windows = pagination.page(of: all)
+        return UnifiedToolOutput(
+            data: ServiceWindowListData(windows: windows, targetApplication: output.data.targetApplication),
+            summary: UnifiedToolOutput.Summary(
+                brief: pagination.brief(returned: windows.count, total: all.count, noun: "windows"),
+                detail: output.summary.detail,
+                status: output.summary.status,
+                counts: output.summary.counts,
+                highlights: output.summary.highlights
+            ),
+            metadata: output.metadata
+        )
+    }
+}
@@ -0,0 +1,74 @@
+import Commander
+import Foundation
+import PeekabooCore
+
+/// Keeps `list apps` entries whose executable path (or bundle path, when the executable is unknown) matches
+/// `--filter-by-path` (case-insensitive substring) and `--filter-by-path-regex`.
+struct ApplicationPathFilter {
+    let substring: String?
+    let expression: NSRegularExpression?
+
+    init(substring: String?, regex: String?) throws {
+        if let substring, substring.isEmpty {
+            throw ValidationError("--filter-by-path must not be empty")
+        }
+        self.substring = substring
+        self.expression = try regex.map { pattern in
+            do {
+                return try NSRegularExpression(pattern: pattern)
+            } catch {
+                throw ValidationError("Invalid --filter-by-path-regex '\(pattern)': \(error.localizedDescription)")
+            }
+        }
+    }
+
+    var isFiltering: Bool {
+        self.substring != nil || self.expression != nil
+    }
+
+    func matches(_ application: ServiceApplicationInfo) -> Bool {
+        guard let path = application.executablePath ?? application.bundlePath else {
+            return !self.isFiltering
+        }
+        if let substring, !path.localizedCaseInsensitiveContains(substring) {
+            return false
+        }
+        if let expression,
+           expression.firstMatch(in: path, range: NSRange(path.startIndex..., in: path)) == nil {
+            return false
+        }
+        return true
+    }
+}
+
+extension ListCommand.AppsSubcommand {
+    func makePathFilter() throws -> ApplicationPathFilter {
+        try ApplicationPathFilter(substring: self.filterByPath, regex: self.filterByPathRegex)
+    }
+
+    static func filteringByPath(
+        _ output: UnifiedToolOutput<ServiceApplicationListData>,
+        with filter: ApplicationPathFilter
+    ) -> UnifiedToolOutput<ServiceApplicationListData> {
+        guard filter.isFiltering else { return output }
+        let applications = output.data.applications.filter(filter.matches)
+
+        var counts = output.summary.counts
+        counts["applications"] = applications.count
+        counts["appsWithWindows"] = applications.count(where: { $0.windowCount > 0 })
+        counts["totalWindows"] = applications.reduce(0) { $0 + $1.windowCount }
+
+        let noun = applications.count == 1 ? "application" : "applications"
+        return UnifiedToolOutput(
+            data: ServiceApplicationListData(applications: applications),
+            summary: UnifiedToolOutput.Summary(
+                brief: "Found \(applications.count) running \(noun) matching the path filter",
+                detail: output.summary.detail,
+                status: output.summary.status,
+                counts: counts,
+                highlights: output.summary.highlights
+            ),
+            metadata: output.metadata
+        )
+    }
+}
@@ -0,0 +1,96 @@
+import Commander
+import Foundation
+import PeekabooCore
+
+extension ListCommand {
+    // MARK: - Profiles
+
+    @MainActor
+    struct ProfilesSubcommand: OutputFormattable, RuntimeOptionsConfigurable {
+        @RuntimeStorage private var runtime: CommandRuntime?
+        var runtimeOptions = CommandRuntimeOptions()
+
+        private var resolvedRuntime: CommandRuntime {
+            guard let runtime else {
+                preconditionFailure("CommandRuntime must be configured before accessing runtime resources")
+            }
+            return runtime
+        }
+
+        private var logger: Logger {
+            self.resolvedRuntime.logger
+        }
+
+        var outputLogger: Logger {
+            self.logger
+        }
+
+        var jsonOutput: Bool {
+            self.runtime?.configuration.jsonOutput ?? self.runtimeOptions.jsonOutput
+        }
+
+        @MainActor
+        mutating func run(using runtime: CommandRuntime) async throws {
+            self.runtime = runtime
+            self.logger.setJsonOutputMode(self.jsonOutput)
+
+            let data = Self.profileList(in: ImageProfiles.directory)
+            if self.jsonOutput {
+                outputSuccessCodable(data: data, logger: self.outputLogger)
+                return
+            }
+
+            guard !data.profiles.isEmpty else {
+                Swift.print("No profiles in \(data.directory)")
+                Swift.print("\n💡 Save defaults there as <name>.json, then run 'peekaboo image --profile <name>'")
+                return
+            }
+            Swift.print("Profiles (\(data.profiles.count) total) in \(data.directory):")
+            for profile in data.profiles {
+                Swift.print("  \(profile.name)")
+            }
+        }
+
+        static func profileList(in directory: String) -> ProfileListData {
+            ProfileListData(
+                directory: directory,
+                profiles: ImageProfiles.names(in: directory).map {
+                    ProfileListData.Profile(name: $0, path: ImageProfiles.path(for: $0, in: directory))
+                }
+            )
+        }
+    }
+}
+
+// MARK: - Profile List Data Model
+
+struct ProfileListData: Codable, Equatable {
+    let directory: String
+    let profiles: [Profile]
+
+    struct Profile: Codable, Equatable {
+        let name: String
+        let path: String
+    }
+}
+
+@MainActor
+extension ListCommand.ProfilesSubcommand: ParsableCommand {
+    nonisolated(unsafe) static var commandDescription: CommandDescription {
+        MainActorCommandDescription.describe {
+            CommandDescription(
+                commandName: "profiles",
+                abstract: "List saved `peekaboo image --profile` names"
+            )
+        }
+    }
+}
+
+extension ListCommand.ProfilesSubcommand: AsyncRuntimeCommand {}
+
+@MainActor
+extension ListCommand.ProfilesSubcommand: CommanderBindableCommand {
+    mutating func applyCommanderValues(_ values: CommanderBindableValues) throws {
+        _ = values
+    }
+}
@@ -0,0 +1,110 @@
+import Commander
+import Foundation
+import PeekabooCore
+
+/// One display change between two `list screens --watch` polls; JSON mode prints one per line.
+struct ScreenListEvent: Encodable, Equatable {
+    enum Kind: String, Encodable {
+        case displayConnected = "display_connected"
+        case displayDisconnected = "display_disconnected"
+    }
+
+    let type: Kind
+    let display: ScreenListData.ScreenDetails?
+    let name: String?
+    let display_id: Int?
+
+    static func connected(_ display: ScreenListData.ScreenDetails) -> ScreenListEvent {
+        ScreenListEvent(type: .displayConnected, display: display, name: nil, display_id: nil)
+    }
+
+    static func disconnected(_ display: ScreenListData.ScreenDetails) -> ScreenListEvent {
+        ScreenListEvent(type: .displayDisconnected, display: nil, name: display.name, display_id: display.displayID)
+    }
+}
+
+/// First JSON line of a watch, so consumers see the starting displays before any events.
+struct ScreenWatchSnapshot: Encodable {
+    let type = "screen_list"
+    let screens: [ScreenListData.ScreenDetails]
+}
+
+extension ListCommand.ScreensSubcommand {
+    static let defaultWatchIntervalMs = 1000
+
+    func validateWatchOptions() throws {
+        guard let watchIntervalMs else { return }
+        guard self.watch else {
+            throw ValidationError("--watch-interval-ms requires --watch")
+        }
+        guard watchIntervalMs > 0 else {
+            throw ValidationError("--watch-interval-ms must be greater than zero")
+        }
+    }
+
+    /// Diffs two snapshots keyed by display ID, since indexes shift when a display is added or removed.
+    static func screenEvents(
+        from previous: [ScreenListData.ScreenDetails],
+        to current: [ScreenListData.ScreenDetails]
+    ) -> [ScreenListEvent] {
+        let previousIDs = Set(previous.map(\.displayID))
+        let currentIDs = Set(current.map(\.displayID))
+        let disconnected = previous.filter { !currentIDs.contains($0.displayID) }.map(ScreenListEvent.disconnected)
+        let connected = current.filter { !previousIDs.contains($0.displayID) }.map(ScreenListEvent.connected)
+        return disconnected + connected
+    }
+
+    /// Human-readable event line: `+Name (W×H)` connected, `-Name` disconnected.
+    static func describe(_ event: ScreenListEvent) -> String {
+        switch event.type {
+        case .displayConnected:
+            guard let display = event.display else { return "+" }
+            return "+\(display.name) (\(display.resolution.width)×\(display.resolution.height))"
+        case .displayDisconnected:
+            return "-\(event.name ?? "display \(event.display_id ?? 0)")"
+        }
+    }
+
+    /// Polls until SIGINT/SIGTERM, printing one line per connected or disconnected display.
+    func watchScreens(initial: [ScreenListData.ScreenDetails]) async throws {
+        let jsonOutput = self.jsonOutput
+        let format = self.outputLogger.outputFormat
+        if jsonOutput {
+            format.writeRecord(ScreenWatchSnapshot(screens: initial))
+        }
+
+        let screens = self.services.screens
+        let interval = Duration.milliseconds(self.watchIntervalMs ?? Self.defaultWatchIntervalMs)
+        let poll = Task { @MainActor in
+            var previous = initial
+            while !Task.isCancelled {
+                try await Task.sleep(for: interval)
+                let current = self.buildScreenListData(from: screens.listScreens()).screens
+                for event in Self.screenEvents(from: previous, to: current) {
+                    if jsonOutput {
+                        format.writeRecord(event)
+                    } else {
+                        Self.emit(Self.describe(event))
+                    }
+                }
+                previous = current
+            }
+        }
+
+        let monitor = CaptureSignalMonitor()
+        monitor.start { _ in poll.cancel() }
+        defer { monitor.stop() }
+
+        do {
+            try await poll.value
+        } catch {
+            // Cancellation surfaces from the sleep; it means a signal ended the watch.
+            guard poll.isCancelled else { throw error }
+        }
+    }
+
+    private static func emit(_ line: String) {
+        print(line)
+        fflush(stdout)
+    }
+}
@@ -11,6 +11,12 @@ extension ListCommand {
 
     @MainActor
     struct ScreensSubcommand: ErrorHandlingCommand, OutputFormattable, RuntimeOptionsConfigurable {
+        @Flag(name: .long, help: "Keep running and report displays connecting or disconnecting until SIGINT")
+        var watch = false
+
+        @Option(name: .long, help: "Poll interval for --watch in milliseconds (default 1000)")
+        var watchIntervalMs: Int?
+
         @RuntimeStorage private var runtime: CommandRuntime?
         var runtimeOptions = CommandRuntimeOptions()
 
@@ -21,7 +27,7 @@ extension ListCommand {
             return runtime
         }
 
-        private var services: any PeekabooServiceProviding {
+        var services: any PeekabooServiceProviding {
             self.resolvedRuntime.services
         }
 
@@ -42,8 +48,16 @@ extension ListCommand {
             self.runtime = runtime
             self.logger.setJsonOutputMode(self.jsonOutput)
 
+            try self.validateWatchOptions()
             let screens = self.services.screens.listScreens()
             let screenListData = self.buildScreenListData(from: screens)
+            if self.watch {
+                if !self.jsonOutput {
+                    self.displayScreenDetails(screens, count: screens.count)
+                }
+                try await self.watchScreens(initial: screenListData.screens)
+                return
+            }
             let output = UnifiedToolOutput(
                 data: screenListData,
                 summary: self.buildScreenSummary(for: screens),
@@ -75,7 +89,7 @@ extension ListCommand {
         }
 
         @MainActor
-        private func buildScreenListData(from screens: [PeekabooCore.ScreenInfo]) -> ScreenListData {
+        func buildScreenListData(from screens: [PeekabooCore.ScreenInfo]) -> ScreenListData {
             let details = screens.map { screen in
                 ScreenListData.ScreenDetails(
                     index: screen.index,
@@ -164,10 +178,14 @@ nonisolated extension ScreenListData: Sendable, Codable {}
 nonisolated extension ScreenListData.ScreenDetails: Sendable, Codable {}
 nonisolated extension ScreenListData.Resolution: Sendable, Codable {}
 nonisolated extension ScreenListData.Position: Sendable, Codable {}
+nonisolated extension ScreenListData.ScreenDetails: Equatable {}
+nonisolated extension ScreenListData.Resolution: Equatable {}
+nonisolated extension ScreenListData.Position: Equatable {}
 
 @MainActor
 extension ListCommand.ScreensSubcommand: CommanderBindableCommand {
     mutating func applyCommanderValues(_ values: CommanderBindableValues) throws {
-        _ = values
+        self.watch = values.flag("watch")
+        self.watchIntervalMs = try values.decodeOption("watchIntervalMs", as: Int.self)
     }
 }
@@ -0,0 +1,73 @@
+import Commander
+import Foundation
+import PeekabooCore
+
+/// Hides macOS shell and agent processes (Dock, Control Center, `…Agent` helpers) from `list apps`.
+struct SystemAppFilter {
+    /// Matched against the bundle ID and the app name; background-only (prohibited) apps never reach the list.
+    static let builtInPatterns = [
+        #"^com\.apple\.(dock|loginwindow|controlcenter|notificationcenterui|WindowManager|Spotlight)$"#,
+        #"^com\.apple\.(systemuiserver|UserNotificationCenter|ViewBridgeAuxiliary|universalAccessAuthWarn)$"#,
+        #"^com\.apple\.wallpaper\..+$"#,
+        #"^com\.apple\..+(Agent|Helper|UIServer)$"#,
+    ]
+
+    private let expressions: [NSRegularExpression]
+
+    init(patterns: [String]) throws {
+        self.expressions = try patterns.map { pattern in
+            do {
+                return try NSRegularExpression(pattern: pattern)
+            } catch {
+                throw ValidationError("Invalid exclude pattern '\(pattern)': \(error.localizedDescription)")
+            }
+        }
+    }
+
+    func matches(_ application: ServiceApplicationInfo) -> Bool {
+        [application.bundleIdentifier, application.name].compactMap(\.self).contains { value in
+            let range = NSRange(value.startIndex..., in: value)
+            return self.expressions.contains { $0.firstMatch(in: value, range: range) != nil }
+        }
+    }
+}
+
+extension ListCommand.AppsSubcommand {
+    /// Built-ins plus `defaults.systemAppPatterns` when hiding system apps, then every `--exclude-pattern`.
+    static func exclusionPatterns(
+        hidesSystemApps: Bool,
+        configured: [String]?,
+        custom: [String]
+    ) -> [String] {
+        let system = hidesSystemApps ? SystemAppFilter.builtInPatterns + (configured ?? []) : []
+        return system + custom
+    }
+
+    static func excluding(
+        _ output: UnifiedToolOutput<ServiceApplicationListData>,
+        matching filter: SystemAppFilter
+    ) -> UnifiedToolOutput<ServiceApplicationListData> {
+        let applications = output.data.applications.filter { !filter.matches($0) }
+        let hidden = output.data.applications.count - applications.count
+        guard hidden > 0 else { return output }
+
+        var counts = output.summary.counts
+        counts["applications"] = applications.count
+        counts["appsWithWindows"] = applications.count(where: { $0.windowCount > 0 })
+        counts["totalWindows"] = applications.reduce(0) { $0 + $1.windowCount }
+        counts["excludedApplications"] = hidden
+
+        let noun = applications.count == 1 ? "application" : "applications"
+        return UnifiedToolOutput(
+            data: ServiceApplicationListData(applications: applications),
+            summary: UnifiedToolOutput.Summary(
+                brief: "Found \(applications.count) running \(noun) (\(hidden) excluded)",
+                detail: output.summary.detail,
+                status: output.summary.status,
+                counts: counts,
+                highlights: output.summary.highlights
+            ),
+            metadata: output.metadata
+        )
+    }
+}
@@ -0,0 +1,61 @@
+import Commander
+import Foundation
+import PeekabooCore
+
+extension ListCommand.AppsSubcommand {
+    /// `--table` is its own output mode, so it can't share a run with JSON, CSV, or grouped text.
+    func validateTableOptions() throws {
+        if self.table {
+            if self.jsonOutput {
+                throw ValidationError("--table and --json are mutually exclusive")
+            }
+            if self.exportCsv {
+                throw ValidationError("--table and --export-csv are mutually exclusive")
+            }
+            if self.groupsApplications {
+                throw ValidationError("--group-by app-bundle cannot be combined with --table; table rows stay flat")
+            }
+        } else if self.noHeader || self.maxColWidth != nil || self.borderStyle != nil {
+            throw ValidationError("--no-header, --max-col-width, and --border-style require --table")
+        }
+        if let maxColWidth, maxColWidth < 2 {
+            throw ValidationError("--max-col-width must be at least 2")
+        }
+    }
+
+    /// Only an interactive terminal gets fitted; piped tables keep full-width columns.
+    func makeTableFormatter(terminal: TerminalCapabilities) -> TableFormatter {
+        TableFormatter(
+            borderStyle: self.borderStyle ?? .none,
+            showsHeader: !self.noHeader,
+            maxColumnWidth: self.maxColWidth,
+            availableWidth: terminal.isInteractive && !terminal.isPiped ? terminal.width : nil
+        )
+    }
+
+    static let tableHeader = ["NAME", "PID", "BUNDLE ID", "WINDOWS", "STATE"]
+    static let tableStatsHeader = ["CPU %", "RSS MB"]
+
+    /// Stats columns appear only when `--with-stats` sampled at least one app.
+    static func tableContents(
+        for applications: [ServiceApplicationInfo]
+    ) -> (header: [String], rows: [[String]]) {
+        let hasStats = applications.contains { $0.cpuPercent != nil || $0.memoryRSSMB != nil }
+        let header = Self.tableHeader + (hasStats ? Self.tableStatsHeader : [])
+        let rows = applications.map { app in
+            var row = [
+                app.name,
+                String(app.processIdentifier),
+                app.bundleIdentifier ?? "",
+                String(app.windowCount),
+                app.isActive ? "active" : app.isHidden ? "hidden" : "",
+            ]
+            if hasStats {
+                row.append(app.cpuPercent.map { String(format: "%.1f", $0) } ?? "")
+                row.append(app.memoryRSSMB.map(String.init) ?? "")
+            }
+            return row
+        }
+        return (header, rows)
+    }
+}
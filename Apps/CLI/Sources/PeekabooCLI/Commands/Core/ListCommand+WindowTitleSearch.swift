@@ -0,0 +1,59 @@
+import Commander
+import Foundation
+import PeekabooCore
+
+extension ListCommand.WindowsSubcommand {
+    /// `--find-app-by-window-title` replaces the app target, so it can't be combined with one or with `--watch`.
+    func validateWindowTitleSearchOptions() throws {
+        guard self.findAppByWindowTitle != nil else { return }
+        if self.app != nil || self.pid != nil || self.pidFile != nil {
+            throw ValidationError("--find-app-by-window-title cannot be combined with --app, --pid, or --pid-file")
+        }
+        if self.watch {
+            throw ValidationError("--find-app-by-window-title cannot be combined with --watch")
+        }
+        if self.findAppByWindowTitle?.trimmingCharacters(in: .whitespacesAndNewlines).isEmpty == true {
+            throw ValidationError("--find-app-by-window-title needs a non-empty title")
+        }
+    }
+
+    /// Apps that own windows, frontmost first, so a visible match wins over a background one with the same title.
+    static func windowTitleSearchOrder(_ applications: [ServiceApplicationInfo]) -> [ServiceApplicationInfo] {
+        let candidates = applications.filter { $0.windowCount > 0 }
+        return candidates.filter(\.isActive) + candidates.filter { !$0.isActive }
+    }
+
+    /// Asks each app for its windows until one has a title containing `title`, then lists just that window with
+    /// its owner, in the same shape as `list windows --app`.
+    func findApplication(
+        byWindowTitle title: String,
+        services: any PeekabooServiceProviding
+    ) async throws -> UnifiedToolOutput<ServiceWindowListData> {
+        let startedAt = Date()
+        let query = title.trimmingCharacters(in: .whitespacesAndNewlines)
+        let listed = try await services.applications.listApplications()
+
+        for app in Self.windowTitleSearchOrder(listed.data.applications) {
+            // Apps that refuse accessibility queries are skipped rather than failing the whole search.
+            guard let output = try? await services.applications.listWindows(
+                for: "PID:\(app.processIdentifier)",
+                timeout: nil
+            ) else { continue }
+            let match = output.data.windows.first {
+                ImageCommand.windowTitle($0.title, matches: query, caseSensitive: self.caseSensitive)
+            }
+            guard let match else { continue }
+
+            return UnifiedToolOutput(
+                data: ServiceWindowListData(windows: [match], targetApplication: output.data.targetApplication ?? app),
+                summary: .init(
+                    brief: "Found '\(match.title)' in \(app.name) (PID: \(app.processIdentifier))",
+                    status: .success,
+                    counts: ["windows": 1]
+                ),
+                metadata: .init(duration: Date().timeIntervalSince(startedAt))
+            )
+        }
+        throw PeekabooError.windowNotFound(criteria: "title containing '\(query)' in any running app")
+    }
+}
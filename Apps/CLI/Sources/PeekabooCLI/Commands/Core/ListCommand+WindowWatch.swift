@@ -0,0 +1,151 @@
+import Commander
+import Foundation
+import PeekabooCore
+
+/// One change between two `list windows --watch` polls; JSON mode prints one per line.
+struct WindowListEvent: Encodable, Equatable {
+    enum Kind: String, Encodable {
+        case windowOpened = "window_opened"
+        case windowClosed = "window_closed"
+        case windowRetitled = "window_retitled"
+    }
+
+    let type: Kind
+    let window: ServiceWindowInfo?
+    let window_id: Int?
+    let new_title: String?
+
+    static func opened(_ window: ServiceWindowInfo) -> WindowListEvent {
+        WindowListEvent(type: .windowOpened, window: window, window_id: nil, new_title: nil)
+    }
+
+    static func closed(windowID: Int) -> WindowListEvent {
+        WindowListEvent(type: .windowClosed, window: nil, window_id: windowID, new_title: nil)
+    }
+
+    static func retitled(windowID: Int, newTitle: String) -> WindowListEvent {
+        WindowListEvent(type: .windowRetitled, window: nil, window_id: windowID, new_title: newTitle)
+    }
+}
+
+/// First JSON line of a watch, so consumers see the starting state before any events.
+struct WindowWatchSnapshot: Encodable {
+    let type = "window_list"
+    let windows: [ServiceWindowInfo]
+    let targetApplication: ServiceApplicationInfo?
+}
+
+extension ListCommand.WindowsSubcommand {
+    static let defaultWatchIntervalMs = 1000
+
+    func validateWatchOptions() throws {
+        if let watchIntervalMs {
+            guard self.watch else {
+                throw ValidationError("--watch-interval-ms requires --watch")
+            }
+            guard watchIntervalMs > 0 else {
+                throw ValidationError("--watch-interval-ms must be greater than zero")
+            }
+        }
+        guard self.watch else { return }
+        if self.exportCsv {
+            throw ValidationError("--watch streams events and cannot be combined with --export-csv")
+        }
+        if self.includeDetails != nil {
+            throw ValidationError("--watch reports full window records; remove --include-details")
+        }
+    }
+
+    /// Diffs two snapshots keyed by window ID: closes first, then opens and retitles in current z-order.
+    static func windowEvents(
+        from previous: [ServiceWindowInfo],
+        to current: [ServiceWindowInfo]
+    ) -> [WindowListEvent] {
+        let previousByID = Dictionary(previous.map { ($0.windowID, $0) }, uniquingKeysWith: { first, _ in first })
+        let currentIDs = Set(current.map(\.windowID))
+
+        var events = previous
+            .filter { !currentIDs.contains($0.windowID) }
+            .map { WindowListEvent.closed(windowID: $0.windowID) }
+        var seen: Set<Int> = []
+        for window in current where seen.insert(window.windowID).inserted {
+            guard let before = previousByID[window.windowID] else {
+                events.append(.opened(window))
+                continue
+            }
+            if before.title != window.title {
+                events.append(.retitled(windowID: window.windowID, newTitle: window.title))
+            }
+        }
+        return events
+    }
+
+    /// Human-readable event line: `+` opened, `-` closed, `~` retitled.
+    static func describe(_ event: WindowListEvent, previous: [ServiceWindowInfo]) -> String {
+        switch event.type {
+        case .windowOpened:
+            let window = event.window
+            return "+ \(window?.windowID ?? 0) \(Self.displayTitle(window?.title ?? ""))"
+        case .windowClosed:
+            let title = previous.first { $0.windowID == event.window_id }?.title ?? ""
+            return "- \(event.window_id ?? 0) \(Self.displayTitle(title))"
+        case .windowRetitled:
+            return "~ \(event.window_id ?? 0) \(Self.displayTitle(event.new_title ?? ""))"
+        }
+    }
+
+    /// Polls until SIGINT/SIGTERM, printing one line per change. An app that quits ends the watch with its error.
+    func watchWindows(
+        appIdentifier: String,
+        initial: ServiceWindowListData,
+        services: any PeekabooServiceProviding
+    ) async throws {
+        let jsonOutput = self.jsonOutput
+        let format = self.outputLogger.outputFormat
+        if jsonOutput {
+            format.writeRecord(WindowWatchSnapshot(
+                windows: initial.windows,
+                targetApplication: initial.targetApplication
+            ))
+        }
+
+        let interval = Duration.milliseconds(self.watchIntervalMs ?? Self.defaultWatchIntervalMs)
+        let poll = Task { @MainActor in
+            var previous = initial.windows
+            while !Task.isCancelled {
+                try await Task.sleep(for: interval)
+                let current = try await services.applications
+                    .listWindows(for: appIdentifier, timeout: nil).data.windows
+                for event in Self.windowEvents(from: previous, to: current) {
+                    if jsonOutput {
+                        format.writeRecord(event)
+                    } else {
+                        Self.emit(Self.describe(event, previous: previous))
+                    }
+                }
+                previous = current
+            }
+        }
+
+        let monitor = CaptureSignalMonitor()
+        monitor.start { _ in poll.cancel() }
+        defer { monitor.stop() }
+
+        do {
+            try await poll.value
+        } catch {
+            // Cancellation can surface from the sleep or mid-lookup; either way it means a signal ended the watch.
+            guard poll.isCancelled else { throw error }
+        }
+    }
+
+    private static func displayTitle(_ title: String) -> String {
+        title.isEmpty ? "[Untitled]" : title
+    }
+
+    /// Flushes per line so piped consumers see events as they happen.
+    private static func emit(_ line: String) {
+        print(line)
+        fflush(stdout)
+    }
+}
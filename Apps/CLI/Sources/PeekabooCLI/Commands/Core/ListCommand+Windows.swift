@@ -6,15 +6,55 @@ import PeekabooCore
 extension ListCommand {
     @MainActor
     struct WindowsSubcommand: ErrorHandlingCommand, OutputFormattable, ApplicationResolvable,
-    RuntimeOptionsConfigurable {
+    RuntimeOptionsConfigurable, ListPaginatable {
         @Option(name: .long, help: "Target application name, bundle ID, or 'PID:12345'")
         var app: String?
 
         @Option(name: .long, help: "Target application by process ID")
         var pid: Int32?
 
+        @Option(name: .long, help: "Target the process whose PID is on the first line of this file")
+        var pidFile: String?
+
+        @Option(name: .long, help: "Find the app owning the first window whose title contains this text")
+        var findAppByWindowTitle: String?
+
         @Option(name: .long, help: "Additional details (comma-separated: off_screen,bounds,ids)")
         var includeDetails: String?
+
+        @Flag(name: .long, help: "Match --app and --find-app-by-window-title case-sensitively")
+        var caseSensitive = false
+
+        @Flag(name: .long, help: "Print windows as RFC 4180 CSV instead of text or JSON")
+        var exportCsv = false
+
+        @Flag(name: .long, help: "Omit the CSV header row (requires --export-csv)")
+        var csvNoHeader = false
+
+        @Flag(name: .long, help: "Print the JSON Schema (draft-07) of the --json output instead of listing")
+        var jsonSchema = false
+
+        @Flag(name: .long, help: "Keep running and report windows opening, closing, or changing title until SIGINT")
+        var watch = false
+
+        @Option(name: .long, help: "Poll interval for --watch in milliseconds (default 1000)")
+        var watchIntervalMs: Int?
+
+        @Option(name: .long, help: "Return at most this many windows after sorting (default: all)")
+        var limit: Int?
+
+        @Option(name: .long, help: "Skip this many windows after sorting (default 0)")
+        var offset: Int?
+
+        @Option(name: .long, help: "Page number starting at 1; requires --page-size")
+        var page: Int?
+
+        @Option(name: .long, help: "Windows per page; sets --limit and computes --offset from --page")
+        var pageSize: Int?
+
+        @Flag(name: .long, help: "Save window bounds under ~/.peekaboo/cache/layouts for 'image --restore-layout'")
+        var cacheLayout = false
+
         @RuntimeStorage private var runtime: CommandRuntime?
         var runtimeOptions = CommandRuntimeOptions()
 
@@ -57,14 +97,70 @@ extension ListCommand {
             self.runtime = runtime
             self.logger.setJsonOutputMode(self.jsonOutput)
 
+            if self.jsonSchema {
+                print(JSONSchema.render(ListJSONSchemas.windows))
+                return
+            }
+
             do {
+                try ListCommand.validateCSVOptions(
+                    exportCsv: self.exportCsv,
+                    csvNoHeader: self.csvNoHeader,
+                    jsonOutput: self.jsonOutput
+                )
+                try self.validateWatchOptions()
+                let pagination = try self.resolvePagination()
+                if pagination.isPaginating, self.watch {
+                    throw ValidationError("--watch cannot be combined with pagination; it always tracks every window")
+                }
+                try self.validateWindowTitleSearchOptions()
+                try PIDFile.validateExclusive(pidFile: self.pidFile, app: self.app, pid: self.pid)
+                if let pidFile {
+                    self.pid = try PIDFile.runningPID(at: pidFile)
+                }
                 try await requireScreenRecordingPermission(services: self.services)
-                let appIdentifier = try self.resolveApplicationIdentifier()
-                let output = try await self.services.applications.listWindows(for: appIdentifier, timeout: nil)
 
-                if self.jsonOutput {
+                let listed: UnifiedToolOutput<ServiceWindowListData>
+                let appIdentifier: String
+                if let findAppByWindowTitle {
+                    listed = try await self.findApplication(
+                        byWindowTitle: findAppByWindowTitle,
+                        services: self.services
+                    )
+                    appIdentifier = listed.data.targetApplication.map { "PID:\($0.processIdentifier)" } ?? ""
+                } else {
+                    appIdentifier = try await self.resolveApplicationIdentifier(
+                        caseSensitive: self.caseSensitive,
+                        services: self.services
+                    )
+                    listed = try await self.services.applications.listWindows(for: appIdentifier, timeout: nil)
+                }
+                let totalCount = listed.data.windows.count
+                if self.cacheLayout {
+                    try await self.cacheWindowLayout(listed.data, appIdentifier: appIdentifier, services: self.services)
+                }
+                let output = Self.paginated(listed, by: pagination)
+
+                if self.watch {
+                    if !self.jsonOutput {
+                        print(CLIFormatter.format(output))
+                    }
+                    try await self.watchWindows(
+                        appIdentifier: appIdentifier,
+                        initial: output.data,
+                        services: self.services
+                    )
+                } else if self.exportCsv {
+                    let header = self.csvNoHeader ? nil : Self.csvHeader
+                    print(CSVWriter.document(header: header, rows: Self.csvRows(for: output.data)), terminator: "")
+                } else if self.jsonOutput {
                     let detailOptions = self.parseIncludeDetails()
-                    self.renderJSON(from: output, detailOptions: detailOptions)
+                    self.renderJSON(
+                        from: output,
+                        detailOptions: detailOptions,
+                        totalCount: totalCount,
+                        offset: pagination.offset
+                    )
                 } else {
                     print(CLIFormatter.format(output))
                 }
@@ -104,10 +200,19 @@ extension ListCommand {
         @MainActor
         private func renderJSON(
             from output: UnifiedToolOutput<ServiceWindowListData>,
-            detailOptions: Set<WindowDetailOption>
+            detailOptions: Set<WindowDetailOption>,
+            totalCount: Int,
+            offset: Int
         ) {
             guard !detailOptions.isEmpty else {
-                outputSuccessCodable(data: output.data, logger: self.outputLogger)
+                let page = WindowListPage(
+                    windows: output.data.windows,
+                    targetApplication: output.data.targetApplication,
+                    total_count: totalCount,
+                    returned_count: output.data.windows.count,
+                    offset: offset
+                )
+                outputSuccessCodable(data: page, logger: self.outputLogger)
                 return
             }
 
@@ -126,6 +231,9 @@ extension ListCommand {
 
                 let windows: [Window]
                 let targetApplication: ServiceApplicationInfo?
+                let total_count: Int
+                let returned_count: Int
+                let offset: Int
             }
 
             let windows = output.data.windows.map { window in
@@ -144,7 +252,10 @@ extension ListCommand {
 
             let filteredOutput = FilteredWindowListData(
                 windows: windows,
-                targetApplication: output.data.targetApplication
+                targetApplication: output.data.targetApplication,
+                total_count: totalCount,
+                returned_count: windows.count,
+                offset: offset
             )
 
             outputSuccessCodable(data: filteredOutput, logger: self.outputLogger)
@@ -162,6 +273,12 @@ extension ListCommand.WindowsSubcommand: ParsableCommand {
                 discussion: """
                 Lists all windows for the specified application using PeekabooServices.
                 Windows are listed in z-order (frontmost first) with optional details.
+                --find-app-by-window-title lists the first window, in any app, whose title contains the text.
+                --export-csv prints one CSV row per window for spreadsheets and dataframes.
+                --watch keeps polling and prints +/-/~ lines (JSON Lines with --json) as windows change.
+                --limit/--offset (or --page/--page-size) return one page of the z-ordered list;
+                JSON adds total_count, returned_count, and offset.
+                --cache-layout saves every window's bounds for 'peekaboo image --restore-layout'.
                 """
             )
         }
@@ -175,11 +292,26 @@ extension ListCommand.WindowsSubcommand: CommanderBindableCommand {
     mutating func applyCommanderValues(_ values: CommanderBindableValues) throws {
         let resolvedApp = values.singleOption("app")
         let resolvedPID = try values.decodeOption("pid", as: Int32.self)
-        guard resolvedApp != nil || resolvedPID != nil else {
+        self.pidFile = values.singleOption("pidFile")
+        self.jsonSchema = values.flag("jsonSchema")
+        self.findAppByWindowTitle = values.singleOption("findAppByWindowTitle")
+        guard resolvedApp != nil || resolvedPID != nil || self.pidFile != nil || self.findAppByWindowTitle != nil ||
+            self.jsonSchema
+        else {
             throw CommanderBindingError.missingArgument(label: "app")
         }
         self.app = resolvedApp
         self.pid = resolvedPID
         self.includeDetails = values.singleOption("includeDetails")
+        self.caseSensitive = values.flag("caseSensitive")
+        self.exportCsv = values.flag("exportCsv")
+        self.csvNoHeader = values.flag("csvNoHeader")
+        self.watch = values.flag("watch")
+        self.watchIntervalMs = try values.decodeOption("watchIntervalMs", as: Int.self)
+        self.limit = try values.decodeOption("limit", as: Int.self)
+        self.offset = try values.decodeOption("offset", as: Int.self)
+        self.page = try values.decodeOption("page", as: Int.self)
+        self.pageSize = try values.decodeOption("pageSize", as: Int.self)
+        self.cacheLayout = values.flag("cacheLayout")
     }
 }
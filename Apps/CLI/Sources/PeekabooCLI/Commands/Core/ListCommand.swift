@@ -28,6 +28,9 @@ struct ListCommand: ParsableCommand {
 
           peekaboo list screens                          # List all displays
           peekaboo list screens --json                   # Output as JSON
+          peekaboo list screens --watch                  # Report displays as they connect/disconnect
+
+          peekaboo list profiles                         # List saved image profiles
 
         SUBCOMMANDS:
           apps          List all running applications with process IDs
@@ -35,6 +38,7 @@ struct ListCommand: ParsableCommand {
           permissions   Check permissions required for Peekaboo
           menubar       List all menu bar items (status icons)
           screens       List all available displays/monitors
+          profiles      List saved `peekaboo image --profile` names
         """,
         subcommands: [
             AppsSubcommand.self,
@@ -42,6 +46,7 @@ struct ListCommand: ParsableCommand {
             PermissionsSubcommand.self,
             MenuBarSubcommand.self,
             ScreensSubcommand.self,
+            ProfilesSubcommand.self,
         ],
         defaultSubcommand: AppsSubcommand.self
     )
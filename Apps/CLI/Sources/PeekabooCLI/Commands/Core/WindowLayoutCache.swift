@@ -0,0 +1,103 @@
+import CoreGraphics
+import Foundation
+import PeekabooCore
+import PeekabooFoundation
+
+/// Window bounds saved by `list windows --cache-layout`, replayed by `image --restore-layout`.
+struct WindowLayout: Codable, Equatable {
+    struct Window: Codable, Equatable {
+        let window_id: Int
+        let title: String
+        let index: Int
+        let bounds: WindowBounds
+    }
+
+    let pid: Int32
+    let app_name: String?
+    let saved_at: Date
+    let windows: [Window]
+
+    init(pid: Int32, appName: String?, windows: [ServiceWindowInfo], savedAt: Date = Date()) {
+        self.pid = pid
+        self.app_name = appName
+        self.saved_at = savedAt
+        self.windows = windows.map { window in
+            Window(
+                window_id: window.windowID,
+                title: window.title,
+                index: window.index,
+                bounds: WindowBounds(
+                    x: Int(window.bounds.minX.rounded()),
+                    y: Int(window.bounds.minY.rounded()),
+                    width: Int(window.bounds.width.rounded()),
+                    height: Int(window.bounds.height.rounded())
+                )
+            )
+        }
+    }
+}
+
+/// One file per process under `~/.peekaboo/cache/layouts`, named by PID, so a relaunched app starts fresh.
+enum WindowLayoutCache {
+    static var defaultDirectory: URL {
+        FileManager.default.homeDirectoryForCurrentUser
+            .appendingPathComponent(".peekaboo")
+            .appendingPathComponent("cache")
+            .appendingPathComponent("layouts")
+    }
+
+    static func url(for pid: Int32, in directory: URL = Self.defaultDirectory) -> URL {
+        directory.appendingPathComponent("\(pid).json")
+    }
+
+    @discardableResult
+    static func save(_ layout: WindowLayout, in directory: URL = Self.defaultDirectory) throws -> URL {
+        let url = Self.url(for: layout.pid, in: directory)
+        let encoder = JSONEncoder()
+        encoder.outputFormatting = [.prettyPrinted, .sortedKeys]
+        encoder.dateEncodingStrategy = .iso8601
+        do {
+            try FileManager.default.createDirectory(at: directory, withIntermediateDirectories: true)
+            try encoder.encode(layout).write(to: url, options: .atomic)
+        } catch {
+            throw PeekabooError.fileIOError(
+                "Could not save window layout to \(url.path): \(error.localizedDescription)"
+            )
+        }
+        return url
+    }
+
+    static func load(pid: Int32, in directory: URL = Self.defaultDirectory) throws -> WindowLayout {
+        let url = Self.url(for: pid, in: directory)
+        guard let data = FileManager.default.contents(atPath: url.path) else {
+            throw PeekabooError.fileIOError(
+                "No cached window layout for PID \(pid) at \(url.path); " +
+                    "run 'peekaboo list windows --cache-layout' first"
+            )
+        }
+        let decoder = JSONDecoder()
+        decoder.dateDecodingStrategy = .iso8601
+        do {
+            return try decoder.decode(WindowLayout.self, from: data)
+        } catch {
+            throw PeekabooError.fileIOError(
+                "Cached window layout at \(url.path) is unreadable: \(error.localizedDescription)"
+            )
+        }
+    }
+
+    /// Pairs each cached window with a live one of the same window ID, falling back to an exact title match for
+    /// windows the app recreated. Each live window is used at most once; cached windows without a match are skipped.
+    static func restorations(
+        for layout: WindowLayout,
+        in windows: [ServiceWindowInfo]
+    ) -> [(window: ServiceWindowInfo, bounds: CGRect)] {
+        var unused = windows
+        return layout.windows.compactMap { cached in
+            let position = unused.firstIndex { $0.windowID == cached.window_id }
+                ?? unused.firstIndex { !cached.title.isEmpty && $0.title == cached.title }
+            guard let position else { return nil }
+            return (unused.remove(at: position), cached.bounds.cgRect)
+        }
+    }
+}
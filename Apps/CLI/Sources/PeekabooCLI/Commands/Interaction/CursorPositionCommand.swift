@@ -0,0 +1,70 @@
+import Commander
+import Foundation
+import PeekabooCore
+import PeekabooFoundation
+
+/// Reports where the mouse pointer is, in the same global coordinates `image --region` and `move` use.
+@available(macOS 14.0, *)
+@MainActor
+struct CursorPositionCommand: ErrorHandlingCommand, OutputFormattable, RuntimeOptionsConfigurable {
+    nonisolated(unsafe) static var commandDescription: CommandDescription {
+        MainActorCommandDescription.describe {
+            CommandDescription(
+                commandName: "cursor-position",
+                abstract: "Print the current mouse pointer location in global display points"
+            )
+        }
+    }
+
+    @RuntimeStorage private var runtime: CommandRuntime?
+    var runtimeOptions = CommandRuntimeOptions()
+
+    private var resolvedRuntime: CommandRuntime {
+        guard let runtime else {
+            preconditionFailure("CommandRuntime must be configured before accessing runtime resources")
+        }
+        return runtime
+    }
+
+    private var services: any PeekabooServiceProviding {
+        self.resolvedRuntime.services
+    }
+
+    private var logger: Logger {
+        self.resolvedRuntime.logger
+    }
+
+    var outputLogger: Logger {
+        self.logger
+    }
+
+    var jsonOutput: Bool {
+        self.resolvedRuntime.configuration.jsonOutput
+    }
+
+    mutating func run(using runtime: CommandRuntime) async throws {
+        self.runtime = runtime
+        self.logger.setJsonOutputMode(self.jsonOutput)
+
+        do {
+            let position = try Self.cursorPosition(from: self.services.automation)
+            output(position) {
+                print("🖱️  \(position.x),\(position.y)")
+            }
+        } catch {
+            self.handleError(error)
+            throw ExitCode.failure
+        }
+    }
+
+    static func cursorPosition(from automation: any UIAutomationServiceProtocol) throws -> CursorPosition {
+        guard let location = automation.currentMouseLocation() else {
+            throw PeekabooError.operationError(message: "The current mouse pointer location is unavailable")
+        }
+        return CursorPosition(location)
+    }
+}
+
+@MainActor
+extension CursorPositionCommand: ParsableCommand {}
+extension CursorPositionCommand: AsyncRuntimeCommand {}
@@ -171,7 +171,7 @@ struct HotkeyCommand: ErrorHandlingCommand, OutputFormattable {
         }
     }
 
-    private static func parseKeyNames(_ keysString: String) -> [String] {
+    static func parseKeyNames(_ keysString: String) -> [String] {
         keysString
             .components(separatedBy: CharacterSet(charactersIn: ",+").union(.whitespacesAndNewlines))
             .map { $0.trimmingCharacters(in: .whitespacesAndNewlines).lowercased() }
@@ -22,6 +22,16 @@ extension AppCommand.LaunchSubcommand: CommanderSignatureProviding {
                     long: "open",
                     parsing: .upToNextOption
                 ),
+                .commandOption(
+                    "launchTimeoutMs",
+                    help: "Milliseconds to wait for the app to finish launching (default 10000); implies waiting",
+                    long: "launch-timeout-ms"
+                ),
+                .commandOption(
+                    "launchArgs",
+                    help: "Whitespace-separated arguments passed to the launched application",
+                    long: "launch-args"
+                ),
             ],
             flags: [
                 .commandFlag(
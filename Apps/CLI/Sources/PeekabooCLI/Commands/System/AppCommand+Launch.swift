@@ -25,6 +25,8 @@ extension AppCommand {
               --bundle-id <id>       Launch by bundle identifier instead of name/path
               --open <path-or-url>   Repeatable; pass documents/URLs to the app right after launch
               --wait-until-ready     Poll until the app reports it is fully launched
+              --launch-timeout-ms N  How long --wait-until-ready polls (default 10000); implies waiting
+              --launch-args "<args>" Whitespace-separated arguments passed to the app's process
               --no-focus             Skip bringing the app to the foreground
 
             EXAMPLES:
@@ -32,6 +34,7 @@ extension AppCommand {
               peekaboo app launch "Safari" --open https://example.com --open https://news.ycombinator.com
               peekaboo app launch "Preview" --open ~/Desktop/report.pdf --no-focus
               peekaboo app launch --bundle-id com.apple.Notes --wait-until-ready
+              peekaboo app launch "Google Chrome" --launch-args "--incognito" --launch-timeout-ms 30000
             """
         )
 
@@ -44,6 +47,12 @@ extension AppCommand {
         @Flag(help: "Wait for the application to be ready")
         var waitUntilReady = false
 
+        @Option(help: "Milliseconds to wait for the app to finish launching (default 10000); implies waiting")
+        var launchTimeoutMs: Int?
+
+        @Option(help: "Whitespace-separated arguments passed to the launched application")
+        var launchArgs: String?
+
         @Flag(help: "Do not bring the app to the foreground after launching")
         var noFocus = false
 
@@ -55,6 +64,10 @@ extension AppCommand {
         var openTargets: [String] = []
         @RuntimeStorage private var runtime: CommandRuntime?
 
+        static let defaultLaunchTimeoutMs = 10000
+        /// How often the wait loop reports that it is still waiting.
+        static let progressIntervalMs = 500
+
         private var resolvedRuntime: CommandRuntime {
             guard let runtime else {
                 preconditionFailure("CommandRuntime must be configured before accessing runtime resources")
@@ -82,6 +95,17 @@ extension AppCommand {
             !self.noFocus
         }
 
+        var shouldWaitUntilReady: Bool {
+            self.waitUntilReady || self.launchTimeoutMs != nil
+        }
+
+        /// `--launch-args` split like a shell command line, so quoted arguments may contain spaces.
+        var launchArguments: [String] {
+            get throws {
+                try self.launchArgs.map { try PostCaptureHook.splitArguments($0, option: "--launch-args") } ?? []
+            }
+        }
+
         /// Resolve the requested app target, launch it, optionally wait until ready, and emit output.
         @MainActor
         mutating func run(using runtime: CommandRuntime) async throws {
@@ -96,7 +120,7 @@ extension AppCommand {
                 self.renderLaunchSuccess(app: launchedApp)
             } catch {
                 self.handleError(error)
-                throw ExitCode(1)
+                throw self.exitCode(for: error)
             }
         }
 
@@ -110,6 +134,10 @@ extension AppCommand {
             guard self.app?.isEmpty == false || self.bundleId?.isEmpty == false else {
                 throw PeekabooError.invalidInput("Provide an application name/path or --bundle-id")
             }
+            if let launchTimeoutMs, launchTimeoutMs <= 0 {
+                throw ValidationError("--launch-timeout-ms must be greater than zero")
+            }
+            _ = try self.launchArguments
         }
 
         private func resolveApplicationURL() throws -> URL {
@@ -125,8 +153,11 @@ extension AppCommand {
         }
 
         private func waitIfNeeded(for app: any RunningApplicationHandle) async throws {
-            guard self.waitUntilReady else { return }
-            try await self.waitForApplicationReady(app)
+            guard self.shouldWaitUntilReady else { return }
+            try await self.waitForApplicationReady(
+                app,
+                timeoutMs: self.launchTimeoutMs ?? Self.defaultLaunchTimeoutMs
+            )
         }
 
         private func activateIfNeeded(_ app: any RunningApplicationHandle) {
@@ -173,31 +204,66 @@ extension AppCommand {
         }
 
         private func launchApplication(at url: URL, name: String) async throws -> any RunningApplicationHandle {
+            let arguments = try self.launchArguments
             if self.openTargets.isEmpty {
-                return try await Self.launcher.launchApplication(at: url, activates: self.shouldFocusAfterLaunch)
+                return try await Self.launcher.launchApplication(
+                    at: url,
+                    arguments: arguments,
+                    activates: self.shouldFocusAfterLaunch
+                )
             } else {
                 let urls = try self.openTargets.map { try Self.resolveOpenTarget($0) }
                 return try await Self.launcher.launchApplication(
                     url,
                     opening: urls,
+                    arguments: arguments,
                     activates: self.shouldFocusAfterLaunch
                 )
             }
         }
 
-        private func waitForApplicationReady(
-            _ app: any RunningApplicationHandle,
-            timeout: TimeInterval = 10
-        ) async throws {
+        /// Polls `isFinishedLaunching`, reporting progress every `progressIntervalMs`.
+        ///
+        /// JSON mode writes one `LaunchProgress` record per interval: to stdout ahead of the result under
+        /// `--output-format ndjson` (see `streamsProgressToStdout`), to stderr otherwise. Human mode prints a dot
+        /// on stderr.
+        private func waitForApplicationReady(_ app: any RunningApplicationHandle, timeoutMs: Int) async throws {
             let startTime = Date()
+            var reportedIntervals = 0
+            defer {
+                if reportedIntervals > 0, !self.jsonOutput {
+                    FileHandle.standardError.write(Data("\n".utf8))
+                }
+            }
             while !app.isFinishedLaunching {
-                if Date().timeIntervalSince(startTime) > timeout {
-                    throw PeekabooError.timeout("Application did not become ready within \(Int(timeout)) seconds")
+                let elapsedMs = Int(Date().timeIntervalSince(startTime) * 1000)
+                if elapsedMs > timeoutMs {
+                    throw PeekabooError.timedOut(
+                        operation: "Waiting for the application to finish launching",
+                        timeoutMs: timeoutMs
+                    )
+                }
+                let intervals = elapsedMs / Self.progressIntervalMs
+                if intervals > reportedIntervals {
+                    reportedIntervals = intervals
+                    self.reportProgress(elapsedMs: intervals * Self.progressIntervalMs)
                 }
                 try await Task.sleep(nanoseconds: 100_000_000) // 0.1 second
             }
         }
 
+        private func reportProgress(elapsedMs: Int) {
+            guard self.jsonOutput else {
+                FileHandle.standardError.write(Data(".".utf8))
+                return
+            }
+            let format = self.outputLogger.outputFormat
+            format.writeRecord(
+                LaunchProgress(elapsed_ms: elapsedMs),
+                to: format.streamsProgressToStdout ? .standardOutput : .standardError
+            )
+        }
+
         static func resolveOpenTarget(
             _ value: String,
             cwd: String = FileManager.default.currentDirectoryPath
@@ -234,5 +300,14 @@ extension AppCommand.LaunchSubcommand: CommanderBindableCommand {
         self.waitUntilReady = values.flag("waitUntilReady")
         self.noFocus = values.flag("noFocus")
         self.openTargets = values.optionValues("open")
+        self.launchTimeoutMs = try values.decodeOption("launchTimeoutMs", as: Int.self)
+        self.launchArgs = values.singleOption("launchArgs")
     }
 }
+
+/// Progress record written while `app launch` waits for the app to finish launching.
+struct LaunchProgress: Encodable, Equatable {
+    let type = "progress"
+    let elapsed_ms: Int
+    let status = "waiting_for_window"
+}
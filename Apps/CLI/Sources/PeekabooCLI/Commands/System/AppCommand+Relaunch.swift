@@ -97,7 +97,7 @@ extension AppCommand {
 
                 // Step 3: Launch the app
                 let appURL = try self.resolveLaunchURL(for: appInfo)
-                let launchedApp = try await Self.launcher.launchApplication(at: appURL, activates: true)
+                let launchedApp = try await Self.launcher.launchApplication(at: appURL, arguments: [], activates: true)
 
                 // Wait until ready if requested
                 if self.waitUntilReady {
@@ -136,7 +136,7 @@ extension AppCommand {
 
             } catch {
                 handleError(error)
-                throw ExitCode(1)
+                throw self.exitCode(for: error)
             }
         }
 
@@ -149,7 +149,7 @@ extension AppCommand {
             }
 
             if await self.services.applications.isApplicationRunning(identifier: identifier) {
-                throw PeekabooError.timeout("App \(appName) did not terminate within 5 seconds")
+                throw PeekabooError.timedOut(operation: "Waiting for \(appName) to terminate", timeoutMs: 5000)
             }
         }
 
@@ -23,8 +23,9 @@ extension NSRunningApplication: RunningApplicationHandle {}
 
 @MainActor
 protocol ApplicationLaunching {
-    func launchApplication(at url: URL, activates: Bool) async throws -> any RunningApplicationHandle
-    func launchApplication(_ url: URL, opening documents: [URL], activates: Bool) async throws
+    func launchApplication(at url: URL, arguments: [String], activates: Bool) async throws
+        -> any RunningApplicationHandle
+    func launchApplication(_ url: URL, opening documents: [URL], arguments: [String], activates: Bool) async throws
         -> any RunningApplicationHandle
     func openTarget(_ targetURL: URL, handlerURL: URL?, activates: Bool) async throws -> any RunningApplicationHandle
 }
@@ -36,25 +37,37 @@ enum ApplicationLaunchEnvironment {
 
 @MainActor
 final class NSWorkspaceApplicationLauncher: ApplicationLaunching {
-    func launchApplication(at url: URL, activates: Bool) async throws -> any RunningApplicationHandle {
+    func launchApplication(
+        at url: URL,
+        arguments: [String],
+        activates: Bool
+    ) async throws -> any RunningApplicationHandle {
         let configuration = NSWorkspace.OpenConfiguration()
         configuration.activates = activates
+        configuration.arguments = arguments
         return try await NSWorkspace.shared.openApplication(at: url, configuration: configuration)
     }
 
     func launchApplication(
         _ url: URL,
         opening documents: [URL],
+        arguments: [String],
         activates: Bool
     ) async throws -> any RunningApplicationHandle {
         let configuration = NSWorkspace.OpenConfiguration()
         configuration.activates = activates
+        configuration.arguments = arguments
         return try await NSWorkspace.shared.open(documents, withApplicationAt: url, configuration: configuration)
     }
 
     func openTarget(_ targetURL: URL, handlerURL: URL?, activates: Bool) async throws -> any RunningApplicationHandle {
         if let handlerURL {
-            return try await self.launchApplication(handlerURL, opening: [targetURL], activates: activates)
+            return try await self.launchApplication(
+                handlerURL,
+                opening: [targetURL],
+                arguments: [],
+                activates: activates
+            )
         } else {
             let configuration = NSWorkspace.OpenConfiguration()
             configuration.activates = activates
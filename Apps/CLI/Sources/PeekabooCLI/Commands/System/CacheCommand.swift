@@ -0,0 +1,238 @@
+import Commander
+import Foundation
+import PeekabooCore
+
+/// Inspect and reset the image hash cache that `peekaboo image --dedup` consults.
+@MainActor
+struct CacheCommand: ParsableCommand {
+    static let commandDescription = CommandDescription(
+        commandName: "cache",
+        abstract: "Manage the image --dedup hash cache",
+        discussion: """
+        `peekaboo image --dedup` records a dHash for every capture it keeps in
+        ~/.peekaboo/cache/image-hashes.json and reuses a file when a later capture matches.
+
+        Examples:
+          peekaboo cache stats
+          peekaboo cache list --json
+          peekaboo cache clear
+        """,
+        subcommands: [List.self, Stats.self, Clear.self],
+        defaultSubcommand: Stats.self,
+        showHelpOnEmptyInvocation: false
+    )
+}
+
+struct ImageHashCacheEntryInfo: Codable {
+    let path: String
+    let hash: String
+    let recorded_at: String
+    let exists: Bool
+}
+
+struct ImageHashCacheStats: Codable {
+    let cache_path: String
+    let entries: Int
+    let missing_files: Int
+    let size_bytes: Int64
+    let oldest: String?
+    let newest: String?
+
+    init(cache: ImageHashCache, url: URL, fileExists: (String) -> Bool) {
+        let attributes = try? FileManager.default.attributesOfItem(atPath: url.path)
+        let recorded = cache.entries.map(\.recorded_at).sorted()
+        self.cache_path = url.path
+        self.entries = cache.entries.count
+        self.missing_files = cache.entries.count(where: { !fileExists($0.path) })
+        self.size_bytes = (attributes?[.size] as? NSNumber)?.int64Value ?? 0
+        self.oldest = recorded.first
+        self.newest = recorded.last
+    }
+}
+
+struct ImageHashCacheClearResult: Codable {
+    let cache_path: String
+    let entries_removed: Int
+}
+
+extension CacheCommand {
+    @MainActor
+    struct List: ErrorHandlingCommand, OutputFormattable, RuntimeOptionsConfigurable {
+        nonisolated(unsafe) static var commandDescription: CommandDescription {
+            MainActorCommandDescription.describe {
+                CommandDescription(
+                    commandName: "list",
+                    abstract: "List cached capture hashes"
+                )
+            }
+        }
+
+        @RuntimeStorage private var runtime: CommandRuntime?
+        var runtimeOptions = CommandRuntimeOptions()
+
+        var outputLogger: Logger {
+            self.runtime?.logger ?? Logger.shared
+        }
+
+        var jsonOutput: Bool {
+            self.runtime?.configuration.jsonOutput ?? self.runtimeOptions.jsonOutput
+        }
+
+        mutating func run(using runtime: CommandRuntime) async throws {
+            self.runtime = runtime
+            do {
+                let entries = try ImageHashCache.load().entries.map { entry in
+                    ImageHashCacheEntryInfo(
+                        path: entry.path,
+                        hash: entry.hash.description,
+                        recorded_at: entry.recorded_at,
+                        exists: FileManager.default.fileExists(atPath: entry.path)
+                    )
+                }
+                self.output(entries) {
+                    if entries.isEmpty {
+                        print("No cached capture hashes")
+                    }
+                    for entry in entries {
+                        let missing = entry.exists ? "" : " (missing)"
+                        print("\(entry.hash)  \(entry.recorded_at)  \(entry.path)\(missing)")
+                    }
+                }
+            } catch {
+                self.handleError(error)
+                throw ExitCode(1)
+            }
+        }
+    }
+
+    @MainActor
+    struct Stats: ErrorHandlingCommand, OutputFormattable, RuntimeOptionsConfigurable {
+        nonisolated(unsafe) static var commandDescription: CommandDescription {
+            MainActorCommandDescription.describe {
+                CommandDescription(
+                    commandName: "stats",
+                    abstract: "Summarize the capture hash cache"
+                )
+            }
+        }
+
+        @RuntimeStorage private var runtime: CommandRuntime?
+        var runtimeOptions = CommandRuntimeOptions()
+
+        var outputLogger: Logger {
+            self.runtime?.logger ?? Logger.shared
+        }
+
+        var jsonOutput: Bool {
+            self.runtime?.configuration.jsonOutput ?? self.runtimeOptions.jsonOutput
+        }
+
+        mutating func run(using runtime: CommandRuntime) async throws {
+            self.runtime = runtime
+            do {
+                let url = ImageHashCache.defaultURL
+                let stats = try ImageHashCacheStats(
+                    cache: ImageHashCache.load(from: url),
+                    url: url,
+                    fileExists: { FileManager.default.fileExists(atPath: $0) }
+                )
+                self.output(stats) {
+                    print("Cache: \(stats.cache_path)")
+                    print("Entries: \(stats.entries) (\(stats.missing_files) with missing files)")
+                    print("Size: \(ByteCountFormatter.string(fromByteCount: stats.size_bytes, countStyle: .file))")
+                    if let oldest = stats.oldest, let newest = stats.newest {
+                        print("Recorded: \(oldest) … \(newest)")
+                    }
+                }
+            } catch {
+                self.handleError(error)
+                throw ExitCode(1)
+            }
+        }
+    }
+
+    @MainActor
+    struct Clear: ErrorHandlingCommand, OutputFormattable, RuntimeOptionsConfigurable {
+        nonisolated(unsafe) static var commandDescription: CommandDescription {
+            MainActorCommandDescription.describe {
+                CommandDescription(
+                    commandName: "clear",
+                    abstract: "Forget every cached capture hash (captured files are kept)"
+                )
+            }
+        }
+
+        @RuntimeStorage private var runtime: CommandRuntime?
+        var runtimeOptions = CommandRuntimeOptions()
+
+        var outputLogger: Logger {
+            self.runtime?.logger ?? Logger.shared
+        }
+
+        var jsonOutput: Bool {
+            self.runtime?.configuration.jsonOutput ?? self.runtimeOptions.jsonOutput
+        }
+
+        mutating func run(using runtime: CommandRuntime) async throws {
+            self.runtime = runtime
+            do {
+                let url = ImageHashCache.defaultURL
+                // A corrupt cache still gets cleared; that is the recovery path `load` points to.
+                let removed = (try? ImageHashCache.load(from: url))?.entries.count ?? 0
+                if FileManager.default.fileExists(atPath: url.path) {
+                    try FileManager.default.removeItem(at: url)
+                }
+                let result = ImageHashCacheClearResult(cache_path: url.path, entries_removed: removed)
+                self.output(result) {
+                    print("Removed \(removed) cached capture hash\(removed == 1 ? "" : "es")")
+                }
+            } catch {
+                self.handleError(error)
+                throw ExitCode(1)
+            }
+        }
+    }
+}
+
+extension CacheCommand.List: AsyncRuntimeCommand {}
+extension CacheCommand.Stats: AsyncRuntimeCommand {}
+extension CacheCommand.Clear: AsyncRuntimeCommand {}
+
+@MainActor
+extension CacheCommand.List: CommanderBindableCommand {
+    mutating func applyCommanderValues(_ values: CommanderBindableValues) throws {
+        _ = values
+    }
+}
+
+@MainActor
+extension CacheCommand.Stats: CommanderBindableCommand {
+    mutating func applyCommanderValues(_ values: CommanderBindableValues) throws {
+        _ = values
+    }
+}
+
+@MainActor
+extension CacheCommand.Clear: CommanderBindableCommand {
+    mutating func applyCommanderValues(_ values: CommanderBindableValues) throws {
+        _ = values
+    }
+}
+
+extension CacheCommand.List: CommanderSignatureProviding {
+    static func commanderSignature() -> CommandSignature {
+        CommandSignature()
+    }
+}
+
+extension CacheCommand.Stats: CommanderSignatureProviding {
+    static func commanderSignature() -> CommandSignature {
+        CommandSignature()
+    }
+}
+
+extension CacheCommand.Clear: CommanderSignatureProviding {
+    static func commanderSignature() -> CommandSignature {
+        CommandSignature()
+    }
+}
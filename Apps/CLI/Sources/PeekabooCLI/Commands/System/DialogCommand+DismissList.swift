@@ -6,7 +6,7 @@ extension DialogCommand {
     // MARK: - Dismiss Dialog
 
     @MainActor
-    struct DismissSubcommand {
+    struct DismissSubcommand: ErrorHandlingCommand, OutputFormattable {
         static let commandDescription = CommandDescription(
             commandName: "dismiss",
             abstract: "Dismiss a dialog using DialogService"
@@ -90,8 +90,8 @@ extension DialogCommand {
                 handleDialogServiceError(error, jsonOutput: self.jsonOutput, logger: self.outputLogger)
                 throw ExitCode(1)
             } catch {
-                handleGenericError(error, jsonOutput: self.jsonOutput, logger: self.outputLogger)
-                throw ExitCode(1)
+                self.handleError(error)
+                throw self.exitCode(for: error)
             }
         }
     }
@@ -99,7 +99,7 @@ extension DialogCommand {
     // MARK: - List Dialog Elements
 
     @MainActor
-    struct ListSubcommand {
+    struct ListSubcommand: ErrorHandlingCommand, OutputFormattable {
         static let commandDescription = CommandDescription(
             commandName: "list",
             abstract: "List elements in current dialog using DialogService"
@@ -211,8 +211,8 @@ extension DialogCommand {
                 handleDialogServiceError(error, jsonOutput: self.jsonOutput, logger: self.outputLogger)
                 throw ExitCode(1)
             } catch {
-                handleGenericError(error, jsonOutput: self.jsonOutput, logger: self.outputLogger)
-                throw ExitCode(1)
+                self.handleError(error)
+                throw self.exitCode(for: error)
             }
         }
     }
@@ -7,7 +7,7 @@ extension DialogCommand {
     // MARK: - Handle File Dialog
 
     @MainActor
-    struct FileSubcommand {
+    struct FileSubcommand: ErrorHandlingCommand, OutputFormattable {
         static let commandDescription = CommandDescription(
             commandName: "file",
             abstract: "Handle file save/open dialogs using DialogService"
@@ -115,24 +115,9 @@ extension DialogCommand {
             } catch let error as DialogError {
                 handleDialogServiceError(error, jsonOutput: self.jsonOutput, logger: self.outputLogger)
                 throw ExitCode(1)
-            } catch let error as PeekabooError {
-                let code: ErrorCode = switch error {
-                case .timeout:
-                    .TIMEOUT
-                case .invalidInput:
-                    .INVALID_INPUT
-                default:
-                    .UNKNOWN_ERROR
-                }
-                if self.jsonOutput {
-                    outputError(message: error.localizedDescription, code: code, logger: self.outputLogger)
-                } else {
-                    fputs("❌ \(error.localizedDescription)\n", stderr)
-                }
-                throw ExitCode(1)
             } catch {
-                handleGenericError(error, jsonOutput: self.jsonOutput, logger: self.outputLogger)
-                throw ExitCode(1)
+                self.handleError(error)
+                throw self.exitCode(for: error)
             }
         }
 
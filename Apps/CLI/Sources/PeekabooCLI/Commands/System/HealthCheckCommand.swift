@@ -0,0 +1,160 @@
+import Commander
+import Foundation
+import PeekabooCore
+import PeekabooFoundation
+
+/// Exercises each automation service once so deployment probes can tell a working host from a broken one.
+@available(macOS 14.0, *)
+@MainActor
+struct HealthCheckCommand: OutputFormattable, RuntimeOptionsConfigurable {
+    nonisolated(unsafe) static var commandDescription: CommandDescription {
+        MainActorCommandDescription.describe {
+            CommandDescription(
+                commandName: "health-check",
+                abstract: "Check that screen, application, window, and permission services respond; exits 1 if not",
+                discussion: """
+                    Runs one cheap call against each service and reports whether it succeeded and how long it took:
+                      screens       at least one display is reported
+                      applications  the frontmost app resolves by PID
+                      windows       the frontmost app's windows can be listed
+                      permissions   Screen Recording and Accessibility are granted
+
+                    EXAMPLES:
+                      peekaboo health-check
+                      peekaboo health-check --json | jq '.data.checks[] | select(.healthy | not)'
+                """
+            )
+        }
+    }
+
+    @RuntimeStorage private var runtime: CommandRuntime?
+    var runtimeOptions = CommandRuntimeOptions()
+
+    private var resolvedRuntime: CommandRuntime {
+        guard let runtime else {
+            preconditionFailure("CommandRuntime must be configured before accessing runtime resources")
+        }
+        return runtime
+    }
+
+    private var configuration: CommandRuntime.Configuration {
+        if let runtime {
+            return runtime.configuration
+        }
+        // Unit tests exercise parsing without injecting a runtime; fall back to parsed flags.
+        return self.runtimeOptions.makeConfiguration()
+    }
+
+    private var services: any PeekabooServiceProviding {
+        self.resolvedRuntime.services
+    }
+
+    private var logger: Logger {
+        self.resolvedRuntime.logger
+    }
+
+    var outputLogger: Logger {
+        self.logger
+    }
+
+    var jsonOutput: Bool {
+        self.configuration.jsonOutput
+    }
+
+    mutating func run(using runtime: CommandRuntime) async throws {
+        self.runtime = runtime
+        self.logger.setJsonOutputMode(self.jsonOutput)
+
+        let report = await Self.run(self.makeChecks())
+        output(report) {
+            for check in report.checks {
+                let marker = check.healthy ? "✅" : "❌"
+                let detail = check.error.map { ": \($0)" } ?? ""
+                print("\(marker) \(check.component) (\(check.latency_ms)ms)\(detail)")
+            }
+        }
+
+        if !report.healthy {
+            throw ExitCode(1)
+        }
+    }
+
+    private func makeChecks() -> [HealthCheck] {
+        let services = self.services
+        return [
+            HealthCheck(component: "screens") {
+                guard !services.screens.listScreens().isEmpty else {
+                    throw PeekabooError.displayNotFound
+                }
+            },
+            HealthCheck(component: "applications") {
+                let frontmost = try await services.applications.getFrontmostApplication()
+                _ = try await services.applications.findApplication(identifier: "PID:\(frontmost.processIdentifier)")
+            },
+            HealthCheck(component: "windows") {
+                let frontmost = try await services.applications.getFrontmostApplication()
+                _ = try await services.applications.listWindows(for: "PID:\(frontmost.processIdentifier)", timeout: nil)
+            },
+            HealthCheck(component: "permissions") {
+                var missing: [String] = []
+                if !services.permissions.checkScreenRecordingPermission() {
+                    missing.append("Screen Recording")
+                }
+                if !services.permissions.checkAccessibilityPermission() {
+                    missing.append("Accessibility")
+                }
+                guard missing.isEmpty else {
+                    throw PeekabooError.permissionDenied("Not granted: \(missing.joined(separator: ", "))")
+                }
+            },
+        ]
+    }
+
+    /// Runs `checks` in order, one at a time, so a slow or hung service shows up in its own latency.
+    static func run(_ checks: [HealthCheck]) async -> HealthCheckReport {
+        var results: [HealthCheckResult] = []
+        for check in checks {
+            let startedAt = ContinuousClock.now
+            var failure: String?
+            do {
+                try await check.probe()
+            } catch {
+                failure = error.localizedDescription
+            }
+            let elapsed = ContinuousClock.now - startedAt
+            results.append(HealthCheckResult(
+                component: check.component,
+                healthy: failure == nil,
+                latency_ms: Int(elapsed / .milliseconds(1)),
+                error: failure
+            ))
+        }
+        return HealthCheckReport(healthy: results.allSatisfy(\.healthy), checks: results)
+    }
+}
+
+struct HealthCheck {
+    let component: String
+    let probe: @MainActor () async throws -> Void
+}
+
+struct HealthCheckResult: Codable, Equatable {
+    let component: String
+    let healthy: Bool
+    let latency_ms: Int
+    let error: String?
+}
+
+struct HealthCheckReport: Codable, Equatable {
+    let healthy: Bool
+    let checks: [HealthCheckResult]
+}
+
+@MainActor
+extension HealthCheckCommand: ParsableCommand {}
+extension HealthCheckCommand: AsyncRuntimeCommand {}
+
+@MainActor
+extension HealthCheckCommand: CommanderBindableCommand {
+    mutating func applyCommanderValues(_: CommanderBindableValues) throws {}
+}
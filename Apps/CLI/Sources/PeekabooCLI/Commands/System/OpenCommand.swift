@@ -89,7 +89,7 @@ struct OpenCommand: ParsableCommand, OutputFormattable, ErrorHandlingCommand, Ru
             self.renderSuccess(app: appInstance, targetURL: targetURL, didFocus: didFocus)
         } catch {
             self.handleError(error)
-            throw ExitCode.failure
+            throw self.exitCode(for: error)
         }
     }
 
@@ -148,7 +148,10 @@ struct OpenCommand: ParsableCommand, OutputFormattable, ErrorHandlingCommand, Ru
 
         let activated = app.activate(options: [])
         if !activated {
-            self.logger.warn("Open succeeded but failed to focus \(app.localizedName ?? "application")")
+            self.logger.warn(
+                .FOCUS_NOT_CONFIRMED,
+                "Open succeeded but failed to focus \(app.localizedName ?? "application")"
+            )
         }
         return activated
     }
@@ -181,7 +184,10 @@ struct OpenCommand: ParsableCommand, OutputFormattable, ErrorHandlingCommand, Ru
         let start = Date()
         while !app.isFinishedLaunching {
             if Date().timeIntervalSince(start) > timeout {
-                throw PeekabooError.timeout("Application did not become ready within \(Int(timeout)) seconds")
+                throw PeekabooError.timedOut(
+                    operation: "Waiting for the application to finish launching",
+                    timeoutMs: Int(timeout * 1000)
+                )
             }
             try await Task.sleep(nanoseconds: 100_000_000)
         }
@@ -95,7 +95,8 @@ struct RunCommand: OutputFormattable {
                     success: output.success,
                     data: output,
                     messages: nil,
-                    debug_logs: self.outputLogger.getDebugLogs()
+                    debug_logs: self.outputLogger.getDebugLogs(),
+                    warnings: self.outputLogger.getWarnings()
                 )
                 outputJSONCodable(response, logger: self.outputLogger)
                 didEmitJSONResponse = true
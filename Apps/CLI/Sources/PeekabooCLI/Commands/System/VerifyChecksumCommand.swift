@@ -0,0 +1,128 @@
+import Commander
+import Foundation
+import PeekabooCore
+import PeekabooFoundation
+
+/// Checks a file against a digest, typically one `peekaboo image --compute-checksum` reported earlier.
+@available(macOS 14.0, *)
+@MainActor
+struct VerifyChecksumCommand: ErrorHandlingCommand, OutputFormattable, RuntimeOptionsConfigurable {
+    nonisolated(unsafe) static var commandDescription: CommandDescription {
+        MainActorCommandDescription.describe {
+            CommandDescription(
+                commandName: "verify-checksum",
+                abstract: "Check that a file still matches a checksum; exits 1 when it does not",
+                discussion: """
+                    EXAMPLES:
+                      peekaboo image --app Safari --path /tmp/shot.png --compute-checksum --json
+                      peekaboo verify-checksum --file /tmp/shot.png --checksum 9f86d08…
+                      peekaboo verify-checksum --file /tmp/shot.png --checksum d41d8cd9… --algorithm md5
+                """,
+                showHelpOnEmptyInvocation: true
+            )
+        }
+    }
+
+    @Option(help: "File to check; ~ is expanded")
+    var file: String
+
+    @Option(help: "Expected hex digest; case is ignored")
+    var checksum: String
+
+    @Option(help: "sha256 (default), sha512, or md5")
+    var algorithm: FileChecksumAlgorithm?
+
+    @RuntimeStorage private var runtime: CommandRuntime?
+    var runtimeOptions = CommandRuntimeOptions()
+
+    private var resolvedRuntime: CommandRuntime {
+        guard let runtime else {
+            preconditionFailure("CommandRuntime must be configured before accessing runtime resources")
+        }
+        return runtime
+    }
+
+    private var configuration: CommandRuntime.Configuration {
+        if let runtime {
+            return runtime.configuration
+        }
+        // Unit tests exercise parsing without injecting a runtime; fall back to parsed flags.
+        return self.runtimeOptions.makeConfiguration()
+    }
+
+    private var logger: Logger {
+        self.resolvedRuntime.logger
+    }
+
+    var outputLogger: Logger {
+        self.logger
+    }
+
+    var jsonOutput: Bool {
+        self.configuration.jsonOutput
+    }
+
+    var resolvedAlgorithm: FileChecksumAlgorithm {
+        self.algorithm ?? .sha256
+    }
+
+    mutating func run(using runtime: CommandRuntime) async throws {
+        self.runtime = runtime
+        self.logger.setJsonOutputMode(self.jsonOutput)
+
+        do {
+            let result = try Self.verify(file: self.file, checksum: self.checksum, algorithm: self.resolvedAlgorithm)
+            guard result.matches else {
+                throw PeekabooError.invalidInput(
+                    "\(result.path) does not match its \(result.algorithm) checksum: " +
+                        "expected \(result.expected), got \(result.actual)"
+                )
+            }
+            output(result) {
+                print("✅ \(result.path) matches its \(result.algorithm) checksum")
+            }
+        } catch {
+            self.handleError(error)
+            throw ExitCode.failure
+        }
+    }
+
+    /// Hashes `file` with `algorithm` and compares it with `checksum`, ignoring case and surrounding whitespace.
+    static func verify(
+        file: String,
+        checksum: String,
+        algorithm: FileChecksumAlgorithm
+    ) throws -> VerifyChecksumResult {
+        let expanded = (file as NSString).expandingTildeInPath
+        let expected = checksum.trimmingCharacters(in: .whitespacesAndNewlines).lowercased()
+        let actual = try algorithm.hexDigest(ofFileAt: expanded)
+        return VerifyChecksumResult(
+            path: expanded,
+            algorithm: algorithm.rawValue,
+            expected: expected,
+            actual: actual,
+            matches: expected == actual
+        )
+    }
+}
+
+struct VerifyChecksumResult: Codable, Equatable {
+    let path: String
+    let algorithm: String
+    let expected: String
+    let actual: String
+    let matches: Bool
+}
+
+@MainActor
+extension VerifyChecksumCommand: ParsableCommand {}
+extension VerifyChecksumCommand: AsyncRuntimeCommand {}
+
+@MainActor
+extension VerifyChecksumCommand: CommanderBindableCommand {
+    mutating func applyCommanderValues(_ values: CommanderBindableValues) throws {
+        self.file = try values.requireOption("file", as: String.self)
+        self.checksum = try values.requireOption("checksum", as: String.self)
+        self.algorithm = try values.decodeOptionEnum("algorithm")
+    }
+}
@@ -0,0 +1,126 @@
+import Commander
+import Foundation
+import PeekabooCore
+import PeekabooFoundation
+
+/// Blocks until a file exists, for scripts that run `peekaboo image` or an `--exec-after` hook in the background.
+@available(macOS 14.0, *)
+@MainActor
+struct WaitForFileCommand: ErrorHandlingCommand, OutputFormattable, RuntimeOptionsConfigurable {
+    static let defaultTimeoutMs = 10000
+    static let pollInterval: Duration = .milliseconds(50)
+
+    nonisolated(unsafe) static var commandDescription: CommandDescription {
+        MainActorCommandDescription.describe {
+            CommandDescription(
+                commandName: "wait-for-file",
+                abstract: "Wait until a file exists, e.g. a capture written by a background peekaboo run",
+                discussion: """
+                    EXAMPLES:
+                      peekaboo image --app Safari --path /tmp/shot.png &
+                      peekaboo wait-for-file --path /tmp/shot.png --timeout-ms 5000
+                """,
+                showHelpOnEmptyInvocation: true
+            )
+        }
+    }
+
+    @Option(help: "File to wait for; ~ is expanded")
+    var path: String
+
+    @Option(help: "Give up after this many milliseconds (default 10000)")
+    var timeoutMs: Int?
+
+    @RuntimeStorage private var runtime: CommandRuntime?
+    var runtimeOptions = CommandRuntimeOptions()
+
+    private var resolvedRuntime: CommandRuntime {
+        guard let runtime else {
+            preconditionFailure("CommandRuntime must be configured before accessing runtime resources")
+        }
+        return runtime
+    }
+
+    private var configuration: CommandRuntime.Configuration {
+        if let runtime {
+            return runtime.configuration
+        }
+        // Unit tests exercise parsing without injecting a runtime; fall back to parsed flags.
+        return self.runtimeOptions.makeConfiguration()
+    }
+
+    private var logger: Logger {
+        self.resolvedRuntime.logger
+    }
+
+    var outputLogger: Logger {
+        self.logger
+    }
+
+    var jsonOutput: Bool {
+        self.configuration.jsonOutput
+    }
+
+    var resolvedTimeoutMs: Int {
+        self.timeoutMs ?? Self.defaultTimeoutMs
+    }
+
+    mutating func run(using runtime: CommandRuntime) async throws {
+        self.runtime = runtime
+        self.logger.setJsonOutputMode(self.jsonOutput)
+
+        do {
+            guard self.resolvedTimeoutMs > 0 else {
+                throw ValidationError("--timeout-ms must be greater than zero")
+            }
+            let result = try await Self.waitForFile(at: self.path, timeoutMs: self.resolvedTimeoutMs)
+            output(result) {
+                print("✅ \(result.path) exists (waited \(result.waited_ms)ms)")
+            }
+        } catch {
+            self.handleError(error)
+            throw self.exitCode(for: error)
+        }
+    }
+
+    /// Polls until a regular file exists at `path`; directories don't count.
+    /// Fails with `TIMEOUT` once `timeoutMs` passes without one.
+    static func waitForFile(
+        at path: String,
+        timeoutMs: Int,
+        pollInterval: Duration = Self.pollInterval
+    ) async throws -> WaitForFileResult {
+        let expanded = (path as NSString).expandingTildeInPath
+        let startedAt = ContinuousClock.now
+        let deadline = startedAt + .milliseconds(timeoutMs)
+
+        while true {
+            var isDirectory: ObjCBool = false
+            if FileManager.default.fileExists(atPath: expanded, isDirectory: &isDirectory), !isDirectory.boolValue {
+                let waited = ContinuousClock.now - startedAt
+                return WaitForFileResult(path: expanded, waited_ms: Int(waited / .milliseconds(1)))
+            }
+            guard ContinuousClock.now < deadline else {
+                throw PeekabooError.timedOut(operation: "Waiting for '\(expanded)'", timeoutMs: timeoutMs)
+            }
+            try await Task.sleep(for: min(pollInterval, deadline - ContinuousClock.now))
+        }
+    }
+}
+
+struct WaitForFileResult: Codable, Equatable {
+    let path: String
+    let waited_ms: Int
+}
+
+@MainActor
+extension WaitForFileCommand: ParsableCommand {}
+extension WaitForFileCommand: AsyncRuntimeCommand {}
+
+@MainActor
+extension WaitForFileCommand: CommanderBindableCommand {
+    mutating func applyCommanderValues(_ values: CommanderBindableValues) throws {
+        self.path = try values.requireOption("path", as: String.self)
+        self.timeoutMs = try values.decodeOption("timeoutMs", as: Int.self)
+    }
+}
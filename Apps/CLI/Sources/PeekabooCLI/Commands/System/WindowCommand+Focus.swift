@@ -201,7 +201,10 @@ extension WindowCommand {
                 )
                 return refreshedWindows.first
             } catch {
-                self.logger.warn("Failed to refetch window info (\(context)): \(error.localizedDescription)")
+                self.logger.warn(
+                    .STALE_PROCESS_INFO,
+                    "Failed to refetch window info (\(context)): \(error.localizedDescription)"
+                )
                 return nil
             }
         }
@@ -2,6 +2,7 @@ import Commander
 import Foundation
 import PeekabooBridge
 import PeekabooCore
+import PeekabooFoundation
 
 /// Shared permission checking and formatting utilities
 enum PermissionHelpers {
@@ -252,6 +253,72 @@ enum PermissionHelpers {
             "process already has permission."
     }
 
+    /// Actionable remediation for a permission failure, or nil for any other error.
+    ///
+    /// macOS grants TCC permissions to the app that launched `peekaboo` (the terminal or IDE), so the
+    /// steps name that app when `TERM_PROGRAM` identifies it.
+    static func explainFailure(
+        _ error: any Error,
+        environment: [String: String] = ProcessInfo.processInfo.environment
+    ) -> String? {
+        let host = self.hostAppName(termProgram: environment["TERM_PROGRAM"])
+        let settingsURL = "x-apple.systempreferences:com.apple.preference.security"
+
+        switch self.deniedPermission(for: error) {
+        case .screenRecording:
+            return "Open System Settings → Privacy & Security → Screen Recording (`open \"\(settingsURL)" +
+                "?Privacy_ScreenCapture\"`), enable \(host), then quit and reopen it. If it is already enabled, " +
+                "run `tccutil reset ScreenCapture` and grant it again when prompted."
+        case .accessibility:
+            return "Open System Settings → Privacy & Security → Accessibility (`open \"\(settingsURL)" +
+                "?Privacy_Accessibility\"`) and enable \(host). If it is already enabled, remove it with the " +
+                "minus button and add it back."
+        case .eventSynthesizing:
+            return "Run `peekaboo permissions request-event-synthesizing` to trigger the macOS prompt, or enable " +
+                "\(host) under System Settings → Privacy & Security → Accessibility."
+        case .appleScript:
+            return "Open System Settings → Privacy & Security → Automation (`open \"\(settingsURL)" +
+                "?Privacy_Automation\"`) and allow \(host) to control the target app."
+        case nil:
+            return nil
+        }
+    }
+
+    private enum DeniedPermission {
+        case screenRecording
+        case accessibility
+        case eventSynthesizing
+        case appleScript
+    }
+
+    private static func deniedPermission(for error: any Error) -> DeniedPermission? {
+        switch error {
+        case PeekabooError.permissionDeniedScreenRecording,
+             CaptureError.screenRecordingPermissionDenied,
+             CaptureError.permissionDeniedScreenRecording:
+            .screenRecording
+        case PeekabooError.permissionDeniedAccessibility, CaptureError.accessibilityPermissionDenied:
+            .accessibility
+        case PeekabooError.permissionDeniedEventSynthesizing:
+            .eventSynthesizing
+        case CaptureError.appleScriptPermissionDenied:
+            .appleScript
+        default:
+            nil
+        }
+    }
+
+    private static func hostAppName(termProgram: String?) -> String {
+        switch termProgram {
+        case "Apple_Terminal": "Terminal"
+        case "iTerm.app": "iTerm"
+        case "vscode": "Visual Studio Code"
+        case "ghostty": "Ghostty"
+        case "WarpTerminal": "Warp"
+        default: "the app you run peekaboo from (your terminal or IDE)"
+        }
+    }
+
     /// Format permissions for help display with dynamic status
     static func formatPermissionsForHelp(
         services: any PeekabooServiceProviding
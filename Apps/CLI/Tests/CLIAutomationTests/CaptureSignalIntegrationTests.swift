@@ -0,0 +1,60 @@
+import Darwin
+import Foundation
+import Testing
+@testable import PeekabooCLI
+
+#if !PEEKABOO_SKIP_AUTOMATION
+@Suite(
+    .serialized,
+    .tags(.automation, .localOnly),
+    .enabled(if: ProcessInfo.processInfo.environment["RUN_LOCAL_TESTS"] == "true"
+        && !(ProcessInfo.processInfo.environment["PEEKABOO_CLI_PATH"] ?? "").isEmpty)
+)
+struct CaptureSignalIntegrationTests {
+    @Test
+    func `SIGINT stops capture live with a final interrupted record`() throws {
+        let output = FileManager.default.temporaryDirectory
+            .appendingPathComponent("peekaboo-sigint-\(UUID().uuidString)", isDirectory: true)
+        defer { try? FileManager.default.removeItem(at: output) }
+
+        let result = try ExternalCommandRunner.runPeekabooCLI(
+            ["capture", "live", "--mode", "screen", "--duration", "30", "--path", output.path, "--json"],
+            interruptingWith: SIGINT,
+            after: 3,
+            allowedExitCodes: [0, 1]
+        )
+        guard result.exitStatus == 0 else {
+            Issue.record("capture live failed before the signal: \(result.combinedOutput)")
+            return
+        }
+
+        let lastLine = try #require(result.stdout.split(separator: "\n").last)
+        let record = try JSONDecoder().decode(CaptureInterruptionRecord.self, from: Data(lastLine.utf8))
+        #expect(record.interrupted)
+        #expect(record.completed_captures > 0)
+        #expect(FileManager.default.fileExists(atPath: output.appendingPathComponent("metadata.json").path))
+    }
+
+    @Test
+    func `SIGTERM stops capture live with a one-line summary`() throws {
+        let output = FileManager.default.temporaryDirectory
+            .appendingPathComponent("peekaboo-sigterm-\(UUID().uuidString)", isDirectory: true)
+        defer { try? FileManager.default.removeItem(at: output) }
+
+        let result = try ExternalCommandRunner.runPeekabooCLI(
+            ["capture", "live", "--mode", "screen", "--duration", "30", "--path", output.path],
+            interruptingWith: SIGTERM,
+            after: 3,
+            allowedExitCodes: [0, 1]
+        )
+        guard result.exitStatus == 0 else {
+            Issue.record("capture live failed before the signal: \(result.combinedOutput)")
+            return
+        }
+
+        let lastLine = try #require(result.stdout.split(separator: "\n").last)
+        #expect(lastLine.hasPrefix("Interrupted by SIGTERM after "))
+        #expect(lastLine.hasSuffix("completed captures") || lastLine.hasSuffix("completed capture"))
+    }
+}
+#endif
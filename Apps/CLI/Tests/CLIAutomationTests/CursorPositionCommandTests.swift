@@ -0,0 +1,44 @@
+import CoreGraphics
+import Foundation
+import PeekabooCore
+import PeekabooFoundation
+import Testing
+@testable import PeekabooCLI
+
+#if !PEEKABOO_SKIP_AUTOMATION
+@Suite(
+    .serialized,
+    .tags(.safe),
+    .enabled(if: CLITestEnvironment.runAutomationRead)
+)
+struct CursorPositionCommandTests {
+    @Test
+    func `JSON output reports the rounded pointer location`() async throws {
+        let context = await self.makeContext(location: CGPoint(x: -1280.4, y: 311.6))
+        let result = try await InProcessCommandRunner.run(["cursor-position", "--json"], services: context.services)
+
+        #expect(result.exitStatus == 0)
+        let data = try #require(result.stdout.data(using: .utf8))
+        let payload = try JSONDecoder().decode(CodableJSONResponse<CursorPosition>.self, from: data)
+        #expect(payload.success)
+        #expect(payload.data == CursorPosition(x: -1280, y: 312))
+    }
+
+    @Test
+    func `An unavailable location fails`() async throws {
+        let context = await self.makeContext(location: nil)
+        let result = try await InProcessCommandRunner.run(["cursor-position", "--json"], services: context.services)
+
+        #expect(result.exitStatus != 0)
+        #expect((result.stdout + result.stderr).contains("pointer location is unavailable"))
+    }
+
+    private func makeContext(location: CGPoint?) async -> TestServicesFactory.AutomationTestContext {
+        await MainActor.run {
+            let context = TestServicesFactory.makeAutomationTestContext()
+            context.automation.stubCurrentMouseLocation = location
+            return context
+        }
+    }
+}
+#endif
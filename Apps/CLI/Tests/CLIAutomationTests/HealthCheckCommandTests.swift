@@ -0,0 +1,39 @@
+import Foundation
+import PeekabooFoundation
+import Testing
+@testable import PeekabooCLI
+
+@Suite(.tags(.safe))
+@MainActor
+struct HealthCheckCommandTests {
+    @Test
+    func `Passing checks report healthy with their latency`() async {
+        let report = await HealthCheckCommand.run([
+            HealthCheck(component: "screens") {},
+            HealthCheck(component: "windows") { try await Task.sleep(for: .milliseconds(30)) },
+        ])
+
+        #expect(report.healthy)
+        #expect(report.checks.map(\.component) == ["screens", "windows"])
+        #expect(report.checks.allSatisfy { $0.error == nil })
+        #expect(report.checks[1].latency_ms >= 25)
+    }
+
+    @Test
+    func `One failing check marks the report unhealthy and keeps running the rest`() async {
+        let report = await HealthCheckCommand.run([
+            HealthCheck(component: "applications") { throw PeekabooError.appNotFound("PID:1") },
+            HealthCheck(component: "permissions") {},
+        ])
+
+        #expect(!report.healthy)
+        #expect(report.checks[0].healthy == false)
+        #expect(report.checks[0].error?.isEmpty == false)
+        #expect(report.checks[1].healthy)
+    }
+
+    @Test
+    func `Parses with global JSON output`() throws {
+        #expect(try HealthCheckCommand.parse(["--json"]).jsonOutput)
+    }
+}
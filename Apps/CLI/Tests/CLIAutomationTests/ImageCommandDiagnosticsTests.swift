@@ -40,5 +40,41 @@ extension ImageCommandTests {
         #expect(response.data.observations[0].state_snapshot != nil)
         try? FileManager.default.removeItem(atPath: path)
     }
+
+    @Test(.tags(.imageCapture))
+    func `JSON output includes phase timing unless disabled`() async throws {
+        let captureResult = Self.makeScreenCaptureResult(size: CGSize(width: 800, height: 600), scale: 1.0)
+        let captureService = StubScreenCaptureService(permissionGranted: true)
+        captureService.captureScreenHandler = { _, _ in
+            captureResult
+        }
+        let services = TestServicesFactory.makePeekabooServices(screenCapture: captureService)
+        let path = Self.makeTempCapturePath("timing.png")
+        defer { try? FileManager.default.removeItem(atPath: path) }
+
+        let timed = try await InProcessCommandRunner.run(
+            ["image", "--mode", "screen", "--path", path, "--json"],
+            services: services
+        )
+        #expect(timed.exitStatus == 0)
+        let timedResponse = try JSONDecoder().decode(
+            CodableJSONResponse<ImageCaptureResult>.self,
+            from: Data(timed.combinedOutput.utf8)
+        )
+        let timing = try #require(timedResponse.data.timing)
+        #expect(timing.capture_ms >= 0)
+        #expect(timing.filter_ms >= 0)
+        #expect(timing.total_ms >= timing.capture_ms + timing.filter_ms)
+        #expect(timing.analysis_ms == nil)
+        // The stubbed pipeline never touches a real display, so anything slow here is overhead in the command.
+        #expect(timing.total_ms < 2000)
+
+        let untimed = try await InProcessCommandRunner.run(
+            ["image", "--mode", "screen", "--path", path, "--json", "--no-timing"],
+            services: services
+        )
+        #expect(untimed.exitStatus == 0)
+        #expect(!untimed.combinedOutput.contains("\"timing\""))
+    }
 }
 #endif
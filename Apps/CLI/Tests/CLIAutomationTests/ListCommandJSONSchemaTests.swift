@@ -0,0 +1,177 @@
+import CoreGraphics
+import Foundation
+import PeekabooCore
+import Testing
+@testable import PeekabooCLI
+
+@Suite(.tags(.unit))
+@MainActor
+struct ListCommandJSONSchemaTests {
+    @Test(.tags(.fast))
+    func `JSON schema flag parses without a target app`() throws {
+        #expect(try ListCommand.AppsSubcommand.parse(["--json-schema"]).jsonSchema)
+        #expect(try ListCommand.WindowsSubcommand.parse(["--json-schema"]).jsonSchema)
+    }
+
+    @Test(.tags(.fast))
+    func `Schemas are draft-07 documents`() throws {
+        let rendered = JSONSchema.render(ListJSONSchemas.apps)
+        let document = try #require(
+            JSONSerialization.jsonObject(with: Data(rendered.utf8)) as? [String: Any]
+        )
+        #expect(document["$schema"] as? String == "http://json-schema.org/draft-07/schema#")
+        #expect((document["required"] as? [String])?.contains("data") == true)
+    }
+
+    @Test(.tags(.fast))
+    func `Apps output validates against its schema`() throws {
+        let app = ServiceApplicationInfo(
+            processIdentifier: 100,
+            bundleIdentifier: "com.google.Chrome",
+            name: "Google Chrome",
+            bundlePath: "/Applications/Google Chrome.app",
+            executablePath: "/Applications/Google Chrome.app/Contents/MacOS/Google Chrome",
+            isActive: true,
+            windowCount: 2,
+            activationPolicy: .regular,
+            cpuPercent: 12.5,
+            memoryRSSMB: 900,
+            launchTime: "2026-10-14T08:30:00Z",
+            uptimeSeconds: 60,
+            displayName: "Chrome",
+            category: "public.app-category.productivity",
+            iconName: "app.icns",
+            version: "130.0",
+            children: [ServiceProcessNode(pid: 110, name: "Helper", children: [
+                ServiceProcessNode(pid: 111, name: "crashpad"),
+            ])]
+        )
+        let minimal = ServiceApplicationInfo(processIdentifier: 200, bundleIdentifier: nil, name: "tool")
+        let payload = CodableJSONResponse(
+            success: true,
+            data: ApplicationListPage(applications: [app, minimal], total_count: 12, returned_count: 2, offset: 10),
+            messages: nil,
+            debug_logs: []
+        )
+
+        #expect(try Self.violations(of: payload, against: ListJSONSchemas.apps).isEmpty)
+    }
+
+    @Test(.tags(.fast))
+    func `Grouped apps output validates against its schema`() throws {
+        let grouped = ListCommand.AppsSubcommand.grouped([
+            ServiceApplicationInfo(
+                processIdentifier: 100,
+                bundleIdentifier: "com.google.Chrome",
+                name: "Google Chrome",
+                bundlePath: "/Applications/Google Chrome.app"
+            ),
+            ServiceApplicationInfo(processIdentifier: 200, bundleIdentifier: nil, name: "tool"),
+        ])
+        let payload = CodableJSONResponse(success: true, data: grouped, messages: nil, debug_logs: [])
+
+        #expect(try Self.violations(of: payload, against: ListJSONSchemas.groupedApps).isEmpty)
+    }
+
+    @Test(.tags(.fast))
+    func `Windows output validates against its schema`() throws {
+        let window = ServiceWindowInfo(
+            windowID: 42,
+            title: "Inbox",
+            bounds: CGRect(x: 10, y: 20, width: 800, height: 600),
+            index: 0,
+            spaceID: 3,
+            spaceName: "Desktop 1",
+            screenIndex: 0,
+            screenName: "Built-in Display",
+            sharingState: .readOnly
+        )
+        let payload = CodableJSONResponse(
+            success: true,
+            data: WindowListPage(
+                windows: [window],
+                targetApplication: ServiceApplicationInfo(processIdentifier: 7, bundleIdentifier: nil, name: "Mail"),
+                total_count: 1,
+                returned_count: 1,
+                offset: 0
+            ),
+            messages: ["ok"],
+            debug_logs: []
+        )
+
+        #expect(try Self.violations(of: payload, against: ListJSONSchemas.windows).isEmpty)
+    }
+
+    @Test(.tags(.fast))
+    func `Validator reports undeclared and missing keys`() {
+        let schema = JSONSchema.object(["name": .string()], required: ["name"]).jsonObject
+        #expect(Self.violations(["extra": 1], schema: schema, definitions: [:], path: "$") == [
+            "$: missing required name",
+            "$.extra: not in schema",
+        ])
+    }
+
+    // MARK: - Draft-07 subset validator
+
+    private static func violations(of payload: some Encodable, against document: [String: Any]) throws -> [String] {
+        let value = try JSONSerialization.jsonObject(with: JSONEncoder().encode(payload))
+        let definitions = document["definitions"] as? [String: [String: Any]] ?? [:]
+        return self.violations(value, schema: document, definitions: definitions, path: "$")
+    }
+
+    private static func violations(
+        _ value: Any,
+        schema: [String: Any],
+        definitions: [String: [String: Any]],
+        path: String
+    ) -> [String] {
+        if let ref = schema["$ref"] as? String {
+            let name = String(ref.dropFirst("#/definitions/".count))
+            guard let resolved = definitions[name] else { return ["\(path): unresolved \(ref)"] }
+            return self.violations(value, schema: resolved, definitions: definitions, path: path)
+        }
+
+        switch schema["type"] as? String {
+        case "object":
+            guard let object = value as? [String: Any] else { return ["\(path): expected object"] }
+            let properties = schema["properties"] as? [String: [String: Any]] ?? [:]
+            var problems = (schema["required"] as? [String] ?? [])
+                .filter { object[$0] == nil }
+                .map { "\(path): missing required \($0)" }
+            for (key, element) in object.sorted(by: { $0.key < $1.key }) {
+                guard let property = properties[key] else {
+                    problems.append("\(path).\(key): not in schema")
+                    continue
+                }
+                problems += self.violations(element, schema: property, definitions: definitions, path: "\(path).\(key)")
+            }
+            return problems
+        case "array":
+            guard let array = value as? [Any] else { return ["\(path): expected array"] }
+            let items = schema["items"] as? [String: Any] ?? [:]
+            return array.enumerated().flatMap { index, element in
+                self.violations(element, schema: items, definitions: definitions, path: "\(path)[\(index)]")
+            }
+        case "string":
+            guard let string = value as? String else { return ["\(path): expected string"] }
+            if let allowed = schema["enum"] as? [String], !allowed.contains(string) {
+                return ["\(path): \(string) not in enum"]
+            }
+            return []
+        case "boolean":
+            return CFGetTypeID(value as CFTypeRef) == CFBooleanGetTypeID() ? [] : ["\(path): expected boolean"]
+        case "integer":
+            guard let number = value as? NSNumber, CFGetTypeID(number) != CFBooleanGetTypeID(),
+                  number.doubleValue == number.doubleValue.rounded()
+            else { return ["\(path): expected integer"] }
+            return []
+        case "number":
+            guard let number = value as? NSNumber, CFGetTypeID(number) != CFBooleanGetTypeID() else {
+                return ["\(path): expected number"]
+            }
+            return []
+        default:
+            return ["\(path): unsupported schema"]
+        }
+    }
+}
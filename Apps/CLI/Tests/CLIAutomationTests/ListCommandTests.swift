@@ -1,3 +1,4 @@
+import Commander
 import CoreGraphics
 import Foundation
 import PeekabooCore
@@ -55,7 +56,8 @@ struct ListCommandCLIHarnessTests {
                 bundleIdentifier: "com.example.viewer",
                 name: "Viewer",
                 isActive: true,
-                windowCount: 4
+                windowCount: 4,
+                uptimeSeconds: 3900
             ),
         ]
         let context = await self.makeContext(applications: applications)
@@ -65,6 +67,7 @@ struct ListCommandCLIHarnessTests {
         let output = self.output(from: result)
         #expect(output.contains("Viewer"))
         #expect(output.contains("PID"))
+        #expect(output.contains("running for 1h 5m"))
     }
 
     @Test
@@ -113,6 +116,51 @@ struct ListCommandCLIHarnessTests {
         #expect(output.contains("\"spaceID\""))
     }
 
+    @Test
+    func `list windows finds the app that owns a window title`() async throws {
+        let applications = [
+            ServiceApplicationInfo(
+                processIdentifier: 404,
+                bundleIdentifier: "com.apple.finder",
+                name: "Finder",
+                windowCount: 1
+            ),
+            ServiceApplicationInfo(
+                processIdentifier: 505,
+                bundleIdentifier: "com.apple.iWork.Numbers",
+                name: "Numbers",
+                windowCount: 2
+            ),
+        ]
+        let windowsByApp = [
+            "Finder": [ServiceWindowInfo(windowID: 1, title: "Documents", bounds: .zero, index: 0)],
+            "Numbers": [
+                ServiceWindowInfo(windowID: 2, title: "Untitled", bounds: .zero, index: 0),
+                ServiceWindowInfo(windowID: 3, title: "Budget 2026.numbers", bounds: .zero, index: 1),
+            ],
+        ]
+        let applicationService = await MainActor.run {
+            StubApplicationService(applications: applications, windowsByApp: windowsByApp)
+        }
+        let context = await self.makeContext(applicationService: applicationService)
+
+        let result = try await self.runList(
+            arguments: ["list", "windows", "--find-app-by-window-title", "budget", "--json"],
+            services: context.services
+        )
+        #expect(result.exitStatus == 0)
+        let data = try #require(self.output(from: result).data(using: .utf8))
+        let payload = try JSONDecoder().decode(CodableJSONResponse<ServiceWindowListData>.self, from: data)
+        #expect(payload.data.targetApplication?.processIdentifier == 505)
+        #expect(payload.data.windows.map(\.windowID) == [3])
+
+        let missing = try await self.runList(
+            arguments: ["list", "windows", "--find-app-by-window-title", "Invoice"],
+            services: context.services
+        )
+        #expect(missing.exitStatus != 0)
+    }
+
     @Test
     func `list apps fails when screen recording permission missing`() async throws {
         let applications = [
@@ -204,6 +252,416 @@ struct ListCommandTests {
         #expect(command.jsonOutput == true)
     }
 
+    @Test(.tags(.fast))
+    func `AppsSubcommand parses stats options`() throws {
+        let command = try AppsSubcommand.parse(["--with-stats", "--stats-sort", "CPU"])
+        #expect(command.withStats == true)
+        #expect(command.statsSort == .cpu)
+
+        let defaults = try AppsSubcommand.parse([])
+        #expect(defaults.withStats == false)
+        #expect(defaults.statsSort == nil)
+    }
+
+    @Test(.tags(.fast))
+    func `AppsSubcommand sorts by sampled stats`() {
+        let apps = [
+            ServiceApplicationInfo(processIdentifier: 1, bundleIdentifier: nil, name: "Beta"),
+            ServiceApplicationInfo(processIdentifier: 2, bundleIdentifier: nil, name: "alpha"),
+            ServiceApplicationInfo(processIdentifier: 3, bundleIdentifier: nil, name: "Gamma"),
+        ]
+        let usage: [Int32: ProcessResourceUsage] = [
+            1: ProcessResourceUsage(cpuPercent: 12.5, memoryRSSMB: 40),
+            2: ProcessResourceUsage(cpuPercent: 3.0, memoryRSSMB: 900),
+        ]
+
+        let sampled = AppsSubcommand.applyingStats(usage, to: apps)
+        #expect(sampled[2].cpuPercent == nil)
+
+        #expect(AppsSubcommand.sorted(sampled, by: .name).map(\.name) == ["alpha", "Beta", "Gamma"])
+        #expect(AppsSubcommand.sorted(sampled, by: .cpu).map(\.name) == ["Beta", "alpha", "Gamma"])
+        #expect(AppsSubcommand.sorted(sampled, by: .memory).map(\.name) == ["alpha", "Beta", "Gamma"])
+    }
+
+    @Test(.tags(.fast))
+    func `AppsSubcommand enriches apps with Info.plist metadata`() throws {
+        #expect(try AppsSubcommand.parse(["--rich-metadata"]).richMetadata == true)
+        #expect(try AppsSubcommand.parse([]).richMetadata == false)
+
+        let metadata = ApplicationBundleMetadata(infoDictionary: [
+            "CFBundleDisplayName": "Xcode",
+            "LSApplicationCategoryType": "public.app-category.developer-tools",
+            "CFBundleIconFile": "Xcode.icns",
+            "CFBundleShortVersionString": "26.0",
+            "CFBundleIconName": "",
+        ])
+        #expect(metadata.iconName == "Xcode.icns")
+
+        let apps = [
+            ServiceApplicationInfo(
+                processIdentifier: 1,
+                bundleIdentifier: "com.apple.dt.Xcode",
+                name: "Xcode",
+                bundlePath: "/Applications/Xcode.app"
+            ),
+            ServiceApplicationInfo(processIdentifier: 2, bundleIdentifier: nil, name: "tool"),
+        ]
+        let enriched = AppsSubcommand.applyingBundleMetadata(to: apps) { path in
+            path == "/Applications/Xcode.app" ? metadata : nil
+        }
+        #expect(enriched[0].displayName == "Xcode")
+        #expect(enriched[0].category == "public.app-category.developer-tools")
+        #expect(enriched[0].version == "26.0")
+        #expect(enriched[1].category == nil)
+    }
+
+    @Test(.tags(.fast))
+    func `AppsSubcommand nests child processes under each app`() throws {
+        #expect(try AppsSubcommand.parse(["--include-children"]).includeChildren == true)
+        #expect(try AppsSubcommand.parse([]).includeChildren == false)
+
+        let table = [
+            ProcessTableEntry(pid: 1, parentPID: 0, name: "launchd"),
+            ProcessTableEntry(pid: 100, parentPID: 1, name: "Google Chrome"),
+            ProcessTableEntry(pid: 120, parentPID: 100, name: "Google Chrome Helper (Renderer)"),
+            ProcessTableEntry(pid: 110, parentPID: 100, name: "Google Chrome Helper (GPU)"),
+            ProcessTableEntry(pid: 130, parentPID: 120, name: "crashpad"),
+            ProcessTableEntry(pid: 200, parentPID: 1, name: "Finder"),
+        ]
+        let apps = AppsSubcommand.applyingChildren(
+            to: [
+                ServiceApplicationInfo(processIdentifier: 100, bundleIdentifier: nil, name: "Google Chrome"),
+                ServiceApplicationInfo(processIdentifier: 200, bundleIdentifier: nil, name: "Finder"),
+            ],
+            table: table
+        )
+
+        #expect(apps[0].children?.map(\.pid) == [110, 120])
+        #expect(apps[0].children?[1].children.map(\.name) == ["crashpad"])
+        #expect(apps[1].children == [])
+        let rendered = CLIFormatter.formatProcessTree(apps[0].children ?? [], depth: 1)
+        #expect(rendered.contains("\n      └ crashpad - PID: 130"))
+    }
+
+    @Test(.tags(.fast))
+    func `AppsSubcommand groups processes by top-level app bundle`() throws {
+        #expect(try AppsSubcommand.parse(["--group-by", "app-bundle"]).groupsApplications)
+        #expect(try !AppsSubcommand.parse(["--group-by", "none"]).groupsApplications)
+        #expect(try !AppsSubcommand.parse([]).groupsApplications)
+
+        let chrome = "/Applications/Google Chrome.app"
+        let helpers = "\(chrome)/Contents/Frameworks/Google Chrome Framework.framework/Helpers"
+        let grouped = AppsSubcommand.grouped([
+            ServiceApplicationInfo(
+                processIdentifier: 110,
+                bundleIdentifier: "com.google.Chrome.helper",
+                name: "Google Chrome Helper",
+                bundlePath: "\(helpers)/Google Chrome Helper.app"
+            ),
+            ServiceApplicationInfo(processIdentifier: 200, bundleIdentifier: nil, name: "tool"),
+            ServiceApplicationInfo(
+                processIdentifier: 100,
+                bundleIdentifier: "com.google.Chrome",
+                name: "Google Chrome",
+                bundlePath: chrome
+            ),
+            ServiceApplicationInfo(
+                processIdentifier: 300,
+                bundleIdentifier: "com.apple.finder",
+                name: "Finder",
+                bundlePath: "/System/Library/CoreServices/Finder.app"
+            ),
+            ServiceApplicationInfo(
+                processIdentifier: 400,
+                bundleIdentifier: "com.example.Updater",
+                name: "Updater",
+                bundlePath: "/Applications/Example.app/Contents/Library/LoginItems/Updater.app"
+            ),
+        ])
+
+        #expect(grouped.schema_version == "2")
+        #expect(grouped.groups.map(\.name) == ["Google Chrome", "Finder", "Example"])
+        #expect(grouped.groups.map(\.bundle_path) == [
+            chrome,
+            "/System/Library/CoreServices/Finder.app",
+            "/Applications/Example.app",
+        ])
+        #expect(grouped.groups[0].applications.map(\.processIdentifier) == [110, 100])
+        #expect(grouped.ungrouped.map(\.name) == ["tool"])
+
+        let rendered = AppsSubcommand.formatGrouped(grouped)
+        #expect(rendered.contains("1. Google Chrome (\(chrome)) - 2 processes\n   └ Google Chrome Helper - PID: 110"))
+        #expect(rendered.contains("Ungrouped:\n1. tool - PID: 200"))
+    }
+
+    @Test(.tags(.fast))
+    func `AppsSubcommand grouping rejects CSV export`() throws {
+        let command = try AppsSubcommand.parse(["--group-by", "app-bundle", "--export-csv"])
+        #expect(throws: ValidationError.self) {
+            try command.validateGroupingOptions()
+        }
+    }
+
+    @Test(.tags(.fast))
+    func `CSV export quotes fields and rejects JSON`() throws {
+        #expect(CSVWriter.row(["plain", "a,b", "say \"hi\"", "two\r\nlines"]) ==
+            "plain,\"a,b\",\"say \"\"hi\"\"\",\"two\r\nlines\"")
+        #expect(CSVWriter.document(header: ["a", "b"], rows: [["1", "2"]]) == "a,b\r\n1,2\r\n")
+        #expect(CSVWriter.document(header: nil, rows: [["1", "2"]]) == "1,2\r\n")
+
+        let windows = try WindowsSubcommand.parse(["--app", "Finder", "--export-csv", "--csv-no-header"])
+        #expect(windows.exportCsv && windows.csvNoHeader)
+        #expect(throws: ValidationError.self) {
+            try ListCommand.validateCSVOptions(exportCsv: true, csvNoHeader: false, jsonOutput: true)
+        }
+        #expect(throws: ValidationError.self) {
+            try ListCommand.validateCSVOptions(exportCsv: false, csvNoHeader: true, jsonOutput: false)
+        }
+        #expect(try AppsSubcommand.parse(["--export-csv"]).hidesSystemApps == false)
+    }
+
+    @Test(.tags(.fast))
+    func `CSV rows carry window geometry and app stats`() {
+        let data = ServiceWindowListData(
+            windows: [
+                ServiceWindowInfo(
+                    windowID: 42,
+                    title: "Report, final",
+                    bounds: CGRect(x: 10, y: 20.5, width: 800, height: 600),
+                    index: 0,
+                    isOnScreen: false
+                ),
+            ],
+            targetApplication: ServiceApplicationInfo(processIdentifier: 7, bundleIdentifier: nil, name: "Pages")
+        )
+        #expect(WindowsSubcommand.csvRows(for: data) == [
+            ["0", "42", "Report, final", "10", "20.5", "800", "600", "false", "7", "Pages"],
+        ])
+
+        let apps = [
+            ServiceApplicationInfo(
+                processIdentifier: 9,
+                bundleIdentifier: "com.example.busy",
+                name: "Busy",
+                cpuPercent: 12.34,
+                memoryRSSMB: 256
+            ),
+            ServiceApplicationInfo(processIdentifier: 10, bundleIdentifier: nil, name: "Idle"),
+        ]
+        let rows = AppsSubcommand.csvRows(for: apps)
+        #expect(rows[0].suffix(2) == ["12.3", "256"])
+        #expect(rows[1] == ["Idle", "", "10", "false", "false", "0", "", "", ""])
+        #expect(rows.allSatisfy { $0.count == AppsSubcommand.csvHeader.count })
+    }
+
+    @Test(.tags(.fast))
+    func `Window title search replaces the app target and prefers the frontmost app`() throws {
+        let search = try WindowsSubcommand.parse(["--find-app-by-window-title", "Budget"])
+        try search.validateWindowTitleSearchOptions()
+        #expect(search.findAppByWindowTitle == "Budget")
+
+        for arguments in [
+            ["--find-app-by-window-title", "Budget", "--app", "Numbers"],
+            ["--find-app-by-window-title", "Budget", "--watch"],
+            ["--find-app-by-window-title", " "],
+        ] {
+            let invalid = try WindowsSubcommand.parse(arguments)
+            #expect(throws: ValidationError.self) {
+                try invalid.validateWindowTitleSearchOptions()
+            }
+        }
+
+        let ordered = WindowsSubcommand.windowTitleSearchOrder([
+            ServiceApplicationInfo(processIdentifier: 1, bundleIdentifier: nil, name: "Back", windowCount: 1),
+            ServiceApplicationInfo(processIdentifier: 2, bundleIdentifier: nil, name: "Agent"),
+            ServiceApplicationInfo(
+                processIdentifier: 3,
+                bundleIdentifier: nil,
+                name: "Front",
+                isActive: true,
+                windowCount: 1
+            ),
+        ])
+        #expect(ordered.map(\.name) == ["Front", "Back"])
+    }
+
+    @Test(.tags(.fast))
+    func `AppsSubcommand table options require --table and reject other output modes`() throws {
+        let command = try AppsSubcommand.parse([
+            "--table", "--no-header", "--max-col-width", "12", "--border-style", "ascii",
+        ])
+        try command.validateTableOptions()
+        #expect(command.noHeader && command.maxColWidth == 12 && command.borderStyle == .ascii)
+
+        for arguments in [
+            ["--table", "--json"],
+            ["--table", "--export-csv"],
+            ["--table", "--group-by", "app-bundle"],
+            ["--no-header"],
+            ["--border-style", "unicode"],
+            ["--table", "--max-col-width", "1"],
+        ] {
+            let invalid = try AppsSubcommand.parse(arguments)
+            #expect(throws: ValidationError.self) {
+                try invalid.validateTableOptions()
+            }
+        }
+    }
+
+    @Test(.tags(.fast))
+    @MainActor
+    func `Table flags resolve through the list apps signature`() throws {
+        let invocation = try CommanderRuntimeRouter.resolve(argv: [
+            "peekaboo", "list", "apps", "--table", "--no-header", "--border-style", "unicode",
+        ])
+        let command = try CommanderCLIBinder.instantiateCommand(
+            ofType: AppsSubcommand.self,
+            parsedValues: invocation.parsedValues
+        )
+        #expect(command.table && command.noHeader && command.borderStyle == .unicode)
+
+        #expect(throws: (any Error).self) {
+            try CommanderRuntimeRouter.resolve(argv: ["peekaboo", "list", "windows", "--table"])
+        }
+    }
+
+    @Test(.tags(.fast))
+    func `Table rows add stats columns only when sampled`() {
+        let plain = AppsSubcommand.tableContents(for: [
+            ServiceApplicationInfo(processIdentifier: 10, bundleIdentifier: nil, name: "Idle", isHidden: true),
+        ])
+        #expect(plain.header == AppsSubcommand.tableHeader)
+        #expect(plain.rows == [["Idle", "10", "", "0", "hidden"]])
+
+        let sampled = AppsSubcommand.tableContents(for: [
+            ServiceApplicationInfo(
+                processIdentifier: 9,
+                bundleIdentifier: "com.example.busy",
+                name: "Busy",
+                cpuPercent: 12.34,
+                memoryRSSMB: 256
+            ),
+        ])
+        #expect(sampled.header.suffix(2) == ["CPU %", "RSS MB"])
+        #expect(sampled.rows[0].suffix(2) == ["12.3", "256"])
+    }
+
+    @Test(.tags(.fast))
+    func `AppsSubcommand hides system apps only in human output by default`() throws {
+        #expect(try AppsSubcommand.parse([]).hidesSystemApps)
+        #expect(try AppsSubcommand.parse(["--json"]).hidesSystemApps == false)
+        #expect(try AppsSubcommand.parse(["--json", "--exclude-system"]).hidesSystemApps)
+        #expect(try AppsSubcommand.parse(["--include-system"]).hidesSystemApps == false)
+
+        let conflicting = try AppsSubcommand.parse(["--exclude-system", "--include-system"])
+        #expect(throws: ValidationError.self) {
+            try conflicting.makeExclusionFilter()
+        }
+        let badPattern = try AppsSubcommand.parse(["--exclude-pattern", "("])
+        #expect(throws: ValidationError.self) {
+            try badPattern.makeExclusionFilter()
+        }
+    }
+
+    @Test(.tags(.fast))
+    func `System app filter excludes built-in and custom patterns`() throws {
+        let apps = [
+            ServiceApplicationInfo(processIdentifier: 1, bundleIdentifier: "com.apple.dock", name: "Dock"),
+            ServiceApplicationInfo(
+                processIdentifier: 2,
+                bundleIdentifier: "com.apple.CoreLocationAgent",
+                name: "Agent"
+            ),
+            ServiceApplicationInfo(processIdentifier: 3, bundleIdentifier: "com.apple.Safari", name: "Safari"),
+            ServiceApplicationInfo(processIdentifier: 4, bundleIdentifier: nil, name: "Slack Helper"),
+        ]
+        let output = UnifiedToolOutput(
+            data: ServiceApplicationListData(applications: apps),
+            summary: .init(brief: "Found 4 running applications", status: .success, counts: ["applications": 4]),
+            metadata: .init(duration: 0)
+        )
+
+        let system = try SystemAppFilter(patterns: AppsSubcommand.exclusionPatterns(
+            hidesSystemApps: true,
+            configured: nil,
+            custom: []
+        ))
+        let filtered = AppsSubcommand.excluding(output, matching: system)
+        #expect(filtered.data.applications.map(\.name) == ["Safari", "Slack Helper"])
+        #expect(filtered.summary.counts["applications"] == 2)
+        #expect(filtered.summary.counts["excludedApplications"] == 2)
+
+        let custom = try SystemAppFilter(patterns: AppsSubcommand.exclusionPatterns(
+            hidesSystemApps: false,
+            configured: ["^com\\.apple\\.dock$"],
+            custom: ["Helper$"]
+        ))
+        #expect(AppsSubcommand.excluding(output, matching: custom).data.applications.map(\.name) == [
+            "Dock", "Agent", "Safari",
+        ])
+    }
+
+    @Test(.tags(.fast))
+    func `Path filters match the executable path, falling back to the bundle path`() throws {
+        let apps = [
+            ServiceApplicationInfo(
+                processIdentifier: 1,
+                bundleIdentifier: "com.tinyspeck.slackmacgap",
+                name: "Slack",
+                bundlePath: "/Applications/Slack.app",
+                executablePath: "/Applications/Slack.app/Contents/MacOS/Slack"
+            ),
+            ServiceApplicationInfo(
+                processIdentifier: 2,
+                bundleIdentifier: nil,
+                name: "devtool",
+                executablePath: "/Users/me/dev/devtool/.build/debug/devtool"
+            ),
+            ServiceApplicationInfo(
+                processIdentifier: 3,
+                bundleIdentifier: "com.apple.Safari",
+                name: "Safari",
+                bundlePath: "/Applications/Safari.app"
+            ),
+            ServiceApplicationInfo(processIdentifier: 4, bundleIdentifier: nil, name: "Unknown"),
+        ]
+        let output = UnifiedToolOutput(
+            data: ServiceApplicationListData(applications: apps),
+            summary: .init(brief: "Found 4 running applications", status: .success, counts: ["applications": 4]),
+            metadata: .init(duration: 0)
+        )
+
+        let dev = try AppsSubcommand.parse(["--filter-by-path", "/users/ME/dev"]).makePathFilter()
+        let filtered = AppsSubcommand.filteringByPath(output, with: dev)
+        #expect(filtered.data.applications.map(\.name) == ["devtool"])
+        #expect(filtered.summary.counts["applications"] == 1)
+
+        let applications = try AppsSubcommand.parse(["--filter-by-path-regex", #"^/Applications/.*\.app"#])
+            .makePathFilter()
+        #expect(AppsSubcommand.filteringByPath(output, with: applications).data.applications.map(\.name) == [
+            "Slack", "Safari",
+        ])
+
+        let both = try ApplicationPathFilter(substring: "slack", regex: "MacOS")
+        #expect(AppsSubcommand.filteringByPath(output, with: both).data.applications.map(\.name) == ["Slack"])
+        let none = try ApplicationPathFilter(substring: nil, regex: nil)
+        #expect(AppsSubcommand.filteringByPath(output, with: none).data.applications.count == 4)
+        #expect(throws: ValidationError.self) {
+            try AppsSubcommand.parse(["--filter-by-path-regex", "("]).makePathFilter()
+        }
+    }
+
+    @Test(.tags(.fast))
+    func `Process sampler reports the current process`() async throws {
+        let pid = ProcessInfo.processInfo.processIdentifier
+        let usage = try await ProcessResourceSampler.sample(pids: [pid, -1], interval: .milliseconds(20))
+
+        let current = try #require(usage[pid])
+        #expect(current.cpuPercent >= 0)
+        #expect(current.memoryRSSMB > 0)
+        #expect(usage[-1] == nil)
+    }
+
     @Test(.tags(.fast))
     func `WindowsSubcommand parsing with required app`() throws {
         // Test parsing windows subcommand with required app
@@ -284,6 +742,60 @@ struct ListCommandTests {
         #expect(json?["window_count"] as? Int == 5)
     }
 
+    @Test(.tags(.fast))
+    func `Sampled application stats encode as numbers`() throws {
+        let app = ServiceApplicationInfo(
+            processIdentifier: 77,
+            bundleIdentifier: "com.example.busy",
+            name: "Busy",
+            cpuPercent: 37.5,
+            memoryRSSMB: 512
+        )
+
+        let data = try JSONEncoder().encode(app)
+        let json = try #require(try JSONSerialization.jsonObject(with: data) as? [String: Any])
+        #expect(json["cpuPercent"] as? Double == 37.5)
+        #expect(json["memoryRSSMB"] as? Int == 512)
+
+        let unsampled = try JSONEncoder().encode(
+            ServiceApplicationInfo(processIdentifier: 78, bundleIdentifier: nil, name: "Idle")
+        )
+        let unsampledJSON = try #require(try JSONSerialization.jsonObject(with: unsampled) as? [String: Any])
+        #expect(unsampledJSON["cpuPercent"] == nil)
+        #expect(unsampledJSON["memoryRSSMB"] == nil)
+    }
+
+    @Test(.tags(.fast))
+    func `Launch time encodes as ISO 8601 with uptime`() throws {
+        let app = ServiceApplicationInfo(
+            processIdentifier: 90,
+            bundleIdentifier: "com.example.server",
+            name: "Server",
+            launchTime: "2026-10-14T08:30:00Z",
+            uptimeSeconds: 9000
+        )
+
+        let data = try JSONEncoder().encode(app)
+        let json = try #require(try JSONSerialization.jsonObject(with: data) as? [String: Any])
+        #expect(json["launchTime"] as? String == "2026-10-14T08:30:00Z")
+        #expect(json["uptimeSeconds"] as? Int == 9000)
+
+        let unknown = try JSONEncoder().encode(
+            ServiceApplicationInfo(processIdentifier: 91, bundleIdentifier: nil, name: "Unknown")
+        )
+        let unknownJSON = try #require(try JSONSerialization.jsonObject(with: unknown) as? [String: Any])
+        #expect(unknownJSON["launchTime"] == nil)
+        #expect(unknownJSON["uptimeSeconds"] == nil)
+    }
+
+    @Test(.tags(.fast))
+    func `Uptime renders as hours and minutes`() {
+        #expect(CLIFormatter.formatUptime(0) == "0h 0m")
+        #expect(CLIFormatter.formatUptime(59) == "0h 0m")
+        #expect(CLIFormatter.formatUptime(9000) == "2h 30m")
+        #expect(CLIFormatter.formatUptime(93780) == "26h 3m")
+    }
+
     @Test(.tags(.fast))
     func `ApplicationListData JSON encoding`() throws {
         // Test ApplicationListData JSON encoding
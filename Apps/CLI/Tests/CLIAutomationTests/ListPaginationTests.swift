@@ -0,0 +1,79 @@
+import Commander
+import Foundation
+import PeekabooCore
+import Testing
+@testable import PeekabooCLI
+
+@Suite(.tags(.unit))
+@MainActor
+struct ListPaginationTests {
+    @Test(.tags(.fast))
+    func `Walking offset by limit covers the full list exactly once`() throws {
+        let items = Array(0..<23)
+        for limit in [1, 5, 10, 23, 50] {
+            var collected: [Int] = []
+            var offset = 0
+            while offset < items.count {
+                let page = try ListPagination.resolve(limit: limit, offset: offset, page: nil, pageSize: nil)
+                collected += page.page(of: items)
+                offset += limit
+            }
+            #expect(collected == items)
+        }
+    }
+
+    @Test(.tags(.fast))
+    func `Pages translate to offsets and past-the-end pages are empty`() throws {
+        let third = try ListPagination.resolve(limit: nil, offset: nil, page: 3, pageSize: 10)
+        #expect(third == ListPagination(offset: 20, limit: 10))
+        #expect(third.page(of: Array(0..<23)) == [20, 21, 22])
+        #expect(try ListPagination.resolve(limit: nil, offset: nil, page: nil, pageSize: 5).offset == 0)
+        #expect(ListPagination(offset: 30, limit: 10).page(of: Array(0..<23)).isEmpty)
+        #expect(ListPagination(offset: 0, limit: 0).page(of: Array(0..<23)).isEmpty)
+        #expect(!ListPagination.all.isPaginating)
+    }
+
+    @Test(.tags(.fast))
+    func `Invalid combinations are rejected`() {
+        let invalid: [(Int?, Int?, Int?, Int?)] = [
+            (-1, nil, nil, nil),
+            (nil, -1, nil, nil),
+            (nil, nil, 2, nil),
+            (nil, nil, 0, 10),
+            (nil, nil, 1, 0),
+            (10, nil, 1, 10),
+            (nil, 5, nil, 10),
+        ]
+        for (limit, offset, page, pageSize) in invalid {
+            #expect(throws: ValidationError.self) {
+                try ListPagination.resolve(limit: limit, offset: offset, page: page, pageSize: pageSize)
+            }
+        }
+    }
+
+    @Test(.tags(.fast))
+    func `Apps and windows parse pagination options`() throws {
+        let apps = try ListCommand.AppsSubcommand.parse(["--limit", "20", "--offset", "40"])
+        #expect(try apps.resolvePagination() == ListPagination(offset: 40, limit: 20))
+
+        let windows = try ListCommand.WindowsSubcommand.parse(["--app", "Finder", "--page", "2", "--page-size", "5"])
+        #expect(try windows.resolvePagination() == ListPagination(offset: 5, limit: 5))
+    }
+
+    @Test(.tags(.fast))
+    func `Paged output keeps the sort order and reports the total`() {
+        let applications = (0..<5).map {
+            ServiceApplicationInfo(processIdentifier: Int32($0), bundleIdentifier: nil, name: "App \($0)")
+        }
+        let output = UnifiedToolOutput(
+            data: ServiceApplicationListData(applications: applications),
+            summary: .init(brief: "Found 5 running applications", status: .success),
+            metadata: .init(duration: 0)
+        )
+
+        let paged = ListCommand.AppsSubcommand.paginated(output, by: ListPagination(offset: 1, limit: 2))
+        #expect(paged.data.applications.map(\.name) == ["App 1", "App 2"])
+        #expect(paged.summary.brief == "Showing 2 of 5 running applications from offset 1")
+        #expect(ListCommand.AppsSubcommand.paginated(output, by: .all).data.applications.count == 5)
+    }
+}
@@ -0,0 +1,73 @@
+import Commander
+import Foundation
+import Testing
+@testable import PeekabooCLI
+
+private typealias ScreensSubcommand = ListCommand.ScreensSubcommand
+
+@Suite(.tags(.unit))
+@MainActor
+struct ListScreensWatchTests {
+    @Test(.tags(.fast))
+    func `Watch options parse and validate`() throws {
+        let command = try ScreensSubcommand.parse(["--watch", "--watch-interval-ms", "500"])
+        #expect(command.watch)
+        #expect(command.watchIntervalMs == 500)
+        try command.validateWatchOptions()
+
+        let orphanInterval = try ScreensSubcommand.parse(["--watch-interval-ms", "500"])
+        #expect(throws: ValidationError.self) { try orphanInterval.validateWatchOptions() }
+
+        let zero = try ScreensSubcommand.parse(["--watch", "--watch-interval-ms", "0"])
+        #expect(throws: ValidationError.self) { try zero.validateWatchOptions() }
+    }
+
+    @Test(.tags(.fast))
+    func `Diff reports connected and disconnected displays by ID`() {
+        let builtIn = Self.display(id: 1, index: 0, name: "Built-in Retina Display")
+        let dell = Self.display(id: 2, index: 1, name: "DELL U2720Q")
+        let lg = Self.display(id: 3, index: 1, name: "LG HDR 4K")
+
+        #expect(ScreensSubcommand.screenEvents(from: [builtIn, dell], to: [builtIn, lg]) == [
+            .disconnected(dell),
+            .connected(lg),
+        ])
+        // Re-indexing alone is not a change.
+        let reindexed = Self.display(id: 2, index: 0, name: "DELL U2720Q")
+        #expect(ScreensSubcommand.screenEvents(from: [builtIn, dell], to: [reindexed]) == [.disconnected(builtIn)])
+        #expect(ScreensSubcommand.screenEvents(from: [builtIn], to: [builtIn]).isEmpty)
+    }
+
+    @Test(.tags(.fast))
+    func `Events render as prefixed lines and typed JSON`() throws {
+        let hdmi = Self.display(id: 7, index: 1, name: "HDMI-1")
+        #expect(ScreensSubcommand.describe(.connected(hdmi)) == "+HDMI-1 (1920×1080)")
+        #expect(ScreensSubcommand.describe(.disconnected(hdmi)) == "-HDMI-1")
+
+        let disconnected = try JSONSerialization.jsonObject(
+            with: JSONEncoder().encode(ScreenListEvent.disconnected(hdmi))
+        ) as? [String: Any]
+        #expect(disconnected?["type"] as? String == "display_disconnected")
+        #expect(disconnected?["name"] as? String == "HDMI-1")
+        #expect(disconnected?["display_id"] as? Int == 7)
+
+        let connected = try JSONSerialization.jsonObject(
+            with: JSONEncoder().encode(ScreenListEvent.connected(hdmi))
+        ) as? [String: Any]
+        #expect(connected?["type"] as? String == "display_connected")
+        #expect((connected?["display"] as? [String: Any])?["name"] as? String == "HDMI-1")
+    }
+
+    private static func display(id: Int, index: Int, name: String) -> ScreenListData.ScreenDetails {
+        ScreenListData.ScreenDetails(
+            index: index,
+            name: name,
+            resolution: .init(width: 1920, height: 1080),
+            position: .init(x: index * 1920, y: 0),
+            visibleArea: .init(width: 1920, height: 1040),
+            isPrimary: index == 0,
+            scaleFactor: 1,
+            displayID: id
+        )
+    }
+}
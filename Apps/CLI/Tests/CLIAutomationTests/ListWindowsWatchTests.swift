@@ -0,0 +1,74 @@
+import Commander
+import CoreGraphics
+import Foundation
+import PeekabooCore
+import Testing
+@testable import PeekabooCLI
+
+private typealias WindowsSubcommand = ListCommand.WindowsSubcommand
+
+@Suite(.tags(.unit))
+@MainActor
+struct ListWindowsWatchTests {
+    @Test(.tags(.fast))
+    func `Watch options parse and validate`() throws {
+        let command = try WindowsSubcommand.parse(["--app", "Mail", "--watch", "--watch-interval-ms", "250"])
+        #expect(command.watch)
+        #expect(command.watchIntervalMs == 250)
+        try command.validateWatchOptions()
+
+        let orphanInterval = try WindowsSubcommand.parse(["--app", "Mail", "--watch-interval-ms", "250"])
+        #expect(throws: ValidationError.self) { try orphanInterval.validateWatchOptions() }
+
+        let zero = try WindowsSubcommand.parse(["--app", "Mail", "--watch", "--watch-interval-ms", "0"])
+        #expect(throws: ValidationError.self) { try zero.validateWatchOptions() }
+
+        let csv = try WindowsSubcommand.parse(["--app", "Mail", "--watch", "--export-csv"])
+        #expect(throws: ValidationError.self) { try csv.validateWatchOptions() }
+    }
+
+    @Test(.tags(.fast))
+    func `Diff reports opened, closed, and retitled windows by ID`() {
+        let previous = [
+            Self.window(1, "Inbox"),
+            Self.window(2, "Draft"),
+            Self.window(3, "Settings"),
+        ]
+        let current = [
+            Self.window(4, "New Message"),
+            Self.window(1, "Inbox (3)"),
+            Self.window(3, "Settings"),
+        ]
+
+        #expect(WindowsSubcommand.windowEvents(from: previous, to: current) == [
+            .closed(windowID: 2),
+            .opened(Self.window(4, "New Message")),
+            .retitled(windowID: 1, newTitle: "Inbox (3)"),
+        ])
+        #expect(WindowsSubcommand.windowEvents(from: current, to: current).isEmpty)
+    }
+
+    @Test(.tags(.fast))
+    func `Events render as prefixed lines and typed JSON`() throws {
+        let previous = [Self.window(2, "Draft")]
+        #expect(WindowsSubcommand.describe(.opened(Self.window(4, "")), previous: previous) == "+ 4 [Untitled]")
+        #expect(WindowsSubcommand.describe(.closed(windowID: 2), previous: previous) == "- 2 Draft")
+        #expect(WindowsSubcommand.describe(.retitled(windowID: 2, newTitle: "Sent"), previous: previous) == "~ 2 Sent")
+
+        let closed = try JSONSerialization.jsonObject(
+            with: JSONEncoder().encode(WindowListEvent.closed(windowID: 2))
+        ) as? [String: Any]
+        #expect(closed?["type"] as? String == "window_closed")
+        #expect(closed?["window_id"] as? Int == 2)
+
+        let opened = try JSONSerialization.jsonObject(
+            with: JSONEncoder().encode(WindowListEvent.opened(Self.window(4, "New")))
+        ) as? [String: Any]
+        #expect(opened?["type"] as? String == "window_opened")
+        #expect((opened?["window"] as? [String: Any])?["window_id"] as? Int == 4)
+    }
+
+    private static func window(_ id: Int, _ title: String) -> ServiceWindowInfo {
+        ServiceWindowInfo(windowID: id, title: title, bounds: CGRect(x: 0, y: 0, width: 800, height: 600))
+    }
+}
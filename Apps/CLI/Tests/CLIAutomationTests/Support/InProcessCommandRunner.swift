@@ -302,6 +302,45 @@ enum ExternalCommandRunner {
         return result
     }
 
+    /// Runs the CLI like `runPeekabooCLI`, but sends `signal` once it has run for `delay` seconds.
+    @discardableResult
+    static func runPeekabooCLI(
+        _ arguments: [String],
+        interruptingWith signal: Int32,
+        after delay: TimeInterval,
+        allowedExitCodes: Set<Int32> = [0],
+        environment: [String: String] = ProcessInfo.processInfo.environment
+    ) throws -> CommandRunResult {
+        guard let executablePath = environment["PEEKABOO_CLI_PATH"], !executablePath.isEmpty else {
+            throw Error.peekabooCLIPathMissing
+        }
+
+        let process = Process()
+        process.executableURL = URL(fileURLWithPath: executablePath)
+        process.arguments = arguments
+        process.environment = environment
+
+        let stdoutPipe = Pipe()
+        let stderrPipe = Pipe()
+        process.standardOutput = stdoutPipe
+        process.standardError = stderrPipe
+
+        try process.run()
+        Thread.sleep(forTimeInterval: delay)
+        if process.isRunning {
+            kill(process.processIdentifier, signal)
+        }
+        process.waitUntilExit()
+
+        let result = CommandRunResult(
+            stdout: String(decoding: stdoutPipe.fileHandleForReading.readDataToEndOfFile(), as: UTF8.self),
+            stderr: String(decoding: stderrPipe.fileHandleForReading.readDataToEndOfFile(), as: UTF8.self),
+            exitStatus: process.terminationStatus
+        )
+        try result.validateExitStatus(allowedExitCodes: allowedExitCodes, arguments: ["peekaboo"] + arguments)
+        return result
+    }
+
     static func decodeJSONResponse<T: Decodable>(
         from result: CommandRunResult,
         as type: T.Type
@@ -458,8 +458,9 @@ final class StubApplicationService: ApplicationServiceProtocol {
         for appIdentifier: String,
         timeout: Float?
     ) async throws -> UnifiedToolOutput<ServiceWindowListData> {
+        let pid = Self.parsePID(appIdentifier)
         let targetApp = self.applications.first {
-            $0.name == appIdentifier || $0.bundleIdentifier == appIdentifier
+            $0.name == appIdentifier || $0.bundleIdentifier == appIdentifier || $0.processIdentifier == pid
         }
         let windows = self.windowsByApp[appIdentifier]
             ?? targetApp.flatMap { self.windowsByApp[$0.name] } ?? []
@@ -0,0 +1,69 @@
+import Foundation
+import PeekabooFoundation
+import Testing
+@testable import PeekabooCLI
+
+@Suite(.tags(.safe))
+@MainActor
+struct WaitForFileCommandTests {
+    @Test
+    func `Options parse with a default timeout`() throws {
+        let command = try WaitForFileCommand.parse(["--path", "/tmp/shot.png"])
+        #expect(command.path == "/tmp/shot.png")
+        #expect(command.resolvedTimeoutMs == WaitForFileCommand.defaultTimeoutMs)
+
+        let custom = try WaitForFileCommand.parse(["--path", "/tmp/shot.png", "--timeout-ms", "250", "--json"])
+        #expect(custom.resolvedTimeoutMs == 250)
+        #expect(custom.jsonOutput)
+    }
+
+    @Test
+    func `Returns once another thread creates the file`() async throws {
+        let url = Self.temporaryURL()
+        defer { try? FileManager.default.removeItem(at: url) }
+        Thread.detachNewThread {
+            Thread.sleep(forTimeInterval: 0.2)
+            FileManager.default.createFile(atPath: url.path, contents: Data("png".utf8))
+        }
+
+        let result = try await WaitForFileCommand.waitForFile(at: url.path, timeoutMs: 5000)
+        #expect(result.path == url.path)
+        #expect(result.waited_ms >= 150)
+        #expect(result.waited_ms < 5000)
+    }
+
+    @Test
+    func `Existing files return immediately`() async throws {
+        let url = Self.temporaryURL()
+        defer { try? FileManager.default.removeItem(at: url) }
+        FileManager.default.createFile(atPath: url.path, contents: Data())
+
+        let result = try await WaitForFileCommand.waitForFile(at: url.path, timeoutMs: 1000)
+        #expect(result.waited_ms < 1000)
+    }
+
+    @Test
+    func `Missing files and directories time out`() async throws {
+        let missing = Self.temporaryURL()
+        let error = try await #require(throws: PeekabooError.self) {
+            try await WaitForFileCommand.waitForFile(at: missing.path, timeoutMs: 100)
+        }
+        guard case let .timedOut(operation, timeoutMs) = error else {
+            Issue.record("Expected timedOut, got \(error)")
+            return
+        }
+        #expect(operation.contains(missing.lastPathComponent))
+        #expect(timeoutMs == 100)
+
+        let directory = Self.temporaryURL()
+        try FileManager.default.createDirectory(at: directory, withIntermediateDirectories: true)
+        defer { try? FileManager.default.removeItem(at: directory) }
+        await #expect(throws: PeekabooError.self) {
+            try await WaitForFileCommand.waitForFile(at: directory.path, timeoutMs: 100)
+        }
+    }
+
+    private static func temporaryURL() -> URL {
+        FileManager.default.temporaryDirectory.appendingPathComponent("peekaboo-wait-\(UUID().uuidString)")
+    }
+}
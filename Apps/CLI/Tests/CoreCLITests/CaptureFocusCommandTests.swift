@@ -0,0 +1,78 @@
+import Commander
+import Foundation
+import PeekabooCore
+import Testing
+@testable import PeekabooCLI
+
+@Suite(.tags(.unit))
+@MainActor
+struct CaptureFocusCommandTests {
+    @Test(.tags(.fast))
+    func `Captures a window only after it holds focus for the debounce`() {
+        var debouncer = FocusChangeDebouncer(debounceMs: 500)
+
+        #expect(debouncer.observe(windowID: 1, atMs: 0) == nil)
+        #expect(debouncer.observe(windowID: 1, atMs: 400) == nil)
+        #expect(debouncer.observe(windowID: 1, atMs: 500) == 1)
+        #expect(debouncer.observe(windowID: 1, atMs: 900) == nil)
+
+        // A window that only flashes past is never captured.
+        #expect(debouncer.observe(windowID: 2, atMs: 1000) == nil)
+        #expect(debouncer.observe(windowID: 3, atMs: 1100) == nil)
+        #expect(debouncer.observe(windowID: 3, atMs: 1600) == 3)
+
+        // Returning to an earlier window counts as a new focus change.
+        #expect(debouncer.observe(windowID: 1, atMs: 1700) == nil)
+        #expect(debouncer.observe(windowID: 1, atMs: 2200) == 1)
+    }
+
+    @Test(.tags(.fast))
+    func `Losing focus restarts the debounce`() {
+        var debouncer = FocusChangeDebouncer(debounceMs: 500)
+
+        #expect(debouncer.observe(windowID: 7, atMs: 0) == nil)
+        #expect(debouncer.observe(windowID: nil, atMs: 300) == nil)
+        #expect(debouncer.observe(windowID: 7, atMs: 400) == nil)
+        #expect(debouncer.observe(windowID: 7, atMs: 600) == nil)
+        #expect(debouncer.observe(windowID: 7, atMs: 900) == 7)
+    }
+
+    @Test(.tags(.fast))
+    func `Filenames number focus changes and sanitize titles`() {
+        let edited = CaptureFocusCommand.filename(index: 3, title: "README.md — Edited", format: .png)
+        #expect(edited == "3_README-md-Edited.png")
+        #expect(CaptureFocusCommand.filename(index: 1, title: "", format: .jpg) == "1_untitled.jpg")
+    }
+
+    @Test(.tags(.fast))
+    func `Options parse and validate`() throws {
+        let command = try CaptureFocusCommand.parse([
+            "--poll-interval-ms", "50", "--debounce-ms", "0", "--max-captures", "4", "--format", "jpg",
+        ])
+        #expect(command.pollIntervalMs == 50)
+        #expect(command.debounceMs == 0)
+        #expect(command.maxCaptures == 4)
+        #expect(command.format == .jpg)
+        try command.validate()
+
+        let zeroPoll = try CaptureFocusCommand.parse(["--poll-interval-ms", "0"])
+        #expect(throws: ValidationError.self) {
+            try zeroPoll.validate()
+        }
+    }
+
+    @Test(.tags(.fast))
+    func `JSON lines are compact and typed`() throws {
+        let record = try JSONOutputFormat.json.encodeRecord(FocusChangeCapture(
+            index: 1,
+            window_id: 42,
+            title: "Docs",
+            path: "/tmp/1_Docs.png",
+            captured_at: "2026-01-01T00:00:00Z"
+        ))
+        let line = String(decoding: record, as: UTF8.self)
+        #expect(line.firstIndex(of: "\n") == line.index(before: line.endIndex))
+        #expect(line.contains(#""type":"capture""#))
+        #expect(line.contains(#""path":"/tmp/1_Docs.png""#))
+    }
+}
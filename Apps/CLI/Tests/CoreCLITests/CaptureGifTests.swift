@@ -0,0 +1,82 @@
+import Commander
+import CoreGraphics
+import Foundation
+import ImageIO
+import PeekabooCore
+import Testing
+@testable import PeekabooCLI
+
+@Suite(.tags(.imageCapture, .unit))
+@MainActor
+struct CaptureGifTests {
+    @Test(.tags(.fast))
+    func `Kept frames become a looping animated GIF`() throws {
+        let directory = FileManager.default.temporaryDirectory.appendingPathComponent("peekaboo-gif-\(UUID())")
+        defer { try? FileManager.default.removeItem(at: directory) }
+        let framePaths = try Self.writeFrames(in: directory)
+        let url = directory.appendingPathComponent("capture.gif")
+
+        try AnimatedGIFWriter(framesPerSecond: 4, loops: true).write(framePaths: framePaths, to: url)
+
+        let source = try #require(CGImageSourceCreateWithURL(url as CFURL, nil))
+        #expect(CGImageSourceGetCount(source) == 3)
+        let image = try #require(CGImageSourceCreateImageAtIndex(source, 2, nil))
+        #expect(image.width == 4 && image.height == 3)
+        let frame = try #require(Self.gifProperties(CGImageSourceCopyPropertiesAtIndex(source, 1, nil)))
+        let delay = try #require(frame[kCGImagePropertyGIFUnclampedDelayTime] as? Double)
+        #expect(abs(delay - 0.25) < 0.01)
+        let file = try #require(Self.gifProperties(CGImageSourceCopyProperties(source, nil)))
+        #expect(file[kCGImagePropertyGIFLoopCount] as? Int == 0)
+    }
+
+    @Test(.tags(.fast))
+    func `--no-gif-loop plays the animation once`() throws {
+        let directory = FileManager.default.temporaryDirectory.appendingPathComponent("peekaboo-gif-\(UUID())")
+        defer { try? FileManager.default.removeItem(at: directory) }
+        let url = directory.appendingPathComponent("capture.gif")
+
+        let framePaths = try Self.writeFrames(in: directory)
+        try AnimatedGIFWriter(framesPerSecond: 2, loops: false).write(framePaths: framePaths, to: url)
+
+        let source = try #require(CGImageSourceCreateWithURL(url as CFURL, nil))
+        #expect(Self.gifProperties(CGImageSourceCopyProperties(source, nil))?[kCGImagePropertyGIFLoopCount] == nil)
+    }
+
+    @Test(.tags(.fast))
+    func `GIF options need --gif-out and a sensible rate`() throws {
+        #expect(throws: ValidationError.self) {
+            try CaptureLiveCommand.parse(["--gif-fps", "5"]).validateGifOptions()
+        }
+        #expect(throws: ValidationError.self) {
+            try CaptureLiveCommand.parse(["--no-gif-loop"]).validateGifOptions()
+        }
+        #expect(throws: ValidationError.self) {
+            try CaptureLiveCommand.parse(["--gif-out", "/tmp/a.gif", "--gif-fps", "0"]).validateGifOptions()
+        }
+        let command = try CaptureLiveCommand.parse(["--gif-out", "/tmp/a.gif", "--gif-fps", "10", "--no-gif-loop"])
+        try command.validateGifOptions()
+        #expect(command.gifFps == 10 && command.noGifLoop)
+    }
+
+    @Test(.tags(.fast))
+    func `A session without kept frames has no GIF to write`() {
+        let url = FileManager.default.temporaryDirectory.appendingPathComponent("peekaboo-gif-\(UUID()).gif")
+        #expect(throws: CaptureError.self) {
+            try AnimatedGIFWriter(framesPerSecond: 2, loops: true).write(framePaths: [], to: url)
+        }
+    }
+
+    private static func writeFrames(in directory: URL) throws -> [String] {
+        let colors: [(UInt8, UInt8, UInt8)] = [(255, 0, 0), (0, 255, 0), (0, 0, 255)]
+        return try colors.enumerated().map { index, color in
+            let url = directory.appendingPathComponent("frame-\(index).png")
+            let image = try TestImages.solid(width: 4, height: 3, red: color.0, green: color.1, blue: color.2)
+            try CapturedImageIO.write(image, to: url, format: .png)
+            return url.path
+        }
+    }
+
+    private static func gifProperties(_ properties: CFDictionary?) -> [CFString: Any]? {
+        (properties as? [CFString: Any])?[kCGImagePropertyGIFDictionary] as? [CFString: Any]
+    }
+}
@@ -54,6 +54,33 @@ struct CommanderBinderAppConfigTests {
         #expect(command.openTargets == ["https://example.com", "~/Documents/report.pdf"])
     }
 
+    @Test
+    func `App launch binding with launch timeout and arguments`() throws {
+        let parsed = ParsedValues(
+            positional: ["Google Chrome"],
+            options: [
+                "launchTimeoutMs": ["30000"],
+                "launchArgs": [#"--incognito  --user-data-dir "/tmp/chrome profile""#],
+            ],
+            flags: []
+        )
+        let command = try CommanderCLIBinder.instantiateCommand(
+            ofType: AppCommand.LaunchSubcommand.self,
+            parsedValues: parsed
+        )
+        #expect(command.launchTimeoutMs == 30000)
+        #expect(command.shouldWaitUntilReady == true)
+        #expect(try command.launchArguments == ["--incognito", "--user-data-dir", "/tmp/chrome profile"])
+
+        let unterminated = try CommanderCLIBinder.instantiateCommand(
+            ofType: AppCommand.LaunchSubcommand.self,
+            parsedValues: ParsedValues(positional: ["Safari"], options: ["launchArgs": [#"--name "open"#]], flags: [])
+        )
+        #expect(throws: ValidationError.self) {
+            try unterminated.launchArguments
+        }
+    }
+
     @Test
     func `App launch binding with --bundle-id only`() throws {
         let parsed = ParsedValues(
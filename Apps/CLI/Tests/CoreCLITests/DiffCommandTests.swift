@@ -0,0 +1,187 @@
+import Commander
+import CoreGraphics
+import Foundation
+import Testing
+@testable import PeekabooCLI
+
+@Suite(.tags(.imageCapture, .unit))
+struct DiffCommandTests {
+    @Test(.tags(.fast))
+    func `Identical images score one with no changes`() throws {
+        let image = try Self.checkerboard(width: 32, height: 24)
+
+        let diff = try ImageDiff.compare(image, image, channel: .luminance)
+
+        #expect(abs(diff.score - 1) < 1e-9)
+        #expect(diff.changedPixels == 0)
+        #expect(diff.totalPixels == 32 * 24)
+        #expect(diff.changedBounds == nil)
+    }
+
+    @Test(.tags(.fast))
+    func `Changes lower SSIM and report their bounding box`() throws {
+        let baseline = try Self.checkerboard(width: 32, height: 24)
+        let candidate = try Self.painting(baseline, rect: CGRect(x: 10, y: 4, width: 6, height: 3), red: 255)
+
+        let diff = try ImageDiff.compare(baseline, candidate, channel: .rgb)
+
+        #expect(diff.score < 0.99)
+        #expect(diff.changedPixels > 0)
+        let box = try #require(diff.changedBounds)
+        #expect(box.x >= 10 && box.x + box.width <= 16)
+        #expect(box.y >= 4 && box.y + box.height <= 7)
+    }
+
+    @Test(.tags(.fast))
+    func `Alpha channel ignores color-only changes`() throws {
+        let baseline = try TestImages.solid(width: 16, height: 16, red: 0, green: 0, blue: 255)
+        let candidate = try TestImages.solid(width: 16, height: 16, red: 255, green: 0, blue: 0)
+
+        #expect(try ImageDiff.compare(baseline, candidate, channel: .alpha).changedPixels == 0)
+        #expect(try ImageDiff.compare(baseline, candidate, channel: .rgb).changedPixels == 16 * 16)
+    }
+
+    @Test(.tags(.fast))
+    func `Mismatched sizes are rejected`() throws {
+        let small = try TestImages.solid(width: 8, height: 8, red: 0, green: 0, blue: 0)
+        let large = try TestImages.solid(width: 16, height: 8, red: 0, green: 0, blue: 0)
+
+        #expect(throws: ValidationError.self) {
+            try ImageDiff.compare(small, large, channel: .luminance)
+        }
+    }
+
+    @Test(.tags(.fast), arguments: DiffAlgorithm.allCases)
+    func `Every algorithm scores identical images one and edits lower`(algorithm: DiffAlgorithm) throws {
+        let baseline = try Self.checkerboard(width: 64, height: 48)
+        let candidate = try Self.painting(baseline, rect: CGRect(x: 0, y: 0, width: 40, height: 30), red: 255)
+
+        let same = try ImageDiff.compare(baseline, baseline, channel: .rgb, algorithm: algorithm)
+        let edited = try ImageDiff.compare(baseline, candidate, channel: .rgb, algorithm: algorithm)
+
+        #expect(abs(same.score - 1) < 1e-9)
+        #expect(edited.score < 1)
+        #expect(edited.changedPixels == same.changedPixels + 40 * 30)
+    }
+
+    @Test(.tags(.fast))
+    func `Histogram ignores layout while pixelwise does not`() throws {
+        let baseline = try Self.checkerboard(width: 32, height: 32)
+        // Shifting by one 2px cell swaps dark and light squares but keeps the colour counts identical.
+        var bytes = try CapturedImageIO.rgbaBytes(of: baseline)
+        bytes = Array(bytes[(2 * 4)...] + bytes[..<(2 * 4)])
+        let shifted = try CapturedImageIO.makeImage(rgbaBytes: bytes, width: 32, height: 32)
+
+        let histogram = try ImageDiff.compare(baseline, shifted, channel: .luminance, algorithm: .histogram)
+        let pixelwise = try ImageDiff.compare(baseline, shifted, channel: .luminance, algorithm: .pixelwise)
+
+        #expect(abs(histogram.score - 1) < 1e-9)
+        #expect(pixelwise.score < 0.9)
+    }
+
+    @Test(.tags(.fast))
+    func `Perceptual hash tolerates uniform brightness shifts`() throws {
+        let baseline = try Self.noise(width: 64, height: 64)
+        let brighter = try CapturedImageIO.makeImage(
+            rgbaBytes: CapturedImageIO.rgbaBytes(of: baseline).enumerated().map { index, value in
+                index % 4 == 3 ? value : value + 10
+            },
+            width: 64,
+            height: 64
+        )
+
+        let diff = try ImageDiff.compare(baseline, brighter, channel: .luminance, algorithm: .phash)
+        #expect(diff.score > 0.95)
+        #expect(diff.changedPixels > 0)
+    }
+
+    @Test(.tags(.fast))
+    func `Highlight paints only changed pixels`() throws {
+        let image = try TestImages.solid(width: 4, height: 4, red: 255, green: 255, blue: 255)
+        var mask = [Bool](repeating: false, count: 16)
+        mask[5] = true
+
+        let highlighted = try ImageDiff.highlight(image, mask: mask, color: #require(HexColor(hex: "#FF00FF")))
+        let pixels = try TestImages.rgbaPixels(of: highlighted)
+
+        #expect(pixels(1, 1) == [255, 0, 255, 255])
+        #expect(pixels(2, 1) == [255, 255, 255, 255])
+    }
+
+    @Test(.tags(.fast))
+    @MainActor
+    func `Parses diff options`() throws {
+        let command = try DiffCommand.parse([
+            "a.png", "b.png",
+            "--ssim-threshold", "0.995",
+            "--fail-on-change",
+            "--diff-channel", "RGB",
+            "--output", "/tmp/diff.png",
+            "--highlight-color", "#00FF00",
+            "--algorithm", "PHASH",
+        ])
+
+        #expect(command.baseline == "a.png")
+        #expect(command.candidate == "b.png")
+        #expect(command.ssimThreshold == 0.995)
+        #expect(command.failOnChange)
+        #expect(command.diffChannel == .rgb)
+        #expect(command.outputPath == "/tmp/diff.png")
+        #expect(command.highlightColor == "#00FF00")
+        #expect(command.algorithm == .phash)
+    }
+
+    @Test(.tags(.fast))
+    @MainActor
+    func `Rejects out-of-range thresholds before loading images`() throws {
+        let command = try DiffCommand.parse(["missing-a.png", "missing-b.png", "--ssim-threshold", "1.5"])
+        #expect(throws: ValidationError.self) {
+            try command.compare()
+        }
+
+        let orphanColor = try DiffCommand.parse(["a.png", "b.png", "--highlight-color", "#FFFFFF"])
+        #expect(throws: ValidationError.self) {
+            try orphanColor.compare()
+        }
+    }
+
+    private static func checkerboard(width: Int, height: Int) throws -> CGImage {
+        var bytes = [UInt8](repeating: 255, count: width * height * 4)
+        for y in 0..<height {
+            for x in 0..<width where (x / 2 + y / 2).isMultiple(of: 2) {
+                let offset = (y * width + x) * 4
+                bytes[offset] = 20
+                bytes[offset + 1] = 20
+                bytes[offset + 2] = 20
+            }
+        }
+        return try CapturedImageIO.makeImage(rgbaBytes: bytes, width: width, height: height)
+    }
+
+    /// Deterministic gray noise in `0..<200`, so brightness shifts never clip.
+    private static func noise(width: Int, height: Int) throws -> CGImage {
+        var state: UInt32 = 0x2545_F491
+        var bytes = [UInt8](repeating: 255, count: width * height * 4)
+        for index in 0..<(width * height) {
+            state = state &* 1_664_525 &+ 1_013_904_223
+            let value = UInt8(state >> 24) % 200
+            bytes[index * 4] = value
+            bytes[index * 4 + 1] = value
+            bytes[index * 4 + 2] = value
+        }
+        return try CapturedImageIO.makeImage(rgbaBytes: bytes, width: width, height: height)
+    }
+
+    private static func painting(_ image: CGImage, rect: CGRect, red: UInt8) throws -> CGImage {
+        var bytes = try CapturedImageIO.rgbaBytes(of: image)
+        for y in Int(rect.minY)..<Int(rect.maxY) {
+            for x in Int(rect.minX)..<Int(rect.maxX) {
+                let offset = (y * image.width + x) * 4
+                bytes[offset] = red
+                bytes[offset + 1] = 0
+                bytes[offset + 2] = 0
+            }
+        }
+        return try CapturedImageIO.makeImage(rgbaBytes: bytes, width: image.width, height: image.height)
+    }
+}
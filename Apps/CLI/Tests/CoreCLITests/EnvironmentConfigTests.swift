@@ -0,0 +1,70 @@
+import Commander
+import Foundation
+import PeekabooCore
+import Testing
+@testable import PeekabooCLI
+
+@Suite(.tags(.unit))
+struct EnvironmentConfigTests {
+    @Test(.tags(.fast))
+    func `Blank values count as unset`() throws {
+        let environment = [
+            "PEEKABOO_OUTPUT_PATH": "  ",
+            "PEEKABOO_FORMAT": "",
+            "PEEKABOO_QUALITY": " ",
+            "PEEKABOO_JSON_OUTPUT": "",
+        ]
+        #expect(EnvironmentConfig.outputPath(environment: environment) == nil)
+        #expect(try EnvironmentConfig.imageFormat(environment: environment) == nil)
+        #expect(try EnvironmentConfig.jpegQuality(environment: environment) == nil)
+        #expect(EnvironmentConfig.jsonOutput(environment: environment) == false)
+    }
+
+    @Test(.tags(.fast))
+    func `Parses format and quality`() throws {
+        #expect(try EnvironmentConfig.imageFormat(environment: ["PEEKABOO_FORMAT": "JPEG"]) == .jpg)
+        #expect(try EnvironmentConfig.imageFormat(environment: ["PEEKABOO_FORMAT": "png"]) == .png)
+        #expect(try EnvironmentConfig.jpegQuality(environment: ["PEEKABOO_QUALITY": "75"]) == 0.75)
+
+        #expect(throws: ValidationError.self) {
+            try EnvironmentConfig.imageFormat(environment: ["PEEKABOO_FORMAT": "gif"])
+        }
+        for invalid in ["0", "101", "high"] {
+            #expect(throws: ValidationError.self) {
+                try EnvironmentConfig.jpegQuality(environment: ["PEEKABOO_QUALITY": invalid])
+            }
+        }
+    }
+
+    @Test(.tags(.fast))
+    func `JSON output accepts common truthy values`() {
+        for value in ["1", "true", "YES"] {
+            #expect(EnvironmentConfig.jsonOutput(environment: ["PEEKABOO_JSON_OUTPUT": value]))
+        }
+        #expect(EnvironmentConfig.jsonOutput(environment: ["PEEKABOO_JSON_OUTPUT": "0"]) == false)
+
+        let options = CommandRuntimeOptions().applyingEnvironmentOverrides(environment: [
+            "PEEKABOO_JSON_OUTPUT": "true",
+        ])
+        #expect(options.jsonOutput)
+    }
+
+    @Test(.tags(.fast))
+    @MainActor
+    func `Print env vars lists every supported variable with current values`() throws {
+        let command = try ImageCommand.parse(["--print-env-vars"])
+        #expect(command.printEnvVars)
+
+        let result = ImageCommand.environmentVariables(environment: ["PEEKABOO_OUTPUT_PATH": "/tmp/shots"])
+        #expect(result.variables.map(\.name) == [
+            "PEEKABOO_OUTPUT_PATH",
+            "PEEKABOO_FORMAT",
+            "PEEKABOO_QUALITY",
+            "PEEKABOO_JSON_OUTPUT",
+            "PEEKABOO_PROFILE",
+        ])
+        #expect(result.variables.first?.value == "/tmp/shots")
+        #expect(result.variables.first { $0.name == "PEEKABOO_QUALITY" }?.flag == nil)
+        #expect(result.variables.dropFirst().allSatisfy { $0.value == nil })
+    }
+}
@@ -0,0 +1,92 @@
+import Foundation
+import PeekabooFoundation
+import Testing
+@testable import PeekabooCLI
+
+@Suite(.tags(.jsonOutput, .unit))
+struct ErrorContextTests {
+    @Test(.tags(.fast))
+    @MainActor
+    func `Nested contexts record the outermost operation first`() async {
+        do {
+            try await withErrorContext("image_command") {
+                try await withErrorContext("perform_capture") {
+                    try await withErrorContext("capture_application_window") {
+                        throw PeekabooError.appNotFound("Nope")
+                    }
+                }
+            }
+            Issue.record("Expected the inner error to propagate")
+        } catch {
+            #expect(error.errorContextChain == ["image_command", "perform_capture", "capture_application_window"])
+            #expect(error.withoutErrorContext is PeekabooError)
+            #expect(error.localizedDescription == PeekabooError.appNotFound("Nope").localizedDescription)
+        }
+    }
+
+    @Test(.tags(.fast))
+    @MainActor
+    func `Cancellation passes through without a wrapper`() async {
+        do {
+            try await withErrorContext("perform_capture") {
+                throw CancellationError()
+            }
+        } catch {
+            #expect(error is CancellationError)
+            #expect(error.errorContextChain.isEmpty)
+        }
+    }
+
+    @Test(.tags(.fast))
+    func `Error info encodes the context chain only when present`() throws {
+        let encoder = JSONEncoder()
+
+        let plain = try encoder.encode(ErrorInfo(message: "boom", code: .CAPTURE_FAILED))
+        let plainObject = try #require(JSONSerialization.jsonObject(with: plain) as? [String: Any])
+        #expect(plainObject["context"] == nil)
+
+        let contextual = try encoder.encode(ErrorInfo(
+            message: "boom",
+            code: .CAPTURE_FAILED,
+            context: ["image_command", "perform_capture"]
+        ))
+        let contextualObject = try #require(JSONSerialization.jsonObject(with: contextual) as? [String: Any])
+        #expect(contextualObject["context"] as? [String] == ["image_command", "perform_capture"])
+    }
+
+    @Test(.tags(.fast))
+    func `PeekabooError variant is the case name`() {
+        #expect(PeekabooError.appNotFound("Safari").variant == "appNotFound")
+        #expect(PeekabooError.windowNotFound(criteria: "Inbox").variant == "windowNotFound")
+        #expect(PeekabooError.displayNotFound.variant == "displayNotFound")
+    }
+
+    @Test(.tags(.fast))
+    func `Error info round-trips the PeekabooError variant and fields`() throws {
+        let error = PeekabooError.ambiguousAppIdentifier("Code", suggestions: ["Visual Studio Code", "Xcode"])
+        let info = ErrorInfo(message: error.localizedDescription, code: .AMBIGUOUS_APP_IDENTIFIER, peekabooError: error)
+
+        let data = try JSONEncoder().encode(info)
+        let object = try #require(JSONSerialization.jsonObject(with: data) as? [String: Any])
+        #expect(object["variant"] as? String == "ambiguousAppIdentifier")
+        #expect(object["fields"] as? [String: String] == [
+            "identifier": "Code",
+            "suggestions": "Visual Studio Code, Xcode",
+        ])
+
+        let decoded = try JSONDecoder().decode(ErrorInfo.self, from: data)
+        #expect(decoded.variant == "ambiguousAppIdentifier")
+        #expect(decoded.fields?["identifier"] == "Code")
+        #expect(decoded.code == ErrorCode.AMBIGUOUS_APP_IDENTIFIER.rawValue)
+    }
+
+    @Test(.tags(.fast))
+    func `Error info omits fields for payload-free variants`() throws {
+        let info = ErrorInfo(message: "Display not found", code: .CAPTURE_FAILED, peekabooError: .displayNotFound)
+        let object = try #require(
+            JSONSerialization.jsonObject(with: JSONEncoder().encode(info)) as? [String: Any]
+        )
+        #expect(object["variant"] as? String == "displayNotFound")
+        #expect(object["fields"] == nil)
+    }
+}
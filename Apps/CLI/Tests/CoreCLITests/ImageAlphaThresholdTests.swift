@@ -0,0 +1,52 @@
+import Commander
+import CoreGraphics
+import Foundation
+import Testing
+@testable import PeekabooCLI
+
+@Suite(.tags(.imageCapture, .unit))
+struct ImageAlphaThresholdTests {
+    @Test(.tags(.fast))
+    func `Pixels below the threshold become fully transparent`() throws {
+        // Premultiplied RGBA: a faint shadow pixel, one right at the threshold, and an opaque one.
+        let image = try CapturedImageIO.makeImage(
+            rgbaBytes: [
+                20, 20, 20, 40,
+                128, 0, 0, 128,
+                255, 255, 255, 255,
+            ],
+            width: 3,
+            height: 1
+        )
+        let pixels = try TestImages.rgbaPixels(of: AlphaThresholdFilter(threshold: 128).apply(to: image))
+
+        #expect(pixels(0, 0) == [0, 0, 0, 0])
+        #expect(pixels(1, 0) == [128, 0, 0, 128])
+        #expect(pixels(2, 0) == [255, 255, 255, 255])
+    }
+
+    @Test(.tags(.fast))
+    @MainActor
+    func `Alpha threshold runs before annotations and is range checked`() throws {
+        let command = try ImageCommand.parse([
+            "--alpha-threshold", "200",
+            "--annotate", "[{\"type\": \"rect\", \"x\": 0, \"y\": 0, \"w\": 4, \"h\": 4}]",
+        ])
+        let chain = try command.makeFilterChain()
+        #expect((chain.filters.first as? AlphaThresholdFilter)?.threshold == 200)
+        #expect(chain.filters.last is AnnotationFilter)
+
+        #expect(throws: ValidationError.self) {
+            try ImageCommand.parse(["--alpha-threshold", "256"]).makeFilterChain()
+        }
+    }
+
+    @Test(.tags(.fast))
+    @MainActor
+    func `JPEG captures warn that alpha is discarded`() throws {
+        let jpeg = try ImageCommand.parse(["--alpha-threshold", "10", "--format", "jpg"])
+        #expect(jpeg.alphaThresholdFormatWarning != nil)
+        #expect(try ImageCommand.parse(["--alpha-threshold", "10"]).alphaThresholdFormatWarning == nil)
+        #expect(try ImageCommand.parse(["--format", "jpg"]).alphaThresholdFormatWarning == nil)
+    }
+}
@@ -0,0 +1,122 @@
+import Commander
+import CoreGraphics
+import Foundation
+import Testing
+@testable import PeekabooCLI
+
+@Suite(.tags(.imageCapture, .unit))
+struct ImageAnnotationTests {
+    @Test(.tags(.fast))
+    func `Parses each annotation type with defaults`() throws {
+        let annotations = try ImageAnnotationParser.parse("""
+        [
+          {"type": "text", "x": 10, "y": 20, "text": "Login", "size": 18, "color": "#00FF00"},
+          {"type": "arrow", "x1": 0, "y1": 0, "x2": 50, "y2": 40},
+          {"type": "rect", "x": 5, "y": 6, "w": 30, "h": 12, "color": "#0000FF", "fill": true}
+        ]
+        """)
+
+        let red = try #require(HexColor(hex: ImageAnnotation.defaultColor))
+        let green = try #require(HexColor(hex: "#00FF00"))
+        let blue = try #require(HexColor(hex: "#0000FF"))
+        #expect(annotations == [
+            .text(origin: CGPoint(x: 10, y: 20), text: "Login", color: green, size: 18),
+            .arrow(from: .zero, to: CGPoint(x: 50, y: 40), color: red, lineWidth: ImageAnnotation.defaultLineWidth),
+            .rect(
+                CGRect(x: 5, y: 6, width: 30, height: 12),
+                color: blue,
+                fill: true,
+                lineWidth: ImageAnnotation.defaultLineWidth
+            ),
+        ])
+    }
+
+    @Test(
+        .tags(.fast),
+        arguments: [
+            "{\"type\": \"rect\"}",
+            "[{\"type\": \"circle\", \"x\": 1, \"y\": 1}]",
+            "[{\"type\": \"text\", \"x\": 1, \"y\": 1, \"text\": \"\"}]",
+            "[{\"type\": \"arrow\", \"x1\": 0, \"y1\": 0, \"x2\": 1}]",
+            "[{\"type\": \"rect\", \"x\": 0, \"y\": 0, \"w\": 0, \"h\": 4}]",
+            "[{\"type\": \"rect\", \"x\": 0, \"y\": 0, \"w\": 4, \"h\": 4, \"color\": \"red\"}]",
+            "not json",
+        ]
+    )
+    func `Rejects malformed annotations`(json: String) {
+        #expect(throws: ValidationError.self) {
+            try ImageAnnotationParser.parse(json)
+        }
+    }
+
+    @Test(.tags(.fast))
+    @MainActor
+    func `Annotate is parsed before capture`() throws {
+        let command = try ImageCommand.parse(["--annotate", "[{\"type\": \"bogus\"}]"])
+        #expect(throws: ValidationError.self) {
+            try command.parsedAnnotations()
+        }
+
+        let unset = try ImageCommand.parse([])
+        #expect(try unset.parsedAnnotations().isEmpty)
+    }
+
+    @Test(.tags(.fast))
+    func `Rect annotations use top-left coordinates`() throws {
+        let base = try TestImages.solid(width: 20, height: 20, red: 255, green: 255, blue: 255)
+        let blue = try #require(HexColor(hex: "#0000FF"))
+
+        let rendered = try ImageAnnotationRenderer.render(
+            [
+                .rect(CGRect(x: 2, y: 2, width: 6, height: 4), color: blue, fill: true, lineWidth: 1),
+                .rect(CGRect(x: 10, y: 10, width: 8, height: 8), color: blue, fill: false, lineWidth: 2),
+            ],
+            onto: base
+        )
+        let pixels = try TestImages.rgbaPixels(of: rendered)
+
+        #expect(pixels(4, 3) == [0, 0, 255, 255])
+        #expect(pixels(4, 17) == [255, 255, 255, 255])
+        // Outlines stay inside the rect and leave the middle untouched.
+        #expect(pixels(10, 14) == [0, 0, 255, 255])
+        #expect(pixels(14, 14) == [255, 255, 255, 255])
+    }
+
+    @Test(.tags(.fast))
+    func `Arrow annotations draw the shaft and head`() throws {
+        let base = try TestImages.solid(width: 60, height: 20, red: 255, green: 255, blue: 255)
+        let red = try #require(HexColor(hex: "#FF0000"))
+
+        let rendered = try ImageAnnotationRenderer.render(
+            [.arrow(from: CGPoint(x: 4, y: 10), to: CGPoint(x: 56, y: 10), color: red, lineWidth: 3)],
+            onto: base
+        )
+        let pixels = try TestImages.rgbaPixels(of: rendered)
+
+        #expect(pixels(20, 10) == [255, 0, 0, 255])
+        #expect(pixels(50, 10) == [255, 0, 0, 255])
+        #expect(pixels(20, 2) == [255, 255, 255, 255])
+    }
+
+    @Test(.tags(.fast))
+    func `Text annotations render glyphs inside their box`() throws {
+        let base = try TestImages.solid(width: 120, height: 60, red: 255, green: 255, blue: 255)
+        let black = HexColor.black
+
+        let rendered = try ImageAnnotationRenderer.render(
+            [.text(origin: CGPoint(x: 4, y: 4), text: "HELLO", color: black, size: 24)],
+            onto: base
+        )
+        let pixels = try TestImages.rgbaPixels(of: rendered)
+
+        let inked = (4..<100).contains { x in
+            (4..<30).contains { y in pixels(x, y)[0] < 128 }
+        }
+        #expect(inked)
+        // Nothing spills below the text line.
+        let below = (0..<120).contains { x in
+            (45..<60).contains { y in pixels(x, y) != [255, 255, 255, 255] }
+        }
+        #expect(!below)
+    }
+}
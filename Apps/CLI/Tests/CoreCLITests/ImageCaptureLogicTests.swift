@@ -173,6 +173,20 @@ struct ImageCaptureLogicTests {
         #expect(try command.areaCaptureRect() == CGRect(x: 10, y: 20, width: 300, height: 200))
     }
 
+    @Test(.tags(.fast))
+    func `Area regions accept geometry and fractional points`() throws {
+        let geometry = try ImageCommand.parse(["--mode", "area", "--region", "300x200-1910+20"])
+        #expect(try geometry.areaCaptureRect() == CGRect(x: -1910, y: 20, width: 300, height: 200))
+
+        let fractional = try ImageCommand.parse(["--mode", "area", "--region", "10.5,20,300,200.5"])
+        #expect(try fractional.areaCaptureRect() == CGRect(x: 10.5, y: 20, width: 300, height: 200.5))
+
+        let negative = try ImageCommand.parse(["--mode", "area", "--region", "1,2,-3,4"])
+        #expect(throws: ValidationError.self) {
+            _ = try negative.areaCaptureRect()
+        }
+    }
+
     @Test(.tags(.fast))
     func `Area region validation`() throws {
         let missing = try ImageCommand.parse(["--mode", "area"])
@@ -251,6 +265,30 @@ struct ImageCaptureLogicTests {
         #expect(jpgCmd.format == .jpg)
     }
 
+    @Test(.tags(.fast), arguments: [
+        ("shot.png", ImageFormat.png),
+        ("shot.PNG", .png),
+        ("shot.jpg", .jpg),
+        ("~/Desktop/shot.jpeg", .jpg),
+    ])
+    func `Image format from path extension`(path: String, expected: ImageFormat) {
+        #expect(ImageFormat(path: path) == expected)
+    }
+
+    @Test(.tags(.fast), arguments: ["shot.webp", "shot.tiff", "shot.tif", "shot.bmp", "shot.avif", "shot", ""])
+    func `Image format from unsupported path is nil`(path: String) {
+        #expect(ImageFormat(path: path) == nil)
+    }
+
+    @Test(.tags(.fast))
+    func `Path extension picks the format when --format is omitted`() throws {
+        let command = try ImageCommand.parse(["--path", "/tmp/shot.jpeg"])
+        #expect(command.format == .jpg)
+
+        let fallback = try ImageCommand.parse(["--path", "/tmp/shot.webp"])
+        #expect(fallback.format == .png)
+    }
+
     @Test(.tags(.fast))
     func `MIME type mapping`() {
         // Test MIME type logic (as used in SavedFile creation)
@@ -0,0 +1,77 @@
+import Commander
+import Foundation
+import Testing
+@testable import PeekabooCLI
+
+@Suite(.tags(.imageCapture, .unit))
+@MainActor
+struct ImageCaptureSessionTests {
+    @Test(.tags(.fast))
+    func `Session metadata parses key=value pairs`() throws {
+        let command = try ImageCommand.parse([
+            "--mode", "screen",
+            "--session-metadata", "job=nightly",
+            "--session-metadata", "query=a=b",
+            "--session-metadata", "job=rerun",
+        ])
+        #expect(try command.parsedSessionMetadata() == ["job": "rerun", "query": "a=b"])
+        try command.validateSessionOptions()
+
+        for invalid in ["missing-separator", "=value"] {
+            let command = try ImageCommand.parse(["--session-metadata", invalid])
+            #expect(throws: ValidationError.self) {
+                try command.validateSessionOptions()
+            }
+        }
+    }
+
+    @Test(.tags(.fast))
+    func `Session id must be a UUID`() throws {
+        let invalid = try ImageCommand.parse(["--session-id", "run-42"])
+        #expect(throws: ValidationError.self) {
+            try invalid.validateSessionOptions()
+        }
+
+        let id = "1f0e6a3c-52b1-4f57-9d3e-8b1f2d9b7c10"
+        let command = try ImageCommand.parse(["--app", "Safari", "--session-id", id, "--session-metadata", "k=v"])
+        try command.validateSessionOptions()
+
+        let started = Date(timeIntervalSince1970: 1_800_000_000)
+        let session = try command.makeCaptureSession(startedAt: started, endedAt: started.addingTimeInterval(2))
+        #expect(session.id == id.uppercased())
+        #expect(session.mode == "window")
+        #expect(session.app == "Safari")
+        #expect(session.metadata == ["k": "v"])
+        #expect(session.started_at == "2027-01-15T08:00:00Z")
+        #expect(session.ended_at == "2027-01-15T08:00:02Z")
+    }
+
+    @Test(.tags(.fast))
+    func `Session options take values through the Commander signature`() throws {
+        let id = "1f0e6a3c-52b1-4f57-9d3e-8b1f2d9b7c10"
+        let invocation = try CommanderRuntimeRouter.resolve(argv: [
+            "peekaboo", "image",
+            "--session-id", id,
+            "--session-metadata", "job=nightly",
+            "--session-metadata", "run=7",
+        ])
+        #expect(invocation.parsedValues.options["sessionId"] == [id])
+        #expect(invocation.parsedValues.options["sessionMetadata"] == ["job=nightly", "run=7"])
+
+        let command = try CommanderCLIBinder.instantiateCommand(
+            ofType: ImageCommand.self,
+            parsedValues: invocation.parsedValues
+        )
+        #expect(command.sessionId == id)
+        #expect(try command.parsedSessionMetadata() == ["job": "nightly", "run": "7"])
+    }
+
+    @Test(.tags(.fast))
+    func `Sessions get a fresh id and report special app modes`() throws {
+        let first = try ImageCommand.parse(["--app", "menubar"]).makeCaptureSession(startedAt: Date())
+        let second = try ImageCommand.parse(["--app", "menubar"]).makeCaptureSession(startedAt: Date())
+        #expect(first.mode == "menubar")
+        #expect(first.id != second.id)
+        #expect(UUID(uuidString: first.id) != nil)
+    }
+}
@@ -0,0 +1,46 @@
+import Commander
+import Foundation
+import PeekabooCore
+import PeekabooFoundation
+import Testing
+@testable import PeekabooCLI
+
+@Suite(.tags(.imageCapture, .unit))
+@MainActor
+struct ImageCaptureTimeoutTests {
+    @Test(.tags(.fast))
+    func `A capture that never returns is abandoned after the timeout`() async throws {
+        let startedAt = Date()
+        let error = try await #require(throws: PeekabooError.self) {
+            try await ImageCommand.withCaptureTimeout(milliseconds: 50) {
+                // Swallows cancellation and still returns a value, like a capture stuck in a hung app.
+                try? await Task.sleep(for: .seconds(10))
+                return 1
+            }
+        }
+        #expect(Date().timeIntervalSince(startedAt) < 5)
+        #expect(error.localizedDescription.contains("timed out after 50ms"))
+    }
+
+    @Test(.tags(.fast))
+    func `Fast captures and their errors pass through`() async throws {
+        let value = try await ImageCommand.withCaptureTimeout(milliseconds: 1000) { 42 }
+        #expect(value == 42)
+
+        let error = try await #require(throws: PeekabooError.self) {
+            try await ImageCommand.withCaptureTimeout(milliseconds: 1000) { () -> Int in
+                throw PeekabooError.timeout("inner")
+            }
+        }
+        #expect(error.localizedDescription.contains("inner"))
+    }
+
+    @Test(.tags(.fast))
+    func `The timeout defaults to five seconds and must be positive`() throws {
+        #expect(try ImageCommand.parse([]).resolvedCaptureTimeoutMs == 5000)
+        #expect(try ImageCommand.parse(["--capture-timeout-ms", "250"]).resolvedCaptureTimeoutMs == 250)
+        #expect(throws: ValidationError.self) {
+            try ImageCommand.parse(["--capture-timeout-ms", "0"]).validateCaptureTimeoutOptions()
+        }
+    }
+}
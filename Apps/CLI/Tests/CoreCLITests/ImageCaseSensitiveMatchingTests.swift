@@ -0,0 +1,80 @@
+import CoreGraphics
+import Foundation
+import PeekabooCore
+import Testing
+@testable import PeekabooCLI
+
+@Suite(.tags(.imageCapture, .unit))
+@MainActor
+struct ImageCaseSensitiveMatchingTests {
+    private let applications = [
+        ServiceApplicationInfo(
+            processIdentifier: 10,
+            bundleIdentifier: "org.whispersystems.signal-desktop",
+            name: "signal-desktop",
+            activationPolicy: .regular
+        ),
+        ServiceApplicationInfo(
+            processIdentifier: 20,
+            bundleIdentifier: "com.example.Signal",
+            name: "Signal",
+            activationPolicy: .regular
+        ),
+        ServiceApplicationInfo(
+            processIdentifier: 30,
+            bundleIdentifier: "com.example.Signal.helper",
+            name: "Signal Helper",
+            activationPolicy: .accessory
+        ),
+    ]
+
+    @Test(.tags(.fast))
+    func `Case-sensitive flag parses on image and list windows`() throws {
+        #expect(try ImageCommand.parse(["--app", "Signal", "--case-sensitive"]).caseSensitive)
+        #expect(try !ImageCommand.parse(["--app", "Signal"]).caseSensitive)
+        #expect(try ListCommand.WindowsSubcommand.parse(["--app", "Signal", "--case-sensitive"]).caseSensitive)
+    }
+
+    @Test(.tags(.fast))
+    func `Exact names only match with the same case`() {
+        #expect(self.matchedPID("Signal") == 20)
+        #expect(self.matchedPID("signal-desktop") == 10)
+        #expect(self.matchedPID("SIGNAL") == nil)
+    }
+
+    @Test(.tags(.fast))
+    func `Partial names keep their case and prefer regular apps`() {
+        // Case-insensitively, "signal" would also hit "Signal" and "Signal Helper".
+        #expect(self.matchedPID("signal") == 10)
+        #expect(self.matchedPID("Sig") == 20)
+        #expect(self.matchedPID("Helper") == 30)
+        #expect(self.matchedPID("helper") == nil)
+        #expect(self.matchedPID("com.example.Signal") == 20)
+    }
+
+    @Test(.tags(.fast))
+    func `Window titles match case-sensitively only when asked`() {
+        let windows = [
+            ServiceWindowInfo(
+                windowID: 1,
+                title: "inbox — archive",
+                bounds: CGRect(x: 0, y: 0, width: 800, height: 600)
+            ),
+            ServiceWindowInfo(
+                windowID: 2,
+                title: "Inbox",
+                bounds: CGRect(x: 0, y: 0, width: 800, height: 600),
+                index: 1
+            ),
+        ]
+
+        #expect(ImageCommand.selectWindow(from: windows, title: "Inbox", index: nil)?.windowID == 1)
+        #expect(ImageCommand.selectWindow(from: windows, title: "Inbox", index: nil, caseSensitive: true)?
+            .windowID == 2)
+        #expect(ImageCommand.selectWindow(from: windows, title: "INBOX", index: nil, caseSensitive: true) == nil)
+    }
+
+    private func matchedPID(_ identifier: String) -> Int32? {
+        ApplicationNameMatcher.caseSensitiveMatch(for: identifier, in: self.applications)?.processIdentifier
+    }
+}
@@ -0,0 +1,80 @@
+import Commander
+import CoreGraphics
+import Foundation
+import PeekabooCore
+import Testing
+@testable import PeekabooCLI
+
+@Suite(.tags(.imageCapture, .unit))
+struct ImageChangedRegionTests {
+    @Test(.tags(.fast))
+    func `Bounding box covers every changed pixel`() throws {
+        let baseline = try TestImages.solid(width: 6, height: 5, red: 255, green: 255, blue: 255)
+        var bytes = try CapturedImageIO.rgbaBytes(of: baseline)
+        for (x, y) in [(1, 1), (3, 2)] {
+            let offset = (y * 6 + x) * 4
+            bytes.replaceSubrange(offset..<offset + 4, with: [255, 0, 0, 255])
+        }
+        let current = try CapturedImageIO.makeImage(rgbaBytes: bytes, width: 6, height: 5)
+
+        let changed = try ChangedRegionBaseline.changedBounds(from: baseline, to: current)
+        #expect(changed == CGRect(x: 1, y: 1, width: 3, height: 2))
+        #expect(try ChangedRegionBaseline.changedBounds(from: baseline, to: baseline) == nil)
+    }
+
+    @Test(.tags(.fast))
+    func `A resized capture counts as entirely changed`() throws {
+        let baseline = try TestImages.solid(width: 4, height: 4, red: 0, green: 0, blue: 0)
+        let current = try TestImages.solid(width: 8, height: 2, red: 0, green: 0, blue: 0)
+
+        #expect(try ChangedRegionBaseline.changedBounds(from: baseline, to: current) ==
+            CGRect(x: 0, y: 0, width: 8, height: 2))
+    }
+
+    @Test(.tags(.fast))
+    func `Margin pads the box but stays inside the image`() {
+        let bounds = CGRect(x: 0, y: 0, width: 100, height: 50)
+
+        #expect(ChangedRegionBaseline.padded(CGRect(x: 10, y: 10, width: 5, height: 5), by: 4, within: bounds) ==
+            CGRect(x: 6, y: 6, width: 13, height: 13))
+        #expect(ChangedRegionBaseline.padded(CGRect(x: 1, y: 45, width: 5, height: 5), by: 4, within: bounds) ==
+            CGRect(x: 0, y: 41, width: 10, height: 9))
+    }
+
+    @Test(.tags(.fast))
+    @MainActor
+    func `Baselines are keyed by a file-safe capture label`() {
+        let directory = URL(fileURLWithPath: "/tmp/baselines")
+        let window = SavedFile(path: "/tmp/a.png", item_label: "Safari: Apple / News", mime_type: "image/png")
+
+        let identifier = ImageCommand.baselineIdentifier(for: window, index: 0, count: 1)
+        #expect(ChangedRegionBaseline.url(for: identifier, in: directory).lastPathComponent ==
+            "Safari__Apple___News_baseline.png")
+        #expect(ImageCommand.baselineIdentifier(for: window, index: 1, count: 2) == "Safari: Apple / News-1")
+        #expect(ChangedRegionBaseline.sanitized("") == "capture")
+    }
+
+    @Test(.tags(.fast))
+    @MainActor
+    func `Margin and reset require changed-region`() throws {
+        let command = try ImageCommand.parse(["--changed-region", "--change-margin", "8", "--reset-baseline"])
+        #expect(command.changedRegion)
+        #expect(command.changeMargin == 8)
+        #expect(command.resetBaseline)
+        try command.validateChangedRegionOptions()
+
+        #expect(throws: ValidationError.self) {
+            try ImageCommand.parse(["--change-margin", "8"]).validateChangedRegionOptions()
+        }
+        #expect(throws: ValidationError.self) {
+            try ImageCommand.parse(["--reset-baseline"]).validateChangedRegionOptions()
+        }
+        // Commander may reject the negative value while parsing, before validation sees it.
+        #expect(throws: (any Error).self) {
+            try ImageCommand.parse(["--changed-region", "--change-margin", "-1"]).validateChangedRegionOptions()
+        }
+        #expect(throws: ValidationError.self) {
+            try ImageCommand.parse(["--changed-region", "--path", "-"]).validateChangedRegionOptions()
+        }
+    }
+}
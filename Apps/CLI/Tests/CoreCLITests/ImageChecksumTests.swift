@@ -0,0 +1,80 @@
+import Commander
+import Foundation
+import PeekabooCore
+import PeekabooFoundation
+import Testing
+@testable import PeekabooCLI
+
+@Suite(.tags(.imageCapture, .unit))
+@MainActor
+struct ImageChecksumTests {
+    @Test(.tags(.fast))
+    func `Digests match the published test vectors`() throws {
+        let url = try Self.file(containing: "abc")
+        defer { try? FileManager.default.removeItem(at: url) }
+
+        #expect(try FileChecksumAlgorithm.sha256.hexDigest(ofFileAt: url.path) ==
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad")
+        #expect(try FileChecksumAlgorithm.md5.hexDigest(ofFileAt: url.path) == "900150983cd24fb0d6963f7d28e17f72")
+        #expect(try FileChecksumAlgorithm.sha512.hexDigest(ofFileAt: url.path).hasPrefix("ddaf35a193617aba"))
+    }
+
+    @Test(.tags(.fast))
+    func `Checksums are opt-in and use the requested algorithm`() throws {
+        let url = try Self.file(containing: "abc")
+        defer { try? FileManager.default.removeItem(at: url) }
+
+        let plain = try ImageCommand.parse([])
+        #expect(try plain.computingChecksums([TestImages.capture(at: url)])[0].file.checksum == nil)
+
+        let sha256 = try ImageCommand.parse(["--compute-checksum"])
+        let hashed = try sha256.computingChecksums([TestImages.capture(at: url)])
+        #expect(hashed[0].file.checksum?.hasPrefix("ba7816bf") == true)
+        #expect(hashed[0].file.checksum_algorithm == "sha256")
+
+        let md5 = try ImageCommand.parse(["--compute-checksum", "--checksum-algorithm", "MD5"])
+        let digested = try md5.computingChecksums([TestImages.capture(at: url)])
+        #expect(digested[0].file.checksum == "900150983cd24fb0d6963f7d28e17f72")
+        #expect(digested[0].file.checksum_algorithm == "md5")
+    }
+
+    @Test(.tags(.fast))
+    func `An algorithm without --compute-checksum is rejected`() throws {
+        let command = try ImageCommand.parse(["--checksum-algorithm", "sha512"])
+        #expect(throws: ValidationError.self) {
+            try command.validateChecksumOptions()
+        }
+        #expect(throws: (any Error).self) {
+            _ = try ImageCommand.parse(["--compute-checksum", "--checksum-algorithm", "crc32"])
+        }
+    }
+
+    @Test(.tags(.fast))
+    func `verify-checksum ignores case and reports mismatches`() throws {
+        let url = try Self.file(containing: "abc")
+        defer { try? FileManager.default.removeItem(at: url) }
+
+        let match = try VerifyChecksumCommand.verify(
+            file: url.path,
+            checksum: "BA7816BF8F01CFEA414140DE5DAE2223B00361A396177A9CB410FF61F20015AD\n",
+            algorithm: .sha256
+        )
+        #expect(match.matches)
+
+        let mismatch = try VerifyChecksumCommand.verify(file: url.path, checksum: "deadbeef", algorithm: .md5)
+        #expect(!mismatch.matches)
+        #expect(mismatch.actual == "900150983cd24fb0d6963f7d28e17f72")
+
+        let parsed = try VerifyChecksumCommand.parse(["--file", url.path, "--checksum", "abc", "--algorithm", "SHA256"])
+        #expect(parsed.resolvedAlgorithm == .sha256)
+        #expect(throws: PeekabooError.self) {
+            try VerifyChecksumCommand.verify(file: url.path + ".missing", checksum: "abc", algorithm: .sha256)
+        }
+    }
+
+    private static func file(containing text: String) throws -> URL {
+        let url = FileManager.default.temporaryDirectory.appendingPathComponent("peekaboo-checksum-\(UUID()).png")
+        try Data(text.utf8).write(to: url)
+        return url
+    }
+}
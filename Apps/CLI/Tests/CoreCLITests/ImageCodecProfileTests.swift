@@ -0,0 +1,56 @@
+import Commander
+import Foundation
+import ImageIO
+import PeekabooCore
+import Testing
+@testable import PeekabooCLI
+
+@Suite(.tags(.imageCapture, .unit))
+@MainActor
+struct ImageCodecProfileTests {
+    @Test(.tags(.fast))
+    func `Profiles must belong to the output format`() throws {
+        let progressive = try ImageCommand.parse(["--format", "jpeg", "--codec-profile", "Progressive"])
+        #expect(progressive.format == .jpg)
+        #expect(progressive.codecProfile == .progressive)
+        try progressive.validateCodecProfileOptions()
+
+        try ImageCommand.parse(["--codec-profile", "paeth"]).validateCodecProfileOptions()
+
+        let mismatched = try ImageCommand.parse(["--format", "png", "--codec-profile", "progressive"])
+        #expect(throws: ValidationError.self) {
+            try mismatched.validateCodecProfileOptions()
+        }
+        #expect(throws: (any Error).self) {
+            _ = try ImageCommand.parse(["--codec-profile", "optimized"])
+        }
+    }
+
+    @Test(.tags(.fast))
+    func `Listing names each format's profiles`() {
+        #expect(ImageCommand.codecProfileList(for: .jpg).profiles.map(\.name) == ["baseline", "progressive"])
+        #expect(ImageCommand.codecProfileList(for: .png).profiles.map(\.name) == [
+            "none", "sub", "up", "average", "paeth", "adaptive",
+        ])
+    }
+
+    @Test(.tags(.fast))
+    func `Re-encoded JPEGs carry the progressive flag and the profile name`() throws {
+        let url = FileManager.default.temporaryDirectory.appendingPathComponent("peekaboo-codec-\(UUID()).jpg")
+        defer { try? FileManager.default.removeItem(at: url) }
+        let image = try TestImages.solid(width: 16, height: 16, red: 0, green: 128, blue: 255)
+        try CapturedImageIO.write(image, to: url, format: .jpg)
+
+        let capture = TestImages.capture(at: url, mimeType: "image/jpeg")
+        let command = try ImageCommand.parse(["--format", "jpg", "--codec-profile", "progressive"])
+        let encoded = try command.encodingWithCodecProfile([capture])
+        #expect(encoded[0].file.codec_profile == "progressive")
+
+        let source = try #require(CGImageSourceCreateWithURL(url as CFURL, nil))
+        let properties = CGImageSourceCopyPropertiesAtIndex(source, 0, nil) as? [CFString: Any]
+        let jfif = properties?[kCGImagePropertyJFIFDictionary] as? [CFString: Any]
+        #expect(jfif?[kCGImagePropertyJFIFIsProgressive] as? Bool == true)
+
+        #expect(try ImageCommand.parse([]).encodingWithCodecProfile([capture])[0].file.codec_profile == nil)
+    }
+}
@@ -0,0 +1,62 @@
+import CoreGraphics
+import Foundation
+import ImageIO
+import PeekabooCore
+import Testing
+@testable import PeekabooCLI
+
+@Suite(.tags(.imageCapture, .unit))
+struct ImageColorSpaceTests {
+    @Test(.tags(.fast))
+    @MainActor
+    func `Color space parses and joins the chain last`() throws {
+        let command = try ImageCommand.parse(["--color-space", "P3", "--vignette"])
+        #expect(command.colorSpace == .p3)
+
+        let chain = try command.makeFilterChain()
+        #expect(chain.filters.first is VignetteFilter)
+        #expect((chain.filters.last as? ColorSpaceFilter)?.colorSpace == .p3)
+        #expect(try ImageCommand.parse([]).colorSpace == nil)
+    }
+
+    @Test(.tags(.fast))
+    func `Converted files embed the requested profile`() throws {
+        let base = try TestImages.solid(width: 8, height: 8, red: 255, green: 0, blue: 0)
+
+        for colorSpace in ImageColorSpace.allCases {
+            let converted = try ColorSpaceFilter(colorSpace: colorSpace).apply(to: base)
+            let expected = try #require(colorSpace.cgColorSpace?.name)
+            #expect(converted.colorSpace?.name == expected)
+
+            let url = FileManager.default.temporaryDirectory
+                .appendingPathComponent("peekaboo-color-space-\(UUID()).png")
+            defer { try? FileManager.default.removeItem(at: url) }
+            try CapturedImageIO.write(converted, to: url, format: .png)
+
+            let source = try #require(CGImageSourceCreateWithURL(url as CFURL, nil))
+            let written = try #require(CGImageSourceCreateImageAtIndex(source, 0, nil))
+            #expect(written.colorSpace?.name == expected)
+        }
+    }
+
+    @Test(.tags(.fast))
+    func `Saturated sRGB red lands inside the P3 gamut`() throws {
+        let base = try TestImages.solid(width: 2, height: 2, red: 255, green: 0, blue: 0)
+        let srgb = try #require(CGColorSpace(name: CGColorSpace.sRGB))
+        let tagged = try #require(base.copy(colorSpace: srgb))
+        let converted = try ColorSpaceFilter(colorSpace: .p3).apply(to: tagged)
+
+        // Read the stored bytes directly; decoding through a context would color-match them back.
+        let data = try #require(converted.dataProvider?.data) as Data
+        // P3 is wider, so pure sRGB red needs less than full P3 red and a little green.
+        #expect(data[0] < 255)
+        #expect(data[1] > 0)
+    }
+
+    @Test(.tags(.fast))
+    func `Saved files record the converted color space`() {
+        let file = SavedFile(path: "/tmp/shot.png", mime_type: "image/png")
+        #expect(file.with(colorSpace: "p3").color_space == "p3")
+        #expect(file.with(verified: true).color_space == nil)
+    }
+}
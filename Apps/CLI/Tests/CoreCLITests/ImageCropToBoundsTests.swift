@@ -0,0 +1,56 @@
+import Commander
+import CoreGraphics
+import Foundation
+import PeekabooCore
+import Testing
+@testable import PeekabooCLI
+
+@Suite(.tags(.imageCapture, .unit))
+@MainActor
+struct ImageCropToBoundsTests {
+    @Test(.tags(.fast))
+    func `Crops are clamped to the image and reported as original bounds`() throws {
+        let url = FileManager.default.temporaryDirectory.appendingPathComponent("peekaboo-crop-\(UUID()).png")
+        defer { try? FileManager.default.removeItem(at: url) }
+        let image = try TestImages.solid(width: 10, height: 8, red: 0, green: 0, blue: 255)
+        try CapturedImageIO.write(image, to: url, format: .png)
+
+        let command = try ImageCommand.parse(["--crop-to-bounds", "6x6+4+4"])
+        let cropped = try command.croppingToBounds([TestImages.capture(at: url)])
+
+        #expect(cropped[0].file.original_bounds == WindowBounds(x: 4, y: 4, width: 6, height: 4))
+        let saved = try CapturedImageIO.load(at: url.path)
+        #expect(saved.width == 6 && saved.height == 4)
+
+        let outside = try ImageCommand.parse(["--crop-to-bounds", "20,20,5,5"])
+        #expect(throws: PeekabooError.self) {
+            try outside.croppingToBounds([TestImages.capture(at: url)])
+        }
+    }
+
+    @Test(.tags(.fast))
+    func `Trimming after a crop reports bounds in the full capture`() throws {
+        let url = FileManager.default.temporaryDirectory.appendingPathComponent("peekaboo-crop-\(UUID()).png")
+        defer { try? FileManager.default.removeItem(at: url) }
+        // White 8x8 with one red pixel at (5, 6).
+        var bytes = [UInt8](repeating: 255, count: 8 * 8 * 4)
+        bytes.replaceSubrange((6 * 8 + 5) * 4..<(6 * 8 + 6) * 4, with: [255, 0, 0, 255])
+        let image = try CapturedImageIO.makeImage(rgbaBytes: bytes, width: 8, height: 8)
+        try CapturedImageIO.write(image, to: url, format: .png)
+
+        let command = try ImageCommand.parse(["--crop-to-bounds", "2,2,6,6", "--trim-whitespace"])
+        let trimmed = try command.trimmingBorders(command.croppingToBounds([TestImages.capture(at: url)]))
+
+        #expect(trimmed[0].file.original_bounds == WindowBounds(x: 5, y: 6, width: 1, height: 1))
+    }
+
+    @Test(.tags(.fast))
+    func `Crop bounds must parse and have a size`() throws {
+        #expect(try ImageCommand.parse([]).cropBoundsValue() == nil)
+        for value in ["1,2,3", "0x10+0+0", "banana"] {
+            #expect(throws: ValidationError.self) {
+                try ImageCommand.parse(["--crop-to-bounds", value]).validateCropToBoundsOptions()
+            }
+        }
+    }
+}
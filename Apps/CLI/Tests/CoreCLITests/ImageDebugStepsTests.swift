@@ -0,0 +1,71 @@
+import CoreGraphics
+import Foundation
+import PeekabooCore
+import Testing
+@testable import PeekabooCLI
+
+@Suite(.tags(.imageCapture, .unit))
+@MainActor
+struct ImageDebugStepsTests {
+    @Test(.tags(.fast))
+    func `Each trim and filter step saves an image next to the capture`() throws {
+        let directory = FileManager.default.temporaryDirectory.appendingPathComponent("peekaboo-debug-\(UUID())")
+        defer { try? FileManager.default.removeItem(at: directory) }
+        let url = directory.appendingPathComponent("capture.png")
+        try CapturedImageIO.write(Self.framedImage(), to: url, format: .png)
+
+        let command = try ImageCommand.parse(["--trim-whitespace", "--vignette", "--debug-capture-steps"])
+        let recorder = try #require(command.makeStepRecorder())
+        let trimmed = try command.trimmingBorders([TestImages.capture(at: url)], recorder: recorder)
+        try command.applyFilterChain(command.makeFilterChain(), to: trimmed, recorder: recorder)
+
+        #expect(recorder.steps.map(\.step) == ["trim", "vignette"])
+        for step in recorder.steps {
+            #expect(step.width == 2 && step.height == 2)
+            #expect(step.duration_ms >= 0)
+            #expect(FileManager.default.fileExists(atPath: step.path))
+            let name = URL(fileURLWithPath: step.path).lastPathComponent
+            #expect(name == "debug_\(step.step)_\(recorder.timestamp).png")
+            #expect(CaptureStepRecorder.isDebugImage(named: name))
+        }
+    }
+
+    @Test(.tags(.fast))
+    func `The next successful run removes stale debug images only`() throws {
+        let directory = FileManager.default.temporaryDirectory.appendingPathComponent("peekaboo-debug-\(UUID())")
+        defer { try? FileManager.default.removeItem(at: directory) }
+        let url = directory.appendingPathComponent("capture.png")
+        let image = try TestImages.solid(width: 2, height: 2, red: 0, green: 0, blue: 0)
+        try CapturedImageIO.write(image, to: url, format: .png)
+        let notes = directory.appendingPathComponent("debug_notes.png")
+        try CapturedImageIO.write(image, to: notes, format: .png)
+
+        let earlier = CaptureStepRecorder(date: Date(timeIntervalSince1970: 0))
+        try earlier.record("vignette", image: image, durationMs: 1, besides: url.path, captureIndex: 1)
+        let current = CaptureStepRecorder()
+        try current.record("trim", image: image, durationMs: 1, besides: url.path, captureIndex: nil)
+
+        let command = try ImageCommand.parse(["--debug-capture-steps"])
+        command.removeStaleDebugImages(for: [TestImages.capture(at: url)], recorder: current)
+
+        #expect(!FileManager.default.fileExists(atPath: earlier.steps[0].path))
+        #expect(FileManager.default.fileExists(atPath: current.steps[0].path))
+        #expect(FileManager.default.fileExists(atPath: notes.path))
+        #expect(FileManager.default.fileExists(atPath: url.path))
+    }
+
+    @Test(.tags(.fast))
+    func `Recording is off unless requested`() throws {
+        #expect(try ImageCommand.parse([]).makeStepRecorder() == nil)
+    }
+
+    /// A 2x2 red block inside a white 6x6 frame.
+    private static func framedImage() throws -> CGImage {
+        var bytes = [UInt8](repeating: 255, count: 6 * 6 * 4)
+        for (x, y) in [(2, 2), (3, 2), (2, 3), (3, 3)] {
+            let offset = (y * 6 + x) * 4
+            bytes.replaceSubrange(offset..<offset + 4, with: [255, 0, 0, 255])
+        }
+        return try CapturedImageIO.makeImage(rgbaBytes: bytes, width: 6, height: 6)
+    }
+}
@@ -0,0 +1,85 @@
+import Commander
+import Foundation
+import PeekabooCore
+import Testing
+@testable import PeekabooCLI
+
+@Suite(.tags(.imageCapture, .unit))
+@MainActor
+struct ImageDedupTests {
+    @Test(.tags(.fast))
+    func `Matches the closest earlier capture within the threshold`() throws {
+        var cache = ImageHashCache()
+        cache.record(path: "/tmp/a.png", hash: DHash(rawValue: 0b1111))
+        cache.record(path: "/tmp/b.png", hash: DHash(rawValue: 0b1110))
+        let probe = DHash(rawValue: 0b0110)
+
+        #expect(cache.match(for: probe, maxDistance: 3, fileExists: { _ in true })?.path == "/tmp/b.png")
+        #expect(cache.match(for: probe, maxDistance: 0, fileExists: { _ in true }) == nil)
+        #expect(cache.match(for: probe, maxDistance: 3, fileExists: { $0 != "/tmp/b.png" })?.path == "/tmp/a.png")
+    }
+
+    @Test(.tags(.fast))
+    func `Recording a path again replaces its entry`() {
+        var cache = ImageHashCache()
+        cache.record(path: "/tmp/a.png", hash: DHash(rawValue: 1))
+        cache.record(path: "/tmp/a.png", hash: DHash(rawValue: 2))
+
+        #expect(cache.entries.map(\.hash) == [DHash(rawValue: 2)])
+        #expect(cache.pruneMissing(fileExists: { _ in false }) == 1)
+        #expect(cache.entries.isEmpty)
+    }
+
+    @Test(.tags(.fast))
+    func `Cache round-trips through disk and tolerates a missing file`() throws {
+        let directory = FileManager.default.temporaryDirectory
+            .appendingPathComponent("peekaboo-dedup-\(UUID().uuidString)")
+        defer { try? FileManager.default.removeItem(at: directory) }
+        let url = directory.appendingPathComponent("cache").appendingPathComponent("image-hashes.json")
+
+        #expect(try ImageHashCache.load(from: url).entries.isEmpty)
+
+        var cache = ImageHashCache()
+        cache.record(path: "/tmp/a.png", hash: DHash(rawValue: 0xDEAD_BEEF))
+        try cache.save(to: url)
+        #expect(try ImageHashCache.load(from: url) == cache)
+        #expect(try String(contentsOf: url, encoding: .utf8).contains("\"00000000deadbeef\""))
+
+        try Data("not json".utf8).write(to: url)
+        #expect(throws: PeekabooError.self) {
+            try ImageHashCache.load(from: url)
+        }
+    }
+
+    @Test(.tags(.fast))
+    func `DHash hex is fixed width and validated`() {
+        #expect(DHash(rawValue: 0xF).description == "000000000000000f")
+        #expect(DHash(hex: "000000000000000f") == DHash(rawValue: 0xF))
+        #expect(DHash(hex: "f") == nil)
+        #expect(DHash(hex: "zzzzzzzzzzzzzzzz") == nil)
+        #expect(DHash(rawValue: 0).distance(to: DHash(rawValue: .max)) == 64)
+    }
+
+    @Test(.tags(.fast))
+    func `Dedup threshold requires dedup and stays within 64 bits`() throws {
+        let command = try ImageCommand.parse(["--dedup", "--dedup-threshold", "5"])
+        #expect(command.dedup)
+        #expect(command.dedupThreshold == 5)
+        try command.validateDedupOptions()
+
+        let orphan = try ImageCommand.parse(["--dedup-threshold", "5"])
+        #expect(throws: ValidationError.self) {
+            try orphan.validateDedupOptions()
+        }
+
+        let tooWide = try ImageCommand.parse(["--dedup", "--dedup-threshold", "65"])
+        #expect(throws: ValidationError.self) {
+            try tooWide.validateDedupOptions()
+        }
+
+        let streamed = try ImageCommand.parse(["--dedup", "--path", "-"])
+        #expect(throws: ValidationError.self) {
+            try streamed.validateDedupOptions()
+        }
+    }
+}
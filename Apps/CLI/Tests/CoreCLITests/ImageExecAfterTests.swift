@@ -0,0 +1,62 @@
+import Commander
+import Foundation
+import PeekabooCore
+import Testing
+@testable import PeekabooCLI
+
+@Suite(.tags(.imageCapture, .unit))
+@MainActor
+struct ImageExecAfterTests {
+    @Test(.tags(.fast))
+    func `Commands split like a shell without expanding anything`() throws {
+        #expect(try PostCaptureHook.splitArguments(#"scp {} "me@host:/shots dir/""#) ==
+            ["scp", "{}", "me@host:/shots dir/"])
+        #expect(try PostCaptureHook.splitArguments(#"echo 'a "b"' c\ d "" $HOME"#) ==
+            ["echo", #"a "b""#, "c d", "", "$HOME"])
+        #expect(throws: ValidationError.self) {
+            try PostCaptureHook.splitArguments(#"echo "unterminated"#)
+        }
+    }
+
+    @Test(.tags(.fast))
+    func `Paths are appended or replace the placeholder`() throws {
+        let paths = ["/tmp/a.png", "/tmp/b.png"]
+        #expect(try PostCaptureHook(command: "open -R").invocation(paths: paths) == ["open", "-R"] + paths)
+        #expect(try PostCaptureHook(command: "scp {} host:/shots/").invocation(paths: paths) ==
+            ["scp"] + paths + ["host:/shots/"])
+    }
+
+    @Test(.tags(.fast))
+    func `Environment assignments need a key`() throws {
+        #expect(try PostCaptureHook.parseEnvironment(["TOKEN=a=b", "EMPTY="]) == ["TOKEN": "a=b", "EMPTY": ""])
+        #expect(throws: ValidationError.self) {
+            try PostCaptureHook.parseEnvironment(["=value"])
+        }
+        #expect(throws: ValidationError.self) {
+            try ImageCommand.parse(["--exec-after-env", "A=1"]).validateExecAfterOptions()
+        }
+        #expect(throws: ValidationError.self) {
+            try ImageCommand.parse(["--exec-after", "true", "--path", "-"]).validateExecAfterOptions()
+        }
+    }
+
+    @Test(.tags(.fast))
+    func `The hook sees the paths and extra environment`() throws {
+        let hook = try PostCaptureHook(
+            command: #"sh -c 'test "$HOOK_MODE" = upload && test "$0" = /tmp/shot.png'"#,
+            environment: ["HOOK_MODE=upload"]
+        )
+        try hook.run(paths: ["/tmp/shot.png"])
+    }
+
+    @Test(.tags(.fast))
+    func `A failing hook reports its exit status and stderr`() throws {
+        let hook = try PostCaptureHook(command: #"sh -c 'echo "upload refused" >&2; exit 3'"#)
+        let error = try #require(throws: PeekabooError.self) {
+            try hook.run(paths: [])
+        }
+        #expect(error.variant == "commandFailed")
+        #expect(error.localizedDescription.contains("status 3"))
+        #expect(error.localizedDescription.contains("upload refused"))
+    }
+}
@@ -0,0 +1,36 @@
+import CoreGraphics
+import Foundation
+import Testing
+@testable import PeekabooCLI
+
+@Suite(.tags(.imageCapture, .unit))
+struct ImageFilterChainTests {
+    @Test(.tags(.fast))
+    func `Filters run in order`() throws {
+        let base = try TestImages.solid(width: 10, height: 10, red: 255, green: 255, blue: 255)
+        let red = try #require(HexColor(hex: "#FF0000"))
+        let blue = try #require(HexColor(hex: "#0000FF"))
+        let area = CGRect(x: 0, y: 0, width: 10, height: 10)
+
+        let chain = CapturedImageFilterChain(filters: [
+            AnnotationFilter(annotations: [.rect(area, color: red, fill: true, lineWidth: 1)]),
+            AnnotationFilter(annotations: [.rect(area, color: blue, fill: true, lineWidth: 1)]),
+        ])
+        let pixels = try TestImages.rgbaPixels(of: chain.apply(to: base))
+
+        #expect(pixels(5, 5) == [0, 0, 255, 255])
+    }
+
+    @Test(.tags(.fast))
+    @MainActor
+    func `Chain only includes requested filters`() throws {
+        #expect(try ImageCommand.parse([]).makeFilterChain().isEmpty)
+
+        let annotated = try ImageCommand.parse([
+            "--annotate", "[{\"type\": \"rect\", \"x\": 0, \"y\": 0, \"w\": 4, \"h\": 4}]",
+        ])
+        let chain = try annotated.makeFilterChain()
+        #expect(chain.filters.count == 1)
+        #expect(chain.filters.first is AnnotationFilter)
+    }
+}
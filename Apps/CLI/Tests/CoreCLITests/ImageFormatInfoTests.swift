@@ -0,0 +1,55 @@
+import CoreGraphics
+import Foundation
+import ImageIO
+import PeekabooCore
+import Testing
+import UniformTypeIdentifiers
+@testable import PeekabooCLI
+
+@Suite(.tags(.imageCapture, .unit))
+@MainActor
+struct ImageFormatInfoTests {
+    @Test(.tags(.fast))
+    func `Every format is listed with extensions the path parser accepts`() {
+        let formats = PeekabooCore.ImageFormat.allCases.map(\.metadata)
+        #expect(formats.map(\.format) == PeekabooCore.ImageFormat.allCases.map(\.rawValue))
+
+        for format in PeekabooCore.ImageFormat.allCases {
+            #expect(format.metadata.extensions.first == format.fileExtension)
+            for fileExtension in format.metadata.extensions {
+                #expect(PeekabooCore.ImageFormat(path: "capture.\(fileExtension)") == format)
+            }
+            #expect(PeekabooCore.ImageFormat(argument: format.metadata.format) == format)
+        }
+    }
+
+    @Test(.tags(.fast))
+    func `Listed MIME types match what the encoder writes`() throws {
+        let context = try CapturedImageIO.makeContext(width: 8, height: 8)
+        let image = try #require(context.makeImage())
+
+        for format in PeekabooCore.ImageFormat.allCases {
+            let url = FileManager.default.temporaryDirectory
+                .appendingPathComponent("peekaboo-format-info-\(UUID())")
+                .appendingPathExtension(format.fileExtension)
+            defer { try? FileManager.default.removeItem(at: url) }
+            try CapturedImageIO.write(image, to: url, format: format)
+
+            let source = try #require(CGImageSourceCreateWithURL(url as CFURL, nil))
+            let typeIdentifier = try #require(CGImageSourceGetType(source)) as String
+            let written = try #require(UTType(typeIdentifier))
+            #expect(written.preferredMIMEType == format.metadata.mime_type)
+            #expect(written.conforms(to: .png) == format.metadata.lossless)
+        }
+    }
+
+    @Test(.tags(.fast))
+    func `Format table has a row per format`() {
+        let table = CommanderRuntimeRouter.renderImageFormatTable(PeekabooCore.ImageFormat.allCases.map(\.metadata))
+        let lines = table.split(separator: "\n")
+        #expect(lines.count == PeekabooCore.ImageFormat.allCases.count + 1)
+        #expect(lines[0].hasPrefix("FORMAT"))
+        #expect(table.contains(".jpg, .jpeg"))
+        #expect(table.contains("image/png"))
+    }
+}
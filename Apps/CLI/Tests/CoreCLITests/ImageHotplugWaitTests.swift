@@ -0,0 +1,65 @@
+import Commander
+import CoreGraphics
+import PeekabooCore
+import PeekabooFoundation
+import Testing
+@testable import PeekabooCLI
+
+@Suite(.tags(.imageCapture, .unit))
+@MainActor
+struct ImageHotplugWaitTests {
+    @Test(.tags(.fast))
+    func `Displays are ready once the requested one is registered`() {
+        let builtIn = [TestImages.screen(index: 0, name: "Built-in Retina Display")]
+        let docked = builtIn + [TestImages.screen(index: 1, name: "DELL U2720Q")]
+
+        #expect(!ImageCommand.displaysReady([], screenIndex: nil, monitorName: nil))
+        #expect(ImageCommand.displaysReady(builtIn, screenIndex: nil, monitorName: nil))
+        #expect(!ImageCommand.displaysReady(builtIn, screenIndex: 1, monitorName: nil))
+        #expect(ImageCommand.displaysReady(docked, screenIndex: 1, monitorName: nil))
+        #expect(!ImageCommand.displaysReady(builtIn, screenIndex: nil, monitorName: "DELL"))
+        #expect(ImageCommand.displaysReady(docked, screenIndex: nil, monitorName: "DELL"))
+    }
+
+    @Test(.tags(.fast))
+    func `Waiting polls until a display appears and reports progress`() async throws {
+        var polls = 0
+        var progress: [Int] = []
+        try await ImageCommand.waitForDisplays(
+            timeoutMs: 5000,
+            interval: .milliseconds(1),
+            ready: {
+                polls += 1
+                return polls >= 3
+            },
+            progress: { progress.append($0) }
+        )
+        #expect(polls == 3)
+        #expect(progress.count == 2)
+        #expect(progress == progress.sorted())
+    }
+
+    @Test(.tags(.fast))
+    func `Waiting times out with no displays available`() async throws {
+        await #expect(throws: PeekabooError.self) {
+            try await ImageCommand.waitForDisplays(
+                timeoutMs: 20,
+                interval: .milliseconds(5),
+                ready: { false },
+                progress: { _ in }
+            )
+        }
+    }
+
+    @Test(.tags(.fast))
+    func `Hotplug wait parses and rejects negative values`() throws {
+        var command = try ImageCommand.parse(["--screen-index", "1", "--monitor-hotplug-wait-ms", "3000"])
+        #expect(command.monitorHotplugWaitMs == 3000)
+        try command.validateMonitorHotplugWaitOptions()
+
+        command.monitorHotplugWaitMs = -1
+        #expect(throws: ValidationError.self) {
+            try command.validateMonitorHotplugWaitOptions()
+        }
+    }
+}
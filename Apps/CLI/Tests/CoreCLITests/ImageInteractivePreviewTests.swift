@@ -0,0 +1,72 @@
+import Commander
+import Foundation
+import PeekabooCore
+import Testing
+@testable import PeekabooCLI
+
+@Suite(.tags(.imageCapture, .unit))
+@MainActor
+struct ImageInteractivePreviewTests {
+    @Test(.tags(.fast))
+    func `Interactive mode keeps inferring the capture target`() throws {
+        let command = try ImageCommand.parse(["--mode", "Interactive", "--app", "Safari", "--preview-timeout-ms", "0"])
+        #expect(command.interactivePreview)
+        #expect(command.mode == nil)
+        #expect(command.determineMode() == .window)
+        try command.validateInteractivePreviewOptions(hasGUISession: true)
+
+        #expect(try !ImageCommand.parse(["--mode", "screen"]).interactivePreview)
+    }
+
+    @Test(.tags(.fast))
+    func `Interactive mode needs a person at a desktop session`() throws {
+        #expect(throws: ValidationError.self) {
+            try ImageCommand.parse(["--mode", "interactive"]).validateInteractivePreviewOptions(hasGUISession: false)
+        }
+        for arguments in [
+            ["--mode", "interactive", "--json"],
+            ["--mode", "interactive", "--path", "-"],
+            ["--mode", "interactive", "--preview-timeout-ms", "-1"],
+            ["--preview-timeout-ms", "500"],
+        ] {
+            let command = try ImageCommand.parse(arguments)
+            #expect(throws: ValidationError.self) {
+                try command.validateInteractivePreviewOptions(hasGUISession: true)
+            }
+        }
+    }
+
+    @Test(.tags(.fast))
+    func `Retry recaptures and discard deletes the files`() async throws {
+        let command = try ImageCommand.parse(["--mode", "interactive", "--preview-timeout-ms", "250"])
+        var decisions: [ImagePreviewDecision] = [.retry, .save]
+        var timeouts: [Int?] = []
+        var written: [URL] = []
+        let capture = {
+            let url = try Self.writeCapture()
+            written.append(url)
+            return [TestImages.capture(at: url)]
+        }
+
+        let saved = try await command.previewingCaptures(presenting: { _, count, timeoutMs in
+            #expect(count == 1)
+            timeouts.append(timeoutMs)
+            return decisions.removeFirst()
+        }, capture: capture)
+        #expect(written.count == 2)
+        #expect(timeouts == [250, 250])
+        #expect(!FileManager.default.fileExists(atPath: written[0].path))
+        #expect(saved.map(\.file.path) == [written[1].path])
+
+        let discarded = try await command.previewingCaptures(presenting: { _, _, _ in .discard }, capture: capture)
+        #expect(discarded.isEmpty)
+        #expect(!FileManager.default.fileExists(atPath: written[2].path))
+        try? FileManager.default.removeItem(at: written[1])
+    }
+
+    private static func writeCapture() throws -> URL {
+        let url = FileManager.default.temporaryDirectory.appendingPathComponent("peekaboo-preview-\(UUID()).png")
+        try Data([0]).write(to: url)
+        return url
+    }
+}
@@ -0,0 +1,48 @@
+import Commander
+import Foundation
+import PeekabooCore
+import Testing
+@testable import PeekabooCLI
+
+@Suite(.tags(.imageCapture, .unit))
+@MainActor
+struct ImageMaxFileSizeTests {
+    @Test(.tags(.fast))
+    func `Files over the limit fail with the measured size`() throws {
+        try ImageCommand.checkFileSize(bytes: 50 * 1024, limitKB: 50)
+
+        let error = try #require(throws: PeekabooError.self) {
+            try ImageCommand.checkFileSize(bytes: 50 * 1024 + 1, limitKB: 50)
+        }
+        #expect(error.variant == "invalidInput")
+        #expect(error.localizedDescription.contains("51kb > 50kb"))
+        #expect(error.localizedDescription.contains("--format jpg"))
+    }
+
+    @Test(.tags(.fast))
+    func `An oversized capture is deleted before the error`() throws {
+        let url = FileManager.default.temporaryDirectory.appendingPathComponent("peekaboo-size-\(UUID()).png")
+        defer { try? FileManager.default.removeItem(at: url) }
+        let image = try TestImages.solid(width: 256, height: 256, red: 10, green: 200, blue: 90)
+        try CapturedImageIO.write(image, to: url, format: .png)
+        let bytes = try #require(FileManager.default.attributesOfItem(atPath: url.path)[.size] as? NSNumber)
+
+        // A limit one KB under the file's size, so the encoder's exact output doesn't matter.
+        let limit = max((bytes.intValue + 1023) / 1024 - 1, 1)
+        let command = try ImageCommand.parse(["--max-file-size-kb", "\(limit)"])
+        let capture = TestImages.capture(at: url)
+
+        #expect(throws: PeekabooError.self) {
+            try command.enforcingFileSizeLimit([capture])
+        }
+        #expect(!FileManager.default.fileExists(atPath: url.path))
+    }
+
+    @Test(.tags(.fast))
+    func `Limit must be positive`() throws {
+        try ImageCommand.parse(["--max-file-size-kb", "200"]).validateMaxFileSizeOptions()
+        #expect(throws: ValidationError.self) {
+            try ImageCommand.parse(["--max-file-size-kb", "0"]).validateMaxFileSizeOptions()
+        }
+    }
+}
@@ -0,0 +1,129 @@
+import Commander
+import CoreGraphics
+import Foundation
+import Testing
+@testable import PeekabooCLI
+
+@Suite(.tags(.imageCapture, .multiDisplay, .unit))
+struct ImageMergeDisplaysTests {
+    @Test(.tags(.fast))
+    func `Side by side displays share one canvas`() {
+        let layout = DisplayMergeLayout(
+            frames: [
+                CGRect(x: 0, y: 0, width: 1440, height: 900),
+                CGRect(x: 1440, y: 0, width: 1920, height: 1080),
+            ],
+            pixelSizes: [
+                CGSize(width: 1440, height: 900),
+                CGSize(width: 1920, height: 1080),
+            ]
+        )
+
+        #expect(layout.canvasSize == CGSize(width: 3360, height: 1080))
+        #expect(layout.placements == [
+            CGRect(x: 0, y: 0, width: 1440, height: 900),
+            CGRect(x: 1440, y: 0, width: 1920, height: 1080),
+        ])
+    }
+
+    @Test(.tags(.fast))
+    func `Displays left of and above the primary shift into positive canvas space`() {
+        let layout = DisplayMergeLayout(
+            frames: [
+                CGRect(x: 0, y: 0, width: 1000, height: 800),
+                CGRect(x: -800, y: 800, width: 800, height: 600),
+            ],
+            pixelSizes: [
+                CGSize(width: 1000, height: 800),
+                CGSize(width: 800, height: 600),
+            ]
+        )
+
+        #expect(layout.canvasSize == CGSize(width: 1800, height: 1400))
+        #expect(layout.placements == [
+            CGRect(x: 800, y: 0, width: 1000, height: 800),
+            CGRect(x: 0, y: 800, width: 800, height: 600),
+        ])
+    }
+
+    @Test(.tags(.fast))
+    func `Mixed scale layouts use the densest display`() {
+        let layout = DisplayMergeLayout(
+            frames: [
+                CGRect(x: 0, y: 0, width: 1512, height: 982),
+                CGRect(x: 1512, y: 0, width: 1920, height: 1080),
+            ],
+            pixelSizes: [
+                CGSize(width: 3024, height: 1964),
+                CGSize(width: 1920, height: 1080),
+            ]
+        )
+
+        #expect(layout.canvasSize == CGSize(width: 6864, height: 2160))
+        #expect(layout.placements[1] == CGRect(x: 3024, y: 0, width: 3840, height: 2160))
+    }
+
+    @Test(.tags(.fast))
+    func `Gap color parses hex with and without alpha`() {
+        #expect(HexColor(hex: "#000000") == .black)
+        #expect(HexColor(hex: "ffffff") == HexColor(red: 1, green: 1, blue: 1, alpha: 1))
+        #expect(HexColor(hex: "#FF000000") == HexColor(red: 1, green: 0, blue: 0, alpha: 0))
+        #expect(HexColor(hex: "#12345") == nil)
+        #expect(HexColor(hex: "black") == nil)
+    }
+
+    @Test(.tags(.fast))
+    @MainActor
+    func `Composite fills uncovered areas with the gap color`() throws {
+        let layout = DisplayMergeLayout(
+            frames: [
+                CGRect(x: 0, y: 0, width: 4, height: 4),
+                CGRect(x: 4, y: 0, width: 4, height: 2),
+            ],
+            pixelSizes: [CGSize(width: 4, height: 4), CGSize(width: 4, height: 2)]
+        )
+        let images = try [
+            TestImages.solid(width: 4, height: 4, red: 255, green: 0, blue: 0),
+            TestImages.solid(width: 4, height: 2, red: 0, green: 0, blue: 255),
+        ]
+        let gap = try #require(HexColor(hex: "#00FF00"))
+
+        let merged = try ImageCommand.compositeMergedImage(images, layout: layout, gapColor: gap)
+        let pixels = try TestImages.rgbaPixels(of: merged)
+
+        #expect(merged.width == 8)
+        #expect(merged.height == 4)
+        // Rows are stored top-down: the top-right quadrant sits above the shorter display.
+        #expect(pixels(0, 0) == [255, 0, 0, 255])
+        #expect(pixels(7, 3) == [0, 0, 255, 255])
+        #expect(pixels(7, 0) == [0, 255, 0, 255])
+    }
+
+    @Test(.tags(.fast))
+    @MainActor
+    func `Merge displays rejects conflicting options`() throws {
+        let withIndex = try ImageCommand.parse(["--merge-displays", "--screen-index", "1"])
+        #expect(throws: ValidationError.self) {
+            try withIndex.validateMergeDisplaysOptions()
+        }
+
+        let withWindowMode = try ImageCommand.parse(["--merge-displays", "--mode", "window"])
+        #expect(throws: ValidationError.self) {
+            try withWindowMode.validateMergeDisplaysOptions()
+        }
+
+        let badColor = try ImageCommand.parse(["--merge-displays", "--gap-color", "teal"])
+        #expect(throws: ValidationError.self) {
+            try badColor.validateMergeDisplaysOptions()
+        }
+
+        let gapWithoutMerge = try ImageCommand.parse(["--gap-color", "#FFFFFF"])
+        #expect(throws: ValidationError.self) {
+            try gapWithoutMerge.validateMergeDisplaysOptions()
+        }
+
+        let valid = try ImageCommand.parse(["--merge-displays", "--gap-color", "#202020"])
+        #expect(valid.mergeDisplays)
+        try valid.validateMergeDisplaysOptions()
+    }
+}
@@ -0,0 +1,57 @@
+import Commander
+import CoreGraphics
+import PeekabooCore
+import Testing
+@testable import PeekabooCLI
+
+@Suite(.tags(.imageCapture, .unit))
+@MainActor
+struct ImageMonitorNameTests {
+    private let screens = [
+        TestImages.screen(index: 0, name: "Built-in Retina Display"),
+        TestImages.screen(index: 1, name: "DELL U2720Q"),
+        TestImages.screen(index: 2, name: "DELL P2419H"),
+        TestImages.screen(index: 3, name: "LG HDR 4K"),
+    ]
+
+    @Test(.tags(.fast))
+    func `Matches monitor names case-insensitively by prefix`() throws {
+        #expect(try ImageCommand.displayIndex(named: "built-in", in: self.screens) == 0)
+        #expect(try ImageCommand.displayIndex(named: "lg", in: self.screens) == 3)
+        #expect(try ImageCommand.displayIndex(named: "dell p", in: self.screens) == 2)
+        #expect(try ImageCommand.displayIndex(named: "  DELL U2720Q ", in: self.screens) == 1)
+    }
+
+    @Test(.tags(.fast))
+    func `Ambiguous or unknown names list the available monitors`() {
+        #expect(throws: ValidationError.self) {
+            try ImageCommand.displayIndex(named: "DELL", in: self.screens)
+        }
+
+        do {
+            _ = try ImageCommand.displayIndex(named: "HDMI-1", in: self.screens)
+            Issue.record("Expected an unknown monitor name to throw")
+        } catch {
+            let description = String(describing: error)
+            #expect(description.contains("'DELL U2720Q'"))
+            #expect(description.contains("'LG HDR 4K'"))
+        }
+    }
+
+    @Test(.tags(.fast))
+    func `Monitor name excludes screen index and merged displays`() throws {
+        let named = try ImageCommand.parse(["--mode", "screen", "--monitor-name", "LG"])
+        #expect(named.monitorName == "LG")
+        try named.validateMonitorNameOptions()
+
+        let both = try ImageCommand.parse(["--mode", "screen", "--monitor-name", "LG", "--screen-index", "1"])
+        #expect(throws: ValidationError.self) {
+            try both.validateMonitorNameOptions()
+        }
+
+        let merged = try ImageCommand.parse(["--merge-displays", "--monitor-name", "LG"])
+        #expect(throws: ValidationError.self) {
+            try merged.validateMonitorNameOptions()
+        }
+    }
+}
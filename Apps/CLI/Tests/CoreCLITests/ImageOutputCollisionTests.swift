@@ -0,0 +1,65 @@
+import Commander
+import Foundation
+import PeekabooCore
+import PeekabooFoundation
+import Testing
+@testable import PeekabooCLI
+
+@Suite(.tags(.imageCapture, .unit))
+@MainActor
+struct ImageOutputCollisionTests {
+    @Test(.tags(.fast))
+    func `Rename picks the first free numeric suffix and reports the collision`() throws {
+        let directory = try Self.makeDirectory()
+        defer { try? FileManager.default.removeItem(at: directory) }
+        let existing = directory.appendingPathComponent("shot.png")
+        FileManager.default.createFile(atPath: existing.path, contents: Data())
+        FileManager.default.createFile(atPath: directory.appendingPathComponent("shot-1.png").path, contents: Data())
+
+        let command = try ImageCommand.parse(["--path", existing.path])
+        #expect(command.resolvedCollisionPolicy == .rename)
+        let url = try command.makeCollisionFreeOutputURL(preferredName: nil, index: nil)
+        #expect(url.lastPathComponent == "shot-2.png")
+
+        let marked = command.markingRenamedFiles([TestImages.capture(at: url), TestImages.capture(at: existing)])
+        #expect(marked[0].file.renamed == true)
+        #expect(marked[0].file.collision_with == existing.path)
+        #expect(marked[1].file.renamed == nil)
+        #expect(marked[1].file.collision_with == nil)
+    }
+
+    @Test(.tags(.fast))
+    func `Overwrite keeps the path and error fails before capturing`() throws {
+        let directory = try Self.makeDirectory()
+        defer { try? FileManager.default.removeItem(at: directory) }
+        let existing = directory.appendingPathComponent("shot.png")
+        FileManager.default.createFile(atPath: existing.path, contents: Data())
+
+        let overwrite = try ImageCommand.parse(["--path", existing.path, "--on-collision", "overwrite"])
+        #expect(try overwrite.makeCollisionFreeOutputURL(preferredName: nil, index: nil) == existing)
+        #expect(overwrite.outputCollisions.originals.isEmpty)
+
+        let strict = try ImageCommand.parse(["--path", existing.path, "--on-collision", "error"])
+        let error = try #require(throws: PeekabooError.self) {
+            try strict.makeCollisionFreeOutputURL(preferredName: nil, index: nil)
+        }
+        #expect(error.localizedDescription.contains("already exists"))
+
+        let fresh = directory.appendingPathComponent("new.png")
+        let unused = try ImageCommand.parse(["--path", fresh.path, "--on-collision", "error"])
+        #expect(try unused.makeCollisionFreeOutputURL(preferredName: nil, index: nil) == fresh)
+    }
+
+    @Test(.tags(.fast))
+    func `Unknown policies are rejected`() {
+        #expect(throws: (any Error).self) {
+            _ = try ImageCommand.parse(["--on-collision", "skip"])
+        }
+    }
+
+    private static func makeDirectory() throws -> URL {
+        let directory = FileManager.default.temporaryDirectory.appendingPathComponent("peekaboo-collision-\(UUID())")
+        try FileManager.default.createDirectory(at: directory, withIntermediateDirectories: true)
+        return directory
+    }
+}
@@ -0,0 +1,46 @@
+import Commander
+import Foundation
+import PeekabooCore
+import Testing
+@testable import PeekabooCLI
+
+@Suite(.tags(.imageCapture, .unit))
+@MainActor
+struct ImageOutputJSONPathTests {
+    @Test(.tags(.fast))
+    func `Writes the JSON envelope to the file, creating parent directories`() throws {
+        let directory = FileManager.default.temporaryDirectory
+            .appendingPathComponent("peekaboo-json-path-\(UUID().uuidString)")
+        defer { try? FileManager.default.removeItem(at: directory) }
+        let url = directory.appendingPathComponent("nested").appendingPathComponent("meta.json")
+        let result = ImageCaptureResult(
+            files: [SavedFile(path: "/tmp/shot.png", item_label: "Safari", mime_type: "image/png").with(path: "-")],
+            observations: [],
+            session: nil,
+            timing: nil
+        )
+
+        try ImageCommand.writeJSONResult(result, debugLogs: ["captured"], to: url.path)
+
+        let response = try JSONDecoder().decode(
+            CodableJSONResponse<ImageCaptureResult>.self,
+            from: Data(contentsOf: url)
+        )
+        #expect(response.success)
+        #expect(response.debug_logs == ["captured"])
+        #expect(response.data.files.map(\.path) == ["-"])
+        #expect(response.data.files.first?.item_label == "Safari")
+    }
+
+    @Test(.tags(.fast))
+    func `Output JSON path combines with stdout streaming but needs a real file`() throws {
+        let streamed = try ImageCommand.parse(["--path", "-", "--output-json-path", "/tmp/meta.json"])
+        #expect(streamed.outputJsonPath == "/tmp/meta.json")
+        try streamed.validateStdoutStreamingOptions()
+
+        let dash = try ImageCommand.parse(["--output-json-path", "-"])
+        #expect(throws: ValidationError.self) {
+            try dash.validateStdoutStreamingOptions()
+        }
+    }
+}
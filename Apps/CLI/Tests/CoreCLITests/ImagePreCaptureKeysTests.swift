@@ -0,0 +1,45 @@
+import Commander
+import Testing
+@testable import PeekabooCLI
+
+@Suite(.tags(.imageCapture, .unit))
+@MainActor
+struct ImagePreCaptureKeysTests {
+    @Test(.tags(.fast))
+    func `Each value is one chord, pressed in order`() throws {
+        let command = try ImageCommand.parse([
+            "--app", "Code",
+            "--pre-capture-keys", "cmd+shift+p",
+            "--pre-capture-keys", "Escape",
+            "--pre-capture-delay-ms", "300",
+            "--allow-input-injection",
+        ])
+        #expect(command.preCaptureChords == [["cmd", "shift", "p"], ["escape"]])
+        #expect(command.preCaptureDelayMs == 300)
+        try command.validatePreCaptureKeysOptions()
+    }
+
+    @Test(.tags(.fast))
+    func `Keys need explicit consent and a focusable app target`() throws {
+        let invalid: [[String]] = [
+            ["--app", "Code", "--pre-capture-keys", "cmd+p"],
+            ["--app", "Code", "--pre-capture-delay-ms", "100"],
+            ["--app", "Code", "--allow-input-injection"],
+            ["--app", "Code", "--pre-capture-keys", " + ", "--allow-input-injection"],
+            ["--mode", "screen", "--pre-capture-keys", "cmd+p", "--allow-input-injection"],
+            ["--app", "frontmost", "--pre-capture-keys", "cmd+p", "--allow-input-injection"],
+            ["--app", "Code", "--window-id", "42", "--pre-capture-keys", "cmd+p", "--allow-input-injection"],
+            [
+                "--app", "Code", "--capture-focus", "background",
+                "--pre-capture-keys", "cmd+p", "--allow-input-injection",
+            ],
+        ]
+        for arguments in invalid {
+            let command = try ImageCommand.parse(arguments)
+            #expect(throws: ValidationError.self) {
+                try command.validatePreCaptureKeysOptions()
+            }
+        }
+        try ImageCommand.parse(["--app", "Code"]).validatePreCaptureKeysOptions()
+    }
+}
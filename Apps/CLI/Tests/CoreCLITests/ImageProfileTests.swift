@@ -0,0 +1,107 @@
+import Commander
+import Foundation
+import PeekabooCore
+import Testing
+@testable import PeekabooCLI
+
+@Suite(.tags(.imageCapture, .unit))
+@MainActor
+struct ImageProfileTests {
+    @Test(.tags(.fast))
+    func `CLI flags beat the profile, which beats the global config`() throws {
+        let directory = try Self.makeProfileDirectory([
+            "work": #"{"format": "jpg", "mode": "frontmost", "retina": true}"#,
+        ])
+        defer { try? FileManager.default.removeItem(atPath: directory) }
+        let global = PeekabooCore.Configuration.DefaultsConfig(
+            imageFormat: "png",
+            captureMode: "screen",
+            captureFocus: "background"
+        )
+
+        let merged = try ImageProfiles.resolve(
+            CommanderBindableValues(positional: [], options: ["profile": ["work"], "mode": ["window"]], flags: []),
+            environment: [:],
+            directory: directory,
+            globalDefaults: global
+        )
+        #expect(merged.options["mode"] == ["window"])
+        #expect(merged.options["format"] == ["jpg"])
+        #expect(merged.options["captureFocus"] == ["background"])
+        #expect(merged.flags.contains("retina"))
+
+        let withoutProfile = try ImageProfiles.resolve(
+            CommanderBindableValues(positional: [], options: [:], flags: []),
+            environment: [:],
+            directory: directory,
+            globalDefaults: global
+        )
+        #expect(withoutProfile.options["format"] == ["png"])
+        #expect(withoutProfile.options["mode"] == ["screen"])
+    }
+
+    @Test(.tags(.fast))
+    func `PEEKABOO_PROFILE selects a profile when --profile is absent`() throws {
+        let directory = try Self.makeProfileDirectory([
+            "ci": #"{"window-padding": 8}"#,
+            "docs": #"{"window-padding": 16}"#,
+        ])
+        defer { try? FileManager.default.removeItem(atPath: directory) }
+
+        let fromEnvironment = try ImageProfiles.resolve(
+            CommanderBindableValues(positional: [], options: [:], flags: []),
+            environment: [EnvironmentConfig.profileName: "ci"],
+            directory: directory,
+            globalDefaults: nil
+        )
+        #expect(fromEnvironment.options["windowPadding"] == ["8"])
+
+        let explicit = try ImageProfiles.resolve(
+            CommanderBindableValues(positional: [], options: ["profile": ["docs"]], flags: []),
+            environment: [EnvironmentConfig.profileName: "ci"],
+            directory: directory,
+            globalDefaults: nil
+        )
+        #expect(explicit.options["windowPadding"] == ["16"])
+        #expect(ImageProfiles.names(in: directory) == ["ci", "docs"])
+    }
+
+    @Test(.tags(.fast))
+    func `A path from a higher layer keeps a lower format from conflicting`() {
+        let merged = ImageProfiles.merging(
+            CommanderBindableValues(positional: [], options: ["path": ["/tmp/shot.jpg"]], flags: []),
+            over: [CommanderBindableValues(positional: [], options: ["format": ["png"]], flags: [])]
+        )
+        #expect(merged.options["path"] == ["/tmp/shot.jpg"])
+        #expect(merged.options["format"] == nil)
+    }
+
+    @Test(.tags(.fast))
+    func `Bad profiles are rejected`() throws {
+        let directory = try Self.makeProfileDirectory([
+            "typo": #"{"formt": "jpg"}"#,
+            "nested": #"{"profile": "other"}"#,
+            "broken": "not json",
+        ])
+        defer { try? FileManager.default.removeItem(atPath: directory) }
+
+        for name in ["typo", "nested", "broken", "missing", "../escape"] {
+            #expect(throws: ValidationError.self) {
+                try ImageProfiles.load(name, from: directory)
+            }
+        }
+        #expect(throws: (any Error).self) {
+            _ = try ImageCommand.parse(["--profile", "peekaboo-missing-\(UUID().uuidString)"])
+        }
+    }
+
+    private static func makeProfileDirectory(_ profiles: [String: String]) throws -> String {
+        let directory = FileManager.default.temporaryDirectory
+            .appendingPathComponent("peekaboo-profiles-\(UUID().uuidString)")
+        try FileManager.default.createDirectory(at: directory, withIntermediateDirectories: true)
+        for (name, contents) in profiles {
+            try contents.write(to: directory.appendingPathComponent("\(name).json"), atomically: true, encoding: .utf8)
+        }
+        return directory.path
+    }
+}
@@ -0,0 +1,96 @@
+import Commander
+import CoreGraphics
+import Foundation
+import PeekabooCore
+import Testing
+@testable import PeekabooCLI
+
+@Suite(.tags(.imageCapture, .unit))
+@MainActor
+struct ImageSmartCropTests {
+    @Test(.tags(.fast))
+    func `The window covers the one detailed patch`() throws {
+        let image = try Self.image(width: 40, height: 30, checker: CGRect(x: 20, y: 12, width: 4, height: 4))
+
+        let region = try #require(try SaliencyCropper.mostSalientRegion(in: image, width: 10, height: 10))
+        #expect(region.size == CGSize(width: 10, height: 10))
+        #expect(region.contains(CGRect(x: 20, y: 12, width: 4, height: 4)))
+    }
+
+    @Test(.tags(.fast))
+    func `A busy patch beats a plain block`() throws {
+        let image = try Self.image(
+            width: 40,
+            height: 30,
+            checker: CGRect(x: 4, y: 4, width: 4, height: 4),
+            block: CGRect(x: 28, y: 18, width: 4, height: 4)
+        )
+
+        let region = try #require(try SaliencyCropper.mostSalientRegion(in: image, width: 8, height: 8))
+        #expect(region.contains(CGRect(x: 4, y: 4, width: 4, height: 4)))
+        #expect(!region.intersects(CGRect(x: 28, y: 18, width: 4, height: 4)))
+    }
+
+    @Test(.tags(.fast))
+    func `Flat images have no saliency and small ones cannot be cropped`() throws {
+        let blank = try TestImages.solid(width: 6, height: 4, red: 255, green: 255, blue: 255)
+        #expect(try SaliencyCropper.saliencyMap(of: blank).allSatisfy { $0 == 0 })
+        let whole = try SaliencyCropper.mostSalientRegion(in: blank, width: 6, height: 4)
+        #expect(whole == CGRect(x: 0, y: 0, width: 6, height: 4))
+        #expect(try SaliencyCropper.mostSalientRegion(in: blank, width: 7, height: 4) == nil)
+    }
+
+    @Test(.tags(.fast))
+    func `Smart crops keep the exact size and report the region in the full capture`() throws {
+        let url = FileManager.default.temporaryDirectory.appendingPathComponent("peekaboo-smart-crop-\(UUID()).png")
+        defer { try? FileManager.default.removeItem(at: url) }
+        let image = try Self.image(width: 40, height: 30, checker: CGRect(x: 20, y: 12, width: 4, height: 4))
+        try CapturedImageIO.write(image, to: url, format: .png)
+
+        let command = try ImageCommand.parse(["--crop-to-bounds", "10,5,30,25", "--smart-crop", "10x10"])
+        let cropped = try command.smartCropping(command.croppingToBounds([TestImages.capture(at: url)]))
+
+        let saved = try CapturedImageIO.load(at: url.path)
+        #expect(saved.width == 10 && saved.height == 10)
+        let region = try #require(cropped[0].file.smart_crop_region)
+        #expect(region.width == 10 && region.height == 10)
+        #expect(region.cgRect.contains(CGRect(x: 20, y: 12, width: 4, height: 4)))
+
+        let tooLarge = try ImageCommand.parse(["--smart-crop", "100x100"])
+        #expect(throws: PeekabooError.self) {
+            try tooLarge.smartCropping([TestImages.capture(at: url)])
+        }
+    }
+
+    @Test(.tags(.fast))
+    func `Smart crop sizes must be WIDTHxHEIGHT`() throws {
+        #expect(try ImageCommand.parse([]).smartCropSize() == nil)
+        #expect(try ImageCommand.parse(["--smart-crop", "800X600"]).smartCropSize()?.width == 800)
+        for value in ["800", "800x", "0x600", "-5x5", "1x2x3", "wide"] {
+            #expect(throws: ValidationError.self) {
+                try ImageCommand.parse(["--smart-crop", value]).validateSmartCropOptions()
+            }
+        }
+    }
+
+    /// White image with a black-and-white `checker` patch and an optional solid black `block`.
+    private static func image(
+        width: Int,
+        height: Int,
+        checker: CGRect,
+        block: CGRect = .null
+    ) throws -> CGImage {
+        var bytes = [UInt8](repeating: 255, count: width * height * 4)
+        for y in 0..<height {
+            for x in 0..<width {
+                let point = CGPoint(x: Double(x) + 0.5, y: Double(y) + 0.5)
+                let isDark = block.contains(point) || (checker.contains(point) && (x + y).isMultiple(of: 2))
+                if isDark {
+                    let offset = (y * width + x) * 4
+                    bytes.replaceSubrange(offset..<offset + 3, with: [0, 0, 0])
+                }
+            }
+        }
+        return try CapturedImageIO.makeImage(rgbaBytes: bytes, width: width, height: height)
+    }
+}
@@ -0,0 +1,91 @@
+import Commander
+import CoreGraphics
+import Foundation
+import PeekabooCore
+import Testing
+@testable import PeekabooCLI
+
+@Suite(.tags(.imageCapture, .unit))
+@MainActor
+struct ImageStitchTests {
+    @Test(.tags(.fast))
+    func `Items parse as kind:value pairs`() throws {
+        #expect(try ImageStitchItem.parseList("screen:0, app:Google Chrome,pid:42,window-id:12345") == [
+            .screen(0), .app("Google Chrome"), .pid(42), .windowID(12345),
+        ])
+        for invalid in ["screen", "screen:-1", "app:", "pid:abc", "window-id:0", "tab:1", "screen:0,"] {
+            #expect(throws: ValidationError.self) {
+                try ImageStitchItem.parseList(invalid)
+            }
+        }
+    }
+
+    @Test(.tags(.fast))
+    func `Horizontal and vertical layouts scale to the smallest side`() {
+        let sizes = [CGSize(width: 2, height: 2), CGSize(width: 8, height: 4)]
+
+        let horizontal = ImageStitchPlan(sizes: sizes, layout: .horizontal, padding: 1)
+        #expect(horizontal.canvasSize == CGSize(width: 7, height: 2))
+        #expect(horizontal.placements == [
+            CGRect(x: 0, y: 0, width: 2, height: 2),
+            CGRect(x: 3, y: 0, width: 4, height: 2),
+        ])
+
+        let vertical = ImageStitchPlan(sizes: sizes, layout: .vertical, padding: 0)
+        #expect(vertical.canvasSize == CGSize(width: 2, height: 3))
+        #expect(vertical.placements == [
+            CGRect(x: 0, y: 0, width: 2, height: 2),
+            CGRect(x: 0, y: 2, width: 2, height: 1),
+        ])
+    }
+
+    @Test(.tags(.fast))
+    func `Grid layout fits each capture into an equal cell`() {
+        let sizes = Array(repeating: CGSize(width: 2, height: 2), count: 3) + [CGSize(width: 4, height: 2)]
+        let plan = ImageStitchPlan(sizes: sizes, layout: .grid, padding: 2)
+
+        #expect(plan.canvasSize == CGSize(width: 6, height: 6))
+        #expect(plan.placements[1] == CGRect(x: 4, y: 0, width: 2, height: 2))
+        #expect(plan.placements[2] == CGRect(x: 0, y: 4, width: 2, height: 2))
+        #expect(plan.placements[3] == CGRect(x: 4, y: 4, width: 2, height: 1))
+    }
+
+    @Test(.tags(.fast))
+    func `Stitched pixels keep each capture in its place`() throws {
+        let red = try TestImages.solid(width: 2, height: 2, red: 255, green: 0, blue: 0)
+        let blue = try TestImages.solid(width: 2, height: 2, red: 0, green: 0, blue: 255)
+        let white = try #require(HexColor(hex: "#FFFFFF"))
+
+        let horizontal = try ImageCommand.stitchImages([red, blue], layout: .horizontal, padding: 1, background: white)
+        #expect(horizontal.width == 5 && horizontal.height == 2)
+        let row = try TestImages.rgbaPixels(of: horizontal)
+        #expect(row(0, 0) == [255, 0, 0, 255])
+        #expect(row(2, 1) == [255, 255, 255, 255])
+        #expect(row(4, 1) == [0, 0, 255, 255])
+
+        let vertical = try ImageCommand.stitchImages([red, blue], layout: .vertical, padding: 0, background: white)
+        let column = try TestImages.rgbaPixels(of: vertical)
+        #expect(column(1, 0) == [255, 0, 0, 255])
+        #expect(column(1, 3) == [0, 0, 255, 255])
+    }
+
+    @Test(.tags(.fast))
+    func `Stitching replaces the usual targeting options`() throws {
+        try ImageCommand.parse(["--stitch-items", "screen:0,app:Safari", "--stitch-layout", "grid"])
+            .validateStitchOptions()
+        let invalid: [[String]] = [
+            ["--stitch-items", "screen:0"],
+            ["--stitch-items", "screen:0,screen:1", "--app", "Safari"],
+            ["--stitch-items", "screen:0,screen:1", "--merge-displays"],
+            ["--stitch-items", "screen:0,screen:1", "--stitch-padding", "-1"],
+            ["--stitch-layout", "vertical"],
+        ]
+        for arguments in invalid {
+            #expect(throws: ValidationError.self) {
+                try ImageCommand.parse(arguments).validateStitchOptions()
+            }
+        }
+        try ImageCommand.parse(["--stitch-items", "screen:0,screen:1", "--gap-color", "#000000"])
+            .validateMergeDisplaysOptions()
+    }
+}
@@ -0,0 +1,70 @@
+import Commander
+import CoreGraphics
+import Foundation
+import Testing
+@testable import PeekabooCLI
+
+@Suite(.tags(.imageCapture, .unit))
+struct ImageTrimTests {
+    @Test(.tags(.fast))
+    func `Content bounds skip a solid border`() throws {
+        let image = try Self.image(width: 6, height: 5, content: [(2, 1), (3, 2)])
+
+        #expect(try BorderTrimmer.contentBounds(of: image, tolerance: 10) == CGRect(x: 2, y: 1, width: 2, height: 2))
+    }
+
+    @Test(.tags(.fast))
+    func `Near-border colors within the tolerance are trimmed`() throws {
+        // The faint pixel differs from white by 5 per channel; the red one is real content.
+        let image = try Self.image(width: 5, height: 5, content: [(2, 2)], faint: [(0, 4)])
+
+        #expect(try BorderTrimmer.contentBounds(of: image, tolerance: 10) == CGRect(x: 2, y: 2, width: 1, height: 1))
+        #expect(try BorderTrimmer.contentBounds(of: image, tolerance: 0) == CGRect(x: 0, y: 2, width: 3, height: 3))
+    }
+
+    @Test(.tags(.fast))
+    func `Uniform images and borderless images are left alone`() throws {
+        let blank = try TestImages.solid(width: 8, height: 8, red: 255, green: 255, blue: 255)
+        #expect(try BorderTrimmer.contentBounds(of: blank, tolerance: 10) == nil)
+
+        let edgeToEdge = try Self.image(width: 3, height: 3, content: [(2, 2)])
+        let corner = try BorderTrimmer.contentBounds(of: edgeToEdge, tolerance: 10)
+        #expect(corner == CGRect(x: 2, y: 2, width: 1, height: 1))
+        let full = try Self.image(width: 3, height: 3, content: [(2, 2), (1, 0), (0, 1)])
+        #expect(try BorderTrimmer.contentBounds(of: full, tolerance: 10) == nil)
+    }
+
+    @Test(.tags(.fast))
+    @MainActor
+    func `Tolerance defaults to 10 and requires trim-whitespace`() throws {
+        #expect(try ImageCommand.parse(["--trim-whitespace"]).trimToleranceValue() == 10)
+        #expect(try ImageCommand.parse(["--trim-whitespace", "--trim-tolerance", "0"]).trimToleranceValue() == 0)
+        #expect(try ImageCommand.parse([]).trimToleranceValue() == nil)
+
+        #expect(throws: ValidationError.self) {
+            try ImageCommand.parse(["--trim-tolerance", "5"]).validateTrimOptions()
+        }
+        #expect(throws: ValidationError.self) {
+            try ImageCommand.parse(["--trim-whitespace", "--trim-tolerance", "300"]).validateTrimOptions()
+        }
+    }
+
+    /// White image with opaque red `content` pixels and almost-white `faint` ones.
+    private static func image(
+        width: Int,
+        height: Int,
+        content: [(Int, Int)],
+        faint: [(Int, Int)] = []
+    ) throws -> CGImage {
+        var bytes = [UInt8](repeating: 255, count: width * height * 4)
+        for (x, y) in faint {
+            let offset = (y * width + x) * 4
+            bytes.replaceSubrange(offset..<offset + 4, with: [250, 250, 250, 255])
+        }
+        for (x, y) in content {
+            let offset = (y * width + x) * 4
+            bytes.replaceSubrange(offset..<offset + 4, with: [255, 0, 0, 255])
+        }
+        return try CapturedImageIO.makeImage(rgbaBytes: bytes, width: width, height: height)
+    }
+}
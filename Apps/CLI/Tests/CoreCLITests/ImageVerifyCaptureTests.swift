@@ -0,0 +1,58 @@
+import CoreGraphics
+import Foundation
+import PeekabooCore
+import Testing
+@testable import PeekabooCLI
+
+@Suite(.tags(.imageCapture, .unit))
+@MainActor
+struct ImageVerifyCaptureTests {
+    @Test(.tags(.fast))
+    func `Complete captures verify`() throws {
+        let url = try Self.writeSample(format: .png)
+        defer { try? FileManager.default.removeItem(at: url) }
+
+        try CapturedImageIO.verify(at: url.path)
+    }
+
+    @Test(.tags(.fast))
+    func `Truncated captures fail verification`() throws {
+        let url = try Self.writeSample(format: .png)
+        defer { try? FileManager.default.removeItem(at: url) }
+        let data = try Data(contentsOf: url)
+        try data.prefix(data.count / 2).write(to: url)
+
+        #expect(throws: PeekabooError.self) {
+            try CapturedImageIO.verify(at: url.path)
+        }
+    }
+
+    @Test(.tags(.fast))
+    func `Non-image files fail verification`() throws {
+        let url = FileManager.default.temporaryDirectory.appendingPathComponent("peekaboo-verify-\(UUID()).png")
+        defer { try? FileManager.default.removeItem(at: url) }
+        try Data("not an image".utf8).write(to: url)
+
+        #expect(throws: PeekabooError.self) {
+            try CapturedImageIO.verify(at: url.path)
+        }
+    }
+
+    @Test(.tags(.fast))
+    func `Verify capture flag parses`() throws {
+        #expect(try ImageCommand.parse(["--verify-capture"]).verifyCapture)
+        #expect(try !ImageCommand.parse([]).verifyCapture)
+    }
+
+    private static func writeSample(format: PeekabooCore.ImageFormat) throws -> URL {
+        let context = try CapturedImageIO.makeContext(width: 64, height: 48)
+        context.setFillColor(HexColor.black.cgColor)
+        context.fill(CGRect(x: 0, y: 0, width: 32, height: 48))
+        let image = try #require(context.makeImage())
+        let url = FileManager.default.temporaryDirectory
+            .appendingPathComponent("peekaboo-verify-\(UUID())")
+            .appendingPathExtension(format.fileExtension)
+        try CapturedImageIO.write(image, to: url, format: format)
+        return url
+    }
+}
@@ -0,0 +1,65 @@
+import Commander
+import CoreGraphics
+import Foundation
+import Testing
+@testable import PeekabooCLI
+
+@Suite(.tags(.imageCapture, .unit))
+struct ImageVignetteTests {
+    @Test(.tags(.fast))
+    func `Center pixel is unchanged`() throws {
+        let base = try TestImages.solid(width: 11, height: 11, red: 200, green: 100, blue: 50)
+        let pixels = try TestImages.rgbaPixels(of: VignetteFilter(strength: 0.5).apply(to: base))
+
+        #expect(pixels(5, 5) == [200, 100, 50, 255])
+    }
+
+    @Test(.tags(.fast))
+    func `Corner pixel is darkened by its distance from the center`() throws {
+        let base = try TestImages.solid(width: 11, height: 11, red: 255, green: 255, blue: 255)
+        let pixels = try TestImages.rgbaPixels(of: VignetteFilter(strength: 0.5).apply(to: base))
+
+        // The corner pixel's center sits 5√2 from the image center; the corner itself is 5.5√2 away.
+        let factor = 1 - 0.5 * (5.0 / 5.5)
+        let expected = UInt8((255 * factor).rounded())
+        #expect(pixels(0, 0) == [expected, expected, expected, 255])
+        #expect(pixels(10, 10) == [expected, expected, expected, 255])
+    }
+
+    @Test(.tags(.fast))
+    func `Zero strength leaves the image untouched`() throws {
+        let base = try TestImages.solid(width: 4, height: 4, red: 10, green: 20, blue: 30)
+        let pixels = try TestImages.rgbaPixels(of: VignetteFilter(strength: 0).apply(to: base))
+
+        #expect(pixels(0, 0) == [10, 20, 30, 255])
+    }
+
+    @Test(.tags(.fast))
+    @MainActor
+    func `Vignette joins the chain after annotations`() throws {
+        let command = try ImageCommand.parse([
+            "--annotate", "[{\"type\": \"rect\", \"x\": 0, \"y\": 0, \"w\": 4, \"h\": 4}]",
+            "--vignette",
+        ])
+        let chain = try command.makeFilterChain()
+
+        #expect(chain.filters.count == 2)
+        #expect(chain.filters.first is AnnotationFilter)
+        let vignette = try #require(chain.filters.last as? VignetteFilter)
+        #expect(vignette.strength == VignetteFilter.defaultStrength)
+    }
+
+    @Test(.tags(.fast))
+    @MainActor
+    func `Vignette strength is validated`() throws {
+        let custom = try ImageCommand.parse(["--vignette", "--vignette-strength", "0.8"]).makeFilterChain()
+        #expect((custom.filters.first as? VignetteFilter)?.strength == 0.8)
+
+        #expect(throws: ValidationError.self) {
+            try ImageCommand.parse(["--vignette", "--vignette-strength", "1.5"]).makeFilterChain()
+        }
+        #expect(throws: ValidationError.self) {
+            try ImageCommand.parse(["--vignette-strength", "0.5"]).makeFilterChain()
+        }
+    }
+}
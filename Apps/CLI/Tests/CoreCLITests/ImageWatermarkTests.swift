@@ -0,0 +1,101 @@
+import Commander
+import CoreGraphics
+import Foundation
+import PeekabooFoundation
+import Testing
+@testable import PeekabooCLI
+
+@Suite(.tags(.imageCapture, .unit))
+struct ImageWatermarkTests {
+    @Test(.tags(.fast))
+    func `An opaque watermark lands in the requested corner`() throws {
+        let base = try TestImages.solid(width: 64, height: 64, red: 255, green: 255, blue: 255)
+        let mark = try TestImages.solid(width: 32, height: 32, red: 255, green: 0, blue: 0)
+
+        let topLeft = try TestImages.rgbaPixels(of: Self.filter(mark, .topleft, opacity: 1).apply(to: base))
+        #expect(topLeft(0, 0) == [255, 0, 0, 255])
+        #expect(topLeft(31, 31) == [255, 0, 0, 255])
+        #expect(topLeft(40, 40) == [255, 255, 255, 255])
+        #expect(topLeft(63, 0) == [255, 255, 255, 255])
+
+        let center = try TestImages.rgbaPixels(of: Self.filter(mark, .center, opacity: 1).apply(to: base))
+        #expect(center(32, 32) == [255, 0, 0, 255])
+        #expect(center(8, 8) == [255, 255, 255, 255])
+    }
+
+    @Test(.tags(.fast))
+    func `Opacity blends the watermark with the capture`() throws {
+        let base = try TestImages.solid(width: 64, height: 64, red: 255, green: 255, blue: 255)
+        let mark = try TestImages.solid(width: 32, height: 32, red: 255, green: 0, blue: 0)
+
+        let pixels = try TestImages.rgbaPixels(of: Self.filter(mark, .bottomright, opacity: 0.5).apply(to: base))
+        let corner = pixels(63, 63)
+        #expect(corner[0] == 255)
+        #expect((126...129).contains(corner[1]))
+        #expect((126...129).contains(corner[2]))
+        #expect(pixels(0, 0) == [255, 255, 255, 255])
+    }
+
+    @Test(.tags(.fast))
+    func `Scale sizes the watermark from the capture width`() throws {
+        let base = try TestImages.solid(width: 64, height: 64, red: 255, green: 255, blue: 255)
+        let mark = try TestImages.solid(width: 32, height: 32, red: 255, green: 0, blue: 0)
+        let filter = Self.filter(mark, .topleft, opacity: 1, scale: 0.25)
+
+        #expect(filter.size(in: CGSize(width: 64, height: 64)) == CGSize(width: 16, height: 16))
+        let pixels = try TestImages.rgbaPixels(of: filter.apply(to: base))
+        #expect(pixels(8, 8) == [255, 0, 0, 255])
+        #expect(pixels(24, 24) == [255, 255, 255, 255])
+    }
+
+    @Test(.tags(.fast))
+    @MainActor
+    func `The watermark joins the chain after the vignette`() throws {
+        let url = FileManager.default.temporaryDirectory.appendingPathComponent("peekaboo-watermark-\(UUID()).png")
+        defer { try? FileManager.default.removeItem(at: url) }
+        try CapturedImageIO.write(
+            TestImages.solid(width: 32, height: 32, red: 0, green: 0, blue: 255),
+            to: url,
+            format: .png
+        )
+
+        let chain = try ImageCommand.parse(["--vignette", "--watermark-image", url.path]).makeFilterChain()
+        #expect(chain.filters.first is VignetteFilter)
+        let watermark = try #require(chain.filters.last as? WatermarkFilter)
+        #expect(watermark.position == .bottomright)
+        #expect(watermark.opacity == WatermarkFilter.defaultOpacity)
+        #expect(watermark.watermark.width == 32)
+
+        let placed = try ImageCommand.parse(["--watermark-image", url.path, "--watermark-position", "top-left"])
+        #expect(try placed.watermarkFilter()?.position == .topleft)
+    }
+
+    @Test(.tags(.fast))
+    @MainActor
+    func `Watermark options are validated before capturing`() throws {
+        #expect(throws: PeekabooError.self) {
+            try ImageCommand.parse(["--watermark-image", "/nonexistent/mark.png"]).watermarkFilter()
+        }
+        #expect(throws: ValidationError.self) {
+            try ImageCommand.parse(["--watermark-opacity", "0.5"]).watermarkFilter()
+        }
+        #expect(throws: ValidationError.self) {
+            try ImageCommand.parse(["--watermark-image", "mark.png", "--watermark-opacity", "1.5"]).watermarkFilter()
+        }
+        #expect(throws: ValidationError.self) {
+            try ImageCommand.parse(["--watermark-image", "mark.png", "--watermark-scale", "0"]).watermarkFilter()
+        }
+        #expect(throws: (any Error).self) {
+            _ = try ImageCommand.parse(["--watermark-image", "mark.png", "--watermark-position", "middle"])
+        }
+    }
+
+    private static func filter(
+        _ watermark: CGImage,
+        _ position: ImageWatermarkPosition,
+        opacity: Double,
+        scale: Double? = nil
+    ) -> WatermarkFilter {
+        WatermarkFilter(watermark: watermark, position: position, opacity: opacity, scale: scale)
+    }
+}
@@ -0,0 +1,67 @@
+import Commander
+import PeekabooCore
+import Testing
+@testable import PeekabooCLI
+
+@Suite(.tags(.imageCapture, .unit))
+@MainActor
+struct ImageWindowFocusCheckTests {
+    @Test(.tags(.fast))
+    func `Refocuses until the target is focused`() async throws {
+        var checks = 0
+        var refocuses = 0
+        let confirmed = try await ImageCommand.confirmFocus(
+            retries: 3,
+            isFocused: {
+                checks += 1
+                return checks == 3
+            },
+            refocus: { refocuses += 1 }
+        )
+
+        #expect(confirmed)
+        #expect(checks == 3)
+        #expect(refocuses == 2)
+    }
+
+    @Test(.tags(.fast))
+    func `Gives up after the retry budget`() async throws {
+        var refocuses = 0
+        let confirmed = try await ImageCommand.confirmFocus(
+            retries: 2,
+            isFocused: { false },
+            refocus: { refocuses += 1 }
+        )
+
+        #expect(!confirmed)
+        #expect(refocuses == 2)
+    }
+
+    @Test(.tags(.fast))
+    func `Focus check options are validated`() throws {
+        let command = try ImageCommand.parse(["--app", "Safari", "--window-focus-check", "--focus-retries", "5"])
+        #expect(command.windowFocusCheck)
+        #expect(command.focusRetries == 5)
+        try command.validateWindowFocusCheckOptions()
+
+        let orphan = try ImageCommand.parse(["--app", "Safari", "--focus-retries", "5"])
+        #expect(throws: ValidationError.self) {
+            try orphan.validateWindowFocusCheckOptions()
+        }
+
+        let background = try ImageCommand.parse([
+            "--app", "Safari", "--window-focus-check", "--capture-focus", "background",
+        ])
+        #expect(throws: ValidationError.self) {
+            try background.validateWindowFocusCheckOptions()
+        }
+    }
+
+    @Test(.tags(.fast))
+    func `Saved files carry focus_confirmed only when checked`() {
+        let file = SavedFile(path: "/tmp/shot.png", mime_type: "image/png")
+        #expect(file.focus_confirmed == nil)
+        #expect(file.with(focusConfirmed: nil).focus_confirmed == nil)
+        #expect(file.with(focusConfirmed: false).focus_confirmed == false)
+    }
+}
@@ -0,0 +1,69 @@
+import Commander
+import CoreGraphics
+import Foundation
+import Testing
+@testable import PeekabooCLI
+
+@Suite(.tags(.imageCapture, .unit))
+struct ImageWindowPaddingTests {
+    private let screen = CGRect(x: 0, y: 0, width: 1440, height: 900)
+
+    @Test(.tags(.fast))
+    @MainActor
+    func `Parses and validates window padding`() throws {
+        let defaulted = try ImageCommand.parse(["--app", "Finder"])
+        #expect(defaulted.windowPadding == 0)
+        #expect(!defaulted.padsWindowCaptures)
+
+        let padded = try ImageCommand.parse(["--app", "Finder", "--window-padding", "16"])
+        #expect(padded.windowPadding == 16)
+        #expect(padded.padsWindowCaptures)
+        try padded.validateWindowPaddingOptions()
+
+        var negative = try ImageCommand.parse(["--app", "Finder"])
+        negative.windowPadding = -4
+        #expect(throws: ValidationError.self) {
+            try negative.validateWindowPaddingOptions()
+        }
+    }
+
+    @Test(.tags(.fast))
+    @MainActor
+    func `Padding grows every side when the window has room`() {
+        let window = CGRect(x: 200, y: 150, width: 800, height: 600)
+        let padded = ImageCommand.paddedBounds(window, padding: 20, within: self.screen)
+        #expect(padded == CGRect(x: 180, y: 130, width: 840, height: 640))
+    }
+
+    @Test(.tags(.fast))
+    @MainActor
+    func `Padding clamps at screen edges`() {
+        let topLeft = CGRect(x: 0, y: 0, width: 600, height: 400)
+        #expect(ImageCommand.paddedBounds(topLeft, padding: 30, within: self.screen) ==
+            CGRect(x: 0, y: 0, width: 630, height: 430))
+
+        let bottomRight = CGRect(x: 900, y: 500, width: 540, height: 400)
+        #expect(ImageCommand.paddedBounds(bottomRight, padding: 30, within: self.screen) ==
+            CGRect(x: 870, y: 470, width: 570, height: 430))
+
+        let fullScreen = self.screen
+        #expect(ImageCommand.paddedBounds(fullScreen, padding: 50, within: self.screen) == self.screen)
+    }
+
+    @Test(.tags(.fast))
+    @MainActor
+    func `Padding respects secondary display origins`() {
+        let secondary = CGRect(x: 1440, y: -200, width: 1920, height: 1080)
+        let window = CGRect(x: 1450, y: -190, width: 400, height: 300)
+        #expect(ImageCommand.paddedBounds(window, padding: 40, within: secondary) ==
+            CGRect(x: 1440, y: -200, width: 450, height: 350))
+    }
+
+    @Test(.tags(.fast))
+    @MainActor
+    func `Padding without a known screen is unclamped`() {
+        let window = CGRect(x: 10, y: 10, width: 100, height: 100)
+        #expect(ImageCommand.paddedBounds(window, padding: 10, within: nil) ==
+            CGRect(x: 0, y: 0, width: 120, height: 120))
+    }
+}
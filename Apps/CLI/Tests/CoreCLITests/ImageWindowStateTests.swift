@@ -0,0 +1,101 @@
+import Commander
+import CoreGraphics
+import Foundation
+import PeekabooCore
+import Testing
+@testable import PeekabooCLI
+
+@Suite(.tags(.imageCapture, .unit))
+struct ImageWindowStateTests {
+    @Test(.tags(.fast))
+    @MainActor
+    func `Parses window state and timeout`() throws {
+        let command = try ImageCommand.parse([
+            "--app", "Slack",
+            "--window-state", "wait-visible",
+            "--focus-timeout-ms", "2500",
+        ])
+        #expect(command.windowState == .waitVisible)
+        #expect(command.focusTimeoutMs == 2500)
+        try command.validateWindowStateOptions()
+
+        let any = try ImageCommand.parse(["--app", "Slack", "--window-state", "ANY"])
+        #expect(any.windowState == .any)
+    }
+
+    @Test(.tags(.fast))
+    @MainActor
+    func `Focus timeout only applies to wait-visible`() throws {
+        let orphan = try ImageCommand.parse(["--app", "Slack", "--focus-timeout-ms", "1000"])
+        #expect(throws: ValidationError.self) {
+            try orphan.validateWindowStateOptions()
+        }
+
+        let zero = try ImageCommand.parse([
+            "--app", "Slack",
+            "--window-state", "wait-visible",
+            "--focus-timeout-ms", "0",
+        ])
+        #expect(throws: ValidationError.self) {
+            try zero.validateWindowStateOptions()
+        }
+    }
+
+    @Test(.tags(.fast))
+    @MainActor
+    func `Any state picks windows by title, index, or best match`() {
+        let windows = [
+            ServiceWindowInfo(
+                windowID: 10,
+                title: "Preferences",
+                bounds: CGRect(x: 0, y: 0, width: 400, height: 300),
+                index: 0,
+                isOffScreen: true,
+                isOnScreen: false
+            ),
+            ServiceWindowInfo(
+                windowID: 11,
+                title: "Main",
+                bounds: CGRect(x: 0, y: 0, width: 1200, height: 800),
+                isMinimized: true,
+                isMainWindow: true,
+                index: 1,
+                isOnScreen: false
+            ),
+        ]
+
+        #expect(ImageCommand.selectWindow(from: windows, title: "prefer", index: nil)?.windowID == 10)
+        #expect(ImageCommand.selectWindow(from: windows, title: nil, index: 1)?.windowID == 11)
+        #expect(ImageCommand.selectWindow(from: windows, title: "Missing", index: nil) == nil)
+        #expect(ImageCommand.selectWindow(from: windows, title: nil, index: nil) != nil)
+    }
+
+    @Test(.tags(.fast))
+    @MainActor
+    func `Off-screen windows are flagged in saved file JSON`() throws {
+        let hidden = ServiceWindowInfo(
+            windowID: 10,
+            title: "Preferences",
+            bounds: CGRect(x: 0, y: 0, width: 400, height: 300),
+            isOffScreen: true,
+            isOnScreen: false
+        )
+        let visible = ServiceWindowInfo(
+            windowID: 11,
+            title: "Main",
+            bounds: CGRect(x: 0, y: 0, width: 400, height: 300)
+        )
+        #expect(ImageCommand.offscreenMarker(for: hidden) == true)
+        #expect(ImageCommand.offscreenMarker(for: visible) == nil)
+
+        let flagged = SavedFile(path: "/tmp/shot.png", mime_type: "image/png", window_was_offscreen: true)
+        let json = try #require(String(data: JSONEncoder().encode(flagged.with(verified: true)), encoding: .utf8))
+        #expect(json.contains("\"window_was_offscreen\":true"))
+
+        let plain = try #require(String(
+            data: JSONEncoder().encode(SavedFile(path: "/tmp/shot.png", mime_type: "image/png")),
+            encoding: .utf8
+        ))
+        #expect(!plain.contains("window_was_offscreen"))
+    }
+}
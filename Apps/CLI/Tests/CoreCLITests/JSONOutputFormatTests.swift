@@ -0,0 +1,184 @@
+import Commander
+import Foundation
+import Testing
+@testable import PeekabooCLI
+
+@Suite(.tags(.unit))
+struct JSONOutputFormatTests {
+    private struct Payload: Codable {
+        let name: String
+        let summary: String
+        let count: Int
+        let offset: Int
+        let large: Int
+        let ratio: Double
+        let enabled: Bool
+        let missing: String?
+        let tags: [String]
+        let ids: [Int]
+    }
+
+    private static let response = CodableJSONResponse(
+        success: true,
+        data: Payload(
+            name: "Safari",
+            summary: String(repeating: "window ", count: 40),
+            count: 3,
+            offset: -200,
+            large: 70000,
+            ratio: 0.25,
+            enabled: false,
+            missing: nil,
+            tags: ["a", "b"],
+            ids: Array(0..<20)
+        ),
+        messages: nil,
+        debug_logs: ["started"]
+    )
+
+    @Test(.tags(.fast))
+    func `MessagePack decodes to the same document as JSON`() throws {
+        let json = try JSONSerialization.jsonObject(with: JSONOutputFormat.json.encode(Self.response))
+        var reader = MessagePackReader(data: JSONOutputFormat.msgpack.encode(Self.response))
+        let decoded = try reader.read()
+
+        #expect(reader.isAtEnd)
+        #expect((decoded as? NSDictionary)?.isEqual(json) == true)
+        let data = try #require((decoded as? [String: Any])?["data"] as? [String: Any])
+        #expect(data["missing"] == nil)
+        #expect(data["enabled"] as? Bool == false)
+        #expect(data["offset"] as? Int == -200)
+    }
+
+    @Test(.tags(.fast))
+    func `Compact formats stay on one line`() throws {
+        for format in [JSONOutputFormat.jsonCompact, .ndjson] {
+            let text = try String(decoding: format.encode(Self.response), as: UTF8.self)
+            #expect(!text.contains("\n"))
+            #expect(text.hasPrefix("{"))
+        }
+        let pretty = try String(decoding: JSONOutputFormat.json.encode(Self.response), as: UTF8.self)
+        #expect(pretty.contains("\n"))
+    }
+
+    @Test(.tags(.fast))
+    func `Stream records are one line per record in every text format`() throws {
+        let progress = LaunchProgress(elapsed_ms: 1500)
+        for format in [JSONOutputFormat.json, .jsonCompact, .ndjson] {
+            let text = try String(decoding: format.encodeRecord(progress), as: UTF8.self)
+            #expect(text == #"{"elapsed_ms":1500,"status":"waiting_for_window","type":"progress"}"# + "\n")
+        }
+
+        var reader = try MessagePackReader(data: JSONOutputFormat.msgpack.encodeRecord(progress))
+        let decoded = try #require(reader.read() as? [String: Any])
+        #expect(reader.isAtEnd)
+        #expect(decoded["elapsed_ms"] as? Int == 1500)
+
+        #expect(JSONOutputFormat.ndjson.streamsProgressToStdout)
+        #expect(!JSONOutputFormat.jsonCompact.streamsProgressToStdout)
+    }
+
+    @Test(.tags(.fast))
+    func `Output format option implies JSON and rejects unknown values`() throws {
+        let parsed = ParsedValues(positional: [], options: ["outputFormat": ["msgpack"]], flags: [])
+        let options = try CommanderCLIBinder.makeRuntimeOptions(from: parsed)
+        #expect(options.outputFormat == .msgpack)
+        #expect(options.jsonOutput)
+
+        let defaults = try CommanderCLIBinder.makeRuntimeOptions(
+            from: ParsedValues(positional: [], options: [:], flags: [])
+        )
+        #expect(defaults.outputFormat == .json)
+        #expect(!defaults.jsonOutput)
+
+        #expect(throws: (any Error).self) {
+            try CommanderCLIBinder.makeRuntimeOptions(
+                from: ParsedValues(positional: [], options: ["outputFormat": ["yaml"]], flags: [])
+            )
+        }
+    }
+}
+
+/// Just enough MessagePack decoding to read back what `MessagePack.encode` writes.
+private struct MessagePackReader {
+    struct Malformed: Error {}
+
+    let data: [UInt8]
+    var position = 0
+
+    init(data: Data) {
+        self.data = Array(data)
+    }
+
+    var isAtEnd: Bool {
+        self.position == self.data.count
+    }
+
+    mutating func read() throws -> Any {
+        let byte = try self.byte()
+        switch byte {
+        case 0x00...0x7F: return Int(byte)
+        case 0xE0...0xFF: return Int(Int8(bitPattern: byte))
+        case 0x80...0x8F: return try self.map(count: Int(byte & 0x0F))
+        case 0x90...0x9F: return try self.array(count: Int(byte & 0x0F))
+        case 0xA0...0xBF: return try self.string(count: Int(byte & 0x1F))
+        case 0xC0: return NSNull()
+        case 0xC2: return false
+        case 0xC3: return true
+        case 0xCB: return try Double(bitPattern: self.integer(bytes: 8))
+        case 0xCC: return try Int(self.integer(bytes: 1))
+        case 0xCD: return try Int(self.integer(bytes: 2))
+        case 0xCE: return try Int(self.integer(bytes: 4))
+        case 0xCF: return try self.integer(bytes: 8)
+        case 0xD0: return try Int(Int8(truncatingIfNeeded: self.integer(bytes: 1)))
+        case 0xD1: return try Int(Int16(truncatingIfNeeded: self.integer(bytes: 2)))
+        case 0xD2: return try Int(Int32(truncatingIfNeeded: self.integer(bytes: 4)))
+        case 0xD3: return try Int(Int64(bitPattern: self.integer(bytes: 8)))
+        case 0xD9: return try self.string(count: Int(self.integer(bytes: 1)))
+        case 0xDA: return try self.string(count: Int(self.integer(bytes: 2)))
+        case 0xDB: return try self.string(count: Int(self.integer(bytes: 4)))
+        case 0xDC: return try self.array(count: Int(self.integer(bytes: 2)))
+        case 0xDD: return try self.array(count: Int(self.integer(bytes: 4)))
+        case 0xDE: return try self.map(count: Int(self.integer(bytes: 2)))
+        case 0xDF: return try self.map(count: Int(self.integer(bytes: 4)))
+        default: throw Malformed()
+        }
+    }
+
+    private mutating func byte() throws -> UInt8 {
+        guard self.position < self.data.count else { throw Malformed() }
+        defer { self.position += 1 }
+        return self.data[self.position]
+    }
+
+    private mutating func integer(bytes: Int) throws -> UInt64 {
+        var value: UInt64 = 0
+        for _ in 0..<bytes {
+            value = try value << 8 | UInt64(self.byte())
+        }
+        return value
+    }
+
+    private mutating func string(count: Int) throws -> String {
+        guard self.position + count <= self.data.count else { throw Malformed() }
+        defer { self.position += count }
+        return String(decoding: self.data[self.position..<self.position + count], as: UTF8.self)
+    }
+
+    private mutating func array(count: Int) throws -> [Any] {
+        var result: [Any] = []
+        for _ in 0..<count {
+            try result.append(self.read())
+        }
+        return result
+    }
+
+    private mutating func map(count: Int) throws -> [String: Any] {
+        var result: [String: Any] = [:]
+        for _ in 0..<count {
+            guard let key = try self.read() as? String else { throw Malformed() }
+            result[key] = try self.read()
+        }
+        return result
+    }
+}
@@ -0,0 +1,114 @@
+import Commander
+import Foundation
+import PeekabooFoundation
+import Testing
+@testable import PeekabooCLI
+
+@Suite(.tags(.jsonOutput, .unit))
+@MainActor
+struct MultipleErrorsTests {
+    private struct JSONCommand: ErrorHandlingCommand {
+        var jsonOutput: Bool { true }
+    }
+
+    @Test(.tags(.fast))
+    func `Combining keeps single errors and flattens batches`() {
+        #expect(PeekabooError.combining([]) == nil)
+
+        let single = PeekabooError.combining([.appNotFound("Safari")])
+        #expect(single?.variant == "appNotFound")
+
+        let combined = PeekabooError.combining([
+            .windowNotFound(criteria: "Inbox"),
+            .multipleErrors([.captureTimeout, .displayNotFound]),
+        ])
+        guard case let .multipleErrors(errors) = combined else {
+            Issue.record("Expected multipleErrors, got \(String(describing: combined))")
+            return
+        }
+        #expect(errors.map(\.variant) == ["windowNotFound", "captureTimeout", "displayNotFound"])
+        #expect(combined?.code == .windowNotFound)
+        #expect(combined?.localizedDescription == "3 operations failed; first: Window not found: Inbox")
+        #expect(combined?.context == ["count": "3", "first_message": "Window not found: Inbox"])
+    }
+
+    @Test(.tags(.fast))
+    func `JSON lists every contained error with its own code`() throws {
+        let error = PeekabooError.multipleErrors([.appNotFound("Safari"), .captureFailed("window 12 vanished")])
+        let info = JSONCommand().errorInfo(for: error, code: .MULTIPLE_ERRORS, context: ["image_command"])
+
+        let data = try JSONEncoder().encode(info)
+        let object = try #require(JSONSerialization.jsonObject(with: data) as? [String: Any])
+        #expect(object["code"] as? String == "MULTIPLE_ERRORS")
+        #expect(object["variant"] as? String == "multipleErrors")
+        #expect(object["context"] as? [String] == ["image_command"])
+
+        let nested = try #require(object["errors"] as? [[String: Any]])
+        #expect(nested.map { $0["code"] as? String } == ["APP_NOT_FOUND", "CAPTURE_FAILED"])
+        #expect(nested.map { $0["message"] as? String } == [
+            "Application 'Safari' not found",
+            "Capture failed: window 12 vanished",
+        ])
+        #expect(nested.allSatisfy { $0["errors"] == nil })
+
+        let decoded = try JSONDecoder().decode(ErrorInfo.self, from: data)
+        #expect(decoded.errors?.count == 2)
+    }
+
+    @Test(.tags(.fast))
+    func `Failed window batches keep the windows that were saved`() throws {
+        let urls = try (0..<2).map { _ in
+            let url = FileManager.default.temporaryDirectory.appendingPathComponent("peekaboo-batch-\(UUID()).png")
+            try Data([0]).write(to: url)
+            return url
+        }
+        defer { urls.forEach { try? FileManager.default.removeItem(at: $0) } }
+        let saved = urls.map { TestImages.capture(at: $0) }
+
+        #expect(try ImageCommand.completedWindowCaptures(saved, failures: []).count == 2)
+
+        #expect(throws: PeekabooError.self) {
+            try ImageCommand.completedWindowCaptures(saved, failures: [
+                PeekabooError.captureFailed("window 12 vanished"),
+                PeekabooError.captureTimeout,
+            ])
+        }
+        #expect(urls.allSatisfy { FileManager.default.fileExists(atPath: $0.path) })
+    }
+
+    @Test(.tags(.fast))
+    func `Capture errors in a batch keep their own codes`() throws {
+        var thrown: (any Error)?
+        do {
+            _ = try ImageCommand.completedWindowCaptures([], failures: [
+                CaptureError.screenRecordingPermissionDenied,
+                CaptureError.detectionTimedOut(2),
+                CaptureError.windowCaptureFailed(nil),
+            ])
+        } catch {
+            thrown = error
+        }
+        let error = try #require(thrown as? PeekabooError)
+        let info = JSONCommand().errorInfo(for: error, code: .MULTIPLE_ERRORS)
+        #expect(info.errors?.map(\.code) == ["PERMISSION_ERROR_SCREEN_RECORDING", "TIMEOUT", "CAPTURE_FAILED"])
+    }
+
+    @Test(.tags(.fast))
+    func `Batches exit with the status of their first error`() {
+        let command = JSONCommand()
+        let timedOutFirst = PeekabooError.multipleErrors([.captureTimeout, .captureFailed("window 12 vanished")])
+        let failedFirst = PeekabooError.multipleErrors([.captureFailed("window 12 vanished"), .captureTimeout])
+
+        #expect(command.exitCode(for: timedOutFirst) == .timedOut)
+        #expect(command.exitCode(for: failedFirst) == .failure)
+    }
+
+    @Test(.tags(.fast))
+    func `Other errors carry no nested list`() throws {
+        let info = JSONCommand().errorInfo(for: PeekabooError.displayNotFound, code: .CAPTURE_FAILED)
+        let object = try #require(
+            JSONSerialization.jsonObject(with: JSONEncoder().encode(info)) as? [String: Any]
+        )
+        #expect(object["errors"] == nil)
+    }
+}
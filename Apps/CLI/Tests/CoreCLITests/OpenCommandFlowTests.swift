@@ -1,3 +1,4 @@
+import Commander
 import Foundation
 import PeekabooAgentRuntime
 import PeekabooAutomation
@@ -155,6 +156,73 @@ struct AppCommandLaunchFlowTests {
         #expect(call.activates == false)
     }
 
+    @Test
+    func `Launch passes --launch-args and waits for a slow app`() async throws {
+        let launcher = StubApplicationLauncher()
+        let slowApp = StubRunningApplication(localizedName: "Chrome", readyAfterChecks: 7)
+        launcher.launchResponses = [slowApp]
+        let resolver = StubApplicationURLResolver()
+        resolver.applicationMap["Chrome"] = URL(fileURLWithPath: "/Applications/Google Chrome.app")
+
+        let originalLauncher = AppCommand.LaunchSubcommand.launcher
+        let originalResolver = AppCommand.LaunchSubcommand.resolver
+        AppCommand.LaunchSubcommand.launcher = launcher
+        AppCommand.LaunchSubcommand.resolver = resolver
+        defer {
+            AppCommand.LaunchSubcommand.launcher = originalLauncher
+            AppCommand.LaunchSubcommand.resolver = originalResolver
+        }
+
+        var command = AppCommand.LaunchSubcommand()
+        command.app = "Chrome"
+        command.noFocus = true
+        command.launchTimeoutMs = 5000
+        command.launchArgs = "--incognito --remote-debugging-port=9222"
+        let runtime = self.makeRuntime()
+        try await command.run(using: runtime)
+
+        let call = try #require(launcher.launchCalls.first)
+        #expect(call.arguments == ["--incognito", "--remote-debugging-port=9222"])
+        #expect(slowApp.isFinishedLaunching)
+    }
+
+    @Test
+    func `Launch times out when the app never becomes ready`() async throws {
+        let launcher = StubApplicationLauncher()
+        launcher.launchResponses = [StubRunningApplication(localizedName: "Stuck", readyAfterChecks: .max)]
+        let resolver = StubApplicationURLResolver()
+        resolver.applicationMap["Stuck"] = URL(fileURLWithPath: "/Applications/Stuck.app")
+
+        let originalLauncher = AppCommand.LaunchSubcommand.launcher
+        let originalResolver = AppCommand.LaunchSubcommand.resolver
+        AppCommand.LaunchSubcommand.launcher = launcher
+        AppCommand.LaunchSubcommand.resolver = resolver
+        defer {
+            AppCommand.LaunchSubcommand.launcher = originalLauncher
+            AppCommand.LaunchSubcommand.resolver = originalResolver
+        }
+
+        var command = AppCommand.LaunchSubcommand()
+        command.app = "Stuck"
+        command.noFocus = true
+        command.launchTimeoutMs = 300
+        let runtime = self.makeRuntime()
+        await #expect(throws: ExitCode.self) {
+            try await command.run(using: runtime)
+        }
+    }
+
+    @Test
+    func `Launch progress lines are single JSON objects`() throws {
+        let line = try JSONOutputFormat.jsonCompact.encodeRecord(LaunchProgress(elapsed_ms: 1500))
+        let object = try #require(
+            JSONSerialization.jsonObject(with: line) as? [String: Any]
+        )
+        #expect(object["type"] as? String == "progress")
+        #expect(object["elapsed_ms"] as? Int == 1500)
+        #expect(object["status"] as? String == "waiting_for_window")
+    }
+
     @Test
     func `Switch to app activates through application service`() async throws {
         let application = ServiceApplicationInfo(
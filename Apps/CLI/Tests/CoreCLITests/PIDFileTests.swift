@@ -0,0 +1,68 @@
+import Commander
+import Foundation
+import PeekabooCore
+import PeekabooFoundation
+import Testing
+@testable import PeekabooCLI
+
+@Suite(.tags(.unit))
+struct PIDFileTests {
+    @Test(.tags(.fast))
+    func `Reads a live PID from the first line`() throws {
+        let pid = ProcessInfo.processInfo.processIdentifier
+        let path = try Self.writePIDFile("  \(pid)\nstarted by launchd\n")
+        defer { try? FileManager.default.removeItem(atPath: path) }
+
+        #expect(try PIDFile.runningPID(at: path) == pid)
+    }
+
+    @Test(.tags(.fast))
+    func `Missing files and stale PIDs are app-not-found errors`() throws {
+        let missing = FileManager.default.temporaryDirectory.appendingPathComponent("peekaboo-\(UUID()).pid").path
+        #expect(Self.errorVariant { try PIDFile.runningPID(at: missing) } == "appNotFound")
+
+        // PIDs top out well below Int32.max on macOS, so this one can never be running.
+        let stale = try Self.writePIDFile("\(Int32.max)\n")
+        defer { try? FileManager.default.removeItem(atPath: stale) }
+        #expect(Self.errorVariant { try PIDFile.runningPID(at: stale) } == "appNotFound")
+
+        let garbage = try Self.writePIDFile("not-a-pid\n")
+        defer { try? FileManager.default.removeItem(atPath: garbage) }
+        #expect(Self.errorVariant { try PIDFile.runningPID(at: garbage) } == "invalidInput")
+    }
+
+    @Test(.tags(.fast))
+    @MainActor
+    func `PID file excludes --app and --pid on image and list windows`() throws {
+        let image = try ImageCommand.parse(["--pid-file", "/var/run/app.pid"])
+        #expect(image.pidFile == "/var/run/app.pid")
+        try PIDFile.validateExclusive(pidFile: image.pidFile, app: image.app, pid: image.pid)
+
+        let windows = try ListCommand.WindowsSubcommand.parse(["--pid-file", "/var/run/app.pid"])
+        #expect(windows.pidFile == "/var/run/app.pid")
+
+        #expect(throws: ValidationError.self) {
+            try PIDFile.validateExclusive(pidFile: "/var/run/app.pid", app: "Safari", pid: nil)
+        }
+        #expect(throws: ValidationError.self) {
+            try PIDFile.validateExclusive(pidFile: "/var/run/app.pid", app: nil, pid: 42)
+        }
+    }
+
+    private static func writePIDFile(_ contents: String) throws -> String {
+        let url = FileManager.default.temporaryDirectory.appendingPathComponent("peekaboo-\(UUID()).pid")
+        try contents.write(to: url, atomically: true, encoding: .utf8)
+        return url.path
+    }
+
+    private static func errorVariant(_ body: () throws -> Int32) -> String? {
+        do {
+            _ = try body()
+            return nil
+        } catch let error as PeekabooError {
+            return error.variant
+        } catch {
+            return nil
+        }
+    }
+}
@@ -0,0 +1,72 @@
+import Commander
+import CoreGraphics
+import Foundation
+import Testing
+@testable import PeekabooCLI
+
+@Suite(.tags(.jsonOutput, .unit), .serialized)
+@MainActor
+struct PeekabooWarningTests {
+    @Test(.tags(.fast))
+    func `Warnings ride along in the envelope and are omitted when empty`() throws {
+        let warning = PeekabooWarning(.CURSOR_NOT_CAPTURED, "pointer unavailable")
+        let response = CodableJSONResponse(
+            success: true,
+            data: Empty(),
+            messages: nil,
+            debug_logs: [],
+            warnings: [warning]
+        )
+        let object = try #require(
+            JSONSerialization.jsonObject(with: JSONEncoder().encode(response)) as? [String: Any]
+        )
+        let warnings = try #require(object["warnings"] as? [[String: String]])
+        #expect(warnings == [["code": "CURSOR_NOT_CAPTURED", "message": "pointer unavailable"]])
+
+        let plain = CodableJSONResponse(success: true, data: Empty(), messages: nil, debug_logs: [])
+        let plainObject = try #require(
+            JSONSerialization.jsonObject(with: JSONEncoder().encode(plain)) as? [String: Any]
+        )
+        #expect(plainObject["warnings"] == nil)
+    }
+
+    @Test(.tags(.fast))
+    func `Promoted warnings fail the run`() throws {
+        let logger = Logger.shared
+        logger.clearWarnings()
+        defer {
+            logger.clearWarnings()
+            logger.setWarningsAsErrors(false)
+        }
+
+        let warning = PeekabooWarning(.FOCUS_NOT_CONFIRMED, "Safari was not focused; capturing anyway")
+        logger.warn(.FOCUS_NOT_CONFIRMED, warning.message)
+        #expect(logger.getWarnings().contains(warning))
+        try CommanderRuntimeExecutor.failOnPromotedWarnings(logger: logger, jsonOutput: true)
+
+        logger.setWarningsAsErrors(true)
+        #expect(throws: ExitCode.self) {
+            try CommanderRuntimeExecutor.failOnPromotedWarnings(logger: logger, jsonOutput: true)
+        }
+        let info = warningsAsErrorsInfo(for: logger.getWarnings())
+        #expect(info.code == "WARNINGS_AS_ERRORS")
+        #expect(info.message.contains("promoted to errors by --warnings-as-errors"))
+
+        let options = try CommanderCLIBinder.makeRuntimeOptions(
+            from: ParsedValues(positional: [], options: [:], flags: ["warnings-as-errors"])
+        )
+        #expect(options.makeConfiguration().warningsAsErrors)
+    }
+
+    @Test(.tags(.fast))
+    func `Only windows straddling a display edge count as partially off-screen`() {
+        let display = CGRect(x: 0, y: 0, width: 1440, height: 900)
+        let second = CGRect(x: 1440, y: 0, width: 1920, height: 1080)
+
+        let straddling = CGRect(x: 1300, y: 100, width: 400, height: 300)
+        #expect(ImageCommand.isPartiallyOffscreen(straddling, displays: [display]))
+        #expect(!ImageCommand.isPartiallyOffscreen(straddling, displays: [display, second]))
+        #expect(!ImageCommand.isPartiallyOffscreen(CGRect(x: 10, y: 10, width: 100, height: 100), displays: [display]))
+        #expect(!ImageCommand.isPartiallyOffscreen(CGRect(x: 5000, y: 0, width: 100, height: 100), displays: [display]))
+    }
+}
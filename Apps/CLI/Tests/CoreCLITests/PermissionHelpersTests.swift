@@ -1,3 +1,4 @@
+import PeekabooFoundation
 import Testing
 @testable import PeekabooCLI
 
@@ -45,3 +46,25 @@ struct PermissionHelpersTests {
         #expect(PermissionHelpers.bridgeScreenRecordingHint(for: response) == nil)
     }
 }
+
+extension PermissionHelpersTests {
+    @Test
+    func `permission failures explain how to grant access in the launching app`() throws {
+        let screen = try #require(PermissionHelpers.explainFailure(
+            PeekabooError.permissionDeniedScreenRecording,
+            environment: ["TERM_PROGRAM": "iTerm.app"]
+        ))
+        #expect(screen.contains("Privacy_ScreenCapture"))
+        #expect(screen.contains("enable iTerm"))
+        #expect(screen.contains("tccutil reset ScreenCapture"))
+
+        let accessibility = PermissionHelpers.explainFailure(
+            CaptureError.accessibilityPermissionDenied,
+            environment: [:]
+        )
+        #expect(accessibility?.contains("your terminal or IDE") == true)
+        #expect(PermissionHelpers.explainFailure(PeekabooError.permissionDeniedEventSynthesizing, environment: [:])?
+            .contains("peekaboo permissions request-event-synthesizing") == true)
+        #expect(PermissionHelpers.explainFailure(PeekabooError.appNotFound("Safari"), environment: [:]) == nil)
+    }
+}
@@ -48,12 +48,14 @@ final class StubRunningApplication: RunningApplicationHandle {
 final class StubApplicationLauncher: ApplicationLaunching {
     struct LaunchCall: Equatable {
         let appURL: URL
+        let arguments: [String]
         let activates: Bool
     }
 
     struct LaunchWithDocsCall: Equatable {
         let appURL: URL
         let documentURLs: [URL]
+        let arguments: [String]
         let activates: Bool
     }
 
@@ -71,8 +73,12 @@ final class StubApplicationLauncher: ApplicationLaunching {
     var launchWithDocsResponses: [StubRunningApplication] = []
     var openResponses: [StubRunningApplication] = []
 
-    func launchApplication(at url: URL, activates: Bool) async throws -> any RunningApplicationHandle {
-        self.launchCalls.append(.init(appURL: url, activates: activates))
+    func launchApplication(
+        at url: URL,
+        arguments: [String],
+        activates: Bool
+    ) async throws -> any RunningApplicationHandle {
+        self.launchCalls.append(.init(appURL: url, arguments: arguments, activates: activates))
         if !self.launchResponses.isEmpty {
             return self.launchResponses.removeFirst()
         }
@@ -82,9 +88,12 @@ final class StubApplicationLauncher: ApplicationLaunching {
     func launchApplication(
         _ url: URL,
         opening documents: [URL],
+        arguments: [String],
         activates: Bool
     ) async throws -> any RunningApplicationHandle {
-        self.launchWithDocsCalls.append(.init(appURL: url, documentURLs: documents, activates: activates))
+        self.launchWithDocsCalls.append(
+            .init(appURL: url, documentURLs: documents, arguments: arguments, activates: activates)
+        )
         if !self.launchWithDocsResponses.isEmpty {
             return self.launchWithDocsResponses.removeFirst()
         }
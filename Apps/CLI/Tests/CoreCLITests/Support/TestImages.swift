@@ -0,0 +1,62 @@
+import CoreGraphics
+import Foundation
+import PeekabooCore
+import Testing
+@testable import PeekabooCLI
+
+/// Small in-memory bitmaps and display fixtures for image post-processing and capture tests.
+enum TestImages {
+    static func solid(width: Int, height: Int, red: UInt8, green: UInt8, blue: UInt8) throws -> CGImage {
+        let context = try #require(CGContext(
+            data: nil,
+            width: width,
+            height: height,
+            bitsPerComponent: 8,
+            bytesPerRow: 0,
+            space: CGColorSpaceCreateDeviceRGB(),
+            bitmapInfo: CGImageAlphaInfo.premultipliedLast.rawValue
+        ))
+        context.setFillColor(
+            red: CGFloat(red) / 255,
+            green: CGFloat(green) / 255,
+            blue: CGFloat(blue) / 255,
+            alpha: 1
+        )
+        context.fill(CGRect(x: 0, y: 0, width: width, height: height))
+        return try #require(context.makeImage())
+    }
+
+    /// Returns an RGBA sampler addressed top-down, matching how screenshots are viewed.
+    static func rgbaPixels(of image: CGImage) throws -> (Int, Int) -> [UInt8] {
+        let bytesPerRow = image.width * 4
+        let snapshot = try CapturedImageIO.rgbaBytes(of: image)
+        return { x, y in
+            let offset = y * bytesPerRow + x * 4
+            return Array(snapshot[offset..<(offset + 4)])
+        }
+    }
+
+    /// A 1920×1080 display laid out left to right by `index`; index 0 is the primary.
+    static func screen(index: Int, name: String) -> ScreenInfo {
+        ScreenInfo(
+            index: index,
+            name: name,
+            frame: CGRect(x: CGFloat(index) * 1920, y: 0, width: 1920, height: 1080),
+            visibleFrame: CGRect(x: CGFloat(index) * 1920, y: 0, width: 1920, height: 1040),
+            isPrimary: index == 0,
+            scaleFactor: 2,
+            displayID: CGDirectDisplayID(index + 1)
+        )
+    }
+
+    /// A capture result for a file on disk, for tests of the post-capture steps.
+    static func capture(at url: URL, mimeType: String = "image/png") -> ImageCapturedFile {
+        ImageCapturedFile(
+            file: SavedFile(path: url.path, mime_type: mimeType),
+            observation: ImageObservationDiagnostics(
+                timings: ObservationTimings(),
+                diagnostics: DesktopObservationDiagnostics()
+            )
+        )
+    }
+}
@@ -0,0 +1,63 @@
+import Testing
+@testable import PeekabooCLI
+
+@Suite(.tags(.unit))
+struct TableFormatterTests {
+    @Test(.tags(.fast))
+    func `Borderless tables align columns and trim trailing spaces`() {
+        let rendered = TableFormatter().render(header: ["NAME", "PID"], rows: [["Finder", "412"], ["Safari", "9"]])
+        #expect(rendered == """
+        NAME    PID
+        Finder  412
+        Safari  9
+        """)
+    }
+
+    @Test(.tags(.fast))
+    func `ASCII borders separate the header and truncate long values`() {
+        let formatter = TableFormatter(borderStyle: .ascii, maxColumnWidth: 5)
+        let rendered = formatter.render(header: ["NAME", "BUNDLE ID"], rows: [["Finder", "com.apple.finder"]])
+        #expect(rendered == """
+        +-------+-------+
+        | NAME  | BUND… |
+        +-------+-------+
+        | Find… | com.… |
+        +-------+-------+
+        """)
+    }
+
+    @Test(.tags(.fast))
+    func `Unicode borders without a header`() {
+        let formatter = TableFormatter(borderStyle: .unicode, showsHeader: false)
+        let rendered = formatter.render(header: ["A", "B"], rows: [["a", "bb"]])
+        #expect(rendered == """
+        ┌───┬────┐
+        │ a │ bb │
+        └───┴────┘
+        """)
+    }
+
+    @Test(.tags(.fast))
+    func `Fitting the terminal shrinks the widest column first`() {
+        let header = ["ID", "DESCRIPTION"]
+        let rows = [["1", "a long description"]]
+
+        let fitted = TableFormatter(availableWidth: 12).render(header: header, rows: rows)
+        #expect(fitted == """
+        ID  DESCRIP…
+        1   a long …
+        """)
+
+        // Columns keep a short stub even when the terminal is narrower than that.
+        let cramped = TableFormatter(availableWidth: 1).render(header: header, rows: rows)
+        #expect(cramped.split(separator: "\n").first == "ID  DE…")
+    }
+
+    @Test(.tags(.fast))
+    func `Truncation keeps the ellipsis inside the width`() {
+        #expect(TableFormatter.truncate("abc", to: 3) == "abc")
+        #expect(TableFormatter.truncate("abcd", to: 3) == "ab…")
+        #expect(TableFormatter.truncate("abcd", to: 1) == "…")
+        #expect(TableFormatter().render(header: [], rows: []).isEmpty)
+    }
+}
@@ -0,0 +1,54 @@
+import Commander
+import Foundation
+import PeekabooFoundation
+import Testing
+@testable import PeekabooCLI
+
+@Suite(.tags(.jsonOutput, .unit))
+@MainActor
+struct TimeoutErrorTests {
+    private struct JSONCommand: ErrorHandlingCommand {
+        var jsonOutput: Bool { true }
+    }
+
+    @Test(.tags(.fast))
+    func `Timed-out operations report what they waited for and how long`() throws {
+        let error = PeekabooError.timeout(operation: "Image capture", duration: 0.25)
+        guard case let .timedOut(operation, timeoutMs) = error else {
+            Issue.record("Expected timedOut, got \(error)")
+            return
+        }
+        #expect(operation == "Image capture")
+        #expect(timeoutMs == 250)
+        #expect(error.localizedDescription == "Image capture timed out after 250ms")
+        #expect(error.code == .timeout)
+
+        let info = JSONCommand().errorInfo(for: error, code: .TIMEOUT)
+        let object = try #require(
+            JSONSerialization.jsonObject(with: JSONEncoder().encode(info)) as? [String: Any]
+        )
+        #expect(object["code"] as? String == "TIMEOUT")
+        #expect(object["operation"] as? String == "Image capture")
+        #expect(object["timeout_ms"] as? Int == 250)
+
+        let other = JSONCommand().errorInfo(for: PeekabooError.displayNotFound, code: .CAPTURE_FAILED)
+        #expect(other.operation == nil)
+        #expect(other.timeout_ms == nil)
+    }
+
+    @Test(.tags(.fast))
+    func `Timeouts exit with their own status`() async {
+        let command = JSONCommand()
+        #expect(command.exitCode(for: PeekabooError.timedOut(operation: "Launch", timeoutMs: 10)) == .timedOut)
+        #expect(command.exitCode(for: PeekabooError.timeout("legacy")) == .timedOut)
+        #expect(command.exitCode(for: PeekabooError.appNotFound("Safari")) == .failure)
+
+        do {
+            try await withErrorContext("image_command") {
+                throw PeekabooError.timedOut(operation: "Image capture", timeoutMs: 5000)
+            }
+        } catch {
+            #expect(command.exitCode(for: error) == .timedOut)
+        }
+    }
+}
@@ -0,0 +1,74 @@
+import Commander
+import CoreGraphics
+import Foundation
+import PeekabooCore
+import Testing
+@testable import PeekabooCLI
+
+@Suite(.tags(.imageCapture, .unit))
+@MainActor
+struct WindowLayoutCacheTests {
+    @Test(.tags(.fast))
+    func `Layouts round-trip through the per-PID cache file`() throws {
+        let directory = FileManager.default.temporaryDirectory.appendingPathComponent("peekaboo-layouts-\(UUID())")
+        defer { try? FileManager.default.removeItem(at: directory) }
+
+        let layout = WindowLayout(
+            pid: 4242,
+            appName: "Safari",
+            windows: [Self.window(id: 7, title: "Docs", bounds: CGRect(x: 10.4, y: 20, width: 800, height: 600))],
+            savedAt: Date(timeIntervalSince1970: 1_700_000_000)
+        )
+        let url = try WindowLayoutCache.save(layout, in: directory)
+        #expect(url.lastPathComponent == "4242.json")
+        #expect(try WindowLayoutCache.load(pid: 4242, in: directory) == layout)
+        #expect(layout.windows[0].bounds == WindowBounds(x: 10, y: 20, width: 800, height: 600))
+
+        #expect(throws: PeekabooError.self) {
+            try WindowLayoutCache.load(pid: 1, in: directory)
+        }
+    }
+
+    @Test(.tags(.fast))
+    func `Cached windows match by ID, then by title, once each`() {
+        let cached = WindowLayout(pid: 1, appName: nil, windows: [
+            Self.window(id: 1, title: "Main", bounds: CGRect(x: 0, y: 0, width: 100, height: 100)),
+            Self.window(id: 2, title: "Inspector", bounds: CGRect(x: 200, y: 0, width: 50, height: 80)),
+            Self.window(id: 3, title: "Gone", bounds: CGRect(x: 0, y: 0, width: 10, height: 10)),
+        ])
+        let live = [
+            Self.window(id: 9, title: "Inspector", bounds: .zero),
+            Self.window(id: 1, title: "Renamed", bounds: .zero),
+        ]
+
+        let restorations = WindowLayoutCache.restorations(for: cached, in: live)
+        #expect(restorations.map { $0.window.windowID } == [1, 9])
+        #expect(restorations.map { $0.bounds } == [
+            CGRect(x: 0, y: 0, width: 100, height: 100),
+            CGRect(x: 200, y: 0, width: 50, height: 80),
+        ])
+    }
+
+    @Test(.tags(.fast))
+    func `Restoring needs an app window capture and listing can opt in to caching`() throws {
+        try ImageCommand.parse(["--app", "Safari", "--restore-layout"]).validateRestoreLayoutOptions()
+        try ImageCommand.parse(["--pid", "123", "--mode", "multi", "--restore-layout"]).validateRestoreLayoutOptions()
+
+        for arguments in [
+            ["--mode", "screen", "--restore-layout"],
+            ["--app", "menubar", "--restore-layout"],
+            ["--app", "Safari", "--window-id", "42", "--restore-layout"],
+        ] {
+            let command = try ImageCommand.parse(arguments)
+            #expect(throws: ValidationError.self) {
+                try command.validateRestoreLayoutOptions()
+            }
+        }
+
+        #expect(try ListCommand.WindowsSubcommand.parse(["--app", "Safari", "--cache-layout"]).cacheLayout)
+    }
+
+    private static func window(id: Int, title: String, bounds: CGRect) -> ServiceWindowInfo {
+        ServiceWindowInfo(windowID: id, title: title, bounds: bounds)
+    }
+}
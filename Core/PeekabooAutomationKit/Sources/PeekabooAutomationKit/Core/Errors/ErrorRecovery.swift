@@ -38,7 +38,32 @@ public struct RetryPolicy: Sendable {
     public static let standard = RetryPolicy()
     public static let aggressive = RetryPolicy(maxAttempts: 5, initialDelay: 0.05)
     public static let conservative = RetryPolicy(maxAttempts: 2, initialDelay: 0.5)
-    public static let noRetry = RetryPolicy(maxAttempts: 1)
+    public static let noRetry = RetryPolicy.none()
+
+    /// Runs the operation once and surfaces its first failure.
+    public static func none() -> RetryPolicy {
+        RetryPolicy(maxAttempts: 1, initialDelay: 0, delayMultiplier: 1, maxDelay: 0)
+    }
+
+    /// Doubles the wait after each failed attempt, starting at `initialDelay`.
+    public static func exponential(maxAttempts: Int, initialDelay: TimeInterval) -> RetryPolicy {
+        RetryPolicy(maxAttempts: maxAttempts, initialDelay: initialDelay)
+    }
+
+    /// Retries straight away, for failures that are races rather than load.
+    public static func immediate(maxAttempts: Int) -> RetryPolicy {
+        RetryPolicy(maxAttempts: maxAttempts, initialDelay: 0, delayMultiplier: 1, maxDelay: 0)
+    }
+
+    /// Wait before the attempt that follows `attempt` (1-based), capped at `maxDelay`.
+    public func delay(afterAttempt attempt: Int) -> TimeInterval {
+        min(self.initialDelay * pow(self.delayMultiplier, Double(max(attempt - 1, 0))), self.maxDelay)
+    }
+
+    /// Whether a failure on `attempt` (1-based) should be retried under this policy.
+    public func shouldRetry(_ error: any Error, afterAttempt attempt: Int) -> Bool {
+        attempt < self.maxAttempts && self.retryableErrors.contains(ErrorStandardizer.standardize(error).code)
+    }
 }
 
 // MARK: - Retry Handler
@@ -50,34 +75,28 @@ public enum RetryHandler {
         policy: RetryPolicy = .standard,
         operation: @Sendable () async throws -> T) async throws -> T
     {
-        // Execute an operation with retry logic
-        var lastError: (any Error)?
-        var delay = policy.initialDelay
+        try await self.withCustomRetry(
+            maxAttempts: policy.maxAttempts,
+            shouldRetry: { policy.shouldRetry($0, afterAttempt: $1) },
+            delayForAttempt: { policy.delay(afterAttempt: $0) },
+            operation: operation)
+    }
 
-        for attempt in 1...policy.maxAttempts {
+    /// Synchronous counterpart of `withRetry`. Blocks the calling thread between attempts; keep it off the main actor.
+    public static func withRetryBlocking<T>(
+        policy: RetryPolicy = .standard,
+        operation: () throws -> T) throws -> T
+    {
+        var attempt = 1
+        while true {
             do {
-                return try await operation()
+                return try operation()
             } catch {
-                lastError = error
-
-                // Check if error is retryable
-                let standardized = ErrorStandardizer.standardize(error)
-                guard policy.retryableErrors.contains(standardized.code),
-                      attempt < policy.maxAttempts
-                else {
-                    throw error
-                }
-
-                // Wait before retry
-                try await Task.sleep(nanoseconds: UInt64(delay * 1_000_000_000))
-
-                // Increase delay for next attempt
-                delay = min(delay * policy.delayMultiplier, policy.maxDelay)
+                guard policy.shouldRetry(error, afterAttempt: attempt) else { throw error }
+                Thread.sleep(forTimeInterval: policy.delay(afterAttempt: attempt))
+                attempt += 1
             }
         }
-
-        throw lastError ?? PeekabooError
-            .operationError(message: "Operation failed after \(policy.maxAttempts) attempts")
     }
 
     /// Execute an operation with custom retry logic
@@ -77,7 +77,7 @@ public struct WindowInfo: Codable, Sendable {
 ///
 /// Represents the rectangular bounds of a window on screen,
 /// including its origin point (x, y) and size (width, height).
-public struct WindowBounds: Codable, Sendable {
+public struct WindowBounds: Codable, Sendable, Equatable {
     public let x: Int
     public let y: Int
     public let width: Int
@@ -1,3 +1,4 @@
+import CoreGraphics
 import Foundation
 
 // MARK: - Image capture primitives (shared with screenshot paths)
@@ -9,6 +10,37 @@ public struct SavedFile: Codable, Sendable {
     public let window_id: UInt32?
     public let window_index: Int?
     public let mime_type: String
+    /// Area actually captured when `--window-padding` grew the window bounds; nil for unpadded captures.
+    public let padded_bounds: WindowBounds?
+    /// True when `--dedup` matched an earlier capture; `path` then points at that file and the new one was removed.
+    public let deduplicated: Bool?
+    /// Whether `--window-focus-check` saw the target focused before capture; nil when the check was not requested.
+    public let focus_confirmed: Bool?
+    /// True once `--verify-capture` re-read the saved file and found it complete; nil when not requested.
+    public let verified: Bool?
+    /// True when the captured window was not on screen (minimized, hidden, or on another Space), which can leave
+    /// the image blank or stale; nil for on-screen windows and non-window captures.
+    public let window_was_offscreen: Bool?
+    /// Color space the file was converted to and tagged with for `--color-space`; nil keeps the display's profile.
+    public let color_space: String?
+    /// Pixel rect of this file within the full capture for `--changed-region`; zero-sized when nothing changed.
+    public let changed_region: ChangedRegion?
+    /// Rect kept by `--trim-whitespace`, in pixels of the untrimmed capture; nil when nothing was trimmed.
+    public let original_bounds: WindowBounds?
+    /// Pointer location in global display points when `--include-cursor-position` sampled it after capture.
+    public let cursor_at_capture: CursorPosition?
+    /// Rect kept by `--smart-crop`, in pixels of the full capture like `original_bounds`; nil when not requested.
+    public let smart_crop_region: WindowBounds?
+    /// True when `--on-collision rename` wrote to a new name because the intended path existed; nil otherwise.
+    public let renamed: Bool?
+    /// The existing file `path` would have replaced, set together with `renamed`.
+    public let collision_with: String?
+    /// Lowercase hex digest of the final file for `--compute-checksum`; nil when not requested.
+    public let checksum: String?
+    /// Algorithm behind `checksum` (`sha256`, `sha512`, or `md5`).
+    public let checksum_algorithm: String?
+    /// Encoder profile the file was re-encoded with for `--codec-profile`; nil keeps the default settings.
+    public let codec_profile: String?
 
     public init(
         path: String,
@@ -16,7 +48,22 @@ public struct SavedFile: Codable, Sendable {
         window_title: String? = nil,
         window_id: UInt32? = nil,
         window_index: Int? = nil,
-        mime_type: String)
+        mime_type: String,
+        padded_bounds: WindowBounds? = nil,
+        deduplicated: Bool? = nil,
+        focus_confirmed: Bool? = nil,
+        verified: Bool? = nil,
+        window_was_offscreen: Bool? = nil,
+        color_space: String? = nil,
+        changed_region: ChangedRegion? = nil,
+        original_bounds: WindowBounds? = nil,
+        cursor_at_capture: CursorPosition? = nil,
+        smart_crop_region: WindowBounds? = nil,
+        renamed: Bool? = nil,
+        collision_with: String? = nil,
+        checksum: String? = nil,
+        checksum_algorithm: String? = nil,
+        codec_profile: String? = nil)
     {
         self.path = path
         self.item_label = item_label
@@ -24,6 +71,51 @@ public struct SavedFile: Codable, Sendable {
         self.window_id = window_id
         self.window_index = window_index
         self.mime_type = mime_type
+        self.padded_bounds = padded_bounds
+        self.deduplicated = deduplicated
+        self.focus_confirmed = focus_confirmed
+        self.verified = verified
+        self.window_was_offscreen = window_was_offscreen
+        self.color_space = color_space
+        self.changed_region = changed_region
+        self.original_bounds = original_bounds
+        self.cursor_at_capture = cursor_at_capture
+        self.smart_crop_region = smart_crop_region
+        self.renamed = renamed
+        self.collision_with = collision_with
+        self.checksum = checksum
+        self.checksum_algorithm = checksum_algorithm
+        self.codec_profile = codec_profile
+    }
+}
+
+/// Pointer location in global display points, top-left origin, like `--region` and window bounds.
+public struct CursorPosition: Codable, Sendable, Equatable {
+    public let x: Int
+    public let y: Int
+
+    public init(x: Int, y: Int) {
+        self.x = x
+        self.y = y
+    }
+
+    public init(_ point: CGPoint) {
+        self.init(x: Int(point.x.rounded()), y: Int(point.y.rounded()))
+    }
+}
+
+/// Pixel rect from the top-left of a capture, e.g. the part `--changed-region` kept.
+public struct ChangedRegion: Codable, Sendable, Equatable {
+    public let x: Int
+    public let y: Int
+    public let w: Int
+    public let h: Int
+
+    public init(x: Int, y: Int, w: Int, h: Int) {
+        self.x = x
+        self.y = y
+        self.w = w
+        self.h = h
     }
 }
 
@@ -125,6 +125,7 @@ public struct CaptureWarning: Codable, Sendable, Equatable {
         case diffDowngraded
         case autoclean
         case transientCaptureFailure
+        case interrupted
     }
 
     public let code: Code
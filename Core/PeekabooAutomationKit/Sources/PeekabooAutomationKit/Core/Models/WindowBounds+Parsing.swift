@@ -0,0 +1,88 @@
+import CoreGraphics
+import Foundation
+
+/// Thrown when a string is neither `x,y,width,height` nor `WIDTHxHEIGHT+X+Y`.
+public struct WindowBoundsParseError: Error, Equatable, LocalizedError {
+    public let input: String
+
+    public init(input: String) {
+        self.input = input
+    }
+
+    public var errorDescription: String? {
+        "Invalid bounds '\(self.input)': expected x,y,width,height or WIDTHxHEIGHT+X+Y with a non-negative size"
+    }
+}
+
+extension WindowBounds: LosslessStringConvertible {
+    /// `x,y,width,height`, the format `init(parsing:)` reads first.
+    public var description: String {
+        "\(self.x),\(self.y),\(self.width),\(self.height)"
+    }
+
+    /// ImageMagick geometry, e.g. `800x600+100-20`.
+    public var geometry: String {
+        "\(self.width)x\(self.height)\(Self.signed(self.x))\(Self.signed(self.y))"
+    }
+
+    public var cgRect: CGRect {
+        CGRect(x: self.x, y: self.y, width: self.width, height: self.height)
+    }
+
+    public init?(_ description: String) {
+        try? self.init(parsing: description)
+    }
+
+    /// Reads `x,y,width,height` (spaces around commas allowed) or ImageMagick `WIDTHxHEIGHT+X+Y`, where each
+    /// offset carries its own sign and `WIDTHxHEIGHT` alone sits at the origin. Sizes must not be negative.
+    public init(parsing string: String) throws {
+        let trimmed = string.trimmingCharacters(in: .whitespacesAndNewlines)
+        let values = trimmed.contains(",") ? Self.commaSeparated(trimmed) : Self.geometryValues(trimmed)
+        guard let values, values.width >= 0, values.height >= 0 else {
+            throw WindowBoundsParseError(input: string)
+        }
+        self.init(x: values.x, y: values.y, width: values.width, height: values.height)
+    }
+
+    private typealias Values = (x: Int, y: Int, width: Int, height: Int)
+
+    private static func commaSeparated(_ string: String) -> Values? {
+        let parts = string
+            .split(separator: ",", omittingEmptySubsequences: false)
+            .map { Int($0.trimmingCharacters(in: .whitespaces)) }
+        guard parts.count == 4, let x = parts[0], let y = parts[1], let width = parts[2], let height = parts[3] else {
+            return nil
+        }
+        return (x, y, width, height)
+    }
+
+    private static func geometryValues(_ string: String) -> Values? {
+        let size = string.prefix { $0 != "+" && $0 != "-" }
+        let dimensions = size.lowercased().split(separator: "x", omittingEmptySubsequences: false)
+        guard dimensions.count == 2,
+              let width = Self.unsigned(dimensions[0]),
+              let height = Self.unsigned(dimensions[1])
+        else { return nil }
+
+        // Each offset is a sign followed by digits; split before the second sign. No offsets means the origin.
+        let offsets = string.dropFirst(size.count)
+        if offsets.isEmpty {
+            return (0, 0, width, height)
+        }
+        guard let secondSign = offsets.dropFirst().firstIndex(where: { $0 == "+" || $0 == "-" }),
+              let x = Int(offsets[offsets.startIndex..<secondSign]),
+              let y = Int(offsets[secondSign...])
+        else { return nil }
+        return (x, y, width, height)
+    }
+
+    /// `Int("+5")` accepts a sign; sizes must be plain digits.
+    private static func unsigned(_ digits: Substring) -> Int? {
+        guard !digits.isEmpty, digits.allSatisfy({ $0.isASCII && $0.isNumber }) else { return nil }
+        return Int(digits)
+    }
+
+    private static func signed(_ value: Int) -> String {
+        value < 0 ? String(value) : "+\(value)"
+    }
+}
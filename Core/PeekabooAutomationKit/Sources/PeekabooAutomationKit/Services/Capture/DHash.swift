@@ -0,0 +1,47 @@
+import CoreGraphics
+import Foundation
+
+/// 64-bit difference hash of an image: each bit records whether a pixel of the 9×8 grayscale thumbnail is
+/// brighter than its right-hand neighbour, so re-encoded or slightly rescaled captures hash alike.
+///
+/// Encodes as a 16-digit lowercase hex string.
+public struct DHash: Hashable, Sendable, Codable, CustomStringConvertible {
+    public let rawValue: UInt64
+
+    public init(rawValue: UInt64) {
+        self.rawValue = rawValue
+    }
+
+    public init(image: CGImage) {
+        self.rawValue = SmartCaptureImageProcessor.perceptualHash(image)
+    }
+
+    public init?(hex: String) {
+        guard hex.count == 16, let value = UInt64(hex, radix: 16) else { return nil }
+        self.rawValue = value
+    }
+
+    /// Number of differing bits, `0...64`.
+    public func distance(to other: DHash) -> Int {
+        SmartCaptureImageProcessor.hammingDistance(self.rawValue, other.rawValue)
+    }
+
+    public var description: String {
+        let hex = String(self.rawValue, radix: 16)
+        return String(repeating: "0", count: 16 - hex.count) + hex
+    }
+
+    public init(from decoder: any Decoder) throws {
+        let container = try decoder.singleValueContainer()
+        let hex = try container.decode(String.self)
+        guard let hash = DHash(hex: hex) else {
+            throw DecodingError.dataCorruptedError(in: container, debugDescription: "Invalid dHash '\(hex)'")
+        }
+        self = hash
+    }
+
+    public func encode(to encoder: any Encoder) throws {
+        var container = encoder.singleValueContainer()
+        try container.encode(self.description)
+    }
+}
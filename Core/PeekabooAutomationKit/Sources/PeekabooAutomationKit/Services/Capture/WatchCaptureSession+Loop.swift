@@ -57,7 +57,7 @@ extension WatchCaptureSession {
             let now = Date()
             let elapsedNs = Self.elapsedNanoseconds(since: timing.start, now: now)
             if self.shouldEndSession(elapsedNs: elapsedNs, durationNs: timing.durationNs) { break }
-            if self.hitFrameCap() || self.hitSizeCap() { break }
+            if self.hitFrameCap() || self.hitSizeCap() || self.hitStopRequest() { break }
 
             let frameStart = Date()
             let cadence = state.activeMode ? timing.cadenceActiveNs : timing.cadenceIdleNs
@@ -181,6 +181,16 @@ extension WatchCaptureSession {
         return true
     }
 
+    func hitStopRequest() -> Bool {
+        guard let reason = self.stopReason else { return false }
+        self.warnings.append(
+            WatchWarning(
+                code: .interrupted,
+                message: "Stopped early after \(reason)",
+                details: ["reason": reason]))
+        return true
+    }
+
     func hitSizeCap() -> Bool {
         guard let maxMb = self.options.maxMegabytes else { return false }
         let currentMb = self.totalBytes / (1024 * 1024)
@@ -91,6 +91,7 @@ public final class WatchCaptureSession {
     var warnings: [CaptureWarning] = []
     var framesDropped: Int = 0
     var totalBytes: Int = 0
+    var stopReason: String?
 
     public init(dependencies: WatchCaptureDependencies, configuration: WatchCaptureConfiguration) {
         let regionValidator = WatchCaptureRegionValidator(screenService: dependencies.screenService)
@@ -121,6 +122,12 @@ public final class WatchCaptureSession {
             regionValidator: regionValidator)
     }
 
+    /// Ends the capture loop before its next frame; `run()` still finalizes video, contact sheet, and metadata.
+    public func requestStop(reason: String) {
+        guard self.stopReason == nil else { return }
+        self.stopReason = reason
+    }
+
     public func run() async throws -> CaptureSessionResult {
         try self.store.prepareOutputRoot()
         if let autocleanWarning = self.store.performAutoclean() {
@@ -76,6 +76,9 @@ public struct ServiceApplicationInfo: Sendable, Codable, Equatable {
     /// Path to the application bundle
     public let bundlePath: String?
 
+    /// Path to the running executable (e.g. `…/Contents/MacOS/Safari`)
+    public let executablePath: String?
+
     /// Whether the application is currently active (frontmost)
     public let isActive: Bool
 
@@ -88,24 +91,84 @@ public struct ServiceApplicationInfo: Sendable, Codable, Equatable {
     /// macOS activation policy, when known.
     public let activationPolicy: ServiceApplicationActivationPolicy?
 
+    /// CPU usage across all cores (100 = one full core), when sampled.
+    public var cpuPercent: Double?
+
+    /// Resident memory in megabytes, when sampled.
+    public var memoryRSSMB: UInt64?
+
+    /// Launch time as an ISO 8601 string, when macOS reports one.
+    public let launchTime: String?
+
+    /// Whole seconds the process had been running when this info was gathered.
+    public let uptimeSeconds: UInt64?
+
+    /// `CFBundleDisplayName` from the bundle's Info.plist, when read.
+    public var displayName: String?
+
+    /// `LSApplicationCategoryType` (e.g. `public.app-category.developer-tools`), when read.
+    public var category: String?
+
+    /// `CFBundleIconName` or `CFBundleIconFile`, when read.
+    public var iconName: String?
+
+    /// `CFBundleShortVersionString`, when read.
+    public var version: String?
+
+    /// Processes descended from this app (helpers, renderers, workers), when requested.
+    public var children: [ServiceProcessNode]?
+
     public init(
         processIdentifier: Int32,
         bundleIdentifier: String?,
         name: String,
         bundlePath: String? = nil,
+        executablePath: String? = nil,
         isActive: Bool = false,
         isHidden: Bool = false,
         windowCount: Int = 0,
-        activationPolicy: ServiceApplicationActivationPolicy? = nil)
+        activationPolicy: ServiceApplicationActivationPolicy? = nil,
+        cpuPercent: Double? = nil,
+        memoryRSSMB: UInt64? = nil,
+        launchTime: String? = nil,
+        uptimeSeconds: UInt64? = nil,
+        displayName: String? = nil,
+        category: String? = nil,
+        iconName: String? = nil,
+        version: String? = nil,
+        children: [ServiceProcessNode]? = nil)
     {
         self.processIdentifier = processIdentifier
         self.bundleIdentifier = bundleIdentifier
         self.name = name
         self.bundlePath = bundlePath
+        self.executablePath = executablePath
         self.isActive = isActive
         self.isHidden = isHidden
         self.windowCount = windowCount
         self.activationPolicy = activationPolicy
+        self.cpuPercent = cpuPercent
+        self.memoryRSSMB = memoryRSSMB
+        self.launchTime = launchTime
+        self.uptimeSeconds = uptimeSeconds
+        self.displayName = displayName
+        self.category = category
+        self.iconName = iconName
+        self.version = version
+        self.children = children
+    }
+}
+
+/// A process in an application's subtree, keyed by parent PID.
+public struct ServiceProcessNode: Sendable, Codable, Equatable {
+    public let pid: Int32
+    public let name: String
+    public let children: [ServiceProcessNode]
+
+    public init(pid: Int32, name: String, children: [ServiceProcessNode] = []) {
+        self.pid = pid
+        self.name = name
+        self.children = children
     }
 }
 
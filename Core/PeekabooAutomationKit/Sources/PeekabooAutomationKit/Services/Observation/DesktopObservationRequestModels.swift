@@ -112,6 +112,8 @@ public struct DesktopDetectionOptions: Sendable, Codable, Equatable {
 public struct DesktopObservationOutputOptions: Sendable, Codable, Equatable {
     public var path: String?
     public var format: ImageFormat
+    /// JPEG compression quality in `0...1`; `nil` keeps the writer's default (0.92). Ignored for PNG.
+    public var jpegQuality: Double?
     public var saveRawScreenshot: Bool
     public var saveAnnotatedScreenshot: Bool
     public var saveSnapshot: Bool
@@ -120,6 +122,7 @@ public struct DesktopObservationOutputOptions: Sendable, Codable, Equatable {
     public init(
         path: String? = nil,
         format: ImageFormat = .png,
+        jpegQuality: Double? = nil,
         saveRawScreenshot: Bool = false,
         saveAnnotatedScreenshot: Bool = false,
         saveSnapshot: Bool = false,
@@ -127,6 +130,7 @@ public struct DesktopObservationOutputOptions: Sendable, Codable, Equatable {
     {
         self.path = path
         self.format = format
+        self.jpegQuality = jpegQuality
         self.saveRawScreenshot = saveRawScreenshot
         self.saveAnnotatedScreenshot = saveAnnotatedScreenshot
         self.saveSnapshot = saveSnapshot
@@ -12,6 +12,8 @@ public protocol ObservationOutputWriting: Sendable {
 
 @MainActor
 public final class ObservationOutputWriter: ObservationOutputWriting {
+    public static let defaultJPEGQuality = 0.92
+
     private let snapshotManager: (any SnapshotManagerProtocol)?
     private let annotationRenderer: ObservationAnnotationRenderer
 
@@ -76,7 +78,8 @@ public final class ObservationOutputWriter: ObservationOutputWriting {
         try FileManager.default.createDirectory(
             at: url.deletingLastPathComponent(),
             withIntermediateDirectories: true)
-        try self.encodedImageData(capture.imageData, format: options.format).write(to: url, options: .atomic)
+        try self.encodedImageData(capture.imageData, format: options.format, jpegQuality: options.jpegQuality)
+            .write(to: url, options: .atomic)
         return url.path
     }
 
@@ -193,7 +196,7 @@ public final class ObservationOutputWriter: ObservationOutputWriting {
             defaultFileName: "peekaboo-observation-\(Self.timestamp()).\(format.fileExtension)")
     }
 
-    private func encodedImageData(_ data: Data, format: ImageFormat) throws -> Data {
+    private func encodedImageData(_ data: Data, format: ImageFormat, jpegQuality: Double?) throws -> Data {
         switch format {
         case .png:
             return data
@@ -201,7 +204,9 @@ public final class ObservationOutputWriter: ObservationOutputWriting {
             guard let image = NSImage(data: data),
                   let tiff = image.tiffRepresentation,
                   let bitmap = NSBitmapImageRep(data: tiff),
-                  let jpeg = bitmap.representation(using: .jpeg, properties: [.compressionFactor: 0.92])
+                  let jpeg = bitmap.representation(
+                      using: .jpeg,
+                      properties: [.compressionFactor: jpegQuality ?? Self.defaultJPEGQuality])
             else {
                 throw OperationError.captureFailed(reason: "Failed to convert screenshot to JPEG")
             }
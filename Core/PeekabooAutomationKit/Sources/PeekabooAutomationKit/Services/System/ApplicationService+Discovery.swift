@@ -218,10 +218,13 @@ extension ApplicationService {
             bundleIdentifier: app.bundleIdentifier,
             name: app.localizedName ?? "Unknown",
             bundlePath: app.bundleURL?.path,
+            executablePath: app.executableURL?.path,
             isActive: app.isActive,
             isHidden: app.isHidden,
             windowCount: self.getWindowCount(for: app),
-            activationPolicy: Self.serviceActivationPolicy(from: app.activationPolicy))
+            activationPolicy: Self.serviceActivationPolicy(from: app.activationPolicy),
+            launchTime: app.launchDate?.ISO8601Format(),
+            uptimeSeconds: app.launchDate.map { UInt64(max(0, Date().timeIntervalSince($0))) })
     }
 
     private static func serviceActivationPolicy(
@@ -0,0 +1,109 @@
+import PeekabooFoundation
+import XCTest
+@testable import PeekabooAutomationKit
+
+final class RetryPolicyTests: XCTestCase {
+    func testNonePolicyMakesASingleAttempt() {
+        let policy = RetryPolicy.none()
+
+        XCTAssertEqual(policy.maxAttempts, 1)
+        XCTAssertEqual(policy.delay(afterAttempt: 1), 0)
+        XCTAssertEqual(Self.blockingAttempts(policy: policy), 1)
+    }
+
+    func testExponentialPolicyDoublesUpToTheCap() {
+        let policy = RetryPolicy.exponential(maxAttempts: 3, initialDelay: 0.1)
+
+        XCTAssertEqual(policy.maxAttempts, 3)
+        XCTAssertEqual(policy.delay(afterAttempt: 1), 0.1, accuracy: 1e-9)
+        XCTAssertEqual(policy.delay(afterAttempt: 2), 0.2, accuracy: 1e-9)
+        XCTAssertEqual(policy.delay(afterAttempt: 10), policy.maxDelay, accuracy: 1e-9)
+        XCTAssertEqual(Self.blockingAttempts(policy: .exponential(maxAttempts: 3, initialDelay: 0.001)), 3)
+    }
+
+    func testImmediatePolicyNeverWaits() {
+        let policy = RetryPolicy.immediate(maxAttempts: 3)
+
+        XCTAssertEqual(policy.maxAttempts, 3)
+        XCTAssertEqual(policy.delay(afterAttempt: 1), 0)
+        XCTAssertEqual(policy.delay(afterAttempt: 2), 0)
+        XCTAssertEqual(Self.blockingAttempts(policy: policy), 3)
+    }
+
+    func testBlockingRetryReturnsOnceTheOperationSucceeds() throws {
+        var attempts = 0
+        let value = try RetryHandler.withRetryBlocking(policy: .immediate(maxAttempts: 3)) {
+            attempts += 1
+            if attempts < 2 { throw PeekabooError.timeout("slow") }
+            return "ok"
+        }
+        XCTAssertEqual(value, "ok")
+        XCTAssertEqual(attempts, 2)
+    }
+
+    func testOnlyRetryableErrorsWithinTheBudgetAreRetried() {
+        let policy = RetryPolicy.immediate(maxAttempts: 3)
+
+        XCTAssertTrue(policy.shouldRetry(PeekabooError.captureFailed("busy"), afterAttempt: 1))
+        XCTAssertFalse(policy.shouldRetry(PeekabooError.captureFailed("busy"), afterAttempt: 3))
+        XCTAssertFalse(policy.shouldRetry(PeekabooError.appNotFound("Safari"), afterAttempt: 1))
+        XCTAssertFalse(RetryPolicy.noRetry.shouldRetry(PeekabooError.captureFailed("busy"), afterAttempt: 1))
+    }
+
+    func testRetryStopsAfterMaxAttempts() async {
+        let counter = AttemptCounter()
+        do {
+            _ = try await RetryHandler.withRetry(policy: .immediate(maxAttempts: 3)) {
+                await counter.increment()
+                throw PeekabooError.captureFailed("busy")
+            }
+            XCTFail("Expected withRetry to throw")
+        } catch {
+            let attempts = await counter.value
+            XCTAssertEqual(attempts, 3)
+        }
+    }
+
+    func testRetryReturnsOnceTheOperationSucceeds() async throws {
+        let counter = AttemptCounter()
+        let value = try await RetryHandler.withRetry(policy: .immediate(maxAttempts: 3)) {
+            await counter.increment()
+            if await counter.value < 2 { throw PeekabooError.timeout("slow") }
+            return "ok"
+        }
+        let attempts = await counter.value
+        XCTAssertEqual(value, "ok")
+        XCTAssertEqual(attempts, 2)
+    }
+
+    func testNonRetryableErrorsFailOnTheFirstAttempt() async {
+        let counter = AttemptCounter()
+        do {
+            _ = try await RetryHandler.withRetry(policy: .exponential(maxAttempts: 3, initialDelay: 0.001)) {
+                await counter.increment()
+                throw PeekabooError.appNotFound("Safari")
+            }
+            XCTFail("Expected withRetry to throw")
+        } catch {
+            let attempts = await counter.value
+            XCTAssertEqual(attempts, 1)
+        }
+    }
+
+    private static func blockingAttempts(policy: RetryPolicy) -> Int {
+        var attempts = 0
+        XCTAssertThrowsError(try RetryHandler.withRetryBlocking(policy: policy) {
+            attempts += 1
+            throw PeekabooError.captureFailed("busy")
+        })
+        return attempts
+    }
+}
+
+private actor AttemptCounter {
+    private(set) var value = 0
+
+    func increment() {
+        self.value += 1
+    }
+}
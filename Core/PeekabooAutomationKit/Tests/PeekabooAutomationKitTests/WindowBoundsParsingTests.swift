@@ -0,0 +1,46 @@
+import XCTest
+@testable import PeekabooAutomationKit
+
+final class WindowBoundsParsingTests: XCTestCase {
+    func testCommaAndGeometryFormatsParseToTheSameBounds() throws {
+        let expected = WindowBounds(x: 100, y: 200, width: 800, height: 600)
+
+        XCTAssertEqual(try WindowBounds(parsing: "100,200,800,600"), expected)
+        XCTAssertEqual(try WindowBounds(parsing: " 100, 200 , 800,600 "), expected)
+        XCTAssertEqual(try WindowBounds(parsing: "800x600+100+200"), expected)
+        XCTAssertEqual(try WindowBounds(parsing: "800X600+100+200"), expected)
+    }
+
+    func testGeometryOffsetsCarryTheirOwnSign() throws {
+        XCTAssertEqual(
+            try WindowBounds(parsing: "640x480-1920+30"),
+            WindowBounds(x: -1920, y: 30, width: 640, height: 480)
+        )
+        XCTAssertEqual(try WindowBounds(parsing: "640x480+0-10"), WindowBounds(x: 0, y: -10, width: 640, height: 480))
+        XCTAssertEqual(try WindowBounds(parsing: "640x480"), WindowBounds(x: 0, y: 0, width: 640, height: 480))
+    }
+
+    func testMalformedInputIsRejected() {
+        let inputs = [
+            "", "1,2,3", "1,2,3,4,5", "a,b,c,d", "1,2,-3,4",
+            "800x", "x600+1+2", "+800x600+1+2", "800x600+1", "800x600++1+2",
+        ]
+        for input in inputs {
+            XCTAssertThrowsError(try WindowBounds(parsing: input), input) { error in
+                XCTAssertEqual(error as? WindowBoundsParseError, WindowBoundsParseError(input: input))
+            }
+            XCTAssertNil(WindowBounds(input), input)
+        }
+    }
+
+    func testDescriptionAndGeometryRoundTrip() throws {
+        for bounds in [
+            WindowBounds(x: 100, y: 200, width: 800, height: 600),
+            WindowBounds(x: -1920, y: -5, width: 1, height: 0),
+        ] {
+            XCTAssertEqual(WindowBounds(bounds.description), bounds)
+            XCTAssertEqual(try WindowBounds(parsing: bounds.geometry), bounds)
+        }
+        XCTAssertEqual(WindowBounds(x: -1920, y: 30, width: 640, height: 480).geometry, "640x480-1920+30")
+    }
+}
@@ -100,10 +100,35 @@ public enum CLIFormatter {
                 result += " [HIDDEN]"
             }
             result += " - Windows: \(app.windowCount)"
+            if let cpuPercent = app.cpuPercent {
+                result += " - CPU: \(String(format: "%.1f", cpuPercent))%"
+            }
+            if let memoryRSSMB = app.memoryRSSMB {
+                result += " - RSS: \(memoryRSSMB) MB"
+            }
+            if let uptimeSeconds = app.uptimeSeconds {
+                result += " - running for \(self.formatUptime(uptimeSeconds))"
+            }
+            if let children = app.children {
+                result += self.formatProcessTree(children, depth: 1)
+            }
         }
         return result
     }
 
+    /// One indented line per process, nested under its parent.
+    public static func formatProcessTree(_ nodes: [ServiceProcessNode], depth: Int) -> String {
+        nodes.map { node in
+            "\n" + String(repeating: "   ", count: depth) + "└ \(node.name) - PID: \(node.pid)" +
+                self.formatProcessTree(node.children, depth: depth + 1)
+        }.joined()
+    }
+
+    /// Renders an uptime as `Xh Ym`; hours keep counting past a day so long-running apps sort visually.
+    public static func formatUptime(_ seconds: UInt64) -> String {
+        "\(seconds / 3600)h \(seconds % 3600 / 60)m"
+    }
+
     private static func formatWindowList(_ data: ServiceWindowListData) -> String {
         guard !data.windows.isEmpty else {
             let appName = data.targetApplication?.name ?? "the requested application"
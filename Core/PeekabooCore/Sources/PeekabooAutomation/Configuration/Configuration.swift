@@ -72,17 +72,21 @@ public struct Configuration: Codable {
         public var imageFormat: String?
         public var captureMode: String?
         public var captureFocus: String?
+        /// Extra regexes (matched against app name or bundle ID) hidden by `list apps --exclude-system`.
+        public var systemAppPatterns: [String]?
 
         public init(
             savePath: String? = nil,
             imageFormat: String? = nil,
             captureMode: String? = nil,
-            captureFocus: String? = nil)
+            captureFocus: String? = nil,
+            systemAppPatterns: [String]? = nil)
         {
             self.savePath = savePath
             self.imageFormat = imageFormat
             self.captureMode = captureMode
             self.captureFocus = captureFocus
+            self.systemAppPatterns = systemAppPatterns
         }
     }
 
@@ -193,6 +193,53 @@ struct WatchCaptureSessionTests {
         #expect(result.warnings.contains { $0.code == .sizeCap })
     }
 
+    @Test
+    @MainActor
+    func `Stop request ends the session early and still writes metadata`() async throws {
+        let png = Self.makePNG(size: CGSize(width: 20, height: 20))
+        let capture = StubScreenCaptureService(result: png, size: CGSize(width: 20, height: 20))
+        let scope = WatchScope(
+            kind: .frontmost,
+            screenIndex: nil,
+            displayUUID: nil,
+            windowId: nil,
+            applicationIdentifier: nil,
+            windowIndex: nil,
+            region: nil)
+
+        let options = WatchCaptureOptions(
+            duration: 60,
+            idleFps: 5,
+            activeFps: 5,
+            changeThresholdPercent: 0,
+            heartbeatSeconds: 0,
+            quietMsToIdle: 0,
+            maxFrames: 100,
+            maxMegabytes: nil,
+            highlightChanges: false,
+            captureFocus: .auto,
+            resolutionCap: nil,
+            diffStrategy: .fast,
+            diffBudgetMs: nil)
+
+        let output = URL(fileURLWithPath: NSTemporaryDirectory(), isDirectory: true)
+            .appendingPathComponent("watch-stop-\(UUID().uuidString)", isDirectory: true)
+
+        let session = WatchCaptureSession(
+            dependencies: WatchCaptureDependencies(screenCapture: capture, screenService: StubScreenService()),
+            configuration: WatchCaptureConfiguration(
+                scope: scope,
+                options: options,
+                outputRoot: output,
+                autoclean: WatchAutocleanConfig(minutes: 1, managed: false)))
+        session.requestStop(reason: "SIGINT")
+
+        let result = try await session.run()
+        #expect(result.warnings.contains { $0.code == .interrupted && $0.details?["reason"] == "SIGINT" })
+        #expect(result.frames.count == 1)
+        #expect(FileManager.default.fileExists(atPath: output.appendingPathComponent("metadata.json").path))
+    }
+
     @Test
     @MainActor
     func `Frame provider prefers stable window id when present`() async throws {
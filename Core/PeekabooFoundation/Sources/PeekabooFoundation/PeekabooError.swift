@@ -35,6 +35,8 @@ public nonisolated enum PeekabooError: LocalizedError, StandardizedError, Peekab
     case fileIOError(String)
     case commandFailed(String)
     case timeout(String)
+    /// A wait with a known limit ran out; prefer this over `timeout(_:)` so callers get the operation and limit.
+    case timedOut(operation: String, timeoutMs: Int)
 
     // Input errors
     case invalidInput(String)
@@ -62,6 +64,9 @@ public nonisolated enum PeekabooError: LocalizedError, StandardizedError, Peekab
     /// Generic errors - removed context since it can't be Sendable
     case operationError(message: String)
 
+    /// Several independent steps of one batch (e.g. one capture per window) failed; see `combining(_:)`.
+    case multipleErrors([PeekabooError])
+
     public var errorDescription: String? {
         switch self {
         case .permissionDeniedScreenRecording:
@@ -111,6 +116,8 @@ public nonisolated enum PeekabooError: LocalizedError, StandardizedError, Peekab
             return "Command failed: \(reason)"
         case let .timeout(reason):
             return "Operation timed out: \(reason)"
+        case let .timedOut(operation, timeoutMs):
+            return "\(operation) timed out after \(timeoutMs)ms"
         case let .invalidInput(message):
             return "Invalid input: \(message)"
         case let .encodingError(message):
@@ -142,6 +149,9 @@ public nonisolated enum PeekabooError: LocalizedError, StandardizedError, Peekab
             return "Not implemented: \(message)"
         case let .operationError(message):
             return message
+        case let .multipleErrors(errors):
+            let first = errors.first?.errorDescription ?? "Unknown error"
+            return "\(errors.count) operations failed; first: \(first)"
         }
     }
 
@@ -190,7 +200,7 @@ public nonisolated enum PeekabooError: LocalizedError, StandardizedError, Peekab
             .fileIOError
         case .commandFailed:
             .interactionFailed
-        case .timeout:
+        case .timeout, .timedOut:
             .timeout
         case .invalidInput:
             .invalidInput
@@ -220,6 +230,8 @@ public nonisolated enum PeekabooError: LocalizedError, StandardizedError, Peekab
             .unknownError
         case .notImplemented:
             .unknownError
+        case let .multipleErrors(errors):
+            errors.first?.code ?? .unknownError
         }
     }
 
@@ -257,6 +269,8 @@ public nonisolated enum PeekabooError: LocalizedError, StandardizedError, Peekab
             return ["reason": reason]
         case let .timeout(reason):
             return ["reason": reason]
+        case let .timedOut(operation, timeoutMs):
+            return ["operation": operation, "timeout_ms": "\(timeoutMs)"]
         case let .invalidInput(message):
             return ["message": message]
         case let .encodingError(message):
@@ -291,6 +305,8 @@ public nonisolated enum PeekabooError: LocalizedError, StandardizedError, Peekab
             return ["message": message]
         case let .notImplemented(message):
             return ["message": message]
+        case let .multipleErrors(errors):
+            return ["count": "\(errors.count)", "first_message": errors.first?.userMessage ?? "Unknown error"]
         default:
             return [:]
         }
@@ -301,6 +317,11 @@ public nonisolated enum PeekabooError: LocalizedError, StandardizedError, Peekab
         self.code.rawValue
     }
 
+    /// The case name (`appNotFound`, `windowNotFound`, …), so clients can match on it instead of `errorDescription`.
+    public var variant: String {
+        Mirror(reflecting: self).children.first?.label ?? String(describing: self)
+    }
+
     // MARK: - PeekabooErrorProtocol Conformance
 
     public var category: ErrorCategory {
@@ -313,7 +334,7 @@ public nonisolated enum PeekabooError: LocalizedError, StandardizedError, Peekab
             .automation
         case .sessionNotFound, .snapshotNotFound, .snapshotStale:
             .session
-        case .captureTimeout, .captureFailed, .timeout:
+        case .captureTimeout, .captureFailed, .timeout, .timedOut:
             .automation
         case .fileIOError:
             .io
@@ -335,6 +356,8 @@ public nonisolated enum PeekabooError: LocalizedError, StandardizedError, Peekab
             .permissions
         case .notImplemented:
             .unknown
+        case let .multipleErrors(errors):
+            errors.first?.category ?? .unknown
         }
     }
 
@@ -354,6 +377,8 @@ public nonisolated enum PeekabooError: LocalizedError, StandardizedError, Peekab
             "Check your API key and network connection"
         case .serviceUnavailable:
             "Ensure all required services are running"
+        case .timedOut:
+            "Retry, or raise the matching timeout flag (see 'peekaboo help')"
         default:
             nil
         }
@@ -375,7 +400,7 @@ extension PeekabooError {
 
     /// Create a timeout error
     public static func timeout(operation: String, duration: TimeInterval) -> PeekabooError {
-        .timeout("Operation '\(operation)' timed out after \(Int(duration)) seconds")
+        .timedOut(operation: operation, timeoutMs: Int((duration * 1000).rounded()))
     }
 
     /// Create an ambiguous app identifier error
@@ -388,6 +413,25 @@ extension PeekabooError {
         .invalidInput("Invalid \(field): \(reason)")
     }
 
+    /// Folds a batch's failures into one error: `nil` when nothing failed, the error itself when only one did,
+    /// and `multipleErrors` otherwise (nested batches are flattened).
+    public static func combining(_ errors: some Sequence<PeekabooError>) -> PeekabooError? {
+        let flattened = errors.flatMap { error -> [PeekabooError] in
+            if case let .multipleErrors(nested) = error {
+                return nested
+            }
+            return [error]
+        }
+        switch flattened.count {
+        case 0:
+            return nil
+        case 1:
+            return flattened[0]
+        default:
+            return .multipleErrors(flattened)
+        }
+    }
+
     /// Create an invalid coordinates error
     public static func invalidCoordinates(x: Double, y: Double) -> PeekabooError {
         .invalidCoordinates